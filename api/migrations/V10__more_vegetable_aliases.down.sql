@@ -0,0 +1,5 @@
+-- ---------------------------------------------------------------------------
+-- V10 rollback: Remove the additional vegetable id aliases
+-- ---------------------------------------------------------------------------
+
+DELETE FROM vegetable_aliases WHERE alias IN ('courgette', 'capsicum');
@@ -0,0 +1,8 @@
+-- ---------------------------------------------------------------------------
+-- V10: More vegetable id aliases
+-- Common alternative names gardeners use for catalogue vegetables.
+-- ---------------------------------------------------------------------------
+
+INSERT INTO vegetable_aliases (alias, vegetable_id) VALUES
+('courgette', 'zucchini'),
+('capsicum',  'pepper');
@@ -0,0 +1,5 @@
+-- ---------------------------------------------------------------------------
+-- V11 rollback: Drop minimum daily sun hours
+-- ---------------------------------------------------------------------------
+
+ALTER TABLE varieties DROP COLUMN min_sun_hours;
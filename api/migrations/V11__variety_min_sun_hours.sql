@@ -0,0 +1,22 @@
+-- ---------------------------------------------------------------------------
+-- V11: Minimum daily sun hours per variety
+-- A numeric companion to the coarse sun_requirement enum, used to filter
+-- candidates for partially shaded plots:
+--   8 h  heat-loving fruiting crops (tomato, peppers, cucurbits, maïs, basil)
+--   6 h  other full-sun crops
+--   4 h  crops that also tolerate partial shade
+--   3 h  shade-tolerant leaves (lettuce, spinach, mint)
+-- ---------------------------------------------------------------------------
+
+ALTER TABLE varieties ADD COLUMN min_sun_hours INTEGER NOT NULL DEFAULT 6;
+
+UPDATE varieties SET min_sun_hours = 8
+ WHERE id IN ('tomato', 'pepper', 'red-pepper', 'eggplant', 'maïs', 'basil',
+              'zucchini', 'cucumber', 'pumpkin');
+
+UPDATE varieties SET min_sun_hours = 4
+ WHERE id IN ('beet', 'carrot', 'celery', 'chive', 'parsley', 'pea', 'radish',
+              'strawberry', 'turnip');
+
+UPDATE varieties SET min_sun_hours = 3
+ WHERE id IN ('lettuce', 'spinach', 'mint');
@@ -0,0 +1,5 @@
+-- ---------------------------------------------------------------------------
+-- V12 rollback: Drop typical yield per plant
+-- ---------------------------------------------------------------------------
+
+ALTER TABLE varieties DROP COLUMN yield_per_plant_g;
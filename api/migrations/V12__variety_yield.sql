@@ -0,0 +1,27 @@
+-- ---------------------------------------------------------------------------
+-- V12: Typical yield per plant
+-- Grams harvested from one plant over its season, rounded to garden-scale
+-- figures. Combined with plants_per_cell it gives the expected harvest of a
+-- cell, used by the planner's `Yield` objective.
+-- ---------------------------------------------------------------------------
+
+ALTER TABLE varieties ADD COLUMN yield_per_plant_g INTEGER NOT NULL DEFAULT 0;
+
+UPDATE varieties SET yield_per_plant_g = 5000 WHERE id IN ('pumpkin', 'zucchini');
+UPDATE varieties SET yield_per_plant_g = 4000 WHERE id IN ('tomato');
+UPDATE varieties SET yield_per_plant_g = 3000 WHERE id IN ('cucumber');
+UPDATE varieties SET yield_per_plant_g = 2000 WHERE id IN ('eggplant');
+UPDATE varieties SET yield_per_plant_g = 1500 WHERE id IN ('artichoke', 'cabbage');
+UPDATE varieties SET yield_per_plant_g = 1000 WHERE id IN ('pepper', 'potato', 'red-pepper');
+UPDATE varieties SET yield_per_plant_g = 800  WHERE id IN ('cauliflower');
+UPDATE varieties SET yield_per_plant_g = 500  WHERE id IN ('celery');
+UPDATE varieties SET yield_per_plant_g = 400  WHERE id IN ('broccoli');
+UPDATE varieties SET yield_per_plant_g = 300  WHERE id IN ('fennel', 'lettuce', 'maïs', 'strawberry');
+UPDATE varieties SET yield_per_plant_g = 250  WHERE id IN ('asparagus');
+UPDATE varieties SET yield_per_plant_g = 200  WHERE id IN ('leek', 'mint', 'rosemary', 'turnip');
+UPDATE varieties SET yield_per_plant_g = 150  WHERE id IN ('basil', 'beet', 'green-bean', 'parsley');
+UPDATE varieties SET yield_per_plant_g = 100  WHERE id IN ('onion', 'spinach');
+UPDATE varieties SET yield_per_plant_g = 80   WHERE id IN ('carrot');
+UPDATE varieties SET yield_per_plant_g = 50   WHERE id IN ('garlic', 'pea', 'thyme');
+UPDATE varieties SET yield_per_plant_g = 30   WHERE id IN ('chive');
+UPDATE varieties SET yield_per_plant_g = 25   WHERE id IN ('radish');
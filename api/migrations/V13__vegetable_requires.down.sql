@@ -0,0 +1,5 @@
+-- ---------------------------------------------------------------------------
+-- V13 rollback: Drop hard companion requirements
+-- ---------------------------------------------------------------------------
+
+ALTER TABLE vegetables DROP COLUMN requires;
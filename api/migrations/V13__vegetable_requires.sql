@@ -0,0 +1,7 @@
+-- ---------------------------------------------------------------------------
+-- V13: Hard companion requirements
+-- A vegetable listing ids in `requires` is only planted next to one of them
+-- (e.g. a climbing bean and its support). Empty for the whole catalogue for now.
+-- ---------------------------------------------------------------------------
+
+ALTER TABLE vegetables ADD COLUMN requires TEXT[] NOT NULL DEFAULT '{}';
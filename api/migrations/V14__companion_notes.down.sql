@@ -0,0 +1,5 @@
+-- ---------------------------------------------------------------------------
+-- V14 rollback: Drop companion notes
+-- ---------------------------------------------------------------------------
+
+DROP TABLE IF EXISTS companion_notes;
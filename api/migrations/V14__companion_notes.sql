@@ -0,0 +1,32 @@
+-- ---------------------------------------------------------------------------
+-- V14: Companion notes
+-- Short explanations of why a documented pair of vegetables helps (or harms)
+-- each other, shown by the companions endpoint. A pair is stored once, in
+-- either order, with one row per locale; 'en' is the fallback and must exist.
+-- ---------------------------------------------------------------------------
+
+CREATE TABLE companion_notes (
+    vegetable_id TEXT NOT NULL REFERENCES vegetables(id) ON DELETE CASCADE,
+    companion_id TEXT NOT NULL REFERENCES vegetables(id) ON DELETE CASCADE,
+    locale       TEXT NOT NULL,
+    note         TEXT NOT NULL,
+    PRIMARY KEY (vegetable_id, companion_id, locale)
+);
+
+INSERT INTO companion_notes (vegetable_id, companion_id, locale, note) VALUES
+('basil',      'tomato',    'en', 'Basil repels whitefly and aphids from tomato.'),
+('basil',      'tomato',    'fr', 'Le basilic éloigne les aleurodes et les pucerons de la tomate.'),
+('onion',      'carrot',    'en', 'Onion masks the scent of carrot from the carrot fly, and carrot returns the favour against the onion fly.'),
+('onion',      'carrot',    'fr', 'L''oignon masque l''odeur de la carotte à la mouche de la carotte, qui le protège en retour de la mouche de l''oignon.'),
+('leek',       'carrot',    'en', 'Leek deters the carrot fly; carrot deters the leek moth.'),
+('leek',       'carrot',    'fr', 'Le poireau éloigne la mouche de la carotte ; la carotte éloigne la teigne du poireau.'),
+('maïs',       'green-bean','en', 'Corn gives climbing beans a support; beans fix nitrogen for the corn.'),
+('maïs',       'green-bean','fr', 'Le maïs sert de tuteur au haricot, qui fixe l''azote pour le maïs.'),
+('garlic',     'strawberry','en', 'Garlic helps keep grey mould and aphids away from strawberry.'),
+('garlic',     'strawberry','fr', 'L''ail aide à protéger le fraisier de la pourriture grise et des pucerons.'),
+('mint',       'brassica',  'en', 'Mint deters cabbage white butterflies and flea beetles from brassicas.'),
+('mint',       'brassica',  'fr', 'La menthe éloigne la piéride et les altises des choux.'),
+('radish',     'cucumber',  'en', 'Radish lures cucumber beetles away from cucumber.'),
+('radish',     'cucumber',  'fr', 'Le radis détourne les chrysomèles du concombre.'),
+('fennel',     'tomato',    'en', 'Fennel releases compounds that stunt the growth of tomato.'),
+('fennel',     'tomato',    'fr', 'Le fenouil libère des substances qui freinent la croissance de la tomate.');
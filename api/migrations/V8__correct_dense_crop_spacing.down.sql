@@ -0,0 +1,7 @@
+-- ---------------------------------------------------------------------------
+-- V8 rollback: Restore original dense crop spacing
+-- ---------------------------------------------------------------------------
+
+UPDATE varieties SET spacing_cm = 5  WHERE id = 'radish';
+UPDATE varieties SET spacing_cm = 20 WHERE id = 'chive';
+UPDATE varieties SET spacing_cm = 30 WHERE id = 'thyme';
@@ -0,0 +1,11 @@
+-- ---------------------------------------------------------------------------
+-- V8: Correct spacing of dense crops
+-- Aligns in-row spacing with square-foot gardening densities so that
+-- plants_per_cell reports realistic yields for a 30 cm cell:
+--   radish 5 -> 8 cm (16 per cell), chive 20 -> 8 cm (16 per cell),
+--   thyme 30 -> 15 cm (4 per cell).
+-- ---------------------------------------------------------------------------
+
+UPDATE varieties SET spacing_cm = 8  WHERE id = 'radish';
+UPDATE varieties SET spacing_cm = 8  WHERE id = 'chive';
+UPDATE varieties SET spacing_cm = 15 WHERE id = 'thyme';
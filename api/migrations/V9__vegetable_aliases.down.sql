@@ -0,0 +1,5 @@
+-- ---------------------------------------------------------------------------
+-- V9 rollback: Remove vegetable id aliases
+-- ---------------------------------------------------------------------------
+
+DROP TABLE IF EXISTS vegetable_aliases;
@@ -0,0 +1,14 @@
+-- ---------------------------------------------------------------------------
+-- V9: Vegetable id aliases
+-- Alternative ids that resolve to a canonical vegetable. The canonical id for
+-- corn is 'maïs' (used by the varieties, translations, companion lists and the
+-- consumption ranking); 'corn' is accepted as an alias.
+-- ---------------------------------------------------------------------------
+
+CREATE TABLE vegetable_aliases (
+    alias        TEXT PRIMARY KEY,
+    vegetable_id TEXT NOT NULL REFERENCES vegetables(id) ON DELETE CASCADE
+);
+
+INSERT INTO vegetable_aliases (alias, vegetable_id) VALUES
+('corn', 'maïs');
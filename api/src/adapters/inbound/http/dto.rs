@@ -1 +1,117 @@
 pub use crate::application::ports::variety_response_repository::VarietyResponse;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Centimetres per inch.
+const CM_PER_INCH: f32 = 2.54;
+/// Grams per avoirdupois ounce.
+const G_PER_OUNCE: f32 = 28.349_523;
+
+/// Unit system catalogue measurements are served in. The catalogue itself stays metric;
+/// only the HTTP boundary converts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "PascalCase")]
+pub enum Units {
+    /// Centimetres and grams, as stored.
+    #[default]
+    #[serde(alias = "metric")]
+    Metric,
+    /// Inches and ounces, added next to the metric figures.
+    #[serde(alias = "imperial")]
+    Imperial,
+}
+
+/// A variety as served by the catalogue endpoints, with its measurements also given in
+/// the requested [`Units`].
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VarietyDto {
+    #[serde(flatten)]
+    pub variety: VarietyResponse,
+    /// Unit system asked for with `?units=`.
+    pub units: Units,
+    /// `spacingCm` in inches, to the nearest half inch. Only with `units: Imperial`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 19.5)]
+    pub spacing_in: Option<f32>,
+    /// `yieldPerPlantG` in ounces, to one decimal. Only with `units: Imperial`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 141.1)]
+    pub yield_per_plant_oz: Option<f32>,
+}
+
+impl VarietyDto {
+    pub fn new(variety: VarietyResponse, units: Units) -> Self {
+        let (spacing_in, yield_per_plant_oz) = match units {
+            Units::Metric => (None, None),
+            Units::Imperial => (
+                Some((variety.spacing_cm as f32 / CM_PER_INCH * 2.0).round() / 2.0),
+                Some((variety.yield_per_plant_g as f32 / G_PER_OUNCE * 10.0).round() / 10.0),
+            ),
+        };
+        Self {
+            variety,
+            units,
+            spacing_in,
+            yield_per_plant_oz,
+        }
+    }
+}
+
+/// Whether the server started with its catalogue loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "PascalCase")]
+pub enum HealthStatus {
+    /// The catalogue was loaded at startup.
+    Ok,
+    /// The database could not be loaded at startup: catalogue requests are expected to
+    /// fail until it is reachable.
+    Degraded,
+}
+
+/// Body of `GET /api/health`.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthResponse {
+    pub status: HealthStatus,
+    /// The startup error behind a `Degraded` status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Inconsistencies the startup catalogue check logged (see the server logs).
+    #[schema(example = 0)]
+    pub catalogue_problems: usize,
+}
+
+/// Outcome of the startup routine, shared with `GET /api/health`.
+#[derive(Debug, Clone, Default)]
+pub struct StartupStatus {
+    /// Why the catalogue could not be loaded at startup; `None` when it was.
+    pub degraded_reason: Option<String>,
+    /// Inconsistencies found by the startup catalogue check.
+    pub catalogue_problems: usize,
+}
+
+impl StartupStatus {
+    pub fn degraded(reason: impl Into<String>) -> Self {
+        Self {
+            degraded_reason: Some(reason.into()),
+            catalogue_problems: 0,
+        }
+    }
+
+    /// Status after a catalogue load ended with `loaded`: its problem count, or degraded
+    /// with its error.
+    pub fn from_load(loaded: &Result<Vec<String>, String>) -> Self {
+        match loaded {
+            Ok(problems) => Self {
+                degraded_reason: None,
+                catalogue_problems: problems.len(),
+            },
+            Err(e) => Self::degraded(e.clone()),
+        }
+    }
+}
+
+/// [`StartupStatus`] as shared with `GET /api/health`: replaced once a server started in
+/// degraded mode manages to load the catalogue.
+pub type SharedStartupStatus = std::sync::RwLock<StartupStatus>;
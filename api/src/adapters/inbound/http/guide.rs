@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use chrono::NaiveDate;
+
+use crate::domain::{
+    models::{
+        response::{PlanResponse, PlannedCell},
+        Matrix,
+    },
+    services::helpers::CELL_SIZE_CM,
+};
+
+/// Media type of the printable planting guide.
+pub const MARKDOWN: &str = "text/markdown; charset=utf-8";
+
+/// Everything the guide says about one species, gathered over every week of the plan.
+struct Species<'a> {
+    id: &'a str,
+    name: &'a str,
+    reason: &'a str,
+    symbol: char,
+    plants: u32,
+    cells: usize,
+    spacing_cm: Option<u32>,
+    days_to_harvest: i64,
+    first_planted: NaiveDate,
+}
+
+/// Grid symbol of the `index`-th species: `A`–`Z`, then `a`–`z`, then `?`.
+fn symbol(index: usize) -> char {
+    match index {
+        0..=25 => (b'A' + index as u8) as char,
+        26..=51 => (b'a' + (index - 26) as u8) as char,
+        _ => '?',
+    }
+}
+
+/// Collects the species of a plan in order of first appearance. A plant counts once
+/// however many weeks it stays: an anchor only adds plants when the previous week did
+/// not already hold the same variety at the same position.
+fn collect_species<'a>(
+    plan: &'a PlanResponse,
+    spacing_cm: &HashMap<String, u32>,
+) -> Vec<Species<'a>> {
+    let mut species: Vec<Species> = Vec::new();
+    let mut previous: Option<&Matrix<PlannedCell>> = None;
+    for week in &plan.weeks {
+        for (r, row) in week.grid.iter().enumerate() {
+            for (c, cell) in row.iter().enumerate() {
+                let (id, name, reason, ppc, harvest) = match cell {
+                    PlannedCell::SelfContained {
+                        id,
+                        name,
+                        reason,
+                        plants_per_cell,
+                        estimated_harvest_date,
+                        ..
+                    }
+                    | PlannedCell::Overflowing {
+                        id,
+                        name,
+                        reason,
+                        plants_per_cell,
+                        estimated_harvest_date,
+                        ..
+                    } => (id, name, reason, *plants_per_cell, *estimated_harvest_date),
+                    _ => continue,
+                };
+                let already_there = previous
+                    .and_then(|grid| grid.get(r)?.get(c))
+                    .is_some_and(|before| before.id() == Some(id));
+                if already_there {
+                    continue;
+                }
+                let cells =
+                    (cell.width_cells().unwrap_or(1) * cell.length_cells().unwrap_or(1)) as usize;
+                match species.iter_mut().find(|s| s.id == id) {
+                    Some(s) => {
+                        s.plants += ppc;
+                        s.cells += cells;
+                    }
+                    None => {
+                        let symbol = symbol(species.len());
+                        species.push(Species {
+                            id,
+                            name,
+                            reason,
+                            symbol,
+                            plants: ppc,
+                            cells,
+                            spacing_cm: spacing_cm.get(id.as_str()).copied(),
+                            days_to_harvest: (harvest - week.period.start).num_days(),
+                            first_planted: week.period.start,
+                        });
+                    }
+                }
+            }
+        }
+        previous = Some(&week.grid);
+    }
+    species
+}
+
+/// Draws one grid as a block of symbols: a species letter for planted cells (including
+/// the cells an overflowing plant covers), `.` empty, `#` blocked, `~` reserved.
+fn draw_grid(out: &mut String, grid: &Matrix<PlannedCell>, species: &[Species]) {
+    let symbol_of = |id: Option<&str>| {
+        species
+            .iter()
+            .find(|s| Some(s.id) == id)
+            .map_or('?', |s| s.symbol)
+    };
+    out.push_str("```\n");
+    for row in grid {
+        let line: Vec<String> = row
+            .iter()
+            .map(|cell| {
+                let ch = match cell {
+                    PlannedCell::Empty => '.',
+                    PlannedCell::Blocked => '#',
+                    PlannedCell::Reserved => '~',
+                    PlannedCell::Overflowed { covered_by } => symbol_of(
+                        grid.get(covered_by.row)
+                            .and_then(|r| r.get(covered_by.col))
+                            .and_then(PlannedCell::id),
+                    ),
+                    other => symbol_of(other.id()),
+                };
+                ch.to_string()
+            })
+            .collect();
+        out.push_str(&line.join(" "));
+        out.push('\n');
+    }
+    out.push_str("```\n\n");
+}
+
+/// Renders a plan as a printable markdown planting guide: the grid of every week that
+/// changed the garden, a quantities table, then one section per species with its
+/// spacing, days to harvest and the reason it was chosen. `spacing_cm` maps variety ids
+/// to their spacing in this plan; varieties missing from it print none.
+pub fn planting_guide(plan: &PlanResponse, spacing_cm: &HashMap<String, u32>) -> String {
+    let species = collect_species(plan, spacing_cm);
+    let mut out = String::from("# Planting guide\n\n");
+    if let (Some(first), Some(last)) = (plan.weeks.first(), plan.weeks.last()) {
+        let _ = writeln!(
+            out,
+            "{} × {} grid of {} cm cells, from {} to {}.\n",
+            plan.rows, plan.cols, CELL_SIZE_CM, first.period.start, last.period.end
+        );
+    }
+
+    out.push_str("## Layout\n\n");
+    let mut previous: Option<&Matrix<PlannedCell>> = None;
+    for week in &plan.weeks {
+        if previous == Some(&week.grid) {
+            continue;
+        }
+        let _ = writeln!(out, "### From {}\n", week.period.start);
+        draw_grid(&mut out, &week.grid, &species);
+        previous = Some(&week.grid);
+    }
+    out.push_str("Legend: `.` empty, `#` blocked, `~` reserved");
+    for s in &species {
+        let _ = write!(out, ", `{}` {}", s.symbol, s.name);
+    }
+    out.push_str(".\n\n");
+
+    out.push_str("## Quantities\n\n");
+    if species.is_empty() {
+        out.push_str("Nothing is planted in this plan.\n");
+        return out;
+    }
+    out.push_str("| Species | Symbol | Plants | Cells | Spacing (cm) | Days to harvest |\n");
+    out.push_str("|---|---|---:|---:|---:|---:|\n");
+    for s in &species {
+        let spacing = s.spacing_cm.map_or("-".to_owned(), |cm| cm.to_string());
+        let _ = writeln!(
+            out,
+            "| {} | `{}` | {} | {} | {} | {} |",
+            s.name, s.symbol, s.plants, s.cells, spacing, s.days_to_harvest
+        );
+    }
+
+    out.push_str("\n## Species\n");
+    for s in &species {
+        let _ = writeln!(out, "\n### {}\n", s.name);
+        let _ = writeln!(out, "- Variety: `{}`", s.id);
+        let _ = writeln!(out, "- First planted: week of {}", s.first_planted);
+        let _ = writeln!(out, "- Plants: {} over {} cell(s)", s.plants, s.cells);
+        if let Some(cm) = s.spacing_cm {
+            let _ = writeln!(out, "- Spacing: {cm} cm");
+        }
+        let _ = writeln!(out, "- Days to harvest: {}", s.days_to_harvest);
+        let _ = writeln!(out, "- Why here: {}", s.reason.trim());
+    }
+    out
+}
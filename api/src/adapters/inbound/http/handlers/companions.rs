@@ -0,0 +1,240 @@
+use actix_web::{get, http::Method, web, HttpRequest, HttpResponse, Responder};
+// Types referenced only in #[utoipa::path] attributes — used at proc-macro expansion time.
+#[allow(unused_imports)]
+use crate::adapters::inbound::http::hateoas::{CompanionDemoApiResponse, ErrorResponse};
+
+use crate::{
+    adapters::inbound::http::{
+        hateoas::{link, ApiResponse},
+        localization::parse_locale,
+        negotiation::hal_json,
+    },
+    application::{
+        ports::{variety_repository::VarietyRepository, vegetable_repository::VegetableRepository},
+        use_cases::{
+            companion_demo::{CompanionDemoError, CompanionDemoUseCase},
+            vegetables::GetCompanionMatrixUseCase,
+        },
+    },
+    domain::{
+        models::{response::CompanionMatrix, variety::Region},
+        services::companion::GOOD_COMPANION_SCORE,
+    },
+};
+
+fn default_demo_side() -> usize {
+    3
+}
+
+/// Query parameters for `GET /api/companions/demo`.
+#[derive(Debug, serde::Deserialize)]
+pub struct CompanionDemoQueryParams {
+    pub a: String,
+    pub b: String,
+    #[serde(default = "default_demo_side")]
+    pub rows: usize,
+    #[serde(default = "default_demo_side")]
+    pub cols: usize,
+    pub region: Option<Region>,
+}
+
+/// Renders the matrix as CSV: a header row and a first column of vegetable ids,
+/// each cell holding the score the row vegetable gets from the column vegetable.
+fn render_csv(matrix: &CompanionMatrix) -> String {
+    let mut out = String::from("id");
+    for v in &matrix.vegetables {
+        out.push(',');
+        out.push_str(&v.id);
+    }
+    out.push('\n');
+    for (v, row) in matrix.vegetables.iter().zip(&matrix.scores) {
+        out.push_str(&v.id);
+        for score in row {
+            out.push(',');
+            out.push_str(&score.to_string());
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Escapes a string for use inside a double-quoted DOT identifier.
+fn dot_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Renders the matrix as a Graphviz digraph: one node per vegetable (labelled with its
+/// name) and one edge per non-neutral pair, green for good and red for bad companions.
+fn render_dot(matrix: &CompanionMatrix) -> String {
+    let mut out = String::from("digraph companions {\n");
+    for v in &matrix.vegetables {
+        out.push_str(&format!(
+            "  {} [label={}];\n",
+            dot_quote(&v.id),
+            dot_quote(&v.name)
+        ));
+    }
+    for (from, row) in matrix.vegetables.iter().zip(&matrix.scores) {
+        for (to, &score) in matrix.vegetables.iter().zip(row) {
+            if score == 0 {
+                continue;
+            }
+            let color = if score >= GOOD_COMPANION_SCORE {
+                "green"
+            } else {
+                "red"
+            };
+            out.push_str(&format!(
+                "  {} -> {} [color={color}];\n",
+                dot_quote(&from.id),
+                dot_quote(&to.id)
+            ));
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+async fn load_matrix(
+    req: &HttpRequest,
+    repo: &dyn VegetableRepository,
+) -> Result<CompanionMatrix, HttpResponse> {
+    let locale = parse_locale(req);
+    GetCompanionMatrixUseCase::new(repo)
+        .execute(&locale)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to build companion matrix: {e}");
+            HttpResponse::InternalServerError()
+                .json(serde_json::json!({ "error": "Internal server error" }))
+        })
+}
+
+/// GET /api/companions/matrix.csv
+/// Returns the N×N companion score matrix between all catalogue vegetables as CSV.
+#[utoipa::path(
+    get,
+    path = "/api/companions/matrix.csv",
+    tag = "companions",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    responses(
+        (status = 200, description = "Companion matrix (+2 good, 0 neutral, -3 bad); header row and first column hold vegetable ids",
+         body = String, content_type = "text/csv"),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+#[get("/companions/matrix.csv")]
+pub async fn get_companion_matrix_csv(
+    req: HttpRequest,
+    repo: web::Data<Box<dyn VegetableRepository>>,
+) -> impl Responder {
+    match load_matrix(&req, repo.as_ref().as_ref()).await {
+        Err(resp) => resp,
+        Ok(matrix) => HttpResponse::Ok()
+            .content_type("text/csv; charset=utf-8")
+            .body(render_csv(&matrix)),
+    }
+}
+
+/// GET /api/companions/graph.dot
+/// Returns the companion relationships as a Graphviz DOT digraph.
+#[utoipa::path(
+    get,
+    path = "/api/companions/graph.dot",
+    tag = "companions",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    responses(
+        (status = 200, description = "Companion graph — green edges for good companions, red edges for bad ones",
+         body = String, content_type = "text/vnd.graphviz"),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+#[get("/companions/graph.dot")]
+pub async fn get_companion_graph_dot(
+    req: HttpRequest,
+    repo: web::Data<Box<dyn VegetableRepository>>,
+) -> impl Responder {
+    match load_matrix(&req, repo.as_ref().as_ref()).await {
+        Err(resp) => resp,
+        Ok(matrix) => HttpResponse::Ok()
+            .content_type("text/vnd.graphviz; charset=utf-8")
+            .body(render_dot(&matrix)),
+    }
+}
+
+/// GET /api/companions/demo
+/// Plans a small empty grid with only two varieties, showing how they end up arranged.
+#[utoipa::path(
+    get,
+    path = "/api/companions/demo",
+    tag = "companions",
+    params(
+        ("a" = String, Query, description = "First variety id (e.g. `tomato`)."),
+        ("b" = String, Query, description = "Second variety id (e.g. `basil`)."),
+        ("rows" = Option<usize>, Query, description = "Grid rows, 1 to 10. Defaults to 3."),
+        ("cols" = Option<usize>, Query, description = "Grid columns, 1 to 10. Defaults to 3."),
+        ("region" = Option<String>, Query, description = "Climate region used to pick a week both varieties are in season. Defaults to `Temperate`."),
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    responses(
+        (status = 200, description = "Planned demo grid and its companion score", body = CompanionDemoApiResponse),
+        (status = 400, description = "Unknown variety, identical varieties, out-of-range dimensions, or no shared season", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+#[get("/companions/demo")]
+pub async fn get_companion_demo(
+    req: HttpRequest,
+    query: web::Query<CompanionDemoQueryParams>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    let query = query.into_inner();
+    match CompanionDemoUseCase::new(repo.as_ref().as_ref())
+        .execute(
+            &query.a,
+            &query.b,
+            query.rows,
+            query.cols,
+            query.region.unwrap_or(Region::Temperate),
+            &locale,
+        )
+        .await
+    {
+        Err(CompanionDemoError::Invalid(msg)) => {
+            HttpResponse::BadRequest().json(serde_json::json!({ "error": msg }))
+        }
+        Err(CompanionDemoError::Repository(e)) => {
+            log::error!("Failed to build companion demo: {e}");
+            HttpResponse::InternalServerError()
+                .json(serde_json::json!({ "error": "Internal server error" }))
+        }
+        Ok(demo) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert(
+                "self".into(),
+                link(
+                    format!(
+                        "/api/companions/demo?a={}&b={}&rows={}&cols={}",
+                        demo.a, demo.b, query.rows, query.cols
+                    ),
+                    Method::GET,
+                ),
+            );
+            links.insert(
+                "a".into(),
+                link(format!("/api/varieties/{}", demo.a), Method::GET),
+            );
+            links.insert(
+                "b".into(),
+                link(format!("/api/varieties/{}", demo.b), Method::GET),
+            );
+            links.insert("plan".into(), link("/api/plan", Method::POST));
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(demo, links))
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use actix_web::{get, http::Method, HttpRequest, HttpResponse, Responder};
+
+use crate::{
+    adapters::inbound::http::{
+        hateoas::{link, ApiResponse},
+        negotiation::hal_json,
+    },
+    application::use_cases::enums::{GetEnumsUseCase, ListCategoriesUseCase},
+};
+// Types referenced only in #[utoipa::path] attributes — used at proc-macro expansion time.
+#[allow(unused_imports)]
+use crate::adapters::inbound::http::hateoas::{CategoriesApiResponse, EnumsApiResponse};
+
+/// GET /api/enums
+/// Returns every accepted value of the request / response enums.
+#[utoipa::path(
+    get,
+    path = "/api/enums",
+    tag = "enums",
+    responses(
+        (status = 200, description = "Serialized variant names, one array per enum", body = EnumsApiResponse),
+    )
+)]
+#[get("/enums")]
+pub async fn get_enums(req: HttpRequest) -> impl Responder {
+    let mut links = std::collections::BTreeMap::new();
+    links.insert("self".into(), link("/api/enums", Method::GET));
+    links.insert("plan".into(), link("/api/plan", Method::POST));
+    hal_json(
+        &req,
+        HttpResponse::Ok(),
+        &ApiResponse::new(GetEnumsUseCase.execute(), links),
+    )
+}
+
+/// GET /api/categories
+/// Returns every variety category with its default colour and icon.
+#[utoipa::path(
+    get,
+    path = "/api/categories",
+    tag = "enums",
+    responses(
+        (status = 200, description = "Categories in declaration order, with rendering hints", body = CategoriesApiResponse),
+    )
+)]
+#[get("/categories")]
+pub async fn get_categories(req: HttpRequest) -> impl Responder {
+    let mut links = std::collections::BTreeMap::new();
+    links.insert("self".into(), link("/api/categories", Method::GET));
+    links.insert("enums".into(), link("/api/enums", Method::GET));
+    links.insert("varieties".into(), link("/api/varieties", Method::GET));
+    hal_json(
+        &req,
+        HttpResponse::Ok(),
+        &ApiResponse::new(ListCategoriesUseCase.execute(), links),
+    )
+}
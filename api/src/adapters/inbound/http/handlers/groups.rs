@@ -9,6 +9,7 @@ use crate::{
     adapters::inbound::http::{
         hateoas::{link, ApiResponse, IntoHttpPagination, PaginatedResponse, PaginationParams},
         localization::parse_locale,
+        negotiation::hal_json,
     },
     application::{
         ports::{group_repository::GroupRepository, vegetable_repository::VegetableRepository},
@@ -59,7 +60,7 @@ pub async fn list_groups(
                 .into_iter()
                 .map(|g| {
                     let id = g.id.clone();
-                    let mut links = std::collections::HashMap::new();
+                    let mut links = std::collections::BTreeMap::new();
                     links.insert(
                         "self".into(),
                         link(format!("/api/groups/{id}"), Method::GET),
@@ -71,9 +72,13 @@ pub async fn list_groups(
                     ApiResponse::new(g, links)
                 })
                 .collect();
-            let mut collection_links = std::collections::HashMap::new();
+            let mut collection_links = std::collections::BTreeMap::new();
             collection_links.insert("self".into(), link("/api/groups", Method::GET));
-            HttpResponse::Ok().json(PaginatedResponse::new(items, collection_links, pagination))
+            hal_json(
+                &req,
+                HttpResponse::Ok(),
+                &PaginatedResponse::new(items, collection_links, pagination),
+            )
         }
     }
 }
@@ -113,7 +118,7 @@ pub async fn get_group(
             "error": format!("Group '{}' not found.", id)
         })),
         Ok(Some(group)) => {
-            let mut links = std::collections::HashMap::new();
+            let mut links = std::collections::BTreeMap::new();
             links.insert(
                 "self".into(),
                 link(format!("/api/groups/{id}"), Method::GET),
@@ -123,7 +128,7 @@ pub async fn get_group(
                 link(format!("/api/groups/{id}/vegetables"), Method::GET),
             );
             links.insert("collection".into(), link("/api/groups", Method::GET));
-            HttpResponse::Ok().json(ApiResponse::new(group, links))
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(group, links))
         }
     }
 }
@@ -191,7 +196,7 @@ pub async fn list_vegetables_by_group(
                 .into_iter()
                 .map(|v| {
                     let vid = v.id.clone();
-                    let mut links = std::collections::HashMap::new();
+                    let mut links = std::collections::BTreeMap::new();
                     links.insert(
                         "self".into(),
                         link(format!("/api/vegetables/{vid}"), Method::GET),
@@ -203,7 +208,7 @@ pub async fn list_vegetables_by_group(
                     ApiResponse::new(v, links)
                 })
                 .collect();
-            let mut collection_links = std::collections::HashMap::new();
+            let mut collection_links = std::collections::BTreeMap::new();
             collection_links.insert(
                 "self".into(),
                 link(format!("/api/groups/{id}/vegetables"), Method::GET),
@@ -212,7 +217,11 @@ pub async fn list_vegetables_by_group(
                 "group".into(),
                 link(format!("/api/groups/{id}"), Method::GET),
             );
-            HttpResponse::Ok().json(PaginatedResponse::new(items, collection_links, pagination))
+            hal_json(
+                &req,
+                HttpResponse::Ok(),
+                &PaginatedResponse::new(items, collection_links, pagination),
+            )
         }
     }
 }
@@ -0,0 +1,44 @@
+use crate::adapters::inbound::http::{
+    dto::{HealthResponse, HealthStatus, SharedStartupStatus},
+    hateoas::{link, ApiResponse},
+    negotiation::hal_json,
+};
+use actix_web::{get, http::Method, web, HttpRequest, HttpResponse, Responder};
+// Types referenced only in #[utoipa::path] attributes — used at proc-macro expansion time.
+#[allow(unused_imports)]
+use crate::adapters::inbound::http::hateoas::HealthApiResponse;
+
+/// GET /api/health
+/// Reports whether the catalogue was loaded, at startup or since.
+#[utoipa::path(
+    get,
+    path = "/api/health",
+    tag = "health",
+    responses(
+        (status = 200, description = "The catalogue was loaded, at startup or since", body = HealthApiResponse),
+        (status = 503, description = "Degraded: the database could not be loaded yet", body = HealthApiResponse),
+    )
+)]
+#[get("/health")]
+pub async fn get_health(
+    req: HttpRequest,
+    startup: web::Data<SharedStartupStatus>,
+) -> impl Responder {
+    let startup = startup.read().unwrap_or_else(|e| e.into_inner()).clone();
+    let response = HealthResponse {
+        status: if startup.degraded_reason.is_some() {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Ok
+        },
+        error: startup.degraded_reason.clone(),
+        catalogue_problems: startup.catalogue_problems,
+    };
+    let builder = match response.status {
+        HealthStatus::Ok => HttpResponse::Ok(),
+        HealthStatus::Degraded => HttpResponse::ServiceUnavailable(),
+    };
+    let mut links = std::collections::BTreeMap::new();
+    links.insert("self".into(), link("/api/health", Method::GET));
+    hal_json(&req, builder, &ApiResponse::new(response, links))
+}
@@ -0,0 +1,181 @@
+use actix_web::{http::Method, post, web, HttpRequest, HttpResponse, Responder, ResponseError};
+// Types referenced only in #[utoipa::path] attributes — used at proc-macro expansion time.
+#[allow(unused_imports)]
+use crate::adapters::inbound::http::hateoas::{
+    BulkPlantApiResponse, ErrorResponse, LayoutValidationApiResponse, PlacementPreviewApiResponse,
+};
+
+use crate::{
+    adapters::inbound::http::{
+        hateoas::{link, ApiResponse},
+        localization::parse_locale,
+        negotiation::hal_json,
+    },
+    application::{
+        models::request::{BulkPlantRequest, LayoutValidationRequest, PlacementPreviewRequest},
+        ports::variety_repository::VarietyRepository,
+        use_cases::{
+            bulk_plant::BulkPlantUseCase,
+            preview_placement::{PreviewPlacementError, PreviewPlacementUseCase},
+            validate_layout::ValidateLayoutUseCase,
+        },
+    },
+};
+
+/// POST /api/layout/validate
+/// Checks a layout the way `POST /api/plan` would, without planning it.
+#[utoipa::path(
+    post,
+    path = "/api/layout/validate",
+    tag = "layout",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    request_body(
+        content = LayoutValidationRequest,
+        description = "Grid layout and optional blocked regions",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Layout is valid; cell counts and warnings", body = LayoutValidationApiResponse),
+        (status = 400, description = "Layout is invalid; `errors` lists why", body = LayoutValidationApiResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+#[post("/layout/validate")]
+pub async fn post_layout_validate(
+    req: HttpRequest,
+    body: web::Json<LayoutValidationRequest>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    match ValidateLayoutUseCase::new(repo.as_ref().as_ref())
+        .execute(&body, &locale)
+        .await
+    {
+        Err(e) => {
+            log::error!("Failed to validate layout: {e}");
+            HttpResponse::InternalServerError()
+                .json(serde_json::json!({ "error": "Internal server error" }))
+        }
+        Ok(report) => {
+            let builder = if report.errors.is_empty() {
+                HttpResponse::Ok()
+            } else {
+                HttpResponse::BadRequest()
+            };
+            let mut links = std::collections::BTreeMap::new();
+            links.insert("self".into(), link("/api/layout/validate", Method::POST));
+            links.insert("plan".into(), link("/api/plan", Method::POST));
+            hal_json(&req, builder, &ApiResponse::new(report, links))
+        }
+    }
+}
+
+/// POST /api/layout/can-place
+/// Previews dropping a plant onto a layout: bad-companion neighbours and score.
+#[utoipa::path(
+    post,
+    path = "/api/layout/can-place",
+    tag = "layout",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    request_body(
+        content = PlacementPreviewRequest,
+        description = "Plant to preview, its top-left cell and the current layout",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Compatibility, conflicting neighbours and score delta", body = PlacementPreviewApiResponse),
+        (status = 400, description = "Malformed layout, block past the grid edge or bad JSON", body = ErrorResponse),
+        (status = 422, description = "Unknown variety or unknown vegetable in layout", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+#[post("/layout/can-place")]
+pub async fn post_layout_can_place(
+    req: HttpRequest,
+    body: web::Json<PlacementPreviewRequest>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    match PreviewPlacementUseCase::new(repo.as_ref().as_ref())
+        .execute(&body, &locale)
+        .await
+    {
+        Err(PreviewPlacementError::Invalid(msg)) => {
+            HttpResponse::BadRequest().json(serde_json::json!({ "error": msg }))
+        }
+        Err(PreviewPlacementError::Unknown(msg)) => {
+            HttpResponse::UnprocessableEntity().json(serde_json::json!({ "error": msg }))
+        }
+        Err(PreviewPlacementError::Repository(e)) => {
+            log::error!("Failed to preview placement: {e}");
+            HttpResponse::InternalServerError()
+                .json(serde_json::json!({ "error": "Internal server error" }))
+        }
+        Ok(preview) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert("self".into(), link("/api/layout/can-place", Method::POST));
+            links.insert(
+                "validate".into(),
+                link("/api/layout/validate", Method::POST),
+            );
+            links.insert(
+                "variety".into(),
+                link(format!("/api/varieties/{}", preview.id), Method::GET),
+            );
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(preview, links))
+        }
+    }
+}
+
+/// POST /api/layout/bulk-plant
+/// Stamps several plants into a layout at once, checking each one.
+#[utoipa::path(
+    post,
+    path = "/api/layout/bulk-plant",
+    tag = "layout",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    request_body(
+        content = BulkPlantRequest,
+        description = "Base layout and the plants to stamp into it, in order",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Resulting layout and each plant's outcome", body = BulkPlantApiResponse),
+        (status = 400, description = "Bad JSON or no placements", body = ErrorResponse),
+        (status = 422, description = "Malformed layout or unknown vegetable in layout", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+#[post("/layout/bulk-plant")]
+pub async fn post_layout_bulk_plant(
+    req: HttpRequest,
+    body: web::Json<BulkPlantRequest>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    if let Err(e) = body.validate() {
+        return HttpResponse::BadRequest().json(serde_json::json!({ "error": e }));
+    }
+    let locale = parse_locale(&req);
+    match BulkPlantUseCase::new(repo.as_ref().as_ref())
+        .execute(&body, &locale)
+        .await
+    {
+        Ok(response) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert("self".into(), link("/api/layout/bulk-plant", Method::POST));
+            links.insert(
+                "validate".into(),
+                link("/api/layout/validate", Method::POST),
+            );
+            links.insert("plan".into(), link("/api/plan", Method::POST));
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(response, links))
+        }
+        Err(e) => e.error_response(),
+    }
+}
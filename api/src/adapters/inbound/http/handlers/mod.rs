@@ -1,9 +1,26 @@
+pub mod companions;
+pub mod enums;
 pub mod groups;
+pub mod health;
+pub mod layout;
 pub mod plan;
 pub mod varieties;
 pub mod vegetables;
 
+pub use companions::{get_companion_demo, get_companion_graph_dot, get_companion_matrix_csv};
+pub use enums::{get_categories, get_enums};
 pub use groups::{get_group, list_groups, list_vegetables_by_group};
-pub use plan::post_plan;
+pub use health::get_health;
+pub use layout::{post_layout_bulk_plant, post_layout_can_place, post_layout_validate};
+pub use plan::{
+    delete_stored_plan, get_plan_example, get_stored_plan, get_stored_plan_cells, head_plan,
+    options_plan, post_plan, post_plan_batch, post_plan_diff, post_plan_place_one, post_plan_quick,
+    post_plan_recommendations, post_plan_size_for, post_plan_succession, post_plan_trace,
+    post_plan_transition, post_plan_what_if, put_stored_plan,
+};
 pub use varieties::{get_variety, list_varieties};
-pub use vegetables::{get_companions, get_varieties_by_vegetable, get_vegetable, list_vegetables};
+pub use vegetables::{
+    get_companions, get_neighbors_preview, get_plan_preview, get_random_vegetable, get_suggestions,
+    get_varieties_by_vegetable, get_vegetable, list_vegetables, list_vegetables_by_category,
+    list_vegetables_grouped_by_category,
+};
@@ -1,19 +1,151 @@
-use actix_web::{http::Method, post, web, HttpRequest, HttpResponse, Responder};
+use std::collections::HashMap;
+
+use actix_web::{
+    delete, get, head,
+    http::{header, Method, StatusCode},
+    options, post, put, web, HttpRequest, HttpResponse, HttpResponseBuilder, Responder,
+    ResponseError,
+};
 // Types referenced only in #[utoipa::path] attributes — used at proc-macro expansion time.
 #[allow(unused_imports)]
-use crate::adapters::inbound::http::hateoas::{ErrorResponse, PlanApiResponse};
+use crate::adapters::inbound::http::hateoas::{
+    BatchPlanApiResponse, BedSizeApiResponse, CompactPlanApiResponse, DebugPlanApiResponse,
+    ErrorResponse, PlaceOneApiResponse, PlanApiResponse, PlanDiffApiResponse,
+    PlanRecommendationsApiResponse, PlanTraceApiResponse, TransitionApiResponse, WhatIfApiResponse,
+};
 
 use crate::{
     adapters::inbound::http::{
-        hateoas::{link, ApiResponse},
+        guide::{planting_guide, MARKDOWN},
+        hateoas::{link, ApiResponse, Links},
+        idempotency::{idempotency_key, IDEMPOTENT_REPLAYED},
         localization::parse_locale,
+        ndjson::{PlanCellsBody, NDJSON},
+        negotiation::{hal_json, negotiate, wants_markdown},
     },
     application::{
-        models::request::PlanRequest, ports::variety_repository::VarietyRepository,
-        use_cases::plan_garden::PlanGardenUseCase,
+        models::request::{
+            BedSizeRequest, PlaceOneRequest, PlanDiffRequest, PlanRecommendationsRequest,
+            PlanRequest, QuickPlanRequest, TransitionRequest, WhatIfRequest,
+        },
+        ports::{
+            plan_store::{PlanStore, ReplaceError},
+            variety_repository::VarietyRepository,
+            vegetable_repository::VegetableRepository,
+        },
+        use_cases::{
+            bed_size::BedSizeUseCase,
+            example_plan::ExamplePlanUseCase,
+            place_one::PlaceOneUseCase,
+            plan_batch::{PlanBatchUseCase, MAX_BATCH_SIZE},
+            plan_diff::DiffPlansUseCase,
+            plan_garden::{PlanGardenError, PlanGardenUseCase},
+            plan_recommendations::RecommendPlantingsUseCase,
+            plan_succession::PlanSuccessionUseCase,
+            plan_transition::PlanTransitionUseCase,
+            quick_plan::QuickPlanUseCase,
+            stored_plans::{
+                DeleteStoredPlanUseCase, GetPlanByIdempotencyKeyUseCase, GetStoredPlanUseCase,
+                StorePlanUseCase, UpdateStoredPlanUseCase,
+            },
+            what_if::WhatIfUseCase,
+        },
     },
+    domain::{models::response::PlanResponse, services::response::compact_plan},
 };
 
+/// Query parameters for `POST /api/plan`.
+#[derive(Debug, serde::Deserialize)]
+pub struct PlanQueryParams {
+    /// Store the generated plan so it can be fetched again via `GET /api/plan/{id}`.
+    #[serde(default)]
+    pub store: bool,
+    /// Response shape; see [`PlanView`].
+    #[serde(default)]
+    pub view: PlanView,
+    /// Also return the planner's internal grid as `rawGrid`; see `DebugPlanResponse`.
+    #[serde(default)]
+    pub debug: bool,
+}
+
+/// Shape of a `POST /api/plan` answer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlanView {
+    /// Every cell carries its variety's name, reason and dates.
+    #[default]
+    Full,
+    /// Cells carry only `{type, id}`; names and reasons sit in one `species` dictionary.
+    Compact,
+}
+
+/// Serialises `plan` in the requested `view`.
+fn plan_view(
+    req: &HttpRequest,
+    builder: HttpResponseBuilder,
+    plan: PlanResponse,
+    links: Links,
+    view: PlanView,
+) -> HttpResponse {
+    match view {
+        PlanView::Full => negotiate(req, builder, &ApiResponse::new(plan, links)),
+        PlanView::Compact => negotiate(req, builder, &ApiResponse::new(compact_plan(plan), links)),
+    }
+}
+
+/// `201 Created` answer for a stored plan, pointing at it via `Location`.
+fn created(req: &HttpRequest, plan: PlanResponse, replayed: bool, view: PlanView) -> HttpResponse {
+    let location = format!("/api/plan/{}", plan.id.as_deref().unwrap_or_default());
+    let mut links = std::collections::BTreeMap::new();
+    links.insert("self".into(), link(location.clone(), Method::GET));
+    links.insert("delete".into(), link(location.clone(), Method::DELETE));
+    links.insert(
+        "cells".into(),
+        link(format!("{location}/cells"), Method::GET),
+    );
+    links.insert("varieties".into(), link("/api/varieties", Method::GET));
+    let mut builder = HttpResponse::Created();
+    builder.insert_header((header::LOCATION, location));
+    if replayed {
+        builder.insert_header((IDEMPOTENT_REPLAYED, "true"));
+    }
+    plan_view(req, builder, plan, links, view)
+}
+
+/// A well-formed request the planner cannot honour (empty or ragged layout, oversized
+/// grid, unknown vegetable, fill target above 100) is `422`; storage errors are `500`.
+/// Malformed JSON never reaches the use case and stays `400`.
+impl ResponseError for PlanGardenError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Plan(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::Repository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let error = match self {
+            Self::Plan(e) => e.to_string(),
+            Self::Repository(_) => {
+                log::error!("Failed to plan garden: {self}");
+                "Internal server error".to_owned()
+            }
+        };
+        HttpResponse::build(self.status_code()).json(serde_json::json!({ "error": error }))
+    }
+}
+
+/// Decodes `layoutRle`, then validates `request`. A layout the planner cannot use is
+/// `422` however it was encoded (see [`PlanGardenError`]); other invalid input is `400`.
+fn prepare(request: &mut PlanRequest) -> Result<(), HttpResponse> {
+    request
+        .expand_layout()
+        .map_err(|e| PlanGardenError::Plan(e).error_response())?;
+    request
+        .validate()
+        .map_err(|e| HttpResponse::BadRequest().json(serde_json::json!({ "error": e })))
+}
+
 /// POST /api/plan
 /// Generates an optimised garden plan based on the provided constraints.
 #[utoipa::path(
@@ -21,7 +153,12 @@ use crate::{
     path = "/api/plan",
     tag = "plan",
     params(
-        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+        ("store" = Option<bool>, Query, description = "Store the plan and answer `201 Created` with a `Location: /api/plan/{id}` header (default `false`)."),
+        ("view" = Option<String>, Query, description = "`full` (default) or `compact`: cells reduced to `{type, id}`, with names and reasons in a `species` dictionary (see `CompactPlanApiResponse`)."),
+        ("debug" = Option<bool>, Query, description = "Add the planner's internal grid as `rawGrid` (see `DebugPlanApiResponse`); default `false`. Cannot be combined with `store`, `view=compact` or an `Idempotency-Key`."),
+        ("Idempotency-Key" = Option<String>, Header, description = "Client-chosen retry key (implies `store`). Repeats with the same key return the plan stored by the first request, with `Idempotent-Replayed: true`, until it expires."),
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`."),
+        ("Accept" = Option<String>, Header, description = "`application/json` (default) or `application/xml`.")
     ),
     request_body(
         content = PlanRequest,
@@ -29,26 +166,833 @@ use crate::{
         content_type = "application/json"
     ),
     responses(
-        (status = 200, description = "Optimised garden plan",        body = PlanApiResponse),
-        (status = 400, description = "Validation error or bad JSON", body = ErrorResponse),
+        (status = 200, description = "Optimised garden plan; a `DebugPlanApiResponse` with `debug=true`",
+         content(("application/json" = PlanApiResponse), ("application/xml" = PlanApiResponse))),
+        (status = 201, description = "Optimised garden plan, stored under the returned `id`",
+         content(("application/json" = PlanApiResponse), ("application/xml" = PlanApiResponse))),
+        (status = 400, description = "Invalid preferences or weights, bad JSON, or `debug` combined with `store`, `view=compact` or an `Idempotency-Key`", body = ErrorResponse),
+        (status = 422, description = "Empty, ragged or oversized layout, fill target above 100, or unknown vegetable in layout", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
     )
 )]
 #[post("/plan")]
 pub async fn post_plan(
     req: HttpRequest,
+    query: web::Query<PlanQueryParams>,
     body: web::Json<PlanRequest>,
     repo: web::Data<Box<dyn VarietyRepository>>,
+    store: web::Data<Box<dyn PlanStore>>,
 ) -> impl Responder {
     let locale = parse_locale(&req);
-    let request = body.into_inner();
+    let store = store.as_ref().as_ref();
+    let key = match idempotency_key(&req) {
+        Ok(key) => key,
+        Err(e) => return HttpResponse::BadRequest().json(serde_json::json!({ "error": e })),
+    };
+    if let Some(plan) = key
+        .as_deref()
+        .and_then(|k| GetPlanByIdempotencyKeyUseCase::new(store).execute(k))
+    {
+        return created(&req, plan, true, query.view);
+    }
+    let mut request = body.into_inner();
+    if let Err(response) = prepare(&mut request) {
+        return response;
+    }
     let use_case = PlanGardenUseCase::new(repo.as_ref().as_ref());
+    if query.debug {
+        if key.is_some() || query.store || query.view == PlanView::Compact {
+            return HttpResponse::BadRequest().json(serde_json::json!({
+                "error": "debug cannot be combined with store, view=compact or an Idempotency-Key."
+            }));
+        }
+        return match use_case.execute_debug(&request, &locale).await {
+            Ok(response) => {
+                let mut links = std::collections::BTreeMap::new();
+                links.insert("self".into(), link("/api/plan?debug=true", Method::POST));
+                links.insert("varieties".into(), link("/api/varieties", Method::GET));
+                negotiate(&req, HttpResponse::Ok(), &ApiResponse::new(response, links))
+            }
+            Err(e) => e.error_response(),
+        };
+    }
     match use_case.execute(&request, &locale).await {
+        Ok(response) => match key {
+            Some(key) => created(
+                &req,
+                StorePlanUseCase::new(store).execute_with_key(&key, response),
+                false,
+                query.view,
+            ),
+            None if query.store => created(
+                &req,
+                StorePlanUseCase::new(store).execute(response),
+                false,
+                query.view,
+            ),
+            None => {
+                let mut links = std::collections::BTreeMap::new();
+                links.insert("self".into(), link("/api/plan", Method::POST));
+                links.insert("varieties".into(), link("/api/varieties", Method::GET));
+                plan_view(&req, HttpResponse::Ok(), response, links, query.view)
+            }
+        },
+        Err(e) => e.error_response(),
+    }
+}
+
+/// Methods `/api/plan` answers to, for the `Allow` header.
+const PLAN_ALLOW: &str = "POST, HEAD, OPTIONS";
+
+/// Request body media types `POST /api/plan` accepts, for the `Accept-Post` header.
+const PLAN_ACCEPT_POST: &str = "application/json";
+
+/// `204 No Content` advertising what `/api/plan` supports.
+fn plan_capabilities() -> HttpResponse {
+    HttpResponse::NoContent()
+        .insert_header((header::ALLOW, PLAN_ALLOW))
+        .insert_header(("Accept-Post", PLAN_ACCEPT_POST))
+        .finish()
+}
+
+/// OPTIONS /api/plan
+/// Advertises the methods and request body types of the plan endpoint.
+#[utoipa::path(
+    options,
+    path = "/api/plan",
+    tag = "plan",
+    responses(
+        (status = 204, description = "No body; `Allow: POST, HEAD, OPTIONS` and `Accept-Post: application/json`"),
+    )
+)]
+#[options("/plan")]
+pub async fn options_plan() -> impl Responder {
+    plan_capabilities()
+}
+
+/// HEAD /api/plan
+/// Lets tooling probe the plan endpoint without planning anything.
+#[utoipa::path(
+    head,
+    path = "/api/plan",
+    tag = "plan",
+    responses(
+        (status = 204, description = "No body; the same `Allow` and `Accept-Post` headers as `OPTIONS`"),
+    )
+)]
+#[head("/plan")]
+pub async fn head_plan() -> impl Responder {
+    plan_capabilities()
+}
+
+/// Entity tag of a stored plan's `version`, e.g. `"v2"`.
+fn plan_etag(version: u64) -> String {
+    format!("\"v{version}\"")
+}
+
+/// Entity tags listed in the request's `If-Match` headers; `None` when there is none.
+fn if_match_tags(req: &HttpRequest) -> Option<Vec<String>> {
+    let mut values = req
+        .headers()
+        .get_all(header::IF_MATCH)
+        .filter_map(|v| v.to_str().ok())
+        .peekable();
+    values.peek()?;
+    Some(
+        values
+            .flat_map(|v| v.split(','))
+            .map(|t| t.trim().to_owned())
+            .collect(),
+    )
+}
+
+/// Links of a stored plan.
+fn stored_plan_links(id: &str) -> Links {
+    let mut links = std::collections::BTreeMap::new();
+    links.insert("self".into(), link(format!("/api/plan/{id}"), Method::GET));
+    links.insert(
+        "update".into(),
+        link(format!("/api/plan/{id}"), Method::PUT),
+    );
+    links.insert(
+        "delete".into(),
+        link(format!("/api/plan/{id}"), Method::DELETE),
+    );
+    links.insert(
+        "cells".into(),
+        link(format!("/api/plan/{id}/cells"), Method::GET),
+    );
+    links.insert("plan".into(), link("/api/plan", Method::POST));
+    links
+}
+
+/// Spacing of every variety placed in `plan`, for its planting guide: the catalogue
+/// spacing scaled by the plan's density, as the planner laid it out.
+async fn guide_spacing(
+    req: &HttpRequest,
+    plan: &PlanResponse,
+    repo: &dyn VarietyRepository,
+) -> Result<HashMap<String, u32>, HttpResponse> {
+    let mut ids: Vec<String> = plan
+        .weeks
+        .iter()
+        .flat_map(|week| week.grid.iter().flatten())
+        .filter_map(|cell| cell.id().map(str::to_owned))
+        .collect();
+    ids.sort();
+    ids.dedup();
+    let varieties = repo
+        .get_by_ids(&ids, &parse_locale(req))
+        .await
+        .map_err(|e| {
+            log::error!("Failed to load the varieties of a planting guide: {e}");
+            HttpResponse::InternalServerError()
+                .json(serde_json::json!({ "error": "Internal server error" }))
+        })?;
+    Ok(varieties
+        .into_iter()
+        .map(|v| (v.id, plan.applied.density.scale_spacing(v.spacing_cm)))
+        .collect())
+}
+
+/// GET /api/plan/{id}
+/// Returns a plan previously stored with `POST /api/plan?store=true`.
+#[utoipa::path(
+    get,
+    path = "/api/plan/{id}",
+    tag = "plan",
+    params(
+        ("id" = String, Path, description = "Stored plan identifier"),
+        ("Accept" = Option<String>, Header, description = "`application/json` (default), `application/xml`, or `text/markdown` for a printable planting guide.")
+    ),
+    responses(
+        (status = 200, description = "Stored garden plan; its `ETag` header is required to update it. With `Accept: text/markdown`, a planting guide: the grid of every week that changed, a quantities table and one section per species",
+         content(("application/json" = PlanApiResponse), ("application/xml" = PlanApiResponse), ("text/markdown" = String))),
+        (status = 404, description = "Unknown or expired plan id", body = ErrorResponse),
+        (status = 500, description = "The varieties of a planting guide could not be loaded", body = ErrorResponse),
+    )
+)]
+#[get("/plan/{id}")]
+pub async fn get_stored_plan(
+    req: HttpRequest,
+    path: web::Path<String>,
+    store: web::Data<Box<dyn PlanStore>>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let id = path.into_inner();
+    match GetStoredPlanUseCase::new(store.as_ref().as_ref()).execute_versioned(&id) {
+        None => HttpResponse::NotFound().json(serde_json::json!({
+            "error": format!("Plan '{}' not found.", id)
+        })),
+        Some((plan, version)) => {
+            let mut builder = HttpResponse::Ok();
+            builder.insert_header((header::ETAG, plan_etag(version)));
+            if wants_markdown(&req) {
+                let spacing_cm = match guide_spacing(&req, &plan, repo.as_ref().as_ref()).await {
+                    Ok(spacing_cm) => spacing_cm,
+                    Err(response) => return response,
+                };
+                return builder
+                    .content_type(MARKDOWN)
+                    .body(planting_guide(&plan, &spacing_cm));
+            }
+            let links = stored_plan_links(&id);
+            negotiate(&req, builder, &ApiResponse::new(plan, links))
+        }
+    }
+}
+
+/// PUT /api/plan/{id}
+/// Replaces a stored plan with an edited one, guarded by `If-Match`.
+#[utoipa::path(
+    put,
+    path = "/api/plan/{id}",
+    tag = "plan",
+    params(
+        ("id" = String, Path, description = "Stored plan identifier"),
+        ("If-Match" = String, Header, description = "`ETag` of the plan as last read (or `*`); the update is refused when the plan changed since."),
+        ("Accept" = Option<String>, Header, description = "`application/json` (default) or `application/xml`.")
+    ),
+    request_body(
+        content = PlanResponse,
+        description = "The edited plan (the `payload` of `GET /api/plan/{id}`)",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Stored plan after the update, with its new `ETag`",
+         content(("application/json" = PlanApiResponse), ("application/xml" = PlanApiResponse))),
+        (status = 404, description = "Unknown or expired plan id", body = ErrorResponse),
+        (status = 412, description = "The plan changed since it was read; the `ETag` header holds its current tag", body = ErrorResponse),
+        (status = 428, description = "No `If-Match` header", body = ErrorResponse),
+    )
+)]
+#[put("/plan/{id}")]
+pub async fn put_stored_plan(
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<PlanResponse>,
+    store: web::Data<Box<dyn PlanStore>>,
+) -> impl Responder {
+    let id = path.into_inner();
+    let Some(tags) = if_match_tags(&req) else {
+        return HttpResponse::build(StatusCode::PRECONDITION_REQUIRED).json(serde_json::json!({
+            "error": "Updating a stored plan requires an If-Match header with its ETag."
+        }));
+    };
+    let matches = |version| tags.iter().any(|t| t == "*" || *t == plan_etag(version));
+    match UpdateStoredPlanUseCase::new(store.as_ref().as_ref()).execute(
+        &id,
+        matches,
+        body.into_inner(),
+    ) {
+        Err(ReplaceError::NotFound) => HttpResponse::NotFound().json(serde_json::json!({
+            "error": format!("Plan '{}' not found.", id)
+        })),
+        Err(ReplaceError::Stale { current }) => HttpResponse::PreconditionFailed()
+            .insert_header((header::ETAG, plan_etag(current)))
+            .json(serde_json::json!({
+                "error": format!(
+                    "Plan '{}' was changed since it was read; its current ETag is {}.",
+                    id,
+                    plan_etag(current)
+                )
+            })),
+        Ok((plan, version)) => {
+            let mut builder = HttpResponse::Ok();
+            builder.insert_header((header::ETAG, plan_etag(version)));
+            let links = stored_plan_links(&id);
+            negotiate(&req, builder, &ApiResponse::new(plan, links))
+        }
+    }
+}
+
+/// DELETE /api/plan/{id}
+/// Removes a stored plan.
+#[utoipa::path(
+    delete,
+    path = "/api/plan/{id}",
+    tag = "plan",
+    params(
+        ("id" = String, Path, description = "Stored plan identifier")
+    ),
+    responses(
+        (status = 204, description = "Plan deleted"),
+        (status = 404, description = "Unknown or expired plan id", body = ErrorResponse),
+    )
+)]
+#[delete("/plan/{id}")]
+pub async fn delete_stored_plan(
+    path: web::Path<String>,
+    store: web::Data<Box<dyn PlanStore>>,
+) -> impl Responder {
+    let id = path.into_inner();
+    if DeleteStoredPlanUseCase::new(store.as_ref().as_ref()).execute(&id) {
+        HttpResponse::NoContent().finish()
+    } else {
+        HttpResponse::NotFound().json(serde_json::json!({
+            "error": format!("Plan '{}' not found.", id)
+        }))
+    }
+}
+
+/// GET /api/plan/{id}/cells
+/// Streams the placed cells of a stored plan as newline-delimited JSON.
+#[utoipa::path(
+    get,
+    path = "/api/plan/{id}/cells",
+    tag = "plan",
+    params(
+        ("id" = String, Path, description = "Stored plan identifier")
+    ),
+    responses(
+        (status = 200, description = "One JSON object per placed cell and week — `week` (index into `weeks`), `row`, `col` plus the cell's fields as in the plan grid (`type`, `id`, …) — each on its own line",
+         content_type = "application/x-ndjson", body = String),
+        (status = 404, description = "Unknown or expired plan id", body = ErrorResponse),
+    )
+)]
+#[get("/plan/{id}/cells")]
+pub async fn get_stored_plan_cells(
+    path: web::Path<String>,
+    store: web::Data<Box<dyn PlanStore>>,
+) -> impl Responder {
+    let id = path.into_inner();
+    match GetStoredPlanUseCase::new(store.as_ref().as_ref()).execute(&id) {
+        None => HttpResponse::NotFound().json(serde_json::json!({
+            "error": format!("Plan '{}' not found.", id)
+        })),
+        Some(plan) => HttpResponse::Ok()
+            .content_type(NDJSON)
+            .body(PlanCellsBody::new(plan.weeks)),
+    }
+}
+
+/// POST /api/plan/diff
+/// Compares two planned grids of equal dimensions cell by cell.
+#[utoipa::path(
+    post,
+    path = "/api/plan/diff",
+    tag = "plan",
+    request_body(
+        content = PlanDiffRequest,
+        description = "The two plans to compare (`before` and `after`)",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Per-cell changes, counts and score delta", body = PlanDiffApiResponse),
+        (status = 400, description = "Grids of different dimensions or bad JSON", body = ErrorResponse),
+    )
+)]
+#[post("/plan/diff")]
+pub async fn post_plan_diff(req: HttpRequest, body: web::Json<PlanDiffRequest>) -> impl Responder {
+    match DiffPlansUseCase.execute(&body) {
         Ok(response) => {
-            let mut links = std::collections::HashMap::new();
-            links.insert("self".into(), link("/api/plan", Method::POST));
-            links.insert("varieties".into(), link("/api/varieties", Method::GET));
-            HttpResponse::Ok().json(ApiResponse::new(response, links))
+            let mut links = std::collections::BTreeMap::new();
+            links.insert("self".into(), link("/api/plan/diff", Method::POST));
+            links.insert("plan".into(), link("/api/plan", Method::POST));
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(response, links))
         }
         Err(e) => HttpResponse::BadRequest().json(serde_json::json!({ "error": e })),
     }
 }
+
+/// POST /api/plan/succession
+/// Plans the garden, then proposes follow-up crops for early-harvested cells.
+#[utoipa::path(
+    post,
+    path = "/api/plan/succession",
+    tag = "plan",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    request_body(
+        content = PlanRequest,
+        description = "Planning constraints and grid layout (same body as `POST /api/plan`)",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Base grid and succession overlay", body = SuccessionApiResponse),
+        (status = 400, description = "Invalid preferences or weights, or bad JSON", body = ErrorResponse),
+        (status = 422, description = "Empty, ragged or oversized layout, fill target above 100, or unknown vegetable in layout", body = ErrorResponse),
+        (status = 500, description = "Internal server error",           body = ErrorResponse),
+    )
+)]
+#[post("/plan/succession")]
+pub async fn post_plan_succession(
+    req: HttpRequest,
+    body: web::Json<PlanRequest>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    let mut request = body.into_inner();
+    if let Err(response) = prepare(&mut request) {
+        return response;
+    }
+    let use_case = PlanSuccessionUseCase::new(repo.as_ref().as_ref());
+    match use_case.execute(&request, &locale).await {
+        Ok(response) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert("self".into(), link("/api/plan/succession", Method::POST));
+            links.insert("plan".into(), link("/api/plan", Method::POST));
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(response, links))
+        }
+        Err(e) => e.error_response(),
+    }
+}
+
+/// POST /api/plan/transition
+/// Turns a planted bed over to another season: keeps perennials and young biennials,
+/// clears the rest and plans the freed cells.
+#[utoipa::path(
+    post,
+    path = "/api/plan/transition",
+    tag = "plan",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    request_body(
+        content = TransitionRequest,
+        description = "Target season, plus the current season's body of `POST /api/plan` with the planted `layout`",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Kept and cleared plants, and the target season's plan", body = TransitionApiResponse),
+        (status = 400, description = "Invalid preferences or weights, or bad JSON", body = ErrorResponse),
+        (status = 422, description = "Empty, ragged or oversized layout, fill target above 100, or unknown vegetable in layout", body = ErrorResponse),
+        (status = 500, description = "Internal server error",           body = ErrorResponse),
+    )
+)]
+#[post("/plan/transition")]
+pub async fn post_plan_transition(
+    req: HttpRequest,
+    body: web::Json<TransitionRequest>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    let mut request = body.into_inner();
+    if let Err(response) = prepare(&mut request.plan) {
+        return response;
+    }
+    let use_case = PlanTransitionUseCase::new(repo.as_ref().as_ref());
+    match use_case
+        .execute(&request, &locale, chrono::Local::now().date_naive())
+        .await
+    {
+        Ok(response) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert("self".into(), link("/api/plan/transition", Method::POST));
+            links.insert("plan".into(), link("/api/plan", Method::POST));
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(response, links))
+        }
+        Err(e) => e.error_response(),
+    }
+}
+
+/// POST /api/plan/trace
+/// Plans the garden and lists every placement decision the planner made, in order.
+#[utoipa::path(
+    post,
+    path = "/api/plan/trace",
+    tag = "plan",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    request_body(
+        content = PlanRequest,
+        description = "Planning constraints and grid layout (same body as `POST /api/plan`)",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Garden plan and its placement trace", body = PlanTraceApiResponse),
+        (status = 400, description = "Invalid preferences or weights, or bad JSON", body = ErrorResponse),
+        (status = 422, description = "Empty, ragged or oversized layout, fill target above 100, or unknown vegetable in layout", body = ErrorResponse),
+        (status = 500, description = "Internal server error",           body = ErrorResponse),
+    )
+)]
+#[post("/plan/trace")]
+pub async fn post_plan_trace(
+    req: HttpRequest,
+    body: web::Json<PlanRequest>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    let mut request = body.into_inner();
+    if let Err(response) = prepare(&mut request) {
+        return response;
+    }
+    let use_case = PlanGardenUseCase::new(repo.as_ref().as_ref());
+    match use_case.execute_trace(&request, &locale).await {
+        Ok(response) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert("self".into(), link("/api/plan/trace", Method::POST));
+            links.insert("plan".into(), link("/api/plan", Method::POST));
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(response, links))
+        }
+        Err(e) => e.error_response(),
+    }
+}
+
+/// POST /api/plan/quick
+/// Plans an empty grid from a season and dimensions alone, with beginner defaults.
+#[utoipa::path(
+    post,
+    path = "/api/plan/quick",
+    tag = "plan",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    request_body(
+        content = QuickPlanRequest,
+        description = "Season and grid dimensions",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Beginner-friendly garden plan", body = PlanApiResponse),
+        (status = 400, description = "Bad JSON",                        body = ErrorResponse),
+        (status = 422, description = "Empty or oversized grid",         body = ErrorResponse),
+        (status = 500, description = "Internal server error",           body = ErrorResponse),
+    )
+)]
+#[post("/plan/quick")]
+pub async fn post_plan_quick(
+    req: HttpRequest,
+    body: web::Json<QuickPlanRequest>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    let use_case = QuickPlanUseCase::new(repo.as_ref().as_ref());
+    match use_case.execute(&body, &locale).await {
+        Ok(response) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert("self".into(), link("/api/plan/quick", Method::POST));
+            links.insert("plan".into(), link("/api/plan", Method::POST));
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(response, links))
+        }
+        Err(e) => e.error_response(),
+    }
+}
+
+/// GET /api/plan/example
+/// Plans a fixed sample summer bed, to show the response shape without a request body.
+#[utoipa::path(
+    get,
+    path = "/api/plan/example",
+    tag = "plan",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    responses(
+        (status = 200, description = "Plan of the sample bed, computed by the planner", body = PlanApiResponse),
+        (status = 500, description = "Internal server error",                         body = ErrorResponse),
+    )
+)]
+#[get("/plan/example")]
+pub async fn get_plan_example(
+    req: HttpRequest,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    let use_case = ExamplePlanUseCase::new(repo.as_ref().as_ref());
+    match use_case.execute(&locale).await {
+        Ok(response) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert("self".into(), link("/api/plan/example", Method::GET));
+            links.insert("plan".into(), link("/api/plan", Method::POST));
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(response, links))
+        }
+        Err(e) => e.error_response(),
+    }
+}
+
+/// POST /api/plan/recommendations
+/// Suggests vegetables to add to a partly planted layout.
+#[utoipa::path(
+    post,
+    path = "/api/plan/recommendations",
+    tag = "plan",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    request_body(
+        content = PlanRecommendationsRequest,
+        description = "Current layout and optional season",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "In-season vegetables ranked by companion benefit to the layout", body = PlanRecommendationsApiResponse),
+        (status = 400, description = "Bad JSON",                        body = ErrorResponse),
+        (status = 422, description = "Unknown vegetable in layout",     body = ErrorResponse),
+        (status = 500, description = "Internal server error",           body = ErrorResponse),
+    )
+)]
+#[post("/plan/recommendations")]
+pub async fn post_plan_recommendations(
+    req: HttpRequest,
+    body: web::Json<PlanRecommendationsRequest>,
+    vegetable_repo: web::Data<Box<dyn VegetableRepository>>,
+    variety_repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    let use_case = RecommendPlantingsUseCase::new(
+        vegetable_repo.as_ref().as_ref(),
+        variety_repo.as_ref().as_ref(),
+    );
+    match use_case.execute(&body, &locale).await {
+        Ok(response) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert(
+                "self".into(),
+                link("/api/plan/recommendations", Method::POST),
+            );
+            links.insert("plan".into(), link("/api/plan", Method::POST));
+            links.insert(
+                "canPlace".into(),
+                link("/api/layout/can-place", Method::POST),
+            );
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(response, links))
+        }
+        Err(e) => e.error_response(),
+    }
+}
+
+/// POST /api/plan/size-for
+/// Sizes the smallest square-ish bed holding a wishlist of plants.
+#[utoipa::path(
+    post,
+    path = "/api/plan/size-for",
+    tag = "plan",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    request_body(
+        content = BedSizeRequest,
+        description = "Varieties and how many plants of each",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Bed dimensions and the room each variety takes", body = BedSizeApiResponse),
+        (status = 400, description = "Bad JSON, no preferences, an invalid quantity or area, or more plants than 10 000 cells hold", body = ErrorResponse),
+        (status = 422, description = "Unknown variety, or blocks needing more than 10 000 cells", body = ErrorResponse),
+        (status = 500, description = "Internal server error",           body = ErrorResponse),
+    )
+)]
+#[post("/plan/size-for")]
+pub async fn post_plan_size_for(
+    req: HttpRequest,
+    body: web::Json<BedSizeRequest>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    if let Err(e) = body.validate() {
+        return HttpResponse::BadRequest().json(serde_json::json!({ "error": e }));
+    }
+    let locale = parse_locale(&req);
+    match BedSizeUseCase::new(repo.as_ref().as_ref())
+        .execute(&body, &locale)
+        .await
+    {
+        Ok(response) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert("self".into(), link("/api/plan/size-for", Method::POST));
+            links.insert("plan".into(), link("/api/plan", Method::POST));
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(response, links))
+        }
+        Err(e) => e.error_response(),
+    }
+}
+
+/// POST /api/plan/place-one
+/// Adds one plant to a layout on its best block, without re-planning the rest.
+#[utoipa::path(
+    post,
+    path = "/api/plan/place-one",
+    tag = "plan",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    request_body(
+        content = PlaceOneRequest,
+        description = "Plant to add and the current layout",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Updated grid with the chosen block and reason", body = PlaceOneApiResponse),
+        (status = 400, description = "Bad JSON",                        body = ErrorResponse),
+        (status = 422, description = "Malformed layout, unknown variety or vegetable, or no free block for the plant", body = ErrorResponse),
+        (status = 500, description = "Internal server error",           body = ErrorResponse),
+    )
+)]
+#[post("/plan/place-one")]
+pub async fn post_plan_place_one(
+    req: HttpRequest,
+    body: web::Json<PlaceOneRequest>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    match PlaceOneUseCase::new(repo.as_ref().as_ref())
+        .execute(&body, &locale)
+        .await
+    {
+        Ok(response) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert("self".into(), link("/api/plan/place-one", Method::POST));
+            links.insert("plan".into(), link("/api/plan", Method::POST));
+            links.insert(
+                "variety".into(),
+                link(format!("/api/varieties/{}", response.id), Method::GET),
+            );
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(response, links))
+        }
+        Err(e) => e.error_response(),
+    }
+}
+
+/// POST /api/plan/what-if
+/// Removes plants from a layout and reports the companion score change, without re-planning.
+#[utoipa::path(
+    post,
+    path = "/api/plan/what-if",
+    tag = "plan",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    request_body(
+        content = WhatIfRequest,
+        description = "Current layout and the cell or variety to remove",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Score before and after, affected neighbours and the updated grid", body = WhatIfApiResponse),
+        (status = 400, description = "Bad JSON, or not exactly one of `cell` and `id`", body = ErrorResponse),
+        (status = 422, description = "Malformed layout, unknown variety or vegetable, or nothing to remove", body = ErrorResponse),
+        (status = 500, description = "Internal server error",           body = ErrorResponse),
+    )
+)]
+#[post("/plan/what-if")]
+pub async fn post_plan_what_if(
+    req: HttpRequest,
+    body: web::Json<WhatIfRequest>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    if let Err(e) = body.validate() {
+        return HttpResponse::BadRequest().json(serde_json::json!({ "error": e }));
+    }
+    let locale = parse_locale(&req);
+    match WhatIfUseCase::new(repo.as_ref().as_ref())
+        .execute(&body, &locale)
+        .await
+    {
+        Ok(response) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert("self".into(), link("/api/plan/what-if", Method::POST));
+            links.insert("placeOne".into(), link("/api/plan/place-one", Method::POST));
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(response, links))
+        }
+        Err(e) => e.error_response(),
+    }
+}
+
+/// POST /api/plan/batch
+/// Plans several independent scenarios in one call.
+#[utoipa::path(
+    post,
+    path = "/api/plan/batch",
+    tag = "plan",
+    params(
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    request_body(
+        content = Vec<PlanRequest>,
+        description = "Up to 20 plan requests (each the same body as `POST /api/plan`)",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "One `Ok` or `Error` item per request, in order", body = BatchPlanApiResponse),
+        (status = 400, description = "Empty or oversized batch, or a body that is not a JSON array", body = ErrorResponse),
+    )
+)]
+#[post("/plan/batch")]
+pub async fn post_plan_batch(
+    req: HttpRequest,
+    body: web::Json<Vec<serde_json::Value>>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    // Items are parsed one by one so a malformed item only fails its own slot.
+    let requests = body
+        .into_inner()
+        .into_iter()
+        .map(|item| serde_json::from_value::<PlanRequest>(item).map_err(|e| e.to_string()))
+        .collect();
+    let use_case = PlanBatchUseCase::new(repo.as_ref().as_ref());
+    match use_case.execute(requests, &locale).await {
+        Ok(response) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert("self".into(), link("/api/plan/batch", Method::POST));
+            links.insert("plan".into(), link("/api/plan", Method::POST));
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(response, links))
+        }
+        Err(e) => HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e,
+            "maxBatchSize": MAX_BATCH_SIZE
+        })),
+    }
+}
@@ -7,13 +7,13 @@ use crate::adapters::inbound::http::hateoas::{
 
 use crate::{
     adapters::inbound::http::{
+        dto::{Units, VarietyDto},
         hateoas::{link, ApiResponse, IntoHttpPagination, PaginatedResponse},
         localization::parse_locale,
+        negotiation::hal_json,
     },
     application::{
-        ports::variety_response_repository::{
-            VarietyListFilter, VarietyResponse, VarietyResponseRepository,
-        },
+        ports::variety_response_repository::{VarietyListFilter, VarietyResponseRepository},
         use_cases::varieties::{GetVarietyUseCase, ListVarietiesUseCase},
     },
     domain::models::variety::{Category, Lifecycle, Region, SoilType, SunExposure},
@@ -41,6 +41,15 @@ pub struct VarietyQueryParams {
     pub region: Option<Region>,
     pub vegetable_id: Option<String>,
     pub search: Option<String>,
+    #[serde(default)]
+    pub units: Units,
+}
+
+/// Query parameters for `GET /api/varieties/{id}`.
+#[derive(Debug, serde::Deserialize)]
+pub struct UnitsQueryParams {
+    #[serde(default)]
+    pub units: Units,
 }
 
 impl VarietyQueryParams {
@@ -75,6 +84,7 @@ impl VarietyQueryParams {
         ("region" = Option<String>, Query, description = "Filter by region (`Temperate`, `Mediterranean`, `Oceanic`, `Continental`, `Mountain`)."),
         ("vegetable_id" = Option<String>, Query, description = "Filter by parent vegetable identifier."),
         ("search" = Option<String>, Query, description = "Case-insensitive substring search on the translated variety name."),
+        ("units" = Option<String>, Query, description = "`imperial` adds `spacingIn` and `yieldPerPlantOz` to each variety (default: `metric`)."),
         ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
     ),
     responses(
@@ -91,6 +101,7 @@ pub async fn list_varieties(
     let locale = parse_locale(&req);
     let page = query.page.max(1);
     let size = query.size.max(1);
+    let units = query.units;
     let filter = query.into_inner().into_filter();
     match ListVarietiesUseCase::new(repo.as_ref().as_ref())
         .execute(&locale, page, size, &filter)
@@ -103,13 +114,13 @@ pub async fn list_varieties(
         }
         Ok(result) => {
             let pagination = result.to_pagination(page, size);
-            let items: Vec<ApiResponse<VarietyResponse>> = result
+            let items: Vec<ApiResponse<VarietyDto>> = result
                 .items
                 .into_iter()
                 .map(|v| {
                     let id = v.id.clone();
                     let vegetable_id = v.vegetable_id.clone();
-                    let mut links = std::collections::HashMap::new();
+                    let mut links = std::collections::BTreeMap::new();
                     links.insert(
                         "self".into(),
                         link(format!("/api/varieties/{id}"), Method::GET),
@@ -121,12 +132,16 @@ pub async fn list_varieties(
                             Method::GET,
                         ),
                     );
-                    ApiResponse::new(v, links)
+                    ApiResponse::new(VarietyDto::new(v, units), links)
                 })
                 .collect();
-            let mut collection_links = std::collections::HashMap::new();
+            let mut collection_links = std::collections::BTreeMap::new();
             collection_links.insert("self".into(), link("/api/varieties", Method::GET));
-            HttpResponse::Ok().json(PaginatedResponse::new(items, collection_links, pagination))
+            hal_json(
+                &req,
+                HttpResponse::Ok(),
+                &PaginatedResponse::new(items, collection_links, pagination),
+            )
         }
     }
 }
@@ -139,6 +154,7 @@ pub async fn list_varieties(
     tag = "varieties",
     params(
         ("id" = String, Path, description = "Variety identifier (e.g. `tomato`, `basil`)"),
+        ("units" = Option<String>, Query, description = "`imperial` adds `spacingIn` and `yieldPerPlantOz` (default: `metric`)."),
         ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
     ),
     responses(
@@ -150,6 +166,7 @@ pub async fn list_varieties(
 pub async fn get_variety(
     req: HttpRequest,
     path: web::Path<String>,
+    query: web::Query<UnitsQueryParams>,
     repo: web::Data<Box<dyn VarietyResponseRepository>>,
 ) -> impl Responder {
     let locale = parse_locale(&req);
@@ -168,7 +185,7 @@ pub async fn get_variety(
         })),
         Ok(Some(variety)) => {
             let vegetable_id = variety.vegetable_id.clone();
-            let mut links = std::collections::HashMap::new();
+            let mut links = std::collections::BTreeMap::new();
             links.insert(
                 "self".into(),
                 link(format!("/api/varieties/{id}"), Method::GET),
@@ -181,7 +198,8 @@ pub async fn get_variety(
                 ),
             );
             links.insert("collection".into(), link("/api/varieties", Method::GET));
-            HttpResponse::Ok().json(ApiResponse::new(variety, links))
+            let variety = VarietyDto::new(variety, query.units);
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(variety, links))
         }
     }
 }
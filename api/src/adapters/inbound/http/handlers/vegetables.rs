@@ -1,33 +1,41 @@
-use actix_web::{get, http::Method, web, HttpRequest, HttpResponse, Responder};
+use actix_web::{get, http::Method, web, HttpRequest, HttpResponse, Responder, ResponseError};
 // Types referenced only in #[utoipa::path] attributes — used at proc-macro expansion time.
 #[allow(unused_imports)]
 use crate::adapters::inbound::http::hateoas::{
-    CompanionsApiResponse, ErrorResponse, VarietiesApiResponse, VegetableApiResponse,
-    VegetablesApiResponse,
+    CompanionSuggestionsApiResponse, CompanionsApiResponse, ErrorResponse,
+    NeighborsPreviewApiResponse, PlanApiResponse, VarietiesApiResponse, VegetableApiResponse,
+    VegetablesApiResponse, VegetablesByCategoryApiResponse,
 };
 
 use crate::{
     adapters::inbound::http::{
-        hateoas::{link, ApiResponse, IntoHttpPagination, PaginatedResponse, PaginationParams},
+        dto::{Units, VarietyDto},
+        hateoas::{
+            link, ApiResponse, IntoHttpPagination, PaginatedResponse, PaginationParams,
+            VegetablesByCategory,
+        },
         localization::parse_locale,
+        negotiation::{hal_json, negotiate, negotiate_cached},
     },
     application::{
         ports::{
-            variety_response_repository::{
-                VarietyListFilter, VarietyResponse, VarietyResponseRepository,
-            },
+            variety_repository::VarietyRepository,
+            variety_response_repository::{VarietyListFilter, VarietyResponseRepository},
             vegetable_repository::VegetableRepository,
         },
         use_cases::{
+            plan_preview::PlanPreviewUseCase,
             varieties::ListVarietiesByVegetableUseCase,
             vegetables::{
-                GetVegetableCompanionsUseCase, GetVegetableUseCase, ListVegetablesUseCase,
+                GetCompanionSuggestionsUseCase, GetNeighborsPreviewUseCase,
+                GetVegetableCompanionsUseCase, GetVegetableUseCase,
+                ListVegetablesByCategoryUseCase, ListVegetablesUseCase, RandomVegetableUseCase,
             },
         },
     },
     domain::models::{
         response::CompanionsResponse,
-        variety::{Category, Lifecycle, Region, SoilType, SunExposure},
+        variety::{Category, Lifecycle, Region, Season, SoilType, SunExposure},
         vegetable::Vegetable,
     },
 };
@@ -54,6 +62,8 @@ pub struct VarietyByVegetableQueryParams {
     pub soil_type: Option<SoilType>,
     pub region: Option<Region>,
     pub search: Option<String>,
+    #[serde(default)]
+    pub units: Units,
 }
 
 impl VarietyByVegetableQueryParams {
@@ -71,6 +81,41 @@ impl VarietyByVegetableQueryParams {
     }
 }
 
+/// Query parameters for `GET /api/vegetables/{id}/suggestions`.
+#[derive(Debug, serde::Deserialize)]
+pub struct SuggestionsQueryParams {
+    pub season: Option<Season>,
+}
+
+/// Query parameters for `GET /api/vegetables/random`.
+#[derive(Debug, serde::Deserialize)]
+pub struct RandomVegetableQueryParams {
+    pub season: Option<Season>,
+    #[serde(default)]
+    pub beginner: bool,
+    pub seed: Option<u64>,
+}
+
+/// Wraps each vegetable in its envelope, linking to itself and its companions.
+fn vegetable_items(vegetables: Vec<Vegetable>) -> Vec<ApiResponse<Vegetable>> {
+    vegetables
+        .into_iter()
+        .map(|v| {
+            let id = v.id.clone();
+            let mut links = std::collections::BTreeMap::new();
+            links.insert(
+                "self".into(),
+                link(format!("/api/vegetables/{id}"), Method::GET),
+            );
+            links.insert(
+                "companions".into(),
+                link(format!("/api/vegetables/{id}/companions"), Method::GET),
+            );
+            ApiResponse::new(v, links)
+        })
+        .collect()
+}
+
 /// GET /api/vegetables
 /// Returns all vegetables from the database.
 #[utoipa::path(
@@ -80,11 +125,14 @@ impl VarietyByVegetableQueryParams {
     params(
         ("page" = Option<usize>, Query, description = "Page number (1-based, default: 1)."),
         ("size" = Option<usize>, Query, description = "Items per page (default: 20)."),
-        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`."),
+        ("Accept" = Option<String>, Header, description = "`application/json` (default) or `application/xml`."),
+        ("If-None-Match" = Option<String>, Header, description = "`ETag` of a cached copy; answers `304` when still current.")
     ),
     responses(
-        (status = 200, description = "Paginated list of all vegetables",
-         body = VegetablesApiResponse),
+        (status = 200, description = "Paginated list of all vegetables; carries an `ETag`",
+         content(("application/json" = VegetablesApiResponse), ("application/xml" = VegetablesApiResponse))),
+        (status = 304, description = "`If-None-Match` matches the current `ETag`; empty body"),
     )
 )]
 #[get("/vegetables")]
@@ -107,26 +155,221 @@ pub async fn list_vegetables(
         }
         Ok(result) => {
             let pagination = result.to_pagination(page, size);
-            let items: Vec<ApiResponse<Vegetable>> = result
-                .items
+            let items = vegetable_items(result.items);
+            let mut collection_links = std::collections::BTreeMap::new();
+            collection_links.insert("self".into(), link("/api/vegetables", Method::GET));
+            negotiate_cached(
+                &req,
+                &PaginatedResponse::new(items, collection_links, pagination),
+            )
+        }
+    }
+}
+
+/// GET /api/vegetables/by-category
+/// Returns the catalogue grouped by variety category, one page per category.
+#[utoipa::path(
+    get,
+    path = "/api/vegetables/by-category",
+    tag = "vegetables",
+    params(
+        ("page" = Option<usize>, Query, description = "Page number within each category (1-based, default: 1)."),
+        ("size" = Option<usize>, Query, description = "Items per page within each category (default: 20)."),
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    responses(
+        (status = 200, description = "Paginated vegetable lists keyed by category (e.g. `herb`)", body = VegetablesByCategoryApiResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+#[get("/vegetables/by-category")]
+pub async fn list_vegetables_grouped_by_category(
+    req: HttpRequest,
+    query: web::Query<PaginationParams>,
+    vegetable_repo: web::Data<Box<dyn VegetableRepository>>,
+    variety_repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    let page = query.page.max(1);
+    let size = query.size.max(1);
+    match ListVegetablesByCategoryUseCase::new(
+        vegetable_repo.as_ref().as_ref(),
+        variety_repo.as_ref().as_ref(),
+    )
+    .execute_grouped(&locale, page, size)
+    .await
+    {
+        Err(e) => {
+            log::error!("Failed to group vegetables by category: {e}");
+            HttpResponse::InternalServerError()
+                .json(serde_json::json!({ "error": "Internal server error" }))
+        }
+        Ok(groups) => {
+            let groups = groups
                 .into_iter()
-                .map(|v| {
-                    let id = v.id.clone();
-                    let mut links = std::collections::HashMap::new();
+                .map(|(category, result)| {
+                    let key = category.to_string();
+                    let pagination = result.to_pagination(page, size);
+                    let mut links = std::collections::BTreeMap::new();
                     links.insert(
                         "self".into(),
-                        link(format!("/api/vegetables/{id}"), Method::GET),
-                    );
-                    links.insert(
-                        "companions".into(),
-                        link(format!("/api/vegetables/{id}/companions"), Method::GET),
+                        link(format!("/api/categories/{key}/vegetables"), Method::GET),
                     );
-                    ApiResponse::new(v, links)
+                    let list =
+                        PaginatedResponse::new(vegetable_items(result.items), links, pagination);
+                    (key, list)
                 })
                 .collect();
-            let mut collection_links = std::collections::HashMap::new();
-            collection_links.insert("self".into(), link("/api/vegetables", Method::GET));
-            HttpResponse::Ok().json(PaginatedResponse::new(items, collection_links, pagination))
+            let mut links = std::collections::BTreeMap::new();
+            links.insert(
+                "self".into(),
+                link("/api/vegetables/by-category", Method::GET),
+            );
+            links.insert("categories".into(), link("/api/categories", Method::GET));
+            hal_json(
+                &req,
+                HttpResponse::Ok(),
+                &ApiResponse::new(VegetablesByCategory(groups), links),
+            )
+        }
+    }
+}
+
+/// GET /api/categories/{category}/vegetables
+/// Returns the vegetables with at least one variety in a category.
+#[utoipa::path(
+    get,
+    path = "/api/categories/{category}/vegetables",
+    tag = "vegetables",
+    params(
+        ("category" = String, Path, description = "Category, case-insensitive (e.g. `herb`, `Fruit`)"),
+        ("page" = Option<usize>, Query, description = "Page number (1-based, default: 1)."),
+        ("size" = Option<usize>, Query, description = "Items per page (default: 20)."),
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    responses(
+        (status = 200, description = "Paginated list of vegetables in this category", body = VegetablesApiResponse),
+        (status = 404, description = "Unknown category", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+#[get("/categories/{category}/vegetables")]
+pub async fn list_vegetables_by_category(
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<PaginationParams>,
+    vegetable_repo: web::Data<Box<dyn VegetableRepository>>,
+    variety_repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    let raw = path.into_inner();
+    let Some(category) = Category::all()
+        .iter()
+        .find(|c| c.to_string().eq_ignore_ascii_case(&raw))
+    else {
+        return HttpResponse::NotFound().json(serde_json::json!({
+            "error": format!("Category '{}' not found.", raw)
+        }));
+    };
+    let page = query.page.max(1);
+    let size = query.size.max(1);
+    match ListVegetablesByCategoryUseCase::new(
+        vegetable_repo.as_ref().as_ref(),
+        variety_repo.as_ref().as_ref(),
+    )
+    .execute(category, &locale, page, size)
+    .await
+    {
+        Err(e) => {
+            log::error!("Failed to fetch vegetables for category '{category}': {e}");
+            HttpResponse::InternalServerError()
+                .json(serde_json::json!({ "error": "Internal server error" }))
+        }
+        Ok(result) => {
+            let pagination = result.to_pagination(page, size);
+            let mut links = std::collections::BTreeMap::new();
+            links.insert(
+                "self".into(),
+                link(
+                    format!("/api/categories/{category}/vegetables"),
+                    Method::GET,
+                ),
+            );
+            links.insert("categories".into(), link("/api/categories", Method::GET));
+            hal_json(
+                &req,
+                HttpResponse::Ok(),
+                &PaginatedResponse::new(vegetable_items(result.items), links, pagination),
+            )
+        }
+    }
+}
+
+/// GET /api/vegetables/random
+/// Returns one random vegetable, optionally in season and beginner-friendly.
+#[utoipa::path(
+    get,
+    path = "/api/vegetables/random",
+    tag = "vegetables",
+    params(
+        ("season" = Option<Season>, Query, description = "Only vegetables with a variety active in this season (e.g. `Summer`)."),
+        ("beginner" = Option<bool>, Query, description = "Only vegetables with at least one beginner-friendly variety (default: `false`)."),
+        ("seed" = Option<u64>, Query, description = "Makes the draw deterministic: the same seed over the same catalogue returns the same vegetable."),
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    responses(
+        (status = 200, description = "A randomly drawn vegetable", body = VegetableApiResponse),
+        (status = 404, description = "No vegetable matches the filters", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+#[get("/vegetables/random")]
+pub async fn get_random_vegetable(
+    req: HttpRequest,
+    query: web::Query<RandomVegetableQueryParams>,
+    vegetable_repo: web::Data<Box<dyn VegetableRepository>>,
+    variety_repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    let query = query.into_inner();
+    let seed = query
+        .seed
+        .unwrap_or_else(|| uuid::Uuid::new_v4().as_u64_pair().0);
+    match RandomVegetableUseCase::new(
+        vegetable_repo.as_ref().as_ref(),
+        variety_repo.as_ref().as_ref(),
+    )
+    .execute(&locale, query.season.as_ref(), query.beginner, seed)
+    .await
+    {
+        Err(e) => {
+            log::error!("Failed to draw a random vegetable: {e}");
+            HttpResponse::InternalServerError()
+                .json(serde_json::json!({ "error": "Internal server error" }))
+        }
+        Ok(None) => HttpResponse::NotFound().json(serde_json::json!({
+            "error": "No vegetable matches the requested filters."
+        })),
+        Ok(Some(vegetable)) => {
+            let id = vegetable.id.clone();
+            let mut links = std::collections::BTreeMap::new();
+            links.insert(
+                "self".into(),
+                link(format!("/api/vegetables/{id}"), Method::GET),
+            );
+            links.insert(
+                "companions".into(),
+                link(format!("/api/vegetables/{id}/companions"), Method::GET),
+            );
+            links.insert(
+                "varieties".into(),
+                link(format!("/api/vegetables/{id}/varieties"), Method::GET),
+            );
+            hal_json(
+                &req,
+                HttpResponse::Ok(),
+                &ApiResponse::new(vegetable, links),
+            )
         }
     }
 }
@@ -139,10 +382,14 @@ pub async fn list_vegetables(
     tag = "vegetables",
     params(
         ("id" = String, Path, description = "Vegetable identifier (e.g. `tomato`, `brassica`)"),
-        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`."),
+        ("Accept" = Option<String>, Header, description = "`application/json` (default) or `application/xml`."),
+        ("If-None-Match" = Option<String>, Header, description = "`ETag` of a cached copy; answers `304` when still current.")
     ),
     responses(
-        (status = 200, description = "Vegetable found", body = VegetableApiResponse),
+        (status = 200, description = "Vegetable found; carries an `ETag`",
+         content(("application/json" = VegetableApiResponse), ("application/xml" = VegetableApiResponse))),
+        (status = 304, description = "`If-None-Match` matches the current `ETag`; empty body"),
         (status = 404, description = "Vegetable not found", body = ErrorResponse),
     )
 )]
@@ -167,7 +414,9 @@ pub async fn get_vegetable(
             "error": format!("Vegetable '{}' not found.", id)
         })),
         Ok(Some(vegetable)) => {
-            let mut links = std::collections::HashMap::new();
+            // Links use the canonical id, even when the vegetable was looked up by alias.
+            let id = &vegetable.id;
+            let mut links = std::collections::BTreeMap::new();
             links.insert(
                 "self".into(),
                 link(format!("/api/vegetables/{id}"), Method::GET),
@@ -177,7 +426,7 @@ pub async fn get_vegetable(
                 link(format!("/api/vegetables/{id}/companions"), Method::GET),
             );
             links.insert("collection".into(), link("/api/vegetables", Method::GET));
-            HttpResponse::Ok().json(ApiResponse::new(vegetable, links))
+            negotiate_cached(&req, &ApiResponse::new(vegetable, links))
         }
     }
 }
@@ -199,6 +448,7 @@ pub async fn get_vegetable(
         ("soil_type" = Option<String>, Query, description = "Filter by soil type (`Clay`, `Sandy`, `Loamy`, `Chalky`, `Humus`)."),
         ("region" = Option<String>, Query, description = "Filter by region (`Temperate`, `Mediterranean`, `Oceanic`, `Continental`, `Mountain`)."),
         ("search" = Option<String>, Query, description = "Case-insensitive substring search on the translated variety name."),
+        ("units" = Option<String>, Query, description = "`imperial` adds `spacingIn` and `yieldPerPlantOz` to each variety (default: `metric`)."),
         ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
     ),
     responses(
@@ -218,6 +468,7 @@ pub async fn get_varieties_by_vegetable(
     let id = path.into_inner();
     let page = query.page.max(1);
     let size = query.size.max(1);
+    let units = query.units;
     let filter = query.into_inner().into_filter();
 
     // 404 if the vegetable doesn't exist
@@ -249,13 +500,13 @@ pub async fn get_varieties_by_vegetable(
         }
         Ok(result) => {
             let pagination = result.to_pagination(page, size);
-            let items: Vec<ApiResponse<VarietyResponse>> = result
+            let items: Vec<ApiResponse<VarietyDto>> = result
                 .items
                 .into_iter()
                 .map(|v| {
                     let vid = v.id.clone();
                     let vegetable_id = v.vegetable_id.clone();
-                    let mut links = std::collections::HashMap::new();
+                    let mut links = std::collections::BTreeMap::new();
                     links.insert(
                         "self".into(),
                         link(format!("/api/varieties/{vid}"), Method::GET),
@@ -267,10 +518,10 @@ pub async fn get_varieties_by_vegetable(
                             Method::GET,
                         ),
                     );
-                    ApiResponse::new(v, links)
+                    ApiResponse::new(VarietyDto::new(v, units), links)
                 })
                 .collect();
-            let mut collection_links = std::collections::HashMap::new();
+            let mut collection_links = std::collections::BTreeMap::new();
             collection_links.insert(
                 "self".into(),
                 link(format!("/api/vegetables/{id}/varieties"), Method::GET),
@@ -279,7 +530,11 @@ pub async fn get_varieties_by_vegetable(
                 "vegetable".into(),
                 link(format!("/api/vegetables/{id}"), Method::GET),
             );
-            HttpResponse::Ok().json(PaginatedResponse::new(items, collection_links, pagination))
+            hal_json(
+                &req,
+                HttpResponse::Ok(),
+                &PaginatedResponse::new(items, collection_links, pagination),
+            )
         }
     }
 }
@@ -292,10 +547,12 @@ pub async fn get_varieties_by_vegetable(
     tag = "vegetables",
     params(
         ("id" = String, Path, description = "Vegetable identifier (e.g. `tomato`, `brassica`)"),
-        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`."),
+        ("Accept" = Option<String>, Header, description = "`application/json` (default) or `application/xml`.")
     ),
     responses(
-        (status = 200, description = "Companion planting info", body = CompanionsApiResponse),
+        (status = 200, description = "Companion planting info",
+         content(("application/json" = CompanionsApiResponse), ("application/xml" = CompanionsApiResponse))),
         (status = 404, description = "Vegetable not found",    body = ErrorResponse),
     )
 )]
@@ -320,24 +577,197 @@ pub async fn get_companions(
             "error": format!("Vegetable '{}' not found.", id)
         })),
         Ok(Some(data)) => {
-            let mut links = std::collections::HashMap::new();
+            let mut links = std::collections::BTreeMap::new();
+            links.insert(
+                "self".into(),
+                link(format!("/api/vegetables/{id}/companions"), Method::GET),
+            );
+            links.insert(
+                "vegetable".into(),
+                link(format!("/api/vegetables/{id}"), Method::GET),
+            );
+            negotiate(
+                &req,
+                HttpResponse::Ok(),
+                &ApiResponse::new(
+                    CompanionsResponse {
+                        id: data.vegetable.id,
+                        name: data.vegetable.name,
+                        good: data.good,
+                        bad: data.bad,
+                        notes: data.notes,
+                    },
+                    links,
+                ),
+            )
+        }
+    }
+}
+
+/// GET /api/vegetables/{id}/suggestions
+/// Returns other catalogue vegetables ranked by how beneficial they are as neighbours.
+#[utoipa::path(
+    get,
+    path = "/api/vegetables/{id}/suggestions",
+    tag = "vegetables",
+    params(
+        ("id" = String, Path, description = "Vegetable identifier (e.g. `tomato`, `brassica`)"),
+        ("season" = Option<String>, Query, description = "Only suggest vegetables active in this season (`Spring`, `Summer`, `Autumn`, `Winter`)."),
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    responses(
+        (status = 200, description = "Ranked neighbour suggestions (bad companions excluded)", body = CompanionSuggestionsApiResponse),
+        (status = 404, description = "Vegetable not found", body = ErrorResponse),
+    )
+)]
+#[get("/vegetables/{id}/suggestions")]
+pub async fn get_suggestions(
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<SuggestionsQueryParams>,
+    vegetable_repo: web::Data<Box<dyn VegetableRepository>>,
+    variety_repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    let id = path.into_inner();
+    match GetCompanionSuggestionsUseCase::new(
+        vegetable_repo.as_ref().as_ref(),
+        variety_repo.as_ref().as_ref(),
+    )
+    .execute(&id, &locale, query.season.as_ref())
+    .await
+    {
+        Err(e) => {
+            log::error!("Failed to fetch suggestions for vegetable '{id}': {e}");
+            HttpResponse::InternalServerError()
+                .json(serde_json::json!({ "error": "Internal server error" }))
+        }
+        Ok(None) => HttpResponse::NotFound().json(serde_json::json!({
+            "error": format!("Vegetable '{}' not found.", id)
+        })),
+        Ok(Some(data)) => {
+            let mut links = std::collections::BTreeMap::new();
             links.insert(
                 "self".into(),
+                link(format!("/api/vegetables/{id}/suggestions"), Method::GET),
+            );
+            links.insert(
+                "companions".into(),
                 link(format!("/api/vegetables/{id}/companions"), Method::GET),
             );
             links.insert(
                 "vegetable".into(),
                 link(format!("/api/vegetables/{id}"), Method::GET),
             );
-            HttpResponse::Ok().json(ApiResponse::new(
-                CompanionsResponse {
-                    id: data.vegetable.id,
-                    name: data.vegetable.name,
-                    good: data.good,
-                    bad: data.bad,
-                },
-                links,
-            ))
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(data, links))
+        }
+    }
+}
+
+/// GET /api/vegetables/{id}/neighbors-preview
+/// Returns a small grid illustrating a vegetable's good and bad neighbours.
+#[utoipa::path(
+    get,
+    path = "/api/vegetables/{id}/neighbors-preview",
+    tag = "vegetables",
+    params(
+        ("id" = String, Path, description = "Vegetable identifier (e.g. `tomato`, `brassica`)"),
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    responses(
+        (status = 200, description = "3×3 grid with the vegetable in the centre and its companion score", body = NeighborsPreviewApiResponse),
+        (status = 404, description = "Vegetable not found", body = ErrorResponse),
+    )
+)]
+#[get("/vegetables/{id}/neighbors-preview")]
+pub async fn get_neighbors_preview(
+    req: HttpRequest,
+    path: web::Path<String>,
+    repo: web::Data<Box<dyn VegetableRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    let id = path.into_inner();
+    match GetNeighborsPreviewUseCase::new(repo.as_ref().as_ref())
+        .execute(&id, &locale)
+        .await
+    {
+        Err(e) => {
+            log::error!("Failed to build neighbours preview for vegetable '{id}': {e}");
+            HttpResponse::InternalServerError()
+                .json(serde_json::json!({ "error": "Internal server error" }))
+        }
+        Ok(None) => HttpResponse::NotFound().json(serde_json::json!({
+            "error": format!("Vegetable '{}' not found.", id)
+        })),
+        Ok(Some(preview)) => {
+            let id = preview.id.clone();
+            let mut links = std::collections::BTreeMap::new();
+            links.insert(
+                "self".into(),
+                link(
+                    format!("/api/vegetables/{id}/neighbors-preview"),
+                    Method::GET,
+                ),
+            );
+            links.insert(
+                "companions".into(),
+                link(format!("/api/vegetables/{id}/companions"), Method::GET),
+            );
+            links.insert(
+                "vegetable".into(),
+                link(format!("/api/vegetables/{id}"), Method::GET),
+            );
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(preview, links))
+        }
+    }
+}
+
+/// GET /api/vegetables/{id}/plan-preview
+/// Plans a small bed planted with this vegetable alone.
+#[utoipa::path(
+    get,
+    path = "/api/vegetables/{id}/plan-preview",
+    tag = "vegetables",
+    params(
+        ("id" = String, Path, description = "Vegetable identifier (e.g. `tomato`, `pumpkin`)"),
+        ("Accept-Language" = Option<String>, Header, description = "BCP 47 language tag (e.g. `fr`, `en`). Falls back to `en`.")
+    ),
+    responses(
+        (status = 200, description = "One-week plan of a 4×8 bed filled with the vegetable", body = PlanApiResponse),
+        (status = 404, description = "Vegetable not found", body = ErrorResponse),
+    )
+)]
+#[get("/vegetables/{id}/plan-preview")]
+pub async fn get_plan_preview(
+    req: HttpRequest,
+    path: web::Path<String>,
+    repo: web::Data<Box<dyn VarietyRepository>>,
+) -> impl Responder {
+    let locale = parse_locale(&req);
+    let id = path.into_inner();
+    match PlanPreviewUseCase::new(repo.as_ref().as_ref())
+        .execute(&id, &locale)
+        .await
+    {
+        Err(e) => e.error_response(),
+        Ok(None) => HttpResponse::NotFound().json(serde_json::json!({
+            "error": format!("Vegetable '{}' not found.", id)
+        })),
+        Ok(Some(plan)) => {
+            let mut links = std::collections::BTreeMap::new();
+            links.insert(
+                "self".into(),
+                link(format!("/api/vegetables/{id}/plan-preview"), Method::GET),
+            );
+            links.insert(
+                "varieties".into(),
+                link(format!("/api/vegetables/{id}/varieties"), Method::GET),
+            );
+            links.insert(
+                "vegetable".into(),
+                link(format!("/api/vegetables/{id}"), Method::GET),
+            );
+            hal_json(&req, HttpResponse::Ok(), &ApiResponse::new(plan, links))
         }
     }
 }
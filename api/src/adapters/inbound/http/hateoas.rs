@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use actix_web::http::Method;
 use serde::{Deserialize, Serialize};
@@ -6,10 +6,17 @@ use utoipa::ToSchema;
 
 use crate::application::ports::Page;
 
-use crate::adapters::inbound::http::dto::VarietyResponse;
+use crate::adapters::inbound::http::dto::{HealthResponse, VarietyDto};
 use crate::domain::models::{
     group::Group,
-    response::{CompanionsResponse, PlanResponse},
+    response::{
+        BatchPlanResponse, BedSizeResponse, BulkPlantResponse, CategoriesResponse,
+        CompactPlanResponse, CompanionDemoResponse, CompanionSuggestionsResponse,
+        CompanionsResponse, DebugPlanResponse, EnumsResponse, LayoutValidationResponse,
+        NeighborsPreviewResponse, PlaceOneResponse, PlacementPreviewResponse, PlanDiffResponse,
+        PlanRecommendationsResponse, PlanResponse, PlanTraceResponse, SuccessionResponse,
+        TransitionResponse, WhatIfResponse,
+    },
     vegetable::Vegetable,
 };
 
@@ -44,8 +51,9 @@ pub struct Link {
     pub method: Method,
 }
 
-/// Map of relation name -> link, serialised as the `_links` field in responses.
-pub type Links = HashMap<String, Link>;
+/// Map of relation name -> link, serialised as the `_links` field in responses, in
+/// relation-name order.
+pub type Links = BTreeMap<String, Link>;
 
 /// Helper to build a `Link` from an href and an HTTP method.
 pub fn link(href: impl Into<String>, method: Method) -> Link {
@@ -97,16 +105,37 @@ impl Default for PaginationParams {
 /// Generic single-item response envelope.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[aliases(
-    VarietyApiResponse   = ApiResponse<VarietyResponse>,
+    VarietyApiResponse   = ApiResponse<VarietyDto>,
     VegetableApiResponse     = ApiResponse<Vegetable>,
     PlanApiResponse        = ApiResponse<PlanResponse>,
+    CompactPlanApiResponse = ApiResponse<CompactPlanResponse>,
+    DebugPlanApiResponse   = ApiResponse<DebugPlanResponse>,
+    PlanTraceApiResponse   = ApiResponse<PlanTraceResponse>,
+    VegetablesByCategoryApiResponse = ApiResponse<VegetablesByCategory>,
+    PlanDiffApiResponse    = ApiResponse<PlanDiffResponse>,
+    PlanRecommendationsApiResponse = ApiResponse<PlanRecommendationsResponse>,
+    WhatIfApiResponse      = ApiResponse<WhatIfResponse>,
+    PlaceOneApiResponse    = ApiResponse<PlaceOneResponse>,
+    BedSizeApiResponse     = ApiResponse<BedSizeResponse>,
+    BatchPlanApiResponse   = ApiResponse<BatchPlanResponse>,
+    SuccessionApiResponse  = ApiResponse<SuccessionResponse>,
+    TransitionApiResponse  = ApiResponse<TransitionResponse>,
     CompanionsApiResponse  = ApiResponse<CompanionsResponse>,
-    GroupApiResponse       = ApiResponse<Group>
+    CompanionSuggestionsApiResponse = ApiResponse<CompanionSuggestionsResponse>,
+    NeighborsPreviewApiResponse = ApiResponse<NeighborsPreviewResponse>,
+    CompanionDemoApiResponse = ApiResponse<CompanionDemoResponse>,
+    EnumsApiResponse       = ApiResponse<EnumsResponse>,
+    CategoriesApiResponse  = ApiResponse<CategoriesResponse>,
+    LayoutValidationApiResponse = ApiResponse<LayoutValidationResponse>,
+    PlacementPreviewApiResponse = ApiResponse<PlacementPreviewResponse>,
+    BulkPlantApiResponse   = ApiResponse<BulkPlantResponse>,
+    GroupApiResponse       = ApiResponse<Group>,
+    HealthApiResponse      = ApiResponse<HealthResponse>
 )]
 pub struct ApiResponse<T> {
     pub payload: T,
     /// HAL-style hypermedia links.
-    #[schema(value_type = HashMap<String, Link>)]
+    #[schema(value_type = BTreeMap<String, Link>)]
     #[serde(rename = "_links")]
     pub links: Links,
 }
@@ -127,7 +156,7 @@ impl<T> ApiResponse<T> {
 pub struct PaginatedResponse<T> {
     pub payload: Vec<T>,
     /// HAL-style hypermedia links.
-    #[schema(value_type = HashMap<String, Link>)]
+    #[schema(value_type = BTreeMap<String, Link>)]
     #[serde(rename = "_links")]
     pub links: Links,
     pub pagination: Pagination,
@@ -143,6 +172,15 @@ impl<T> PaginatedResponse<T> {
     }
 }
 
+/// One paginated vegetable list per category, keyed by the category's display form
+/// (e.g. `herb`).
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(transparent)]
+pub struct VegetablesByCategory(
+    #[schema(value_type = BTreeMap<String, VegetablesApiResponse>)]
+    pub  BTreeMap<String, PaginatedResponse<ApiResponse<Vegetable>>>,
+);
+
 /// Extension trait that converts a `Page<T>` (application layer) into an HTTP
 /// `Pagination` metadata block, so handlers do not have to repeat the
 /// `total_pages` calculation.
@@ -0,0 +1,35 @@
+use actix_web::{http::header::HeaderName, HttpRequest};
+
+/// Request header making `POST /api/plan` safe to retry: the plan is stored under the
+/// key, and repeats with the same key replay it instead of planning again.
+pub const IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
+
+/// Response header set to `true` when the body replays a plan stored by an earlier
+/// request with the same [`IDEMPOTENCY_KEY`].
+pub const IDEMPOTENT_REPLAYED: HeaderName = HeaderName::from_static("idempotent-replayed");
+
+/// Longest accepted idempotency key.
+const MAX_IDEMPOTENCY_KEY_LEN: usize = 255;
+
+/// Reads the `Idempotency-Key` header; `Err` when it is present but not a short
+/// printable token.
+pub fn idempotency_key(req: &HttpRequest) -> Result<Option<String>, String> {
+    let Some(value) = req.headers().get(&IDEMPOTENCY_KEY) else {
+        return Ok(None);
+    };
+    value
+        .to_str()
+        .ok()
+        .map(str::trim)
+        .filter(|key| {
+            !key.is_empty()
+                && key.len() <= MAX_IDEMPOTENCY_KEY_LEN
+                && key.chars().all(|c| c.is_ascii_graphic())
+        })
+        .map(|key| Some(key.to_owned()))
+        .ok_or_else(|| {
+            format!(
+                "Idempotency-Key must be 1 to {MAX_IDEMPOTENCY_KEY_LEN} printable ASCII characters."
+            )
+        })
+}
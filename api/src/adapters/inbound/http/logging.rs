@@ -0,0 +1,44 @@
+use std::io::Write;
+
+use crate::adapters::inbound::http::request_id::current_request_id;
+
+/// Environment variable selecting the log line format: `json`, or plain text otherwise.
+pub const LOG_FORMAT_ENV: &str = "GARDEN_LOG_FORMAT";
+
+/// Initialises the global logger.
+///
+/// The level filter still comes from `RUST_LOG`. With `GARDEN_LOG_FORMAT=json` every
+/// line is a JSON object (`timestamp`, `level`, `target`, `message`, `requestId`);
+/// otherwise the usual text format is used. Both formats include the `X-Request-Id` of
+/// the request being served, when there is one.
+pub fn init() {
+    let json = std::env::var(LOG_FORMAT_ENV).is_ok_and(|v| v.eq_ignore_ascii_case("json"));
+    let mut builder = env_logger::Builder::from_default_env();
+    if json {
+        builder.format(|buf, record| {
+            let line = serde_json::json!({
+                "timestamp": buf.timestamp_millis().to_string(),
+                "level": record.level().as_str(),
+                "target": record.target(),
+                "message": record.args().to_string(),
+                "requestId": current_request_id(),
+            });
+            writeln!(buf, "{line}")
+        });
+    } else {
+        builder.format(|buf, record| {
+            let request_id = current_request_id()
+                .map(|id| format!(" request_id={id}"))
+                .unwrap_or_default();
+            writeln!(
+                buf,
+                "[{} {} {}{request_id}] {}",
+                buf.timestamp(),
+                record.level(),
+                record.target(),
+                record.args()
+            )
+        });
+    }
+    builder.init();
+}
@@ -1,6 +1,12 @@
 pub mod dto;
+mod guide;
 mod handlers;
 pub mod hateoas;
+pub mod idempotency;
 pub mod localization;
+pub mod logging;
+mod ndjson;
+mod negotiation;
 mod openapi;
+pub mod request_id;
 pub mod routes;
@@ -0,0 +1,87 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use actix_web::{
+    body::{BodySize, MessageBody},
+    web::Bytes,
+};
+use serde::Serialize;
+
+use crate::domain::models::response::{PlannedCell, WeeklyPlan};
+
+/// Media type of newline-delimited JSON bodies.
+pub const NDJSON: &str = "application/x-ndjson";
+
+/// One line of a plan cell export: a placed cell with its position.
+#[derive(Serialize)]
+struct CellLine<'a> {
+    week: usize,
+    row: usize,
+    col: usize,
+    #[serde(flatten)]
+    cell: &'a PlannedCell,
+}
+
+/// Streams the placed cells of every week of a plan as NDJSON, one line per cell in
+/// week then row-major order, serialising each line only when the client reads it.
+pub struct PlanCellsBody {
+    weeks: Vec<WeeklyPlan>,
+    /// Position of the next cell to look at, as `(week, row, col)`.
+    next: (usize, usize, usize),
+}
+
+impl PlanCellsBody {
+    pub fn new(weeks: Vec<WeeklyPlan>) -> Self {
+        Self {
+            weeks,
+            next: (0, 0, 0),
+        }
+    }
+
+    /// The next placed cell, advancing past empty, blocked and reserved ones.
+    fn next_line(&mut self) -> Option<Result<Bytes, serde_json::Error>> {
+        loop {
+            let (week, row, col) = self.next;
+            let grid = &self.weeks.get(week)?.grid;
+            let Some(cells) = grid.get(row) else {
+                self.next = (week + 1, 0, 0);
+                continue;
+            };
+            let Some(cell) = cells.get(col) else {
+                self.next = (week, row + 1, 0);
+                continue;
+            };
+            self.next = (week, row, col + 1);
+            if !cell.is_placed() {
+                continue;
+            }
+            let line = CellLine {
+                week,
+                row,
+                col,
+                cell,
+            };
+            return Some(serde_json::to_vec(&line).map(|mut bytes| {
+                bytes.push(b'\n');
+                Bytes::from(bytes)
+            }));
+        }
+    }
+}
+
+impl MessageBody for PlanCellsBody {
+    type Error = serde_json::Error;
+
+    fn size(&self) -> BodySize {
+        BodySize::Stream
+    }
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Bytes, Self::Error>>> {
+        Poll::Ready(self.get_mut().next_line())
+    }
+}
@@ -0,0 +1,192 @@
+use std::hash::{Hash, Hasher};
+
+use actix_web::{http::header, HttpRequest, HttpResponse, HttpResponseBuilder};
+use serde::Serialize;
+
+/// Root element wrapping every XML response body.
+const XML_ROOT: &str = "response";
+
+/// Media type of the HAL envelopes (`payload` + `_links`), the default JSON flavour.
+pub const HAL_JSON: &str = "application/hal+json";
+
+/// Response formats an envelope endpoint can produce.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    HalJson,
+    Json,
+    Xml,
+}
+
+/// Media types of the `Accept` header, parameters stripped, in the client's order.
+fn accepted(req: &HttpRequest) -> impl Iterator<Item = &str> {
+    req.headers()
+        .get(header::ACCEPT)
+        .and_then(|v| v.to_str().ok())
+        .into_iter()
+        .flat_map(|s| s.split(','))
+        .map(|m| m.split(';').next().unwrap_or(m).trim())
+}
+
+/// Maps a media type to the envelope format producing it, if any.
+fn format_of(media_type: &str) -> Option<Format> {
+    match media_type {
+        HAL_JSON => Some(Format::HalJson),
+        "application/json" => Some(Format::Json),
+        "application/xml" | "text/xml" => Some(Format::Xml),
+        _ => None,
+    }
+}
+
+/// Picks the first supported media type of the `Accept` header, in the client's order.
+/// Absent, wildcard-only or unsupported headers get HAL JSON.
+fn negotiated_format(req: &HttpRequest) -> Format {
+    accepted(req).find_map(format_of).unwrap_or(Format::HalJson)
+}
+
+/// Returns `true` when the client asked for XML (`application/xml` or `text/xml`) in its
+/// `Accept` header. JSON stays the default for absent, wildcard or JSON-first headers.
+pub fn wants_xml(req: &HttpRequest) -> bool {
+    negotiated_format(req) == Format::Xml
+}
+
+/// Returns `true` when the client asked for `text/markdown` ahead of any envelope format.
+/// Only endpoints with a printable rendering call it; the others skip the type and
+/// negotiate the next one.
+pub fn wants_markdown(req: &HttpRequest) -> bool {
+    accepted(req)
+        .find(|&m| m == "text/markdown" || format_of(m).is_some())
+        .is_some_and(|m| m == "text/markdown")
+}
+
+/// Serialises an envelope as `application/hal+json`, or as plain `application/json`
+/// when the client explicitly prefers it. For endpoints without an XML representation.
+pub fn hal_json<T: Serialize>(
+    req: &HttpRequest,
+    mut builder: HttpResponseBuilder,
+    body: &T,
+) -> HttpResponse {
+    if negotiated_format(req) != Format::Json {
+        builder.content_type(HAL_JSON);
+    }
+    builder.json(body)
+}
+
+/// XML has no anonymous sequences, so a nested array (e.g. a plan `grid`) would be
+/// flattened into one run of sibling elements. Each inner array is wrapped so that every
+/// outer element (one grid row) holds its items as `<cell>` children.
+fn nest_inner_arrays(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+    match value {
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| match nest_inner_arrays(item) {
+                    Value::Array(inner) => serde_json::json!({ "cell": inner }),
+                    other => other,
+                })
+                .collect(),
+        ),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(k, v)| (k, nest_inner_arrays(v)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+/// Serialises `body` as (HAL) JSON (see [`hal_json`]), or as XML when the client
+/// negotiated it (see [`wants_xml`]).
+pub fn negotiate<T: Serialize>(
+    req: &HttpRequest,
+    mut builder: HttpResponseBuilder,
+    body: &T,
+) -> HttpResponse {
+    if !wants_xml(req) {
+        return hal_json(req, builder, body);
+    }
+    // Round-trip through JSON text rather than `to_value`: `f32` fields would otherwise
+    // be widened to `f64` and print as e.g. `0.5400000214576721`.
+    let xml = serde_json::to_vec(body)
+        .and_then(|json| serde_json::from_slice::<serde_json::Value>(&json))
+        .map_err(|e| e.to_string())
+        .and_then(|value| {
+            quick_xml::se::to_string_with_root(XML_ROOT, &nest_inner_arrays(value))
+                .map_err(|e| e.to_string())
+        });
+    match xml {
+        Ok(xml) => builder
+            .content_type("application/xml; charset=utf-8")
+            .body(xml),
+        Err(e) => {
+            log::error!("Failed to serialise response as XML: {e}");
+            HttpResponse::InternalServerError()
+                .json(serde_json::json!({ "error": "Internal server error" }))
+        }
+    }
+}
+
+/// Hashes a JSON value with object keys visited in sorted order, so that maps
+/// serialised in arbitrary order (e.g. `_links`) still produce the same tag.
+fn hash_value(value: &serde_json::Value, hasher: &mut impl Hasher) {
+    use serde_json::Value;
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            entries.len().hash(hasher);
+            for (key, v) in entries {
+                key.hash(hasher);
+                hash_value(v, hasher);
+            }
+        }
+        Value::Array(items) => {
+            items.len().hash(hasher);
+            items.iter().for_each(|v| hash_value(v, hasher));
+        }
+        other => other.to_string().hash(hasher),
+    }
+}
+
+/// Strong entity tag of `body` as it would be negotiated for `req`: a hash of its JSON
+/// serialisation and of the negotiated format, so JSON, HAL JSON and XML representations
+/// (and every locale) get distinct tags.
+fn entity_tag<T: Serialize>(req: &HttpRequest, body: &T) -> Option<String> {
+    let json = serde_json::to_value(body).ok()?;
+    // `DefaultHasher::new()` uses fixed keys: the tag is stable for identical bodies.
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    hash_value(&json, &mut hasher);
+    (negotiated_format(req) as u8).hash(&mut hasher);
+    Some(format!("\"{:016x}\"", hasher.finish()))
+}
+
+/// Returns `true` when the request's `If-None-Match` lists `etag` (or `*`).
+/// Weak comparison: a `W/` prefix on the client's tags is ignored.
+fn if_none_match(req: &HttpRequest, etag: &str) -> bool {
+    req.headers()
+        .get_all(header::IF_NONE_MATCH)
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(|t| t.trim().trim_start_matches("W/"))
+        .any(|t| t == "*" || t == etag)
+}
+
+/// Like [`negotiate`], for cacheable `200` responses: sets an `ETag` derived from the
+/// body and answers `304 Not Modified` (empty body) when `If-None-Match` already holds it.
+pub fn negotiate_cached<T: Serialize>(req: &HttpRequest, body: &T) -> HttpResponse {
+    let Some(etag) = entity_tag(req, body) else {
+        return negotiate(req, HttpResponse::Ok(), body);
+    };
+    let vary = (header::VARY, "Accept, Accept-Language");
+    if if_none_match(req, &etag) {
+        return HttpResponse::NotModified()
+            .insert_header((header::ETAG, etag))
+            .insert_header(vary)
+            .finish();
+    }
+    let mut builder = HttpResponse::Ok();
+    builder
+        .insert_header((header::ETAG, etag))
+        .insert_header(vary);
+    negotiate(req, builder, body)
+}
@@ -1,20 +1,47 @@
 use utoipa::OpenApi;
 
-use crate::adapters::inbound::http::dto::VarietyResponse;
+use crate::adapters::inbound::http::dto::{
+    HealthResponse, HealthStatus, Units, VarietyDto, VarietyResponse,
+};
 use crate::adapters::inbound::http::hateoas::{
-    CompanionsApiResponse, ErrorResponse, GroupApiResponse, GroupsApiResponse, Link, Pagination,
-    PlanApiResponse, VarietiesApiResponse, VarietyApiResponse, VegetableApiResponse,
-    VegetablesApiResponse,
+    BatchPlanApiResponse, BedSizeApiResponse, BulkPlantApiResponse, CategoriesApiResponse,
+    CompactPlanApiResponse, CompanionDemoApiResponse, CompanionSuggestionsApiResponse,
+    CompanionsApiResponse, DebugPlanApiResponse, EnumsApiResponse, ErrorResponse, GroupApiResponse,
+    GroupsApiResponse, HealthApiResponse, LayoutValidationApiResponse, Link,
+    NeighborsPreviewApiResponse, Pagination, PlaceOneApiResponse, PlacementPreviewApiResponse,
+    PlanApiResponse, PlanDiffApiResponse, PlanRecommendationsApiResponse, PlanTraceApiResponse,
+    SuccessionApiResponse, TransitionApiResponse, VarietiesApiResponse, VarietyApiResponse,
+    VegetableApiResponse, VegetablesApiResponse, VegetablesByCategory,
+    VegetablesByCategoryApiResponse, WhatIfApiResponse,
+};
+use crate::application::models::request::{
+    BedSizeRequest, BulkPlacement, BulkPlantRequest, LayoutCell, LayoutRun,
+    LayoutValidationRequest, PlaceOneRequest, PlacementPreviewRequest, PlanDiffRequest,
+    PlanRecommendationsRequest, PlanRequest, QuickPlanRequest, TransitionRequest, WhatIfRequest,
 };
-use crate::application::models::request::{LayoutCell, PlanRequest};
 use crate::domain::models::{
     group::Group,
-    request::{Level, Period, PreferenceEntry, SowingRecord},
+    request::{
+        BeginnerMode, BlockedRegion, Density, Level, Objective, PackMode, Period, PlanSnapshot,
+        PreferenceEntry, SowingRecord, Zone,
+    },
     response::{
-        CompanionInfo, CompanionsResponse, PlanResponse, PlannedCell, SowingTask, WeeklyPlan,
+        Adjacency, AdjacencyKind, AppliedPreference, AppliedRequest, BatchPlanItem,
+        BatchPlanResponse, BedSizeItem, BedSizeResponse, BulkPlacementOutcome, BulkPlantResponse,
+        BulkRejection, CandidateEliminations, CategoriesResponse, CategoryInfo, CellChangeKind,
+        CellDiff, CompactCell, CompactPlanResponse, CompactWeeklyPlan, CompanionDemoResponse,
+        CompanionInfo, CompanionSuggestion, CompanionSuggestionsResponse, CompanionsResponse,
+        DebugPlanResponse, DiffCounts, EnumsResponse, GridRegion, LayoutValidationResponse,
+        NeighborImpact, NeighborPreviewCell, NeighborRole, NeighborsPreviewResponse,
+        PlaceOneResponse, PlacementConflict, PlacementPreviewResponse, PlacementStep,
+        PlanDiffResponse, PlanHealth, PlanRecommendationsResponse, PlanResponse, PlanTraceResponse,
+        PlannedCell, PlantingRecommendation, RelocationSuggestion, RemovedPlant, SowingTask,
+        SpeciesEntry, SuccessionPlanting, SuccessionResponse, TransitionPlant, TransitionResponse,
+        UnplacedCandidate, UnplacedReason, WeeklyPlan, WhatIfResponse,
     },
     variety::{
-        CalendarWindow, Category, Lifecycle, Month, Region, RegionCalendar, SoilType, SunExposure,
+        CalendarWindow, Category, Lifecycle, Month, Region, RegionCalendar, Season, SoilType,
+        SunExposure,
     },
     vegetable::Vegetable,
     Coordinate,
@@ -35,29 +62,93 @@ use crate::domain::models::{
         crate::adapters::inbound::http::handlers::varieties::list_varieties,
         crate::adapters::inbound::http::handlers::varieties::get_variety,
         crate::adapters::inbound::http::handlers::vegetables::list_vegetables,
+        crate::adapters::inbound::http::handlers::vegetables::list_vegetables_grouped_by_category,
+        crate::adapters::inbound::http::handlers::vegetables::list_vegetables_by_category,
+        crate::adapters::inbound::http::handlers::vegetables::get_random_vegetable,
         crate::adapters::inbound::http::handlers::vegetables::get_vegetable,
         crate::adapters::inbound::http::handlers::vegetables::get_companions,
         crate::adapters::inbound::http::handlers::vegetables::get_varieties_by_vegetable,
+        crate::adapters::inbound::http::handlers::vegetables::get_suggestions,
+        crate::adapters::inbound::http::handlers::vegetables::get_neighbors_preview,
+        crate::adapters::inbound::http::handlers::vegetables::get_plan_preview,
+        crate::adapters::inbound::http::handlers::companions::get_companion_matrix_csv,
+        crate::adapters::inbound::http::handlers::companions::get_companion_graph_dot,
+        crate::adapters::inbound::http::handlers::companions::get_companion_demo,
+        crate::adapters::inbound::http::handlers::enums::get_enums,
+        crate::adapters::inbound::http::handlers::health::get_health,
+        crate::adapters::inbound::http::handlers::enums::get_categories,
         crate::adapters::inbound::http::handlers::plan::post_plan,
+        crate::adapters::inbound::http::handlers::plan::options_plan,
+        crate::adapters::inbound::http::handlers::plan::head_plan,
+        crate::adapters::inbound::http::handlers::plan::get_stored_plan,
+        crate::adapters::inbound::http::handlers::plan::put_stored_plan,
+        crate::adapters::inbound::http::handlers::plan::delete_stored_plan,
+        crate::adapters::inbound::http::handlers::plan::get_stored_plan_cells,
+        crate::adapters::inbound::http::handlers::plan::post_plan_diff,
+        crate::adapters::inbound::http::handlers::plan::post_plan_succession,
+        crate::adapters::inbound::http::handlers::plan::post_plan_transition,
+        crate::adapters::inbound::http::handlers::plan::post_plan_quick,
+        crate::adapters::inbound::http::handlers::plan::post_plan_trace,
+        crate::adapters::inbound::http::handlers::plan::get_plan_example,
+        crate::adapters::inbound::http::handlers::plan::post_plan_recommendations,
+        crate::adapters::inbound::http::handlers::plan::post_plan_place_one,
+        crate::adapters::inbound::http::handlers::plan::post_plan_size_for,
+        crate::adapters::inbound::http::handlers::plan::post_plan_what_if,
+        crate::adapters::inbound::http::handlers::plan::post_plan_batch,
+        crate::adapters::inbound::http::handlers::layout::post_layout_validate,
+        crate::adapters::inbound::http::handlers::layout::post_layout_can_place,
+        crate::adapters::inbound::http::handlers::layout::post_layout_bulk_plant,
     ),
     components(
         schemas(
             // Enums
-            SoilType, SunExposure, Region, Category, Lifecycle, Level, Month,
+            SoilType, SunExposure, Region, Season, Category, Lifecycle, Level, BeginnerMode, PackMode, Objective, Density, Month,
             // Variety calendar
             CalendarWindow, RegionCalendar,
             // Variety response DTO
-            VarietyResponse,
+            VarietyResponse, VarietyDto, Units,
             // Group
             Group,
             // Vegetable
             Vegetable,
             // Plan request
-            LayoutCell, PreferenceEntry, Period, SowingRecord, PlanRequest,
+            LayoutCell, LayoutRun, PreferenceEntry, Period, SowingRecord, PlanRequest, BlockedRegion, Zone,
+            QuickPlanRequest,
+            // Plan recommendations
+            PlanRecommendationsRequest, PlanRecommendationsResponse, PlantingRecommendation,
+            // Place one
+            PlaceOneRequest, PlaceOneResponse,
+            BedSizeRequest, BedSizeResponse, BedSizeItem,
+            // What-if removal
+            WhatIfRequest, RemovedPlant, NeighborImpact, WhatIfResponse,
             // Plan response
-            Coordinate, PlannedCell, SowingTask, WeeklyPlan, PlanResponse,
+            Coordinate, PlannedCell, SowingTask, WeeklyPlan, PlanResponse, RelocationSuggestion,
+            AppliedRequest, AppliedPreference, GridRegion, PlanHealth, AdjacencyKind, Adjacency,
+            UnplacedReason, UnplacedCandidate, CandidateEliminations,
+            CompactCell, CompactWeeklyPlan, SpeciesEntry, CompactPlanResponse,
+            DebugPlanResponse, PlacementStep, PlanTraceResponse,
+            // Plan diff
+            PlanSnapshot, PlanDiffRequest, CellChangeKind, CellDiff, DiffCounts, PlanDiffResponse,
+            // Plan succession
+            SuccessionPlanting, SuccessionResponse,
+            // Season transition
+            TransitionRequest, TransitionPlant, TransitionResponse,
+            // Plan batch
+            BatchPlanItem, BatchPlanResponse,
             // Companions
-            CompanionInfo, CompanionsResponse,
+            CompanionInfo, CompanionsResponse, CompanionSuggestion, CompanionSuggestionsResponse,
+            NeighborRole, NeighborPreviewCell, NeighborsPreviewResponse,
+            CompanionDemoResponse,
+            // Enum catalogue
+            EnumsResponse, CategoriesResponse, CategoryInfo,
+            // Layout validation
+            LayoutValidationRequest, LayoutValidationResponse,
+            // Placement preview
+            PlacementPreviewRequest, PlacementPreviewResponse, PlacementConflict,
+            // Bulk plant
+            BulkPlantRequest, BulkPlacement, BulkPlantResponse, BulkPlacementOutcome, BulkRejection,
+            // Health
+            HealthStatus, HealthResponse,
             // Shared
             Link, Pagination, ErrorResponse,
             // Concrete response envelopes (via #[aliases])
@@ -67,15 +158,41 @@ use crate::domain::models::{
             GroupsApiResponse,
             VegetableApiResponse,
             VegetablesApiResponse,
+            VegetablesByCategory,
+            VegetablesByCategoryApiResponse,
             PlanApiResponse,
+            CompactPlanApiResponse,
+            DebugPlanApiResponse,
+            PlanTraceApiResponse,
+            PlanDiffApiResponse,
+            PlanRecommendationsApiResponse,
+            PlaceOneApiResponse,
+            BedSizeApiResponse,
+            WhatIfApiResponse,
+            BatchPlanApiResponse,
+            SuccessionApiResponse,
+            TransitionApiResponse,
             CompanionsApiResponse,
+            CompanionSuggestionsApiResponse,
+            NeighborsPreviewApiResponse,
+            CompanionDemoApiResponse,
+            EnumsApiResponse,
+            CategoriesApiResponse,
+            LayoutValidationApiResponse,
+            PlacementPreviewApiResponse,
+            BulkPlantApiResponse,
+            HealthApiResponse,
         )
     ),
     tags(
         (name = "groups",     description = "Group catalogue — top-level botanical/culinary categories"),
         (name = "varieties", description = "Variety catalogue — list, detail, companion lookup"),
         (name = "vegetables",  description = "Vegetable catalogue — group varieties by species/type"),
+        (name = "companions", description = "Companion relationships across the whole catalogue — CSV matrix, Graphviz graph and a two-species placement demo"),
+        (name = "enums",      description = "Accepted enum values — seasons, soils, sun exposures, regions, categories (with rendering hints), lifecycles, levels"),
         (name = "plan",       description = "Garden planning — generate an optimised planting layout"),
+        (name = "layout",     description = "Layout checks — validate a grid or preview a placement before planning it"),
+        (name = "health",     description = "Server health — whether the catalogue was loaded at startup"),
     )
 )]
 pub struct ApiDoc;
@@ -1,26 +1,68 @@
-use actix_web::web;
+use actix_web::{middleware::from_fn, web};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
 use crate::adapters::inbound::http::handlers::{
-    get_companions, get_group, get_varieties_by_vegetable, get_variety, get_vegetable, list_groups,
-    list_varieties, list_vegetables, list_vegetables_by_group, post_plan,
+    delete_stored_plan, get_categories, get_companion_demo, get_companion_graph_dot,
+    get_companion_matrix_csv, get_companions, get_enums, get_group, get_health,
+    get_neighbors_preview, get_plan_example, get_plan_preview, get_random_vegetable,
+    get_stored_plan, get_stored_plan_cells, get_suggestions, get_varieties_by_vegetable,
+    get_variety, get_vegetable, head_plan, list_groups, list_varieties, list_vegetables,
+    list_vegetables_by_category, list_vegetables_by_group, list_vegetables_grouped_by_category,
+    options_plan, post_layout_bulk_plant, post_layout_can_place, post_layout_validate, post_plan,
+    post_plan_batch, post_plan_diff, post_plan_place_one, post_plan_quick,
+    post_plan_recommendations, post_plan_size_for, post_plan_succession, post_plan_trace,
+    post_plan_transition, post_plan_what_if, put_stored_plan,
 };
 use crate::adapters::inbound::http::openapi::ApiDoc;
+use crate::adapters::inbound::http::request_id::request_id;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/api")
+            .wrap(from_fn(request_id))
             .service(list_groups)
             .service(get_group)
             .service(list_vegetables_by_group)
             .service(list_varieties)
             .service(get_variety)
             .service(get_companions)
+            .service(get_suggestions)
+            .service(get_neighbors_preview)
+            .service(get_plan_preview)
+            .service(get_companion_matrix_csv)
+            .service(get_companion_graph_dot)
+            .service(get_companion_demo)
             .service(list_vegetables)
+            .service(list_vegetables_grouped_by_category)
+            .service(get_random_vegetable)
             .service(get_vegetable)
             .service(get_varieties_by_vegetable)
-            .service(post_plan),
+            .service(get_enums)
+            .service(get_categories)
+            .service(get_health)
+            .service(list_vegetables_by_category)
+            .service(post_layout_validate)
+            .service(post_layout_can_place)
+            .service(post_layout_bulk_plant)
+            .service(post_plan)
+            .service(options_plan)
+            .service(head_plan)
+            .service(post_plan_batch)
+            .service(post_plan_diff)
+            .service(post_plan_succession)
+            .service(post_plan_transition)
+            .service(post_plan_quick)
+            .service(post_plan_trace)
+            .service(post_plan_recommendations)
+            .service(post_plan_place_one)
+            .service(post_plan_size_for)
+            .service(post_plan_what_if)
+            .service(get_plan_example)
+            .service(get_stored_plan)
+            .service(get_stored_plan_cells)
+            .service(put_stored_plan)
+            .service(delete_stored_plan),
     )
     .service(SwaggerUi::new("/swagger-ui/{_:.*}").url("/api-docs/openapi.json", ApiDoc::openapi()));
 }
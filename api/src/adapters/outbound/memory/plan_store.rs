@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+use crate::application::ports::plan_store::{PlanStore, ReplaceError};
+use crate::domain::models::response::PlanResponse;
+
+/// How long a stored plan stays retrievable.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+/// Maximum number of plans kept at once; the oldest is evicted beyond it.
+pub const DEFAULT_CAPACITY: usize = 1_000;
+
+struct StoredPlan {
+    stored_at: Instant,
+    /// Idempotency key the plan was stored under, if any.
+    key: Option<String>,
+    /// Starts at `1`, bumped on every replacement.
+    version: u64,
+    plan: PlanResponse,
+}
+
+/// Process-local [`PlanStore`]: plans are lost on restart, expire after a TTL and the
+/// oldest ones are evicted once `capacity` is reached.
+pub struct InMemoryPlanStore {
+    plans: Mutex<HashMap<Uuid, StoredPlan>>,
+    ttl: Duration,
+    capacity: usize,
+}
+
+impl InMemoryPlanStore {
+    pub fn new(ttl: Duration, capacity: usize) -> Self {
+        Self {
+            plans: Mutex::new(HashMap::new()),
+            ttl,
+            capacity: capacity.max(1),
+        }
+    }
+
+    fn is_expired(&self, stored: &StoredPlan, now: Instant) -> bool {
+        now.duration_since(stored.stored_at) >= self.ttl
+    }
+
+    /// Live plan bound to `key`, if any.
+    fn find_key(&self, plans: &HashMap<Uuid, StoredPlan>, key: &str) -> Option<Uuid> {
+        let now = Instant::now();
+        plans
+            .iter()
+            .find(|(_, stored)| stored.key.as_deref() == Some(key) && !self.is_expired(stored, now))
+            .map(|(id, _)| *id)
+    }
+
+    fn store(&self, key: Option<&str>, plan: PlanResponse) -> String {
+        let now = Instant::now();
+        let mut plans = self.plans.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(existing) = key.and_then(|k| self.find_key(&plans, k)) {
+            return existing.to_string();
+        }
+        plans.retain(|_, stored| !self.is_expired(stored, now));
+        while plans.len() >= self.capacity {
+            let Some(oldest) = plans
+                .iter()
+                .min_by_key(|(_, stored)| stored.stored_at)
+                .map(|(id, _)| *id)
+            else {
+                break;
+            };
+            plans.remove(&oldest);
+        }
+        let id = Uuid::new_v4();
+        plans.insert(
+            id,
+            StoredPlan {
+                stored_at: now,
+                key: key.map(str::to_owned),
+                version: 1,
+                plan,
+            },
+        );
+        id.to_string()
+    }
+}
+
+impl Default for InMemoryPlanStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_TTL, DEFAULT_CAPACITY)
+    }
+}
+
+impl PlanStore for InMemoryPlanStore {
+    fn insert(&self, plan: PlanResponse) -> String {
+        self.store(None, plan)
+    }
+
+    fn insert_with_key(&self, key: &str, plan: PlanResponse) -> String {
+        self.store(Some(key), plan)
+    }
+
+    fn id_for_key(&self, key: &str) -> Option<String> {
+        let plans = self.plans.lock().unwrap_or_else(|e| e.into_inner());
+        self.find_key(&plans, key).map(|id| id.to_string())
+    }
+
+    fn get_versioned(&self, id: &str) -> Option<(PlanResponse, u64)> {
+        let id = Uuid::parse_str(id).ok()?;
+        let plans = self.plans.lock().unwrap_or_else(|e| e.into_inner());
+        plans
+            .get(&id)
+            .filter(|stored| !self.is_expired(stored, Instant::now()))
+            .map(|stored| (stored.plan.clone(), stored.version))
+    }
+
+    fn replace(&self, id: &str, expected: u64, plan: PlanResponse) -> Result<u64, ReplaceError> {
+        let id = Uuid::parse_str(id).map_err(|_| ReplaceError::NotFound)?;
+        let mut plans = self.plans.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let stored = plans
+            .get_mut(&id)
+            .filter(|stored| !self.is_expired(stored, now))
+            .ok_or(ReplaceError::NotFound)?;
+        if expected != stored.version {
+            return Err(ReplaceError::Stale {
+                current: stored.version,
+            });
+        }
+        stored.version += 1;
+        stored.plan = plan;
+        Ok(stored.version)
+    }
+
+    fn remove(&self, id: &str) -> bool {
+        let Ok(id) = Uuid::parse_str(id) else {
+            return false;
+        };
+        let mut plans = self.plans.lock().unwrap_or_else(|e| e.into_inner());
+        plans
+            .remove(&id)
+            .is_some_and(|stored| !self.is_expired(&stored, Instant::now()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{
+        request::{Density, Objective, PackMode},
+        response::AppliedRequest,
+        variety::Region,
+    };
+
+    fn plan() -> PlanResponse {
+        PlanResponse {
+            id: None,
+            rows: 1,
+            cols: 1,
+            plantable_cells: 1,
+            blocked_cells: 0,
+            usable_area_m2: 0.09,
+            weeks: vec![],
+            normalized_score: 0.0,
+            warnings: vec![],
+            suggestions: vec![],
+            applied: AppliedRequest {
+                period: None,
+                seasons: vec![],
+                region: Region::Temperate,
+                rows: 1,
+                cols: 1,
+                cell_size_cm: 30,
+                preferences: vec![],
+                available_sun_hours: None,
+                pack: PackMode::default(),
+                objective: Objective::default(),
+                weights: Default::default(),
+                target_fill_pct: None,
+                max_total_plants: None,
+                avoid_pairs: Vec::new(),
+                avoid_pair_penalty: 0,
+                neutral_competitor_penalty: 0,
+                fill: true,
+                suggest_fixes: false,
+                strict_companions: false,
+                mutual_companions: false,
+                density: Density::default(),
+                season_overlap: false,
+                shoulder_varieties: vec![],
+                spacing_multiplier: 1.0,
+            },
+            empty_regions: vec![],
+            health: Default::default(),
+            adjacencies: vec![],
+            unplaced_candidates: vec![],
+            no_candidates: None,
+            deterministic: true,
+        }
+    }
+
+    #[test]
+    fn test_capacity_evicts_oldest_plan() {
+        let store = InMemoryPlanStore::new(DEFAULT_TTL, 2);
+        let first = store.insert(plan());
+        let second = store.insert(plan());
+        let third = store.insert(plan());
+        assert!(store.get(&first).is_none());
+        assert!(store.get(&second).is_some());
+        assert!(store.get(&third).is_some());
+    }
+
+    #[test]
+    fn test_same_key_returns_first_plan_id() {
+        let store = InMemoryPlanStore::default();
+        let first = store.insert_with_key("retry-1", plan());
+        let second = store.insert_with_key("retry-1", plan());
+        assert_eq!(first, second);
+        assert_eq!(store.id_for_key("retry-1"), Some(first));
+        assert_ne!(store.insert_with_key("retry-2", plan()), second);
+    }
+
+    #[test]
+    fn test_key_expires_with_plan() {
+        let store = InMemoryPlanStore::new(Duration::ZERO, 10);
+        store.insert_with_key("retry-1", plan());
+        assert!(store.id_for_key("retry-1").is_none());
+    }
+
+    #[test]
+    fn test_replace_requires_the_current_version() {
+        let store = InMemoryPlanStore::default();
+        let id = store.insert(plan());
+        assert_eq!(store.replace(&id, 1, plan()), Ok(2));
+        assert_eq!(
+            store.replace(&id, 1, plan()),
+            Err(ReplaceError::Stale { current: 2 })
+        );
+        assert_eq!(store.get_versioned(&id).map(|(_, v)| v), Some(2));
+        assert_eq!(
+            store.replace("unknown", 1, plan()),
+            Err(ReplaceError::NotFound)
+        );
+    }
+
+    #[test]
+    fn test_expired_plan_is_not_returned() {
+        let store = InMemoryPlanStore::new(Duration::ZERO, 10);
+        let id = store.insert(plan());
+        assert!(store.get(&id).is_none());
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt::Display;
+use std::future::Future;
+use std::time::Duration;
+
+use deadpool_postgres::Pool;
+
+use crate::adapters::outbound::postgres::{
+    variety_repository::PostgresVarietyRepository,
+    vegetable_repository::PostgresVegetableRepository,
+};
+use crate::application::use_cases::check_catalogue::CheckCatalogueUseCase;
+
+mod embedded {
+    use refinery::embed_migrations;
+    embed_migrations!("migrations");
+}
+
+/// Environment variable holding how many times the startup database load is attempted.
+pub const STARTUP_ATTEMPTS_ENV: &str = "GARDEN_DB_STARTUP_ATTEMPTS";
+
+/// How often, and how patiently, a failing startup step is retried: the delay doubles
+/// after every failure, from `initial_delay` up to `max_delay`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total number of tries, the first one included; at least 1.
+    pub attempts: u32,
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            attempts: 5,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The default policy, with `attempts` from [`STARTUP_ATTEMPTS_ENV`] when it is set
+    /// to a positive number.
+    pub fn from_env() -> Self {
+        let attempts = std::env::var(STARTUP_ATTEMPTS_ENV)
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|&n| n > 0);
+        let default = Self::default();
+        Self {
+            attempts: attempts.unwrap_or(default.attempts),
+            ..default
+        }
+    }
+
+    /// Wait after the `failed`-th failed attempt (1-based).
+    pub fn delay(&self, failed: u32) -> Duration {
+        let factor = 2u32.saturating_pow(failed.saturating_sub(1));
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+}
+
+/// Runs `op` until it succeeds or `policy.attempts` tries failed, sleeping
+/// [`RetryPolicy::delay`] in between. Every failure is logged with `step`, the attempt
+/// number and the error; the last error is returned.
+pub async fn retry<T, E, F, Fut>(policy: &RetryPolicy, step: &str, mut op: F) -> Result<T, E>
+where
+    E: Display,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let attempts = policy.attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt >= attempts => {
+                log::error!("{step} failed (attempt {attempt}/{attempts}): {e}");
+                return Err(e);
+            }
+            Err(e) => {
+                let delay = policy.delay(attempt);
+                log::warn!(
+                    "{step} failed (attempt {attempt}/{attempts}), retrying in {} ms: {e}",
+                    delay.as_millis()
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Runs the pending migrations.
+pub async fn migrate(pool: &Pool) -> Result<(), String> {
+    let mut client = pool.get().await.map_err(|e| e.to_string())?;
+    embedded::migrations::runner()
+        .run_async(&mut **client)
+        .await
+        .map(drop)
+        .map_err(|e| e.to_string())
+}
+
+/// The startup routine: runs the migrations, then loads and checks the catalogue (see
+/// [`CheckCatalogueUseCase`]), each step retried under `policy`. Returns the catalogue
+/// problems, or the last error once a step ran out of attempts.
+pub async fn load_catalogue(pool: &Pool, policy: &RetryPolicy) -> Result<Vec<String>, String> {
+    retry(policy, "Database migration", || migrate(pool)).await?;
+    let vegetables = PostgresVegetableRepository::new(pool.clone());
+    let varieties = PostgresVarietyRepository::new(pool.clone());
+    let check = CheckCatalogueUseCase::new(&vegetables, &varieties);
+    retry(policy, "Catalogue load", || check.execute())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// [`load_catalogue`] until it succeeds, one attempt every `policy.max_delay`: what a
+/// server started in degraded mode runs in the background to recover once the database
+/// is back.
+pub async fn reload_until_loaded(pool: &Pool, policy: &RetryPolicy) -> Vec<String> {
+    let once = RetryPolicy {
+        attempts: 1,
+        ..policy.clone()
+    };
+    loop {
+        tokio::time::sleep(policy.max_delay).await;
+        if let Ok(problems) = load_catalogue(pool, &once).await {
+            return problems;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_doubles_up_to_the_cap() {
+        let policy = RetryPolicy::default();
+        let delays: Vec<u128> = (1..=6).map(|n| policy.delay(n).as_millis()).collect();
+        assert_eq!(delays, [500, 1000, 2000, 4000, 8000, 8000]);
+    }
+
+    #[tokio::test]
+    async fn test_retry_stops_after_the_first_success() {
+        let policy = RetryPolicy {
+            attempts: 5,
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+        };
+        let mut calls = 0;
+        let result: Result<u32, String> = retry(&policy, "Test step", || {
+            calls += 1;
+            let outcome = if calls < 3 {
+                Err("down".to_owned())
+            } else {
+                Ok(calls)
+            };
+            async move { outcome }
+        })
+        .await;
+        assert_eq!(result, Ok(3));
+        assert_eq!(calls, 3);
+    }
+}
@@ -43,15 +43,19 @@ fn row_to_vegetable(row: &tokio_postgres::Row) -> Result<Vegetable, RepositoryEr
     let veg_name: String = row.try_get("veg_name").unwrap_or_default();
     let veg_good_companions: Vec<String> = row.try_get("veg_good_companions").unwrap_or_default();
     let veg_bad_companions: Vec<String> = row.try_get("veg_bad_companions").unwrap_or_default();
+    let veg_requires: Vec<String> = row.try_get("veg_requires").unwrap_or_default();
     let veg_variety_ids: Vec<String> = row.try_get("veg_variety_ids").unwrap_or_default();
     let veg_group_id: String = row.try_get("veg_group_id")?;
+    let veg_aliases: Option<Vec<String>> = row.try_get("veg_aliases")?;
     Ok(Vegetable {
         id: veg_id,
         name: veg_name,
+        aliases: veg_aliases.unwrap_or_default(),
         group_id: veg_group_id,
         variety_ids: veg_variety_ids,
         good_companions: veg_good_companions,
         bad_companions: veg_bad_companions,
+        requires: veg_requires,
     })
 }
 
@@ -82,6 +86,8 @@ fn row_to_variety(
         beginner_friendly: row.try_get("beginner_friendly")?,
         soil_types: parse_enum_vec::<SoilType>(&soil_types_raw)?,
         sun_requirement: parse_enum_vec::<SunExposure>(&sun_requirement_raw)?,
+        min_sun_hours: row.try_get::<_, i32>("min_sun_hours")? as u32,
+        yield_per_plant_g: row.try_get::<_, i32>("yield_per_plant_g")? as u32,
         calendars,
     })
 }
@@ -187,14 +193,19 @@ const SELECT_COLUMNS: &str = r#"
         v.beginner_friendly,
         v.soil_types,
         v.sun_requirement,
+        v.min_sun_hours,
+        v.yield_per_plant_g,
         v.calendars,
         veg.id                                                                     AS veg_id,
         COALESCE(vt_req.name, vt_en.name)                                         AS veg_name,
         veg.group_id                                                               AS veg_group_id,
         veg.good_companions                                                        AS veg_good_companions,
         veg.bad_companions                                                         AS veg_bad_companions,
+        veg.requires                                                               AS veg_requires,
         (SELECT ARRAY_AGG(v2.id ORDER BY v2.id) FROM varieties v2
-          WHERE v2.vegetable_id = veg.id)                                          AS veg_variety_ids
+          WHERE v2.vegetable_id = veg.id)                                          AS veg_variety_ids,
+        (SELECT ARRAY_AGG(a.alias ORDER BY a.alias) FROM vegetable_aliases a
+          WHERE a.vegetable_id = veg.id)                                           AS veg_aliases
     FROM varieties v
     JOIN vegetables veg
            ON veg.id = v.vegetable_id
@@ -230,7 +241,13 @@ impl VarietyRepository for PostgresVarietyRepository {
             return Ok(Vec::new());
         }
         let client = self.pool.get().await?;
-        let query = format!("{SELECT_COLUMNS} WHERE v.id = ANY($2) ORDER BY v.id");
+        // Aliases select the namesake variety of their vegetable.
+        let query = format!(
+            "{SELECT_COLUMNS}
+             WHERE v.id = ANY($2)
+                OR v.id IN (SELECT vegetable_id FROM vegetable_aliases WHERE alias = ANY($2))
+             ORDER BY v.id"
+        );
         let rows = client.query(query.as_str(), &[&locale, &ids]).await?;
         rows_to_varieties(&rows)
     }
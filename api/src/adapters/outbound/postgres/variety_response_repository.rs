@@ -9,6 +9,7 @@ use crate::application::ports::{
 use crate::domain::models::variety::{
     CalendarWindow, Category, Lifecycle, RegionCalendar, SoilType, SunExposure,
 };
+use crate::domain::services::helpers::{cell_span, plants_per_cell};
 
 pub struct PostgresVarietyResponseRepository {
     pool: Pool,
@@ -42,22 +43,29 @@ fn row_to_variety_response(row: &tokio_postgres::Row) -> Result<VarietyResponse,
 
     let soil_types_raw: Vec<String> = row.try_get("soil_types")?;
     let sun_requirement_raw: Vec<String> = row.try_get("sun_requirement")?;
-    let category_str: String = row.try_get("category")?;
+    let category = parse_enum::<Category>(&row.try_get::<_, String>("category")?)?;
     let lifecycle_str: String = row.try_get("lifecycle")?;
+    let spacing_cm = row.try_get::<_, i32>("spacing_cm")? as u32;
 
     Ok(VarietyResponse {
         id: row.try_get("id")?,
         vegetable_id: row.try_get("vegetable_id")?,
         name: row.try_get("name")?,
         latin_name: row.try_get("latin_name")?,
-        category: parse_enum::<Category>(&category_str)?,
+        color_hex: category.color_hex().to_owned(),
+        icon: category.icon().to_owned(),
+        category,
         lifecycle: parse_enum::<Lifecycle>(&lifecycle_str)?,
-        spacing_cm: row.try_get::<_, i32>("spacing_cm")? as u32,
+        spacing_cm,
+        cell_span: cell_span(spacing_cm),
+        plants_per_cell: plants_per_cell(spacing_cm),
         days_to_harvest: row.try_get::<_, i32>("days_to_harvest")? as u32,
         days_to_plant: row.try_get::<_, i32>("days_to_plant")? as u32,
         beginner_friendly: row.try_get("beginner_friendly")?,
         soil_types: parse_enum_vec::<SoilType>(&soil_types_raw)?,
         sun_requirement: parse_enum_vec::<SunExposure>(&sun_requirement_raw)?,
+        min_sun_hours: row.try_get::<_, i32>("min_sun_hours")? as u32,
+        yield_per_plant_g: row.try_get::<_, i32>("yield_per_plant_g")? as u32,
         calendars,
     })
 }
@@ -82,6 +90,8 @@ const SELECT_COLUMNS: &str = r#"
         v.beginner_friendly,
         v.soil_types,
         v.sun_requirement,
+        v.min_sun_hours,
+        v.yield_per_plant_g,
         v.calendars
     FROM varieties v
     LEFT JOIN variety_translations t_req
@@ -18,9 +18,12 @@ const SELECT_COLUMNS: &str = r#"
     SELECT
         v.id,
         COALESCE(t_req.name, t_en.name) AS name,
+        (SELECT ARRAY_AGG(a.alias ORDER BY a.alias) FROM vegetable_aliases a
+          WHERE a.vegetable_id = v.id) AS aliases,
         v.group_id,
         v.good_companions,
         v.bad_companions,
+        v.requires,
         ARRAY_AGG(vr.id ORDER BY vr.id) FILTER (WHERE vr.id IS NOT NULL) AS variety_ids
     FROM vegetables v
     LEFT JOIN vegetable_translations t_req
@@ -37,13 +40,17 @@ fn row_to_vegetable(row: &tokio_postgres::Row) -> Result<Vegetable, RepositoryEr
     let variety_ids: Vec<String> = row.try_get("variety_ids").unwrap_or_default();
     let good_companions: Vec<String> = row.try_get("good_companions").unwrap_or_default();
     let bad_companions: Vec<String> = row.try_get("bad_companions").unwrap_or_default();
+    let requires: Vec<String> = row.try_get("requires").unwrap_or_default();
+    let aliases: Option<Vec<String>> = row.try_get("aliases")?;
     Ok(Vegetable {
         id: row.try_get("id")?,
         name: row.try_get("name")?,
+        aliases: aliases.unwrap_or_default(),
         group_id: row.try_get("group_id")?,
         variety_ids,
         good_companions,
         bad_companions,
+        requires,
     })
 }
 
@@ -62,7 +69,13 @@ impl VegetableRepository for PostgresVegetableRepository {
         locale: &str,
     ) -> Result<Option<Vegetable>, RepositoryError> {
         let client = self.pool.get().await?;
-        let query = format!("{SELECT_COLUMNS} WHERE v.id = $2 {GROUP_BY}");
+        // `id` may be the canonical id or one of its aliases.
+        let query = format!(
+            "{SELECT_COLUMNS}
+             WHERE v.id = $2
+                OR v.id IN (SELECT vegetable_id FROM vegetable_aliases WHERE alias = $2)
+             {GROUP_BY}"
+        );
         let rows = client.query(query.as_str(), &[&locale, &id]).await?;
         rows.first().map(row_to_vegetable).transpose()
     }
@@ -77,8 +90,8 @@ impl VegetableRepository for PostgresVegetableRepository {
         let limit = size as i64;
         let offset = ((page - 1) * size) as i64;
         let query = format!(
-            "SELECT COUNT(*) OVER() AS total_count, id, name, group_id,
-                good_companions, bad_companions, variety_ids
+            "SELECT COUNT(*) OVER() AS total_count, id, name, aliases, group_id,
+                good_companions, bad_companions, requires, variety_ids
              FROM (
                  {SELECT_COLUMNS} {GROUP_BY} ORDER BY v.id
              ) sub
@@ -113,9 +126,12 @@ impl VegetableRepository for PostgresVegetableRepository {
             SELECT
                 v.id,
                 COALESCE(t_req.name, t_en.name) AS name,
+                (SELECT ARRAY_AGG(a.alias ORDER BY a.alias) FROM vegetable_aliases a
+                  WHERE a.vegetable_id = v.id) AS aliases,
                 v.group_id,
                 v.good_companions,
                 v.bad_companions,
+                v.requires,
                 ARRAY_AGG(vr.id ORDER BY vr.id) FILTER (WHERE vr.id IS NOT NULL) AS variety_ids
             FROM vegetables v
             LEFT JOIN vegetable_translations t_req
@@ -129,8 +145,8 @@ impl VegetableRepository for PostgresVegetableRepository {
             ORDER BY v.id
         "#;
         let query = format!(
-            "SELECT COUNT(*) OVER() AS total_count, id, name, group_id,
-                good_companions, bad_companions, variety_ids
+            "SELECT COUNT(*) OVER() AS total_count, id, name, aliases, group_id,
+                good_companions, bad_companions, requires, variety_ids
              FROM ({inner}) sub
              LIMIT $3 OFFSET $4"
         );
@@ -147,4 +163,31 @@ impl VegetableRepository for PostgresVegetableRepository {
             .collect::<Result<Vec<_>, _>>()?;
         Ok(Page { items, total })
     }
+
+    async fn get_companion_notes(
+        &self,
+        id: &str,
+        locale: &str,
+    ) -> Result<Vec<(String, String)>, RepositoryError> {
+        let client = self.pool.get().await?;
+        // Every pair has an 'en' note; the requested locale overrides it when present.
+        let query = r#"
+            SELECT
+                CASE WHEN n_en.vegetable_id = $2 THEN n_en.companion_id
+                     ELSE n_en.vegetable_id END AS other_id,
+                COALESCE(n_req.note, n_en.note) AS note
+            FROM companion_notes n_en
+            LEFT JOIN companion_notes n_req
+                   ON n_req.vegetable_id = n_en.vegetable_id
+                  AND n_req.companion_id = n_en.companion_id
+                  AND n_req.locale = $1
+            WHERE n_en.locale = 'en'
+              AND $2 IN (n_en.vegetable_id, n_en.companion_id)
+            ORDER BY other_id
+        "#;
+        let rows = client.query(query, &[&locale, &id]).await?;
+        rows.iter()
+            .map(|row| Ok((row.try_get("other_id")?, row.try_get("note")?)))
+            .collect()
+    }
 }
@@ -1,13 +1,18 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use serde::Deserialize;
 use utoipa::ToSchema;
 
 use crate::domain::models::{
-    request::{Level, Period, PreferenceEntry, SowingRecord},
-    variety::{Region, SoilType, SunExposure},
+    error::{PlanError, MAX_GRID_CELLS},
+    request::{
+        BeginnerMode, BlockedRegion, Density, Level, Objective, PackMode, Period, PlanSnapshot,
+        PreferenceEntry, SowingRecord, Zone,
+    },
+    variety::{Region, Season, SoilType, SunExposure},
     Coordinate, Matrix,
 };
+use crate::domain::services::helpers::{cells_for_area, CELL_SIZE_CM};
 
 /// HTTP-facing layout cell, deserialized from the `layout` array in `POST /api/plan`.
 /// Pre-planted cells reference a variety by ID; the use case resolves IDs to [`Variety`]
@@ -50,6 +55,9 @@ pub enum LayoutCell {
     Empty,
     /// Non-plantable zone (path, alley, obstacle).
     Blocked,
+    /// Cell kept free for a later sowing: never planted, yet not counted as blocked nor
+    /// reported as empty.
+    Reserved,
 }
 
 /// HTTP-facing planning request, deserialized from the `POST /api/plan` body.
@@ -64,15 +72,53 @@ pub struct PlanRequest {
     #[serde(default)]
     pub period: Option<Period>,
     pub sun: Option<SunExposure>,
+    /// Hours of direct sun the plot receives per day. Finer than `sun`: varieties
+    /// needing more sun than this are excluded from planning.
+    #[serde(default)]
+    #[schema(example = 4)]
+    pub available_sun_hours: Option<u32>,
     pub soil: Option<SoilType>,
     pub region: Region,
     pub level: Option<Level>,
+    /// With `level: Beginner`, `Strict` (default) never plans non-beginner-friendly
+    /// varieties; `Prefer` keeps them as candidates, tried after every
+    /// beginner-friendly one.
+    #[serde(default)]
+    pub beginner_mode: Option<BeginnerMode>,
     /// Preferred varieties with optional per-variety plant count.
     pub preferences: Option<Vec<PreferenceEntry>>,
     /// Variety IDs to exclude from planning — these will never be auto-placed
     /// regardless of other filters. Pre-placed cells in `layout` are not affected.
     #[serde(default)]
     pub exclusions: Vec<String>,
+    /// Rank per variety id ordering non-preferred candidates (lower first), e.g.
+    /// `{ "basil": 1, "tomato": 2 }`. Ids left out keep their French consumption rank
+    /// (1–34, unknown ids 999).
+    #[serde(default)]
+    pub ranking: HashMap<String, usize>,
+    /// Pairs of vegetable or variety ids to keep apart this time, in either order (e.g.
+    /// `[["mint", "lettuce"]]`), whatever the catalogue says about them. A soft
+    /// constraint: blocks next to the other member of a pair lose `avoidPairPenalty`
+    /// points but are still used when nothing else is free.
+    #[serde(default)]
+    #[schema(example = json!([["mint", "lettuce"]]))]
+    pub avoid_pairs: Vec<[String; 2]>,
+    /// Points an avoided neighbour costs a candidate block; defaults to 5, more than
+    /// a bad companion (3). At most [`MAX_NEIGHBOUR_PENALTY`].
+    #[serde(default)]
+    #[schema(example = 5)]
+    pub avoid_pair_penalty: Option<u32>,
+    /// Points each neutral neighbour of the same category (fruit, leaf, root…) costs a
+    /// candidate block, nudging the planner towards mixing categories. Default `0`, off;
+    /// at most [`MAX_NEIGHBOUR_PENALTY`].
+    #[serde(default)]
+    #[schema(example = 1)]
+    pub neutral_competitor_penalty: Option<u32>,
+    /// Also plan "shoulder" varieties: those out of season in a planned week's month but
+    /// active in the last month of the previous season or the first month of the next
+    /// one (e.g. a June sowing planned in April). Listed in `applied.shoulderVarieties`.
+    #[serde(default)]
+    pub season_overlap: bool,
     /// Varieties already sown from seed, keyed by variety id.
     /// Each entry is a list of sowing batches, each with an optional date and a seed count.
     /// Example: `{ "tomato": [{ "sowingDate": "2025-03-15", "seedsSown": 10 }] }`
@@ -81,8 +127,489 @@ pub struct PlanRequest {
     /// Combined grid layout — defines dimensions and pre-filled cells.
     /// Each cell is a `LayoutCell` object: `{"type":"Empty"}` (free),
     /// `{"type":"SelfContained","id":"..."}` (pre-planted), or `{"type":"Blocked"}` (blocked).
+    /// May be omitted when `layoutRle` is given.
+    #[serde(default)]
+    #[schema(value_type = Vec<Vec<LayoutCell>>)]
+    pub layout: Matrix<LayoutCell>,
+    /// Run-length encoded alternative to `layout` for large uniform beds: each row is a
+    /// list of runs, a layout cell plus how many consecutive times it repeats, e.g.
+    /// `[[{"type": "Empty", "count": 100}]]`. Expanded by [`Self::expand_layout`].
+    #[serde(default)]
+    pub layout_rle: Option<Vec<Vec<LayoutRun>>>,
+    /// Rectangles of cells to block in addition to the layout's `Blocked` cells.
+    /// Regions reaching past the grid edge are clamped with a warning.
+    #[serde(default)]
+    pub blocked_regions: Vec<BlockedRegion>,
+    /// Single cells to block, for a few scattered obstacles (a water butt, a post).
+    /// Cells outside the grid are ignored with a warning.
+    #[serde(default)]
+    #[schema(example = json!([{"row": 1, "col": 2}, {"row": 3, "col": 0}]))]
+    pub blocked_cells: Vec<Coordinate>,
+    /// Rectangles a vegetable or variety is pinned to, e.g. climbing beans only along the
+    /// north edge. A species with zones is only planted inside them; cells outside every
+    /// zone stay open to the other species.
+    #[serde(default)]
+    #[schema(example = json!([{"id": "green-bean", "region": {"topLeft": {"row": 0, "col": 0}, "width": 6, "height": 1}}]))]
+    pub zones: Vec<Zone>,
+    /// Which cells may be planted, one boolean per layout cell: `false` cells are blocked.
+    /// Convenient for oddly-shaped beds; must have the same dimensions as `layout`.
+    #[serde(default)]
+    #[schema(value_type = Option<Vec<Vec<bool>>>, example = json!([[false, true, false], [true, true, true], [false, true, false]]))]
+    pub plantable_mask: Option<Matrix<bool>>,
+    /// How remaining cells are packed once preferences are placed.
+    /// `Priority` (default) follows candidate priority; `BestFit` tries the candidates
+    /// that best fill the largest free space first to leave fewer empty cells.
+    #[serde(default)]
+    pub pack: Option<PackMode>,
+    /// What block choice optimises: `CompanionScore` (default), `Diversity`, `Yield`,
+    /// `LowMaintenance` or `CompanionThenDiversity`.
+    #[serde(default)]
+    pub objective: Option<Objective>,
+    /// Planting density: `Intensive` (spacing −20%), `Standard` (default) or `Spacious`
+    /// (spacing +20%).
+    #[serde(default)]
+    pub density: Option<Density>,
+    /// Blend of several objectives, e.g. `{ "CompanionScore": 0.7, "Diversity": 0.3 }`.
+    /// Weights must be non-negative and are normalised to sum to 1; when set they
+    /// override `objective`.
+    #[serde(default)]
+    pub weights: BTreeMap<Objective, f32>,
+    /// Stop filling once this percentage (0–100) of plantable cells is occupied,
+    /// leaving the rest empty. Omit to pack the whole bed.
+    #[serde(default)]
+    #[schema(minimum = 0, maximum = 100, example = 70)]
+    pub target_fill_pct: Option<u8>,
+    /// Seed budget: stop placing once this many plants are planted over the whole
+    /// period, leaving the rest of the grid empty. Every plant of a cell counts (e.g.
+    /// 16 for a radish cell); plants from `layout` do not.
+    #[serde(default)]
+    #[schema(minimum = 1, example = 40)]
+    pub max_total_plants: Option<u32>,
+    /// For each pair of pre-placed bad companions in `layout`, propose the nearest free
+    /// cell to move one of them to, returned in `suggestions` (never applied).
+    #[serde(default)]
+    pub suggest_fixes: bool,
+    /// When `false`, only the preferences are placed (one plant each unless `quantity`
+    /// says otherwise) and every other cell is left empty. Defaults to `true`.
+    #[serde(default = "default_fill")]
+    #[schema(default = true)]
+    pub fill: bool,
+    /// Soft planning time limit in milliseconds. Once spent, filling stops and the grid
+    /// is returned as it stands, with a warning. Defaults to `GARDEN_PLAN_TIMEOUT_MS`,
+    /// unlimited when unset.
+    #[serde(default)]
+    #[schema(example = 2000)]
+    pub timeout_ms: Option<u64>,
+    /// Never place a variety next to one of its bad companions; cells that can only
+    /// take a bad neighbour stay empty instead.
+    #[serde(default)]
+    pub strict_companions: bool,
+    /// Score companionship both ways: a neighbour listing the candidate as a good (or
+    /// bad) companion counts too, so mutually good pairs are preferred. Default `false`
+    /// only looks at the candidate's own lists.
+    #[serde(default)]
+    pub mutual_companions: bool,
+}
+
+fn default_fill() -> bool {
+    true
+}
+
+/// A run of identical cells in a `layoutRle` row.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct LayoutRun {
+    /// The repeated cell, written as in `layout`.
+    #[serde(flatten)]
+    pub cell: LayoutCell,
+    /// Number of consecutive cells; defaults to `1`.
+    #[serde(default = "default_run_count")]
+    #[schema(example = 10)]
+    pub count: usize,
+}
+
+fn default_run_count() -> usize {
+    1
+}
+
+/// Largest `areaM2` a preference may ask for: the area of the largest accepted grid,
+/// [`MAX_GRID_CELLS`] cells of 30 cm (900 m²).
+const MAX_AREA_M2: f32 = (MAX_GRID_CELLS as u32 * CELL_SIZE_CM.pow(2) / 10_000) as f32;
+
+/// Largest per-neighbour penalty a request may set: far above any companion score, yet
+/// small enough that a block's penalties cannot overflow its score.
+pub const MAX_NEIGHBOUR_PENALTY: u32 = 1_000;
+
+/// Rejects a `quantity` of `0`, a preference setting both `quantity` and `areaM2`, and a
+/// non-positive, non-finite or larger-than-any-grid `areaM2`.
+fn validate_preferences(preferences: &[PreferenceEntry]) -> Result<(), String> {
+    for p in preferences {
+        if p.quantity == Some(0) {
+            return Err(format!(
+                "Preference quantity for '{}' must be at least 1; omit it to let the planner decide.",
+                p.id
+            ));
+        }
+        if p.quantity.is_some() && p.area_m2.is_some() {
+            return Err(format!(
+                "Preference for '{}' sets both quantity and areaM2; use only one.",
+                p.id
+            ));
+        }
+        if p.area_m2.is_some_and(|a| !a.is_finite() || a <= 0.0) {
+            return Err(format!(
+                "Preference areaM2 for '{}' must be a positive number of square meters.",
+                p.id
+            ));
+        }
+        if p.area_m2.is_some_and(|a| a > MAX_AREA_M2) {
+            return Err(format!(
+                "Preference areaM2 for '{}' must be at most {MAX_AREA_M2} square meters, the area of the largest grid.",
+                p.id
+            ));
+        }
+    }
+    Ok(())
+}
+
+impl PlanRequest {
+    /// Replaces `layout` with the decoded `layoutRle`, when given. Rejects a request
+    /// setting both, a run of `0` cells, rows decoding to different widths, and grids
+    /// over [`MAX_GRID_CELLS`] cells (checked before any cell is allocated), with the
+    /// [`PlanError`] a plain `layout` of that shape gets.
+    pub fn expand_layout(&mut self) -> Result<(), PlanError> {
+        let Some(rle) = self.layout_rle.take() else {
+            return Ok(());
+        };
+        if !self.layout.is_empty() {
+            return Err(PlanError::LayoutAndLayoutRle);
+        }
+        if let Some(row) = rle
+            .iter()
+            .position(|row| row.iter().any(|run| run.count == 0))
+        {
+            return Err(PlanError::EmptyRun { row });
+        }
+        let widths: Vec<usize> = rle
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .fold(0usize, |w, run| w.saturating_add(run.count))
+            })
+            .collect();
+        let cols = widths.first().copied().unwrap_or(0);
+        if let Some(row) = widths.iter().position(|w| *w != cols) {
+            return Err(PlanError::RaggedRow {
+                row,
+                len: widths[row],
+                expected: cols,
+            });
+        }
+        if rle.len().saturating_mul(cols) > MAX_GRID_CELLS {
+            return Err(PlanError::GridTooLarge {
+                rows: rle.len(),
+                cols,
+            });
+        }
+        self.layout = rle
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .flat_map(|run| std::iter::repeat_n(run.cell, run.count))
+                    .collect()
+            })
+            .collect();
+        Ok(())
+    }
+
+    /// Rejects input that can never describe a meaningful plan: a preference with an
+    /// explicit `quantity` of `0`, a preference setting both `quantity` and `areaM2` (or a
+    /// non-positive `areaM2`), objective `weights` that are negative, all zero, summing
+    /// past `f32::MAX` or weight `CompanionThenDiversity`, or an `avoidPairPenalty` or
+    /// `neutralCompetitorPenalty` over [`MAX_NEIGHBOUR_PENALTY`].
+    pub fn validate(&self) -> Result<(), String> {
+        if self
+            .weights
+            .contains_key(&Objective::CompanionThenDiversity)
+        {
+            return Err(
+                "CompanionThenDiversity cannot be weighted; weight CompanionScore and Diversity instead."
+                    .into(),
+            );
+        }
+        if self.weights.values().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err("Objective weights must be non-negative numbers.".into());
+        }
+        let total: f32 = self.weights.values().sum();
+        if !self.weights.is_empty() && total == 0.0 {
+            return Err("At least one objective weight must be positive.".into());
+        }
+        if !total.is_finite() {
+            return Err(
+                "Objective weights are too large: their sum must be a finite number.".into(),
+            );
+        }
+        if let Some(mask) = &self.plantable_mask {
+            let same_shape = mask.len() == self.layout.len()
+                && mask
+                    .iter()
+                    .zip(&self.layout)
+                    .all(|(m, l)| m.len() == l.len());
+            if !same_shape {
+                return Err("plantableMask must have the same dimensions as layout.".into());
+            }
+        }
+        if let Some(zone) = self
+            .zones
+            .iter()
+            .find(|z| z.region.width == 0 || z.region.height == 0)
+        {
+            return Err(format!(
+                "Zone for '{}' must be at least one cell wide and high.",
+                zone.id
+            ));
+        }
+        if self.max_total_plants == Some(0) {
+            return Err("maxTotalPlants must be at least 1; omit it to set no limit.".into());
+        }
+        if self
+            .avoid_pair_penalty
+            .is_some_and(|p| p > MAX_NEIGHBOUR_PENALTY)
+        {
+            return Err(format!(
+                "avoidPairPenalty must be at most {MAX_NEIGHBOUR_PENALTY}."
+            ));
+        }
+        if self
+            .neutral_competitor_penalty
+            .is_some_and(|p| p > MAX_NEIGHBOUR_PENALTY)
+        {
+            return Err(format!(
+                "neutralCompetitorPenalty must be at most {MAX_NEIGHBOUR_PENALTY}."
+            ));
+        }
+        validate_preferences(self.preferences.as_deref().unwrap_or(&[]))
+    }
+
+    /// `true` when a beginner asked for beginner-friendly varieties to be preferred rather
+    /// than required.
+    pub fn prefers_beginner(&self) -> bool {
+        matches!(self.level, Some(Level::Beginner))
+            && matches!(self.beginner_mode, Some(BeginnerMode::Prefer))
+    }
+
+    /// `weights` scaled to sum to 1, without zero entries. Call after [`Self::validate`].
+    pub fn normalized_weights(&self) -> BTreeMap<Objective, f32> {
+        let total: f32 = self.weights.values().sum();
+        self.weights
+            .iter()
+            .filter(|(_, w)| **w > 0.0)
+            .map(|(objective, w)| (objective.clone(), w / total))
+            .collect()
+    }
+}
+
+/// HTTP-facing body of `POST /api/plan/diff`: the two plans to compare.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanDiffRequest {
+    /// The reference plan.
+    pub before: PlanSnapshot,
+    /// The re-planned grid, compared against `before`.
+    pub after: PlanSnapshot,
+}
+
+/// HTTP-facing body of `POST /api/plan/quick`: the few inputs a novice provides.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QuickPlanRequest {
+    /// Season to plan for; the plan covers its next first week (or the current week
+    /// when already in that season).
+    pub season: Season,
+    /// Grid height in cells.
+    #[schema(example = 4)]
+    pub rows: usize,
+    /// Grid width in cells.
+    #[schema(example = 6)]
+    pub cols: usize,
+    /// Defaults to `Temperate`.
+    #[serde(default)]
+    pub region: Option<Region>,
+}
+
+/// HTTP-facing body of `POST /api/plan/transition`: a planted bed and the season to
+/// turn it over to.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TransitionRequest {
+    /// Season to plan; the plan covers its three months, starting with the first one
+    /// after the current `period` (or after today when `period` is omitted).
+    pub target_season: Season,
+    /// The current season, as for `POST /api/plan`: `layout` holds what is planted now
+    /// and `period` the season it was planted for.
+    #[serde(flatten)]
+    pub plan: PlanRequest,
+}
+
+/// HTTP-facing body of `POST /api/plan/size-for`: the plants a bed should hold.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BedSizeRequest {
+    /// Varieties and how many plants (or how much area) of each; a preference with
+    /// neither counts as one plant. `near` and `keepTogether` are ignored.
+    pub preferences: Vec<PreferenceEntry>,
+}
+
+impl BedSizeRequest {
+    /// At least one preference, each valid as in `POST /api/plan`, and no more plants
+    /// or area than [`MAX_GRID_CELLS`] cells could hold even at one cell per plant.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.preferences.is_empty() {
+            return Err("'preferences' must list at least one variety.".into());
+        }
+        validate_preferences(&self.preferences)?;
+        let cells = self.preferences.iter().fold(0usize, |total, p| {
+            let cells = match (p.quantity, p.area_m2) {
+                (Some(quantity), _) => quantity as usize,
+                (None, Some(area)) => cells_for_area(area),
+                (None, None) => 1,
+            };
+            total.saturating_add(cells)
+        });
+        if cells > MAX_GRID_CELLS {
+            return Err(format!(
+                "The preferences need at least {cells} cells, more than the maximum of {MAX_GRID_CELLS} cells."
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// HTTP-facing body of `POST /api/layout/validate`.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutValidationRequest {
+    /// Grid layout to check, as sent to `POST /api/plan`.
+    #[schema(value_type = Vec<Vec<LayoutCell>>)]
+    pub layout: Matrix<LayoutCell>,
+    /// Rectangles of cells to block in addition to the layout's `Blocked` cells.
+    #[serde(default)]
+    pub blocked_regions: Vec<BlockedRegion>,
+}
+
+/// HTTP-facing body of `POST /api/plan/recommendations`.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanRecommendationsRequest {
+    /// Current grid layout, as sent to `POST /api/plan`.
+    #[schema(value_type = Vec<Vec<LayoutCell>>)]
+    pub layout: Matrix<LayoutCell>,
+    /// Only recommend vegetables active in this season; defaults to the current one.
+    #[serde(default)]
+    pub season: Option<Season>,
+}
+
+/// HTTP-facing body of `POST /api/layout/can-place`.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlacementPreviewRequest {
+    /// Variety id (or alias) of the plant to preview.
+    #[schema(example = "fennel")]
+    pub id: String,
+    /// Row of the plant's top-left cell (0-based).
+    pub row: usize,
+    /// Column of the plant's top-left cell (0-based).
+    pub col: usize,
+    /// Current grid layout, as sent to `POST /api/plan`.
+    #[schema(value_type = Vec<Vec<LayoutCell>>)]
+    pub layout: Matrix<LayoutCell>,
+    /// Rectangles of cells to block in addition to the layout's `Blocked` cells.
+    #[serde(default)]
+    pub blocked_regions: Vec<BlockedRegion>,
+}
+
+/// HTTP-facing body of `POST /api/plan/place-one`.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceOneRequest {
+    /// Variety id (or alias) of the plant to add.
+    #[schema(example = "basil")]
+    pub id: String,
+    /// Current grid layout, as sent to `POST /api/plan`.
+    #[schema(value_type = Vec<Vec<LayoutCell>>)]
+    pub layout: Matrix<LayoutCell>,
+    /// Rectangles of cells to block in addition to the layout's `Blocked` cells.
+    #[serde(default)]
+    pub blocked_regions: Vec<BlockedRegion>,
+}
+
+/// HTTP-facing body of `POST /api/plan/what-if`: what to pull out of a layout, by
+/// `cell` or by variety `id`.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WhatIfRequest {
+    /// Current grid layout, as sent to `POST /api/plan`.
     #[schema(value_type = Vec<Vec<LayoutCell>>)]
     pub layout: Matrix<LayoutCell>,
+    /// Rectangles of cells to block in addition to the layout's `Blocked` cells.
+    #[serde(default)]
+    pub blocked_regions: Vec<BlockedRegion>,
+    /// Any cell of the plant to remove.
+    #[serde(default)]
+    pub cell: Option<Coordinate>,
+    /// Variety id (or alias) to remove everywhere it is planted.
+    #[serde(default)]
+    #[schema(example = "basil")]
+    pub id: Option<String>,
+}
+
+impl WhatIfRequest {
+    /// Exactly one of `cell` and `id` must say what to remove.
+    pub fn validate(&self) -> Result<(), String> {
+        match (&self.cell, &self.id) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            _ => Err("Set exactly one of 'cell' and 'id' to say what to remove.".into()),
+        }
+    }
+}
+
+/// One plant stamped into a layout by `POST /api/layout/bulk-plant`.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkPlacement {
+    /// Variety id (or alias) of the plant.
+    #[schema(example = "tomato")]
+    pub id: String,
+    /// Row of the plant's top-left cell (0-based).
+    pub row: usize,
+    /// Column of the plant's top-left cell (0-based).
+    pub col: usize,
+}
+
+/// HTTP-facing body of `POST /api/layout/bulk-plant`.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkPlantRequest {
+    /// Base grid layout, as sent to `POST /api/plan`.
+    #[schema(value_type = Vec<Vec<LayoutCell>>)]
+    pub layout: Matrix<LayoutCell>,
+    /// Rectangles of cells to block in addition to the layout's `Blocked` cells.
+    #[serde(default)]
+    pub blocked_regions: Vec<BlockedRegion>,
+    /// Plants to stamp in, applied in order: a later plant sees the earlier accepted ones.
+    pub placements: Vec<BulkPlacement>,
+    /// Also reject a plant whose block touches a bad companion.
+    #[serde(default)]
+    pub check_companions: bool,
+}
+
+impl BulkPlantRequest {
+    /// At least one placement is required.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.placements.is_empty() {
+            return Err("'placements' must list at least one plant.".into());
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -108,4 +635,32 @@ mod tests {
             other => panic!("expected SelfContained, got {other:?}"),
         }
     }
+
+    fn area_preference(area_m2: f32) -> PreferenceEntry {
+        serde_json::from_value(serde_json::json!({"id": "basil", "areaM2": area_m2})).unwrap()
+    }
+
+    #[test]
+    fn test_area_preference_bounds() {
+        assert!(validate_preferences(&[area_preference(0.54)]).is_ok());
+        assert!(validate_preferences(&[area_preference(MAX_AREA_M2)]).is_ok());
+        for area in [0.0, -1.0, f32::INFINITY, f32::NAN] {
+            assert_eq!(
+                validate_preferences(&[PreferenceEntry {
+                    area_m2: Some(area),
+                    ..area_preference(1.0)
+                }]),
+                Err(
+                    "Preference areaM2 for 'basil' must be a positive number of square meters."
+                        .into()
+                ),
+                "{area}"
+            );
+        }
+        // 2 km x 2 km.
+        assert_eq!(
+            validate_preferences(&[area_preference(4_000_000.0)]),
+            Err("Preference areaM2 for 'basil' must be at most 900 square meters, the area of the largest grid.".into())
+        );
+    }
 }
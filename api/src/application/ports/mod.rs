@@ -1,4 +1,5 @@
 pub mod group_repository;
+pub mod plan_store;
 pub mod variety_repository;
 pub mod variety_response_repository;
 pub mod vegetable_repository;
@@ -0,0 +1,35 @@
+use crate::domain::models::response::PlanResponse;
+
+/// Why [`PlanStore::replace`] left a stored plan unchanged.
+#[derive(Debug, PartialEq)]
+pub enum ReplaceError {
+    /// No plan is stored under this id (or it expired).
+    NotFound,
+    /// The plan was changed since the caller read it; holds its current version.
+    Stale { current: u64 },
+}
+
+/// Outbound port: keeps generated plans so they can be fetched again by id.
+///
+/// Stores may evict plans on their own (expiry, capacity); a plan that is gone is
+/// reported exactly like one that never existed.
+pub trait PlanStore: Send + Sync {
+    /// Stores `plan` and returns its newly generated identifier.
+    fn insert(&self, plan: PlanResponse) -> String;
+    /// Stores `plan` bound to the client-supplied idempotency `key` and returns its id.
+    /// When `key` is already bound to a stored plan, nothing is stored and that plan's
+    /// id is returned instead. The binding expires together with the plan.
+    fn insert_with_key(&self, key: &str, plan: PlanResponse) -> String;
+    /// Id of the plan bound to idempotency `key`, if it is still stored.
+    fn id_for_key(&self, key: &str) -> Option<String>;
+    fn get(&self, id: &str) -> Option<PlanResponse> {
+        self.get_versioned(id).map(|(plan, _)| plan)
+    }
+    /// The plan together with its version: `1` once stored, bumped by every
+    /// [`Self::replace`].
+    fn get_versioned(&self, id: &str) -> Option<(PlanResponse, u64)>;
+    /// Replaces the plan when its version is still `expected` and returns the new version.
+    fn replace(&self, id: &str, expected: u64, plan: PlanResponse) -> Result<u64, ReplaceError>;
+    /// Removes the plan; returns `false` when no plan had this id.
+    fn remove(&self, id: &str) -> bool;
+}
@@ -27,6 +27,7 @@ pub struct VarietyFilter {
 pub trait VarietyRepository: Send + Sync {
     async fn get_all(&self, locale: &str) -> Result<Vec<Variety>, RepositoryError>;
     /// Fetches varieties whose IDs are in `ids`. Ordering is not guaranteed.
+    /// A vegetable alias in `ids` fetches that vegetable's namesake variety.
     /// Silently omits IDs that are not found.
     async fn get_by_ids(
         &self,
@@ -41,13 +41,28 @@ pub struct VarietyResponse {
     pub latin_name: String,
     pub calendars: Vec<RegionCalendar>,
     pub sun_requirement: Vec<SunExposure>,
+    /// Minimum hours of direct sun per day the variety needs to crop well.
+    pub min_sun_hours: u32,
+    /// Typical harvest of one plant over its season, in grams.
+    pub yield_per_plant_g: u32,
     pub soil_types: Vec<SoilType>,
     pub spacing_cm: u32,
+    /// Cells per side of the square block one plant takes in a plan, derived from
+    /// `spacing_cm` as the planner does.
+    #[schema(example = 2)]
+    pub cell_span: u32,
+    /// Plants the planner puts in each cell of that block (`1` when `cellSpan > 1`).
+    #[schema(example = 1)]
+    pub plants_per_cell: u32,
     pub days_to_harvest: u32,
     pub days_to_plant: u32,
     pub lifecycle: Lifecycle,
     pub beginner_friendly: bool,
     pub category: Category,
+    /// Default colour of `category`, as `#RRGGBB` (see `GET /api/categories`).
+    pub color_hex: String,
+    /// Default emoji of `category`.
+    pub icon: String,
 }
 
 /// Outbound port: provides read access to the variety catalogue returning
@@ -24,4 +24,11 @@ pub trait VegetableRepository: Send + Sync {
         page: usize,
         size: usize,
     ) -> Result<Page<Vegetable>, RepositoryError>;
+    /// Notes on the documented companion pairs `id` belongs to (in either position),
+    /// as `(other vegetable id, note)`.
+    async fn get_companion_notes(
+        &self,
+        id: &str,
+        locale: &str,
+    ) -> Result<Vec<(String, String)>, RepositoryError>;
 }
@@ -0,0 +1,42 @@
+use crate::application::models::request::BedSizeRequest;
+use crate::application::ports::variety_repository::VarietyRepository;
+use crate::application::use_cases::plan_garden::{alias_lookup, PlanGardenError};
+use crate::domain::models::{error::PlanError, request::Preference, response::BedSizeResponse};
+use crate::domain::services::sizing::bed_size_for;
+
+/// Use case: size a bed for a wishlist of plants — the reverse of planning into a
+/// fixed grid.
+pub struct BedSizeUseCase<'a> {
+    repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> BedSizeUseCase<'a> {
+    pub fn new(repo: &'a dyn VarietyRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        request: &BedSizeRequest,
+        locale: &str,
+    ) -> Result<BedSizeResponse, PlanGardenError> {
+        let ids: Vec<String> = request.preferences.iter().map(|p| p.id.clone()).collect();
+        let lookup = alias_lookup(self.repo.get_by_ids(&ids, locale).await?);
+        let preferences = request
+            .preferences
+            .iter()
+            .map(|p| {
+                let variety = lookup
+                    .get(&p.id)
+                    .cloned()
+                    .ok_or_else(|| PlanError::UnknownVariety { id: p.id.clone() })?;
+                Ok(Preference {
+                    variety,
+                    quantity: p.quantity,
+                    area_m2: p.area_m2,
+                })
+            })
+            .collect::<Result<Vec<_>, PlanError>>()?;
+        Ok(bed_size_for(&preferences)?)
+    }
+}
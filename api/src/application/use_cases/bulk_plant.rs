@@ -0,0 +1,75 @@
+use chrono::Local;
+
+use crate::application::models::request::BulkPlantRequest;
+use crate::application::ports::variety_repository::VarietyRepository;
+use crate::application::use_cases::plan_garden::{
+    alias_lookup, layout_ids, resolve_layout, PlanGardenError,
+};
+use crate::domain::models::{
+    error::PlanError, response::BulkPlantResponse, variety::Region, warnings::Warnings, Coordinate,
+};
+use crate::domain::services::{
+    grid::{initialize_grid, validate_layout, GridSize},
+    repair::bulk_plant,
+    response::build_grid_cells,
+};
+
+/// Use case: stamp several plants into a layout at once, reporting for each whether it
+/// was accepted and why not.
+pub struct BulkPlantUseCase<'a> {
+    repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> BulkPlantUseCase<'a> {
+    pub fn new(repo: &'a dyn VarietyRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        request: &BulkPlantRequest,
+        locale: &str,
+    ) -> Result<BulkPlantResponse, PlanGardenError> {
+        let ids: Vec<String> = layout_ids(&request.layout)
+            .chain(request.placements.iter().map(|p| p.id.clone()))
+            .collect();
+        let lookup = alias_lookup(self.repo.get_by_ids(&ids, locale).await?);
+        let (layout, unknown) = resolve_layout(&request.layout, &lookup);
+        if let Some(id) = unknown.into_iter().next() {
+            return Err(PlanError::UnknownVegetable { id }.into());
+        }
+        let GridSize(rows, cols) = validate_layout(&layout)?;
+
+        let today = Local::now().date_naive();
+        let mut grid = initialize_grid(
+            rows,
+            cols,
+            &layout,
+            &request.blocked_regions,
+            today,
+            &Region::Temperate,
+            &mut Warnings::new(),
+        );
+        let placements = request.placements.iter().map(|p| {
+            let anchor = Coordinate {
+                row: p.row,
+                col: p.col,
+            };
+            (p.id.as_str(), anchor)
+        });
+        let outcomes = bulk_plant(
+            &mut grid,
+            placements,
+            &lookup,
+            request.check_companions,
+            today,
+        );
+        let accepted = outcomes.iter().filter(|o| o.accepted).count();
+        Ok(BulkPlantResponse {
+            accepted,
+            rejected: outcomes.len() - accepted,
+            placements: outcomes,
+            grid: build_grid_cells(&grid),
+        })
+    }
+}
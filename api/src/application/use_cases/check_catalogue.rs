@@ -0,0 +1,45 @@
+use std::collections::HashSet;
+
+use crate::application::ports::{
+    variety_repository::VarietyRepository, vegetable_repository::VegetableRepository,
+    RepositoryError,
+};
+
+/// Use case: check every vegetable and variety of the catalogue with
+/// [`Vegetable::validate`](crate::domain::models::vegetable::Vegetable::validate) and
+/// [`Variety::validate`](crate::domain::models::variety::Variety::validate).
+pub struct CheckCatalogueUseCase<'a> {
+    vegetables: &'a dyn VegetableRepository,
+    varieties: &'a dyn VarietyRepository,
+}
+
+impl<'a> CheckCatalogueUseCase<'a> {
+    pub fn new(
+        vegetables: &'a dyn VegetableRepository,
+        varieties: &'a dyn VarietyRepository,
+    ) -> Self {
+        Self {
+            vegetables,
+            varieties,
+        }
+    }
+
+    /// Returns every problem found, each reported once; empty when the catalogue is
+    /// consistent.
+    pub async fn execute(&self) -> Result<Vec<String>, RepositoryError> {
+        let vegetables = self.vegetables.get_all("en").await?;
+        let varieties = self.varieties.get_all("en").await?;
+        let known_ids: HashSet<String> = vegetables.iter().map(|v| v.id.clone()).collect();
+        let mut problems: Vec<String> = Vec::new();
+        let results = vegetables
+            .iter()
+            .map(|v| v.validate(&known_ids))
+            .chain(varieties.iter().map(|v| v.validate(&known_ids)));
+        for problem in results.filter_map(Result::err).flatten() {
+            if !problems.contains(&problem) {
+                problems.push(problem);
+            }
+        }
+        Ok(problems)
+    }
+}
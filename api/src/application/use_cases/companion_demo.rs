@@ -0,0 +1,149 @@
+use std::fmt;
+
+use chrono::{Duration, NaiveDate, Weekday};
+
+use crate::application::ports::{variety_repository::VarietyRepository, RepositoryError};
+use crate::application::use_cases::plan_garden::alias_lookup;
+use crate::domain::models::{
+    request::{LayoutCell, Period, PlanParams, Preference},
+    response::CompanionDemoResponse,
+    variety::{Month, Region, Variety},
+    warnings::Language,
+};
+use crate::domain::services::{
+    filter::{filter_candidates_base, is_active_month},
+    planner::plan_garden,
+};
+
+/// Largest number of rows or columns a demo grid may have.
+pub const MAX_DEMO_SIDE: usize = 10;
+
+/// Year the demo week is taken from; only the month matters to the planner.
+const DEMO_YEAR: i32 = 2025;
+
+/// Why [`CompanionDemoUseCase`] could not produce a demo.
+#[derive(Debug)]
+pub enum CompanionDemoError {
+    /// The query names unknown varieties, out-of-range dimensions, or a pair that is
+    /// never in season together.
+    Invalid(String),
+    /// The variety catalogue could not be read.
+    Repository(RepositoryError),
+}
+
+impl fmt::Display for CompanionDemoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(msg) => f.write_str(msg),
+            Self::Repository(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for CompanionDemoError {}
+
+impl From<RepositoryError> for CompanionDemoError {
+    fn from(e: RepositoryError) -> Self {
+        Self::Repository(e)
+    }
+}
+
+/// First month (January to December) in which both varieties can be sown or planted
+/// in `region`.
+fn shared_month(a: &Variety, b: &Variety, region: &Region) -> Option<u32> {
+    let active = |v: &Variety, month: Month| {
+        v.calendars
+            .iter()
+            .any(|c| &c.region == region && is_active_month(c, month))
+    };
+    (1..=12).find(|&m| active(a, Month::from_u32(m)) && active(b, Month::from_u32(m)))
+}
+
+/// Use case: plan a small empty grid with only two varieties, to show how the planner
+/// arranges them next to each other.
+pub struct CompanionDemoUseCase<'a> {
+    repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> CompanionDemoUseCase<'a> {
+    pub fn new(repo: &'a dyn VarietyRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        a: &str,
+        b: &str,
+        rows: usize,
+        cols: usize,
+        region: Region,
+        locale: &str,
+    ) -> Result<CompanionDemoResponse, CompanionDemoError> {
+        if !(1..=MAX_DEMO_SIDE).contains(&rows) || !(1..=MAX_DEMO_SIDE).contains(&cols) {
+            return Err(CompanionDemoError::Invalid(format!(
+                "rows and cols must be between 1 and {MAX_DEMO_SIDE}."
+            )));
+        }
+
+        let lookup = alias_lookup(
+            self.repo
+                .get_by_ids(&[a.to_owned(), b.to_owned()], locale)
+                .await?,
+        );
+        let resolve = |id: &str| {
+            lookup
+                .get(id)
+                .cloned()
+                .ok_or_else(|| CompanionDemoError::Invalid(format!("Unknown variety '{id}'.")))
+        };
+        let (a, b) = (resolve(a)?, resolve(b)?);
+        if a.id == b.id {
+            return Err(CompanionDemoError::Invalid(
+                "a and b must be two different varieties.".into(),
+            ));
+        }
+
+        let month = shared_month(&a, &b, &region).ok_or_else(|| {
+            CompanionDemoError::Invalid(format!(
+                "'{}' and '{}' are never in season together in this region.",
+                a.id, b.id
+            ))
+        })?;
+        let start = NaiveDate::from_weekday_of_month_opt(DEMO_YEAR, month, Weekday::Mon, 1)
+            .expect("every month has a first Monday");
+        let period = Period {
+            start,
+            end: start + Duration::days(6),
+        };
+
+        let params = PlanParams {
+            period: Some(period.clone()),
+            preferences: [&a, &b]
+                .into_iter()
+                .map(|v| Preference {
+                    variety: v.clone(),
+                    quantity: None,
+                    area_m2: None,
+                })
+                .collect(),
+            language: Language::from_locale(locale),
+            ..PlanParams::new(region, vec![vec![LayoutCell::Empty; cols]; rows])
+        };
+        let candidates = filter_candidates_base(&[a.clone(), b.clone()], &params);
+        let plan = plan_garden(candidates, &params)
+            .map_err(|e| CompanionDemoError::Invalid(e.to_string()))?;
+        let week = plan
+            .weeks
+            .into_iter()
+            .next()
+            .expect("a one-week period yields one weekly plan");
+
+        Ok(CompanionDemoResponse {
+            a: a.id,
+            b: b.id,
+            period,
+            grid: week.grid,
+            score: week.score,
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use crate::domain::models::request::Level;
+use crate::domain::models::response::{CategoriesResponse, CategoryInfo, EnumsResponse};
+use crate::domain::models::variety::{Category, Lifecycle, Region, Season, SoilType, SunExposure};
+
+/// Use case: list every value accepted for the request / response enums, so that
+/// clients can build their forms without hardcoding them.
+pub struct GetEnumsUseCase;
+
+impl GetEnumsUseCase {
+    pub fn execute(&self) -> EnumsResponse {
+        EnumsResponse {
+            seasons: Season::all().to_vec(),
+            soil_types: SoilType::all().to_vec(),
+            sun_exposures: SunExposure::all().to_vec(),
+            regions: Region::all().to_vec(),
+            categories: Category::all().to_vec(),
+            lifecycles: Lifecycle::all().to_vec(),
+            levels: Level::all().to_vec(),
+        }
+    }
+}
+
+/// Use case: list every variety category with the colour and icon clients should
+/// render it with, so that every frontend shows them alike.
+pub struct ListCategoriesUseCase;
+
+impl ListCategoriesUseCase {
+    pub fn execute(&self) -> CategoriesResponse {
+        CategoriesResponse {
+            categories: Category::all()
+                .iter()
+                .map(|category| CategoryInfo {
+                    category: category.clone(),
+                    color_hex: category.color_hex().to_owned(),
+                    icon: category.icon().to_owned(),
+                })
+                .collect(),
+        }
+    }
+}
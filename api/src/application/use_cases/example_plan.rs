@@ -0,0 +1,96 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::NaiveDate;
+
+use crate::application::models::request::{LayoutCell, PlanRequest};
+use crate::application::ports::variety_repository::VarietyRepository;
+use crate::application::use_cases::plan_garden::{PlanGardenError, PlanGardenUseCase};
+use crate::domain::models::{
+    request::{Period, PreferenceEntry},
+    response::PlanResponse,
+    variety::Region,
+};
+
+/// Grid dimensions of the example bed.
+const EXAMPLE_ROWS: usize = 4;
+const EXAMPLE_COLS: usize = 6;
+
+/// Use case: plan a fixed, curated summer bed, so the API can be explored without
+/// writing a request. It runs the real planner, so the example always matches it.
+pub struct ExamplePlanUseCase<'a> {
+    repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> ExamplePlanUseCase<'a> {
+    pub fn new(repo: &'a dyn VarietyRepository) -> Self {
+        Self { repo }
+    }
+
+    /// The request the example stands for: one early-June week on a 4×6 temperate bed,
+    /// a tomato and two basil plants preferred and a pre-placed lettuce in the corner.
+    pub fn example_request() -> PlanRequest {
+        let start = NaiveDate::from_ymd_opt(2025, 6, 2).expect("valid date");
+        let end = NaiveDate::from_ymd_opt(2025, 6, 8).expect("valid date");
+        let mut layout = vec![vec![LayoutCell::Empty; EXAMPLE_COLS]; EXAMPLE_ROWS];
+        layout[0][0] = LayoutCell::SelfContained {
+            id: "lettuce".into(),
+            plants_per_cell: None,
+            planted_date: None,
+        };
+        PlanRequest {
+            period: Some(Period { start, end }),
+            sun: None,
+            available_sun_hours: None,
+            soil: None,
+            region: Region::Temperate,
+            level: None,
+            beginner_mode: None,
+            preferences: Some(vec![
+                PreferenceEntry {
+                    id: "tomato".into(),
+                    quantity: Some(1),
+                    area_m2: None,
+                    near: Vec::new(),
+                    keep_together: false,
+                },
+                PreferenceEntry {
+                    id: "basil".into(),
+                    quantity: Some(2),
+                    area_m2: None,
+                    near: vec!["tomato".into()],
+                    keep_together: false,
+                },
+            ]),
+            exclusions: Vec::new(),
+            ranking: HashMap::new(),
+            avoid_pairs: Vec::new(),
+            avoid_pair_penalty: None,
+            neutral_competitor_penalty: None,
+            season_overlap: false,
+            sown: HashMap::new(),
+            layout,
+            layout_rle: None,
+            blocked_regions: Vec::new(),
+            blocked_cells: Vec::new(),
+            zones: Vec::new(),
+            plantable_mask: None,
+            pack: None,
+            objective: None,
+            density: None,
+            weights: BTreeMap::new(),
+            target_fill_pct: None,
+            max_total_plants: None,
+            suggest_fixes: false,
+            fill: true,
+            timeout_ms: None,
+            strict_companions: false,
+            mutual_companions: false,
+        }
+    }
+
+    pub async fn execute(&self, locale: &str) -> Result<PlanResponse, PlanGardenError> {
+        PlanGardenUseCase::new(self.repo)
+            .execute(&Self::example_request(), locale)
+            .await
+    }
+}
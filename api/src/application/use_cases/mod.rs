@@ -1,4 +1,22 @@
+pub mod bed_size;
+pub mod bulk_plant;
+pub mod check_catalogue;
+pub mod companion_demo;
+pub mod enums;
+pub mod example_plan;
 pub mod groups;
+pub mod place_one;
+pub mod plan_batch;
+pub mod plan_diff;
 pub mod plan_garden;
+pub mod plan_preview;
+pub mod plan_recommendations;
+pub mod plan_succession;
+pub mod plan_transition;
+pub mod preview_placement;
+pub mod quick_plan;
+pub mod stored_plans;
+pub mod validate_layout;
 pub mod varieties;
 pub mod vegetables;
+pub mod what_if;
@@ -0,0 +1,95 @@
+use chrono::Local;
+
+use crate::application::models::request::PlaceOneRequest;
+use crate::application::ports::variety_repository::VarietyRepository;
+use crate::application::use_cases::plan_garden::{
+    alias_lookup, layout_ids, resolve_layout, PlanGardenError,
+};
+use crate::domain::models::{
+    error::PlanError, request::PlacementOptions, response::PlaceOneResponse, variety::Region,
+    warnings::Warnings,
+};
+use crate::domain::services::{
+    grid::{initialize_grid, validate_layout, GridSize},
+    helpers::cell_span,
+    placement::{place_one, PlacementWeek},
+    response::{build_grid_cells, build_reason},
+};
+
+/// Use case: add one plant to an existing layout on the block the planner scores best
+/// against its neighbours, without re-planning anything else.
+pub struct PlaceOneUseCase<'a> {
+    repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> PlaceOneUseCase<'a> {
+    pub fn new(repo: &'a dyn VarietyRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        request: &PlaceOneRequest,
+        locale: &str,
+    ) -> Result<PlaceOneResponse, PlanGardenError> {
+        let ids: Vec<String> = layout_ids(&request.layout)
+            .chain(std::iter::once(request.id.clone()))
+            .collect();
+        let lookup = alias_lookup(self.repo.get_by_ids(&ids, locale).await?);
+        let variety =
+            lookup
+                .get(&request.id)
+                .cloned()
+                .ok_or_else(|| PlanError::UnknownVariety {
+                    id: request.id.clone(),
+                })?;
+        let (layout, unknown) = resolve_layout(&request.layout, &lookup);
+        if let Some(id) = unknown.into_iter().next() {
+            return Err(PlanError::UnknownVegetable { id }.into());
+        }
+        let GridSize(rows, cols) = validate_layout(&layout)?;
+
+        let today = Local::now().date_naive();
+        let mut grid = initialize_grid(
+            rows,
+            cols,
+            &layout,
+            &request.blocked_regions,
+            today,
+            &Region::Temperate,
+            &mut Warnings::new(),
+        );
+        let options = PlacementOptions::default();
+        let week = PlacementWeek {
+            rows,
+            cols,
+            week_idx: 0,
+            week_start: today,
+            options: &options,
+            companions: &[],
+            deadline: None,
+            plant_budget: None,
+            trace: None,
+        };
+        let (anchor, score) =
+            place_one(&mut grid, &variety, &week, build_reason).ok_or_else(|| {
+                PlanError::NoFreeBlock {
+                    id: variety.id.clone(),
+                    span: cell_span(variety.spacing_cm),
+                }
+            })?;
+
+        let placed = grid.cells[anchor.row][anchor.col]
+            .variety
+            .as_ref()
+            .expect("place_one fills the anchor cell");
+        Ok(PlaceOneResponse {
+            id: variety.id.clone(),
+            anchor,
+            span: placed.span,
+            score,
+            reason: placed.reason.clone(),
+            grid: build_grid_cells(&grid),
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use crate::application::models::request::PlanRequest;
+use crate::application::ports::variety_repository::VarietyRepository;
+use crate::application::use_cases::plan_garden::{PlanGardenError, PlanGardenUseCase};
+use crate::domain::models::response::{BatchPlanItem, BatchPlanResponse};
+
+/// Largest number of requests accepted in one batch.
+pub const MAX_BATCH_SIZE: usize = 20;
+
+/// Use case: plan several independent scenarios in one call.
+///
+/// Each request goes through the same pipeline as `POST /api/plan`; a failing request
+/// becomes an `Error` item at its position instead of failing the whole batch. Storage
+/// failures are logged and reported as `Internal server error`, as `POST /api/plan` does.
+pub struct PlanBatchUseCase<'a> {
+    repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> PlanBatchUseCase<'a> {
+    pub fn new(repo: &'a dyn VarietyRepository) -> Self {
+        Self { repo }
+    }
+
+    /// `requests` holds one entry per submitted item; items that could not even be
+    /// parsed are passed as `Err` and reported as-is. Returns `Err` only for an empty
+    /// batch or one larger than [`MAX_BATCH_SIZE`].
+    pub async fn execute(
+        &self,
+        requests: Vec<Result<PlanRequest, String>>,
+        locale: &str,
+    ) -> Result<BatchPlanResponse, String> {
+        if requests.is_empty() {
+            return Err("A batch must contain at least one plan request.".into());
+        }
+        if requests.len() > MAX_BATCH_SIZE {
+            return Err(format!(
+                "A batch may contain at most {MAX_BATCH_SIZE} plan requests, got {}.",
+                requests.len()
+            ));
+        }
+
+        let planner = PlanGardenUseCase::new(self.repo);
+        let mut items = Vec::with_capacity(requests.len());
+        for request in requests {
+            let request = request.and_then(|mut r| {
+                r.expand_layout().map_err(|e| e.to_string())?;
+                r.validate().map(|()| r)
+            });
+            let outcome = match request {
+                Ok(request) => planner
+                    .execute(&request, locale)
+                    .await
+                    .map_err(|e| match e {
+                        PlanGardenError::Plan(e) => e.to_string(),
+                        PlanGardenError::Repository(_) => {
+                            log::error!("Failed to plan a batch item: {e}");
+                            "Internal server error".to_owned()
+                        }
+                    }),
+                Err(e) => Err(e),
+            };
+            items.push(match outcome {
+                Ok(plan) => BatchPlanItem::Ok {
+                    plan: Box::new(plan),
+                },
+                Err(error) => BatchPlanItem::Error { error },
+            });
+        }
+
+        let succeeded = items
+            .iter()
+            .filter(|i| matches!(i, BatchPlanItem::Ok { .. }))
+            .count();
+        Ok(BatchPlanResponse {
+            failed: items.len() - succeeded,
+            succeeded,
+            items,
+        })
+    }
+}
@@ -0,0 +1,12 @@
+use crate::application::models::request::PlanDiffRequest;
+use crate::domain::models::response::PlanDiffResponse;
+use crate::domain::services::diff::diff_plans;
+
+/// Use case: compare two planned grids and report what changed.
+pub struct DiffPlansUseCase;
+
+impl DiffPlansUseCase {
+    pub fn execute(&self, request: &PlanDiffRequest) -> Result<PlanDiffResponse, String> {
+        diff_plans(&request.before, &request.after)
+    }
+}
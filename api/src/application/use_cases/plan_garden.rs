@@ -1,10 +1,82 @@
 use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+use chrono::Datelike;
 
 use crate::application::models::request::{LayoutCell as RawLayoutCell, PlanRequest};
-use crate::application::ports::variety_repository::{VarietyFilter, VarietyRepository};
-use crate::domain::models::request::{LayoutCell, Level, PlanParams, Preference, SownEntry};
-use crate::domain::models::{response::PlanResponse, variety::Variety};
-use crate::domain::services::{filter::filter_candidates_base, planner::plan_garden};
+use crate::application::ports::{
+    variety_repository::{VarietyFilter, VarietyRepository},
+    RepositoryError,
+};
+use crate::domain::models::request::{
+    LayoutCell, Level, PlacementOptions, PlanParams, Preference, SownEntry,
+    DEFAULT_AVOID_PAIR_PENALTY,
+};
+use crate::domain::models::{
+    error::PlanError,
+    garden::GardenGrid,
+    response::{
+        CandidateEliminations, DebugPlanResponse, PlacementStep, PlanResponse, PlanTraceResponse,
+    },
+    variety::{Month, Variety},
+    warnings::{Language, Warnings},
+    Matrix,
+};
+use crate::domain::services::{
+    filter::{
+        count_eliminations, filter_candidates_base, filter_varieties, species_order,
+        CandidateCriteria,
+    },
+    placement::PlacementTrace,
+    planner::plan_garden_traced,
+};
+
+/// Environment variable holding the default planning time limit, in milliseconds.
+pub const PLAN_TIMEOUT_ENV: &str = "GARDEN_PLAN_TIMEOUT_MS";
+
+/// Planning time limit from [`PLAN_TIMEOUT_ENV`]; `None` when unset or not a number.
+fn default_timeout_ms() -> Option<u64> {
+    std::env::var(PLAN_TIMEOUT_ENV).ok()?.parse().ok()
+}
+
+/// Sorted planning candidates and enriched parameters, ready for the domain planner.
+pub(crate) struct PreparedPlan {
+    pub candidates: Vec<Variety>,
+    pub params: PlanParams,
+}
+
+/// Why [`PlanGardenUseCase`] could not produce a plan.
+#[derive(Debug)]
+pub enum PlanGardenError {
+    /// The planner rejected the request.
+    Plan(PlanError),
+    /// The variety catalogue could not be read.
+    Repository(RepositoryError),
+}
+
+impl fmt::Display for PlanGardenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Plan(e) => e.fmt(f),
+            Self::Repository(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for PlanGardenError {}
+
+impl From<PlanError> for PlanGardenError {
+    fn from(e: PlanError) -> Self {
+        Self::Plan(e)
+    }
+}
+
+impl From<RepositoryError> for PlanGardenError {
+    fn from(e: RepositoryError) -> Self {
+        Self::Repository(e)
+    }
+}
 
 /// Use case: generate an optimised garden plan.
 ///
@@ -29,134 +101,351 @@ impl<'a> PlanGardenUseCase<'a> {
         &self,
         request: &PlanRequest,
         locale: &str,
-    ) -> Result<PlanResponse, String> {
+    ) -> Result<PlanResponse, PlanGardenError> {
+        Ok(self.execute_debug(request, locale).await?.plan)
+    }
+
+    /// [`Self::execute`], keeping the planner's internal grid alongside the plan.
+    pub async fn execute_debug(
+        &self,
+        request: &PlanRequest,
+        locale: &str,
+    ) -> Result<DebugPlanResponse, PlanGardenError> {
+        let (plan, raw_grid, _) = self.run(request, locale, false).await?;
+        Ok(DebugPlanResponse { plan, raw_grid })
+    }
+
+    /// [`Self::execute`], with every placement decision in the order it was made.
+    pub async fn execute_trace(
+        &self,
+        request: &PlanRequest,
+        locale: &str,
+    ) -> Result<PlanTraceResponse, PlanGardenError> {
+        let (plan, _, trace) = self.run(request, locale, true).await?;
+        Ok(PlanTraceResponse { plan, trace })
+    }
+
+    /// Step 4: plans the prepared request, returning the plan, the internal grid and,
+    /// when `traced`, the placement decisions.
+    async fn run(
+        &self,
+        request: &PlanRequest,
+        locale: &str,
+        traced: bool,
+    ) -> Result<(PlanResponse, GardenGrid, Vec<PlacementStep>), PlanGardenError> {
+        let prepared = self.prepare(request, locale).await?;
+        log::debug!(
+            "Planning {}x{} grid with {} candidate(s)",
+            request.layout.len(),
+            request.layout.first().map_or(0, Vec::len),
+            prepared.candidates.len()
+        );
+        let PreparedPlan { candidates, params } = prepared;
+        let trace = traced.then(PlacementTrace::default);
+        let (mut plan, raw_grid) = plan_garden_traced(candidates.clone(), &params, trace.as_ref())?;
+        let steps = trace.map_or_else(Vec::new, PlacementTrace::into_steps);
+        if let Some(eliminations) = self
+            .explain_no_candidates(request, &candidates, &params, &plan, locale)
+            .await?
+        {
+            let warning = Warnings::no_candidates(params.language, &eliminations);
+            plan.warnings.insert(0, warning);
+            plan.no_candidates = Some(eliminations);
+        }
+        log::info!(
+            "Plan generated: {} week(s), {} warning(s)",
+            plan.weeks.len(),
+            plan.warnings.len()
+        );
+        Ok((plan, raw_grid, steps))
+    }
+
+    /// When no candidate is in season for any planned week, counts the catalogue
+    /// varieties each of the request's constraints rules out; `None` otherwise.
+    async fn explain_no_candidates(
+        &self,
+        request: &PlanRequest,
+        candidates: &[Variety],
+        params: &PlanParams,
+        plan: &PlanResponse,
+        locale: &str,
+    ) -> Result<Option<CandidateEliminations>, PlanGardenError> {
+        let Some(period) = &plan.applied.period else {
+            return Ok(None);
+        };
+        let months: Vec<Month> = period
+            .start
+            .iter_weeks()
+            .take_while(|start| *start <= period.end)
+            .map(|start| Month::from_u32(start.month()))
+            .collect();
+        if months
+            .iter()
+            .any(|&month| !filter_varieties(candidates, params, month).is_empty())
+        {
+            return Ok(None);
+        }
+        let catalogue = self.repo.get_all(locale).await?;
+        let filter = VarietyFilter::from(request);
+        Ok(Some(count_eliminations(
+            &catalogue,
+            &CandidateCriteria {
+                region: &filter.region,
+                sun: filter.sun.as_ref(),
+                available_sun_hours: request.available_sun_hours,
+                soil: filter.soil.as_ref(),
+                beginner_only: filter.beginner_only,
+                exclusions: &filter.exclusions,
+                season_overlap: request.season_overlap,
+                months: &months,
+            },
+        )))
+    }
+
+    /// Steps 1–3: loads and sorts the candidates and builds the enriched [`PlanParams`].
+    pub(crate) async fn prepare(
+        &self,
+        request: &PlanRequest,
+        locale: &str,
+    ) -> Result<PreparedPlan, PlanGardenError> {
         let filter = VarietyFilter::from(request);
+        // Planned varieties use the density preset's spacing; pre-placed ones keep theirs.
+        let density = request.density.clone().unwrap_or_default();
+        let with_density = |v: &Variety| Variety {
+            spacing_cm: density.scale_spacing(v.spacing_cm),
+            ..v.clone()
+        };
         // SQL-filtered candidates — avoid loading the full catalogue into memory.
-        let filtered = self
+        let filtered: Vec<Variety> = self
             .repo
             .get_for_planning(&filter, locale)
-            .await
-            .map_err(|e| e.to_string())?;
+            .await?
+            .iter()
+            .map(with_density)
+            .collect();
         // Collect all variety IDs needed for enrichment (preferences, sown, layout cells)
         // and fetch only those — avoids loading the full catalogue.
         let mut needed_ids: std::collections::HashSet<String> = std::collections::HashSet::new();
         for p in request.preferences.as_deref().unwrap_or(&[]) {
             needed_ids.insert(p.id.clone());
+            needed_ids.extend(p.near.iter().cloned());
         }
         for id in request.sown.keys() {
             needed_ids.insert(id.clone());
         }
-        for row in &request.layout {
-            for cell in row {
-                match cell {
-                    RawLayoutCell::SelfContained { id, .. }
-                    | RawLayoutCell::Overflowing { id, .. } => {
-                        needed_ids.insert(id.clone());
-                    }
-                    _ => {}
-                }
-            }
-        }
+        needed_ids.extend(layout_ids(&request.layout));
         let ids: Vec<String> = needed_ids.into_iter().collect();
-        let resolved = self
-            .repo
-            .get_by_ids(&ids, locale)
-            .await
-            .map_err(|e| e.to_string())?;
-        let lookup: HashMap<String, Variety> =
-            resolved.into_iter().map(|v| (v.id.clone(), v)).collect();
+        let resolved = self.repo.get_by_ids(&ids, locale).await?;
+        let lookup = alias_lookup(resolved);
 
-        // Enrich preferences with resolved Variety objects (unknown IDs are silently dropped).
+        // Enrich preferences with resolved Variety objects; unknown IDs are dropped and
+        // warned about by the planner.
+        let mut unknown_preferences: Vec<String> = Vec::new();
         let preferences: Vec<Preference> = request
             .preferences
             .as_deref()
             .unwrap_or(&[])
             .iter()
             .filter_map(|p| {
-                lookup.get(&p.id).map(|v| Preference {
-                    variety: v.clone(),
+                let variety = lookup.get(&p.id);
+                if variety.is_none() && !unknown_preferences.contains(&p.id) {
+                    unknown_preferences.push(p.id.clone());
+                }
+                variety.map(|v| Preference {
+                    variety: with_density(v),
                     quantity: p.quantity,
+                    area_m2: p.area_m2,
                 })
             })
             .collect();
 
+        // Resolve `near` hints to canonical ids; unknown ones are warned about by the planner.
+        let mut near: Vec<(String, String)> = Vec::new();
+        let mut unknown_near: Vec<String> = Vec::new();
+        let mut keep_together: Vec<String> = Vec::new();
+        for p in request.preferences.as_deref().unwrap_or(&[]) {
+            let Some(variety) = lookup.get(&p.id) else {
+                continue;
+            };
+            if p.keep_together {
+                keep_together.push(variety.id.clone());
+            }
+            for id in &p.near {
+                match lookup.get(id) {
+                    Some(neighbor) => near.push((variety.id.clone(), neighbor.id.clone())),
+                    None if !unknown_near.contains(id) => unknown_near.push(id.clone()),
+                    None => {}
+                }
+            }
+        }
+
         // Enrich sown entries with resolved Variety objects.
-        let sown: Vec<SownEntry> = request
+        let mut sown: Vec<SownEntry> = request
             .sown
             .iter()
             .filter_map(|(id, records)| {
                 lookup.get(id).map(|v| SownEntry {
-                    variety: v.clone(),
+                    variety: with_density(v),
                     records: records.clone(),
                 })
             })
             .collect();
+        sown.sort_by(|a, b| species_order(&a.variety.id, &b.variety.id));
 
-        // Enrich layout cells with resolved Variety objects (unknown IDs → Empty).
-        let layout =
-            request
-                .layout
-                .iter()
-                .map(|row| {
-                    row.iter()
-                        .map(|cell| match cell {
-                            RawLayoutCell::SelfContained {
-                                id,
-                                plants_per_cell,
-                                planted_date,
-                            } => lookup.get(id).map_or(LayoutCell::Empty, |v| {
-                                LayoutCell::SelfContained {
-                                    variety: v.clone(),
-                                    plants_per_cell: *plants_per_cell,
-                                    planted_date: *planted_date,
-                                }
-                            }),
-                            RawLayoutCell::Overflowing {
-                                id,
-                                plants_per_cell,
-                                width_cells,
-                                length_cells,
-                                planted_date,
-                            } => lookup.get(id).map_or(LayoutCell::Empty, |v| {
-                                LayoutCell::Overflowing {
-                                    variety: v.clone(),
-                                    plants_per_cell: *plants_per_cell,
-                                    width_cells: *width_cells,
-                                    length_cells: *length_cells,
-                                    planted_date: *planted_date,
-                                }
-                            }),
-                            RawLayoutCell::Overflowed { covered_by } => LayoutCell::Overflowed {
-                                covered_by: *covered_by,
-                            },
-                            RawLayoutCell::Empty => LayoutCell::Empty,
-                            RawLayoutCell::Blocked => LayoutCell::Blocked,
-                        })
-                        .collect()
-                })
-                .collect();
+        // Enrich layout cells with resolved Variety objects; an unknown ID is an error.
+        let (layout, unknown) = resolve_layout(&request.layout, &lookup);
+        if let Some(id) = unknown.into_iter().next() {
+            return Err(PlanError::UnknownVegetable { id }.into());
+        }
 
         let params = PlanParams {
             period: request.period.clone(),
             region: request.region.clone(),
+            available_sun_hours: request.available_sun_hours,
             preferences,
+            ranking: request.ranking.clone(),
+            season_overlap: request.season_overlap,
+            prefer_beginner: request.prefers_beginner(),
             sown,
             layout,
+            blocked_regions: request.blocked_regions.clone(),
+            blocked_cells: request.blocked_cells.clone(),
+            plantable_mask: request.plantable_mask.clone(),
+            placement: PlacementOptions {
+                pack: request.pack.clone().unwrap_or_default(),
+                objective: request.objective.clone().unwrap_or_default(),
+                weights: request.normalized_weights(),
+                target_fill_pct: request.target_fill_pct,
+                max_total_plants: request.max_total_plants,
+                avoid_pairs: request
+                    .avoid_pairs
+                    .iter()
+                    .map(|[a, b]| (a.clone(), b.clone()))
+                    .collect(),
+                avoid_pair_penalty: request
+                    .avoid_pair_penalty
+                    .map_or(DEFAULT_AVOID_PAIR_PENALTY, |p| p as i32),
+                neutral_competitor_penalty: request
+                    .neutral_competitor_penalty
+                    .map_or(0, |p| p as i32),
+                near,
+                keep_together,
+                preferences_only: !request.fill,
+                strict_companions: request.strict_companions,
+                mutual_companions: request.mutual_companions.then(|| {
+                    filtered
+                        .iter()
+                        .chain(lookup.values())
+                        .map(|v| (v.vegetable.id.clone(), v.vegetable.clone()))
+                        .collect()
+                }),
+                density,
+                zones: request.zones.clone(),
+                time_budget: request
+                    .timeout_ms
+                    .or_else(default_timeout_ms)
+                    .map(Duration::from_millis),
+            },
+            suggest_fixes: request.suggest_fixes,
+            unknown_near,
+            unknown_preferences,
+            language: Language::from_locale(locale),
         };
 
         // Sort by preferences / French consumption rank (application logic).
         let candidates = filter_candidates_base(&filtered, &params);
-        plan_garden(candidates, &params)
+        Ok(PreparedPlan { candidates, params })
     }
 }
 
+/// Indexes varieties by id and, for a vegetable's namesake variety, by each of the
+/// vegetable's aliases too — so aliased request ids enrich to the canonical variety.
+pub(crate) fn alias_lookup(varieties: Vec<Variety>) -> HashMap<String, Variety> {
+    let mut lookup = HashMap::new();
+    for v in varieties {
+        if v.id == v.vegetable.id {
+            for alias in &v.vegetable.aliases {
+                lookup.insert(alias.clone(), v.clone());
+            }
+        }
+        lookup.insert(v.id.clone(), v);
+    }
+    lookup
+}
+
+/// Variety ids referenced by the pre-placed cells of `layout`.
+pub(crate) fn layout_ids(layout: &[Vec<RawLayoutCell>]) -> impl Iterator<Item = String> + '_ {
+    layout.iter().flatten().filter_map(|cell| match cell {
+        RawLayoutCell::SelfContained { id, .. } | RawLayoutCell::Overflowing { id, .. } => {
+            Some(id.clone())
+        }
+        _ => None,
+    })
+}
+
+/// Enriches `layout` with the varieties of `lookup`. Cells naming an unknown id become
+/// `Empty`; those ids are returned too, deduplicated, in row-major order.
+pub(crate) fn resolve_layout(
+    layout: &[Vec<RawLayoutCell>],
+    lookup: &HashMap<String, Variety>,
+) -> (Matrix<LayoutCell>, Vec<String>) {
+    let mut unknown: Vec<String> = Vec::new();
+    let mut resolve = |id: &String| {
+        let variety = lookup.get(id).cloned();
+        if variety.is_none() && !unknown.contains(id) {
+            unknown.push(id.clone());
+        }
+        variety
+    };
+    let resolved = layout
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| match cell {
+                    RawLayoutCell::SelfContained {
+                        id,
+                        plants_per_cell,
+                        planted_date,
+                    } => {
+                        resolve(id).map_or(LayoutCell::Empty, |variety| LayoutCell::SelfContained {
+                            variety,
+                            plants_per_cell: *plants_per_cell,
+                            planted_date: *planted_date,
+                        })
+                    }
+                    RawLayoutCell::Overflowing {
+                        id,
+                        plants_per_cell,
+                        width_cells,
+                        length_cells,
+                        planted_date,
+                    } => resolve(id).map_or(LayoutCell::Empty, |variety| LayoutCell::Overflowing {
+                        variety,
+                        plants_per_cell: *plants_per_cell,
+                        width_cells: *width_cells,
+                        length_cells: *length_cells,
+                        planted_date: *planted_date,
+                    }),
+                    RawLayoutCell::Overflowed { covered_by } => LayoutCell::Overflowed {
+                        covered_by: *covered_by,
+                    },
+                    RawLayoutCell::Empty => LayoutCell::Empty,
+                    RawLayoutCell::Blocked => LayoutCell::Blocked,
+                    RawLayoutCell::Reserved => LayoutCell::Reserved,
+                })
+                .collect()
+        })
+        .collect();
+    (resolved, unknown)
+}
+
 impl From<&PlanRequest> for VarietyFilter {
     fn from(req: &PlanRequest) -> Self {
         Self {
             region: req.region.clone(),
             sun: req.sun.clone(),
             soil: req.soil.clone(),
-            beginner_only: matches!(req.level, Some(Level::Beginner)),
+            beginner_only: matches!(req.level, Some(Level::Beginner)) && !req.prefers_beginner(),
             exclusions: req.exclusions.clone(),
         }
     }
@@ -0,0 +1,80 @@
+use chrono::{Duration, NaiveDate, Weekday};
+
+use crate::application::ports::variety_repository::VarietyRepository;
+use crate::application::use_cases::plan_garden::PlanGardenError;
+use crate::domain::models::{
+    request::{LayoutCell, Period, PlanParams},
+    response::PlanResponse,
+    variety::{Month, Region, Variety},
+    warnings::Language,
+};
+use crate::domain::services::{
+    filter::{filter_candidates_base, is_active_month},
+    planner::plan_garden,
+};
+
+/// Grid dimensions of the preview bed: wide enough for two of the largest (4×4) blocks.
+const PREVIEW_ROWS: usize = 4;
+const PREVIEW_COLS: usize = 8;
+
+/// Year the preview week is taken from; only the month matters to the planner.
+const PREVIEW_YEAR: i32 = 2025;
+
+/// Region and first month (January to December) in which `variety` can be sown or
+/// planted: `Temperate` when its calendar allows it, otherwise the first region that
+/// does.
+fn preview_season(variety: &Variety) -> Option<(Region, u32)> {
+    let mut calendars: Vec<_> = variety.calendars.iter().collect();
+    calendars.sort_by_key(|c| c.region != Region::Temperate);
+    calendars.into_iter().find_map(|c| {
+        (1..=12)
+            .find(|&m| is_active_month(c, Month::from_u32(m)))
+            .map(|m| (c.region.clone(), m))
+    })
+}
+
+/// Use case: plan a small empty bed with a single vegetable, to show its block size,
+/// plants per cell and how many fit.
+pub struct PlanPreviewUseCase<'a> {
+    repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> PlanPreviewUseCase<'a> {
+    pub fn new(repo: &'a dyn VarietyRepository) -> Self {
+        Self { repo }
+    }
+
+    /// Plans a one-week 4×8 bed filled with the vegetable's namesake variety (or its first
+    /// variety) in the first month it is in season. Returns `Ok(None)` when `vegetable_id`
+    /// has no variety.
+    pub async fn execute(
+        &self,
+        vegetable_id: &str,
+        locale: &str,
+    ) -> Result<Option<PlanResponse>, PlanGardenError> {
+        let varieties = self.repo.get_by_vegetable_id(vegetable_id, locale).await?;
+        let Some(variety) = varieties
+            .iter()
+            .find(|v| v.id == v.vegetable.id)
+            .or(varieties.first())
+            .cloned()
+        else {
+            return Ok(None);
+        };
+
+        let (region, month) = preview_season(&variety).unwrap_or((Region::Temperate, 6));
+        let start = NaiveDate::from_weekday_of_month_opt(PREVIEW_YEAR, month, Weekday::Mon, 1)
+            .expect("every month has a first Monday");
+        let layout = vec![vec![LayoutCell::Empty; PREVIEW_COLS]; PREVIEW_ROWS];
+        let params = PlanParams {
+            period: Some(Period {
+                start,
+                end: start + Duration::days(6),
+            }),
+            language: Language::from_locale(locale),
+            ..PlanParams::new(region, layout)
+        };
+        let candidates = filter_candidates_base(&[variety], &params);
+        Ok(Some(plan_garden(candidates, &params)?))
+    }
+}
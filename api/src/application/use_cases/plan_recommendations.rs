@@ -0,0 +1,73 @@
+use chrono::{Datelike, Local};
+
+use crate::application::models::request::PlanRecommendationsRequest;
+use crate::application::ports::{
+    variety_repository::VarietyRepository, vegetable_repository::VegetableRepository,
+};
+use crate::application::use_cases::plan_garden::{
+    alias_lookup, layout_ids, resolve_layout, PlanGardenError,
+};
+use crate::application::use_cases::vegetables::vegetable_seasons;
+use crate::domain::models::{
+    error::PlanError, request::LayoutCell, response::PlanRecommendationsResponse,
+    variety::season_for_month, vegetable::Vegetable,
+};
+use crate::domain::services::companion::rank_recommendations;
+
+/// Use case: suggest what to add to a partly planted layout, ranked by companion
+/// benefit to the vegetables already in it.
+pub struct RecommendPlantingsUseCase<'a> {
+    vegetable_repo: &'a dyn VegetableRepository,
+    variety_repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> RecommendPlantingsUseCase<'a> {
+    pub fn new(
+        vegetable_repo: &'a dyn VegetableRepository,
+        variety_repo: &'a dyn VarietyRepository,
+    ) -> Self {
+        Self {
+            vegetable_repo,
+            variety_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: &PlanRecommendationsRequest,
+        locale: &str,
+    ) -> Result<PlanRecommendationsResponse, PlanGardenError> {
+        let ids: Vec<String> = layout_ids(&request.layout).collect();
+        let lookup = alias_lookup(self.variety_repo.get_by_ids(&ids, locale).await?);
+        let (layout, unknown) = resolve_layout(&request.layout, &lookup);
+        if let Some(id) = unknown.into_iter().next() {
+            return Err(PlanError::UnknownVegetable { id }.into());
+        }
+
+        let mut present: Vec<Vegetable> = Vec::new();
+        for cell in layout.iter().flatten() {
+            if let LayoutCell::SelfContained { variety, .. }
+            | LayoutCell::Overflowing { variety, .. } = cell
+            {
+                if !present.iter().any(|v| v.id == variety.vegetable.id) {
+                    present.push(Vegetable::clone(&variety.vegetable));
+                }
+            }
+        }
+        present.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let season = request
+            .season
+            .clone()
+            .unwrap_or_else(|| season_for_month(Local::now().month()));
+        let catalogue = self.vegetable_repo.get_all(locale).await?;
+        let seasons = vegetable_seasons(&self.variety_repo.get_all(locale).await?);
+        let recommendations = rank_recommendations(&present, &catalogue, &seasons, &season);
+
+        Ok(PlanRecommendationsResponse {
+            present: present.into_iter().map(|v| v.id).collect(),
+            season,
+            recommendations,
+        })
+    }
+}
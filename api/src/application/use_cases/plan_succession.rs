@@ -0,0 +1,33 @@
+use crate::application::models::request::PlanRequest;
+use crate::application::ports::variety_repository::VarietyRepository;
+use crate::application::use_cases::plan_garden::{PlanGardenError, PlanGardenUseCase};
+use crate::domain::models::response::SuccessionResponse;
+use crate::domain::services::{planner::plan_garden, succession::plan_succession};
+
+/// Use case: plan a garden, then propose follow-up crops for cells freed by
+/// early harvests within the same period.
+pub struct PlanSuccessionUseCase<'a> {
+    repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> PlanSuccessionUseCase<'a> {
+    pub fn new(repo: &'a dyn VarietyRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        request: &PlanRequest,
+        locale: &str,
+    ) -> Result<SuccessionResponse, PlanGardenError> {
+        let prepared = PlanGardenUseCase::new(self.repo)
+            .prepare(request, locale)
+            .await?;
+        let plan = plan_garden(prepared.candidates.clone(), &prepared.params)?;
+        Ok(plan_succession(
+            plan,
+            &prepared.candidates,
+            &prepared.params,
+        ))
+    }
+}
@@ -0,0 +1,93 @@
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+
+use crate::application::models::request::TransitionRequest;
+use crate::application::ports::variety_repository::VarietyRepository;
+use crate::application::use_cases::plan_garden::{PlanGardenError, PlanGardenUseCase};
+use crate::domain::models::{request::Period, response::TransitionResponse, variety::Season};
+use crate::domain::services::{planner::plan_garden, transition::clear_spent_plants};
+
+/// The first `season` starting after `after`: from the first Monday of its first month
+/// (planned weeks start on Mondays) to the last day of its third month.
+fn next_season_period(season: &Season, after: NaiveDate) -> Period {
+    let first_day =
+        |year| NaiveDate::from_ymd_opt(year, season.first_month(), 1).expect("months are 1 to 12");
+    let this_year = first_day(after.year());
+    let first_day = if this_year > after {
+        this_year
+    } else {
+        first_day(after.year() + 1)
+    };
+    Period {
+        start: NaiveDate::from_weekday_of_month_opt(
+            first_day.year(),
+            first_day.month(),
+            Weekday::Mon,
+            1,
+        )
+        .expect("every month has a first Monday"),
+        end: first_day + Months::new(3) - Duration::days(1),
+    }
+}
+
+/// Use case: turn a planted bed over to the next season, keeping the plants that are
+/// still growing and planting the freed cells.
+pub struct PlanTransitionUseCase<'a> {
+    repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> PlanTransitionUseCase<'a> {
+    pub fn new(repo: &'a dyn VarietyRepository) -> Self {
+        Self { repo }
+    }
+
+    /// Call after [`PlanRequest::validate`](crate::application::models::request::PlanRequest::validate)
+    /// on `request.plan`; `today` stands in for a missing `period`.
+    pub async fn execute(
+        &self,
+        request: &TransitionRequest,
+        locale: &str,
+        today: NaiveDate,
+    ) -> Result<TransitionResponse, PlanGardenError> {
+        let current_end = request.plan.period.as_ref().map_or(today, |p| p.end);
+        let period = next_season_period(&request.target_season, current_end);
+        let mut plan_request = request.plan.clone();
+        plan_request.period = Some(period.clone());
+
+        let mut prepared = PlanGardenUseCase::new(self.repo)
+            .prepare(&plan_request, locale)
+            .await?;
+        let (kept, cleared) = clear_spent_plants(&mut prepared.params.layout, period.start);
+        let plan = plan_garden(prepared.candidates, &prepared.params)?;
+        Ok(TransitionResponse {
+            target_season: request.target_season.clone(),
+            kept,
+            cleared,
+            plan,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_next_season_period() {
+        let summer = next_season_period(&Season::Summer, date(2025, 5, 31));
+        assert_eq!(
+            (summer.start, summer.end),
+            (date(2025, 6, 2), date(2025, 8, 31))
+        );
+        let spring = next_season_period(&Season::Spring, date(2025, 5, 31));
+        assert_eq!(spring.start, date(2026, 3, 2));
+        let winter = next_season_period(&Season::Winter, date(2025, 11, 30));
+        assert_eq!(
+            (winter.start, winter.end),
+            (date(2025, 12, 1), date(2026, 2, 28))
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt;
+
+use chrono::Local;
+
+use crate::application::models::request::PlacementPreviewRequest;
+use crate::application::ports::{variety_repository::VarietyRepository, RepositoryError};
+use crate::application::use_cases::plan_garden::{alias_lookup, layout_ids, resolve_layout};
+use crate::domain::models::{
+    response::PlacementPreviewResponse, variety::Region, warnings::Warnings, Coordinate,
+};
+use crate::domain::services::{
+    grid::{initialize_grid, validate_layout},
+    helpers::cell_span,
+    repair::preview_placement,
+};
+
+/// Why [`PreviewPlacementUseCase`] could not produce a preview.
+#[derive(Debug)]
+pub enum PreviewPlacementError {
+    /// The layout is malformed or the plant's block runs past the grid.
+    Invalid(String),
+    /// The previewed variety, or one in the layout, is not in the catalogue.
+    Unknown(String),
+    /// The variety catalogue could not be read.
+    Repository(RepositoryError),
+}
+
+impl fmt::Display for PreviewPlacementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(msg) | Self::Unknown(msg) => f.write_str(msg),
+            Self::Repository(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for PreviewPlacementError {}
+
+impl From<RepositoryError> for PreviewPlacementError {
+    fn from(e: RepositoryError) -> Self {
+        Self::Repository(e)
+    }
+}
+
+/// Use case: report whether dropping a plant onto a layout would put it next to a bad
+/// companion, and what companion score it would earn there.
+pub struct PreviewPlacementUseCase<'a> {
+    repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> PreviewPlacementUseCase<'a> {
+    pub fn new(repo: &'a dyn VarietyRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        request: &PlacementPreviewRequest,
+        locale: &str,
+    ) -> Result<PlacementPreviewResponse, PreviewPlacementError> {
+        let ids: Vec<String> = layout_ids(&request.layout)
+            .chain(std::iter::once(request.id.clone()))
+            .collect();
+        let lookup = alias_lookup(self.repo.get_by_ids(&ids, locale).await?);
+        let variety = lookup.get(&request.id).cloned().ok_or_else(|| {
+            PreviewPlacementError::Unknown(format!("Unknown variety '{}'.", request.id))
+        })?;
+        let (layout, unknown) = resolve_layout(&request.layout, &lookup);
+        if let Some(id) = unknown.into_iter().next() {
+            return Err(PreviewPlacementError::Unknown(format!(
+                "Unknown vegetable '{id}' in layout."
+            )));
+        }
+        validate_layout(&layout).map_err(|e| PreviewPlacementError::Invalid(e.to_string()))?;
+
+        let (rows, cols) = (layout.len(), layout[0].len());
+        let span = cell_span(variety.spacing_cm) as usize;
+        // Subtract rather than add: `row` and `col` come from the client unbounded.
+        let fits = |at: usize, len: usize| at < len && len - at >= span;
+        if !fits(request.row, rows) || !fits(request.col, cols) {
+            return Err(PreviewPlacementError::Invalid(format!(
+                "'{}' needs a {span}×{span} block at [{},{}], which runs past the {rows}×{cols} grid.",
+                variety.id, request.row, request.col
+            )));
+        }
+
+        // Planted dates only shift harvest estimates, which the preview does not expose.
+        let grid = initialize_grid(
+            rows,
+            cols,
+            &layout,
+            &request.blocked_regions,
+            Local::now().date_naive(),
+            &Region::Temperate,
+            &mut Warnings::new(),
+        );
+        let anchor = Coordinate {
+            row: request.row,
+            col: request.col,
+        };
+        Ok(preview_placement(&grid, &layout, &variety, anchor))
+    }
+}
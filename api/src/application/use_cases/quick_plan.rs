@@ -0,0 +1,162 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+
+use crate::application::models::request::{LayoutCell, PlanRequest, QuickPlanRequest};
+use crate::application::ports::variety_repository::VarietyRepository;
+use crate::application::use_cases::plan_garden::{PlanGardenError, PlanGardenUseCase};
+use crate::domain::models::{
+    error::{PlanError, MAX_GRID_CELLS},
+    request::{Level, Period, PreferenceEntry},
+    response::PlanResponse,
+    variety::{season_for_month, Region, Season},
+};
+
+/// Easy, high-value crops a quick plan prefers, in priority order. Varieties out of
+/// season are skipped by the planner as usual.
+pub const QUICK_PLAN_PREFERENCES: &[&str] = &[
+    "tomato",
+    "zucchini",
+    "cucumber",
+    "lettuce",
+    "green-bean",
+    "radish",
+    "basil",
+    "strawberry",
+];
+
+/// Monday-to-Sunday week a quick plan covers: the current week when `today` already
+/// falls in `season`, otherwise the first week of the season's next start.
+fn quick_plan_week(season: &Season, today: NaiveDate) -> Period {
+    let start = if season_for_month(today.month()) == *season {
+        today - Duration::days(today.weekday().num_days_from_monday() as i64)
+    } else {
+        let month = season.first_month();
+        let first_monday = |year| {
+            NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Mon, 1)
+                .expect("every month has a first Monday")
+        };
+        let this_year = first_monday(today.year());
+        if this_year >= today {
+            this_year
+        } else {
+            first_monday(today.year() + 1)
+        }
+    };
+    Period {
+        start,
+        end: start + Duration::days(6),
+    }
+}
+
+/// Use case: plan a garden from a season and grid size alone, filling in
+/// beginner-friendly defaults and delegating to [`PlanGardenUseCase`].
+pub struct QuickPlanUseCase<'a> {
+    repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> QuickPlanUseCase<'a> {
+    pub fn new(repo: &'a dyn VarietyRepository) -> Self {
+        Self { repo }
+    }
+
+    /// The full planning request a quick request stands for.
+    pub fn derive_request(quick: &QuickPlanRequest, today: NaiveDate) -> PlanRequest {
+        PlanRequest {
+            period: Some(quick_plan_week(&quick.season, today)),
+            sun: None,
+            available_sun_hours: None,
+            soil: None,
+            region: quick.region.clone().unwrap_or(Region::Temperate),
+            level: Some(Level::Beginner),
+            beginner_mode: None,
+            preferences: Some(
+                QUICK_PLAN_PREFERENCES
+                    .iter()
+                    .map(|id| PreferenceEntry {
+                        id: (*id).to_owned(),
+                        quantity: None,
+                        area_m2: None,
+                        near: Vec::new(),
+                        keep_together: false,
+                    })
+                    .collect(),
+            ),
+            exclusions: Vec::new(),
+            ranking: HashMap::new(),
+            avoid_pairs: Vec::new(),
+            avoid_pair_penalty: None,
+            neutral_competitor_penalty: None,
+            season_overlap: false,
+            sown: HashMap::new(),
+            layout: vec![vec![LayoutCell::Empty; quick.cols]; quick.rows],
+            layout_rle: None,
+            blocked_regions: Vec::new(),
+            blocked_cells: Vec::new(),
+            zones: Vec::new(),
+            plantable_mask: None,
+            pack: None,
+            objective: None,
+            density: None,
+            weights: BTreeMap::new(),
+            target_fill_pct: None,
+            max_total_plants: None,
+            suggest_fixes: false,
+            fill: true,
+            timeout_ms: None,
+            strict_companions: true,
+            mutual_companions: false,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        quick: &QuickPlanRequest,
+        locale: &str,
+    ) -> Result<PlanResponse, PlanGardenError> {
+        // Reject empty and oversized grids before allocating their layout: `vec!` builds
+        // one row of `cols` cells even when `rows` is 0.
+        if quick.rows == 0 {
+            return Err(PlanError::EmptyLayout.into());
+        }
+        if quick.cols == 0 {
+            return Err(PlanError::EmptyRow.into());
+        }
+        if quick.rows.saturating_mul(quick.cols) > MAX_GRID_CELLS {
+            return Err(PlanError::GridTooLarge {
+                rows: quick.rows,
+                cols: quick.cols,
+            }
+            .into());
+        }
+        let request = Self::derive_request(quick, Local::now().date_naive());
+        PlanGardenUseCase::new(self.repo)
+            .execute(&request, locale)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_quick_plan_week_picks_current_or_next_season_start() {
+        // In season: the current Monday-to-Sunday week.
+        let week = quick_plan_week(&Season::Summer, date(2025, 7, 10));
+        assert_eq!(
+            (week.start, week.end),
+            (date(2025, 7, 7), date(2025, 7, 13))
+        );
+        // Before the season: its first week this year.
+        let week = quick_plan_week(&Season::Summer, date(2025, 4, 1));
+        assert_eq!(week.start, date(2025, 6, 2));
+        // After the season: its first week next year.
+        let week = quick_plan_week(&Season::Spring, date(2025, 10, 1));
+        assert_eq!(week.start, date(2026, 3, 2));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::application::ports::plan_store::{PlanStore, ReplaceError};
+use crate::domain::models::response::PlanResponse;
+
+/// Use case: keep a generated plan so it can be shared and fetched again by id.
+pub struct StorePlanUseCase<'a> {
+    store: &'a dyn PlanStore,
+}
+
+impl<'a> StorePlanUseCase<'a> {
+    pub fn new(store: &'a dyn PlanStore) -> Self {
+        Self { store }
+    }
+
+    /// Stores `plan` and returns it with its generated `id` set.
+    pub fn execute(&self, mut plan: PlanResponse) -> PlanResponse {
+        let id = self.store.insert(plan.clone());
+        plan.id = Some(id);
+        plan
+    }
+
+    /// Stores `plan` under idempotency `key`. When another request stored a plan under
+    /// the same key first, that plan is returned instead and `plan` is discarded.
+    pub fn execute_with_key(&self, key: &str, mut plan: PlanResponse) -> PlanResponse {
+        let id = self.store.insert_with_key(key, plan.clone());
+        match self.store.get(&id) {
+            Some(stored) => PlanResponse {
+                id: Some(id),
+                ..stored
+            },
+            None => {
+                plan.id = Some(id);
+                plan
+            }
+        }
+    }
+}
+
+/// Use case: replay the plan stored under an idempotency key, so retried requests
+/// return the original result without planning again.
+pub struct GetPlanByIdempotencyKeyUseCase<'a> {
+    store: &'a dyn PlanStore,
+}
+
+impl<'a> GetPlanByIdempotencyKeyUseCase<'a> {
+    pub fn new(store: &'a dyn PlanStore) -> Self {
+        Self { store }
+    }
+
+    pub fn execute(&self, key: &str) -> Option<PlanResponse> {
+        let id = self.store.id_for_key(key)?;
+        GetStoredPlanUseCase::new(self.store).execute(&id)
+    }
+}
+
+/// Use case: retrieve a stored plan by identifier.
+pub struct GetStoredPlanUseCase<'a> {
+    store: &'a dyn PlanStore,
+}
+
+impl<'a> GetStoredPlanUseCase<'a> {
+    pub fn new(store: &'a dyn PlanStore) -> Self {
+        Self { store }
+    }
+
+    pub fn execute(&self, id: &str) -> Option<PlanResponse> {
+        self.execute_versioned(id).map(|(plan, _)| plan)
+    }
+
+    /// The stored plan and its version, for concurrency checks on later updates.
+    pub fn execute_versioned(&self, id: &str) -> Option<(PlanResponse, u64)> {
+        self.store.get_versioned(id).map(|(plan, version)| {
+            let plan = PlanResponse {
+                id: Some(id.to_owned()),
+                ..plan
+            };
+            (plan, version)
+        })
+    }
+}
+
+/// Use case: replace a stored plan with an edited one, provided nobody else changed it
+/// since the caller read it (optimistic concurrency).
+pub struct UpdateStoredPlanUseCase<'a> {
+    store: &'a dyn PlanStore,
+}
+
+impl<'a> UpdateStoredPlanUseCase<'a> {
+    pub fn new(store: &'a dyn PlanStore) -> Self {
+        Self { store }
+    }
+
+    /// Stores `plan` under `id` when `matches` accepts the stored version. Returns the
+    /// plan with its `id` set and its new version.
+    pub fn execute(
+        &self,
+        id: &str,
+        matches: impl Fn(u64) -> bool,
+        plan: PlanResponse,
+    ) -> Result<(PlanResponse, u64), ReplaceError> {
+        let (_, current) = self.store.get_versioned(id).ok_or(ReplaceError::NotFound)?;
+        if !matches(current) {
+            return Err(ReplaceError::Stale { current });
+        }
+        let plan = PlanResponse { id: None, ..plan };
+        let version = self.store.replace(id, current, plan.clone())?;
+        let plan = PlanResponse {
+            id: Some(id.to_owned()),
+            ..plan
+        };
+        Ok((plan, version))
+    }
+}
+
+/// Use case: delete a stored plan. Returns `false` when the id is unknown.
+pub struct DeleteStoredPlanUseCase<'a> {
+    store: &'a dyn PlanStore,
+}
+
+impl<'a> DeleteStoredPlanUseCase<'a> {
+    pub fn new(store: &'a dyn PlanStore) -> Self {
+        Self { store }
+    }
+
+    pub fn execute(&self, id: &str) -> bool {
+        self.store.remove(id)
+    }
+}
@@ -0,0 +1,47 @@
+use chrono::Local;
+
+use crate::application::models::request::LayoutValidationRequest;
+use crate::application::ports::{variety_repository::VarietyRepository, RepositoryError};
+use crate::application::use_cases::plan_garden::{alias_lookup, layout_ids, resolve_layout};
+use crate::domain::models::{
+    error::PlanError, response::LayoutValidationResponse, variety::Region,
+};
+use crate::domain::services::grid::dry_run_layout;
+
+/// Use case: check a layout the way `POST /api/plan` would, without planning it.
+///
+/// Unknown variety ids are reported as errors and their cells read as `Empty`, so the
+/// rest of the layout is still checked.
+pub struct ValidateLayoutUseCase<'a> {
+    repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> ValidateLayoutUseCase<'a> {
+    pub fn new(repo: &'a dyn VarietyRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        request: &LayoutValidationRequest,
+        locale: &str,
+    ) -> Result<LayoutValidationResponse, RepositoryError> {
+        let ids: Vec<String> = layout_ids(&request.layout).collect();
+        let lookup = alias_lookup(self.repo.get_by_ids(&ids, locale).await?);
+        let (layout, unknown) = resolve_layout(&request.layout, &lookup);
+
+        // Planted dates only shift harvest estimates, which the report does not expose.
+        let mut report = dry_run_layout(
+            &layout,
+            &request.blocked_regions,
+            Local::now().date_naive(),
+            &Region::Temperate,
+        );
+        report.errors.extend(
+            unknown
+                .into_iter()
+                .map(|id| PlanError::UnknownVegetable { id }.to_string()),
+        );
+        Ok(report)
+    }
+}
@@ -1,5 +1,59 @@
-use crate::application::ports::{vegetable_repository::VegetableRepository, Page, RepositoryError};
-use crate::domain::models::{response::CompanionInfo, vegetable::Vegetable};
+use std::collections::HashMap;
+
+use crate::application::ports::{
+    variety_repository::VarietyRepository, vegetable_repository::VegetableRepository, Page,
+    RepositoryError,
+};
+use crate::domain::models::{
+    response::{
+        CompanionInfo, CompanionMatrix, CompanionSuggestionsResponse, NeighborsPreviewResponse,
+    },
+    variety::{Category, Season, Variety},
+    vegetable::Vegetable,
+};
+use crate::domain::services::companion::{companion_matrix, neighbors_preview, rank_suggestions};
+use crate::domain::services::filter::species_order;
+
+/// Seasons each vegetable is active in, keyed by vegetable id, in calendar order.
+/// A vegetable is active in a season when any of its varieties is.
+pub(crate) fn vegetable_seasons(varieties: &[Variety]) -> HashMap<String, Vec<Season>> {
+    let mut seasons: HashMap<String, Vec<Season>> = HashMap::new();
+    for variety in varieties {
+        let entry = seasons.entry(variety.vegetable.id.clone()).or_default();
+        for s in variety.active_seasons() {
+            if !entry.contains(&s) {
+                entry.push(s);
+            }
+        }
+    }
+    for entry in seasons.values_mut() {
+        entry.sort_by_key(|s| s.clone() as u8);
+    }
+    seasons
+}
+
+/// Categories each vegetable has varieties in, keyed by vegetable id.
+fn vegetable_categories(varieties: &[Variety]) -> HashMap<String, Vec<Category>> {
+    let mut categories: HashMap<String, Vec<Category>> = HashMap::new();
+    for variety in varieties {
+        let entry = categories.entry(variety.vegetable.id.clone()).or_default();
+        if !entry.contains(&variety.category) {
+            entry.push(variety.category.clone());
+        }
+    }
+    categories
+}
+
+/// The `page`-th slice of `size` items (1-based) of `items`.
+fn paginate<T>(items: Vec<T>, page: usize, size: usize) -> Page<T> {
+    let total = items.len();
+    let items = items
+        .into_iter()
+        .skip((page - 1).saturating_mul(size))
+        .take(size)
+        .collect();
+    Page { items, total }
+}
 
 /// Use case: list all vegetables from the catalogue.
 pub struct ListVegetablesUseCase<'a> {
@@ -45,6 +99,8 @@ pub struct CompanionData {
     pub vegetable: Vegetable,
     pub good: Vec<CompanionInfo>,
     pub bad: Vec<CompanionInfo>,
+    /// Notes on documented pairs, ordered like the companion lists.
+    pub notes: Vec<String>,
 }
 
 /// Use case: resolve good and bad companion information for a given vegetable.
@@ -68,7 +124,7 @@ impl<'a> GetVegetableCompanionsUseCase<'a> {
         };
         let all = self.repo.get_all(locale).await?;
 
-        let good = vegetable
+        let mut good: Vec<CompanionInfo> = vegetable
             .good_companions
             .iter()
             .filter_map(|cid| {
@@ -79,7 +135,7 @@ impl<'a> GetVegetableCompanionsUseCase<'a> {
             })
             .collect();
 
-        let bad = vegetable
+        let mut bad: Vec<CompanionInfo> = vegetable
             .bad_companions
             .iter()
             .filter_map(|cid| {
@@ -90,14 +146,101 @@ impl<'a> GetVegetableCompanionsUseCase<'a> {
             })
             .collect();
 
+        good.sort_by(|a, b| species_order(&a.id, &b.id));
+        bad.sort_by(|a, b| species_order(&a.id, &b.id));
+
+        let mut notes = self.repo.get_companion_notes(&vegetable.id, locale).await?;
+        notes.sort_by(|(a, _), (b, _)| species_order(a, b));
+
         Ok(Some(CompanionData {
             vegetable,
             good,
             bad,
+            notes: notes.into_iter().map(|(_, note)| note).collect(),
+        }))
+    }
+}
+
+/// Use case: rank catalogue vegetables as neighbours for a given vegetable.
+pub struct GetCompanionSuggestionsUseCase<'a> {
+    vegetable_repo: &'a dyn VegetableRepository,
+    variety_repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> GetCompanionSuggestionsUseCase<'a> {
+    pub fn new(
+        vegetable_repo: &'a dyn VegetableRepository,
+        variety_repo: &'a dyn VarietyRepository,
+    ) -> Self {
+        Self {
+            vegetable_repo,
+            variety_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: &str,
+        locale: &str,
+        season: Option<&Season>,
+    ) -> Result<Option<CompanionSuggestionsResponse>, RepositoryError> {
+        let vegetable = match self.vegetable_repo.get_by_id(id, locale).await? {
+            None => return Ok(None),
+            Some(v) => v,
+        };
+        let all = self.vegetable_repo.get_all(locale).await?;
+        let seasons = vegetable_seasons(&self.variety_repo.get_all(locale).await?);
+        let suggestions = rank_suggestions(&vegetable, &all, &seasons, season);
+        Ok(Some(CompanionSuggestionsResponse {
+            id: vegetable.id,
+            name: vegetable.name,
+            suggestions,
         }))
     }
 }
 
+/// Use case: build the illustrative 3×3 neighbours grid of a vegetable.
+pub struct GetNeighborsPreviewUseCase<'a> {
+    repo: &'a dyn VegetableRepository,
+}
+
+impl<'a> GetNeighborsPreviewUseCase<'a> {
+    pub fn new(repo: &'a dyn VegetableRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        id: &str,
+        locale: &str,
+    ) -> Result<Option<NeighborsPreviewResponse>, RepositoryError> {
+        let vegetable = match self.repo.get_by_id(id, locale).await? {
+            None => return Ok(None),
+            Some(v) => v,
+        };
+        let all = self.repo.get_all(locale).await?;
+        Ok(Some(neighbors_preview(&vegetable, &all)))
+    }
+}
+
+/// Use case: build the companion matrix across the whole vegetable catalogue,
+/// ordered by vegetable id.
+pub struct GetCompanionMatrixUseCase<'a> {
+    repo: &'a dyn VegetableRepository,
+}
+
+impl<'a> GetCompanionMatrixUseCase<'a> {
+    pub fn new(repo: &'a dyn VegetableRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, locale: &str) -> Result<CompanionMatrix, RepositoryError> {
+        let mut all = self.repo.get_all(locale).await?;
+        all.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(companion_matrix(&all))
+    }
+}
+
 /// Use case: list vegetables belonging to a specific group.
 pub struct ListVegetablesByGroupUseCase<'a> {
     repo: &'a dyn VegetableRepository,
@@ -120,3 +263,141 @@ impl<'a> ListVegetablesByGroupUseCase<'a> {
             .await
     }
 }
+
+/// Use case: list catalogue vegetables by variety category. A vegetable belongs to
+/// every category one of its varieties is in; vegetables keep catalogue (id) order.
+pub struct ListVegetablesByCategoryUseCase<'a> {
+    vegetable_repo: &'a dyn VegetableRepository,
+    variety_repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> ListVegetablesByCategoryUseCase<'a> {
+    pub fn new(
+        vegetable_repo: &'a dyn VegetableRepository,
+        variety_repo: &'a dyn VarietyRepository,
+    ) -> Self {
+        Self {
+            vegetable_repo,
+            variety_repo,
+        }
+    }
+
+    /// Catalogue vegetables, each with the categories of its varieties.
+    async fn categorised(
+        &self,
+        locale: &str,
+    ) -> Result<Vec<(Vegetable, Vec<Category>)>, RepositoryError> {
+        let mut categories = vegetable_categories(&self.variety_repo.get_all(locale).await?);
+        Ok(self
+            .vegetable_repo
+            .get_all(locale)
+            .await?
+            .into_iter()
+            .map(|v| {
+                let c = categories.remove(&v.id).unwrap_or_default();
+                (v, c)
+            })
+            .collect())
+    }
+
+    /// One page of the vegetables in `category`.
+    pub async fn execute(
+        &self,
+        category: &Category,
+        locale: &str,
+        page: usize,
+        size: usize,
+    ) -> Result<Page<Vegetable>, RepositoryError> {
+        let members = self
+            .categorised(locale)
+            .await?
+            .into_iter()
+            .filter(|(_, c)| c.contains(category))
+            .map(|(v, _)| v)
+            .collect();
+        Ok(paginate(members, page, size))
+    }
+
+    /// One page of vegetables per category, in [`Category::all`] order.
+    pub async fn execute_grouped(
+        &self,
+        locale: &str,
+        page: usize,
+        size: usize,
+    ) -> Result<Vec<(Category, Page<Vegetable>)>, RepositoryError> {
+        let vegetables = self.categorised(locale).await?;
+        Ok(Category::all()
+            .iter()
+            .map(|category| {
+                let members = vegetables
+                    .iter()
+                    .filter(|(_, c)| c.contains(category))
+                    .map(|(v, _)| v.clone())
+                    .collect();
+                (category.clone(), paginate(members, page, size))
+            })
+            .collect())
+    }
+}
+
+/// SplitMix64 step: a small, seedable generator so the same seed always draws the
+/// same vegetable.
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Use case: draw one random vegetable, optionally restricted to a season and to
+/// vegetables with at least one beginner-friendly variety.
+pub struct RandomVegetableUseCase<'a> {
+    vegetable_repo: &'a dyn VegetableRepository,
+    variety_repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> RandomVegetableUseCase<'a> {
+    pub fn new(
+        vegetable_repo: &'a dyn VegetableRepository,
+        variety_repo: &'a dyn VarietyRepository,
+    ) -> Self {
+        Self {
+            vegetable_repo,
+            variety_repo,
+        }
+    }
+
+    /// Picks among the matching vegetables (in id order) using `seed`; `None` when
+    /// nothing matches.
+    pub async fn execute(
+        &self,
+        locale: &str,
+        season: Option<&Season>,
+        beginner: bool,
+        seed: u64,
+    ) -> Result<Option<Vegetable>, RepositoryError> {
+        let varieties = self.variety_repo.get_all(locale).await?;
+        let seasons = vegetable_seasons(&varieties);
+        let mut candidates: Vec<Vegetable> = self
+            .vegetable_repo
+            .get_all(locale)
+            .await?
+            .into_iter()
+            .filter(|v| {
+                season.is_none_or(|s| seasons.get(&v.id).is_some_and(|active| active.contains(s)))
+            })
+            .filter(|v| {
+                !beginner
+                    || varieties
+                        .iter()
+                        .any(|variety| variety.vegetable.id == v.id && variety.beginner_friendly)
+            })
+            .collect();
+        if candidates.is_empty() {
+            return Ok(None);
+        }
+        candidates.sort_by(|a, b| a.id.cmp(&b.id));
+        let index = (splitmix64(seed) % candidates.len() as u64) as usize;
+        Ok(Some(candidates.swap_remove(index)))
+    }
+}
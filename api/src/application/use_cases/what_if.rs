@@ -0,0 +1,74 @@
+use chrono::Local;
+
+use crate::application::models::request::WhatIfRequest;
+use crate::application::ports::variety_repository::VarietyRepository;
+use crate::application::use_cases::plan_garden::{
+    alias_lookup, layout_ids, resolve_layout, PlanGardenError,
+};
+use crate::domain::models::{
+    error::PlanError, response::WhatIfResponse, variety::Region, warnings::Warnings,
+};
+use crate::domain::services::{
+    grid::{initialize_grid, validate_layout, GridSize},
+    removal::{anchor_at, anchors_of, simulate_removal},
+};
+
+/// Use case: pull plants out of an existing layout and report the companion score
+/// change, without re-planning anything.
+pub struct WhatIfUseCase<'a> {
+    repo: &'a dyn VarietyRepository,
+}
+
+impl<'a> WhatIfUseCase<'a> {
+    pub fn new(repo: &'a dyn VarietyRepository) -> Self {
+        Self { repo }
+    }
+
+    /// Call after [`WhatIfRequest::validate`].
+    pub async fn execute(
+        &self,
+        request: &WhatIfRequest,
+        locale: &str,
+    ) -> Result<WhatIfResponse, PlanGardenError> {
+        let ids: Vec<String> = layout_ids(&request.layout)
+            .chain(request.id.clone())
+            .collect();
+        let lookup = alias_lookup(self.repo.get_by_ids(&ids, locale).await?);
+        let (layout, unknown) = resolve_layout(&request.layout, &lookup);
+        if let Some(id) = unknown.into_iter().next() {
+            return Err(PlanError::UnknownVegetable { id }.into());
+        }
+        let GridSize(rows, cols) = validate_layout(&layout)?;
+
+        let mut grid = initialize_grid(
+            rows,
+            cols,
+            &layout,
+            &request.blocked_regions,
+            Local::now().date_naive(),
+            &Region::Temperate,
+            &mut Warnings::new(),
+        );
+        let anchors = match (request.cell, &request.id) {
+            (Some(cell), _) => vec![anchor_at(&grid, cell).ok_or(PlanError::NoPlantAt {
+                row: cell.row,
+                col: cell.col,
+            })?],
+            (None, Some(id)) => {
+                let variety = lookup
+                    .get(id)
+                    .ok_or_else(|| PlanError::UnknownVariety { id: id.clone() })?;
+                let anchors = anchors_of(&grid, &variety.id);
+                if anchors.is_empty() {
+                    return Err(PlanError::NotInLayout {
+                        id: variety.id.clone(),
+                    }
+                    .into());
+                }
+                anchors
+            }
+            (None, None) => Vec::new(),
+        };
+        Ok(simulate_removal(&mut grid, &anchors, &lookup))
+    }
+}
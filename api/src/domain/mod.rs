@@ -1,4 +1,17 @@
+//! The planning engine: catalogue models and pure planning services, free of any HTTP,
+//! database or runtime type so it can be embedded on its own (a CLI, a WASM build).
+//!
+//! The re-exports below are its entry points: narrow the catalogue with
+//! [`filter_candidates_base`], plan a grid with [`plan_garden`], and score neighbours
+//! with [`companion_score`] / [`is_compatible`]. Failures are [`PlanError`]s.
 pub mod models;
 pub mod services;
 #[cfg(test)]
 pub mod test_fixtures;
+
+pub use models::error::PlanError;
+pub use services::{
+    companion::{companion_score, is_compatible},
+    filter::{filter_candidates_base, filter_varieties},
+    planner::plan_garden,
+};
@@ -0,0 +1,79 @@
+use std::fmt;
+
+/// Largest grid the planner accepts, in cells (a 30 m × 30 m plot at 30 cm cells).
+pub const MAX_GRID_CELLS: usize = 10_000;
+
+/// Why the planner rejected a request.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlanError {
+    /// The layout has no rows.
+    EmptyLayout,
+    /// The layout's rows have no cells.
+    EmptyRow,
+    /// A layout row does not have as many cells as the first one.
+    RaggedRow {
+        row: usize,
+        len: usize,
+        expected: usize,
+    },
+    /// The request sets both `layout` and `layoutRle`.
+    LayoutAndLayoutRle,
+    /// A `layoutRle` run repeats its cell `0` times.
+    EmptyRun { row: usize },
+    /// The layout has more than [`MAX_GRID_CELLS`] cells.
+    GridTooLarge { rows: usize, cols: usize },
+    /// A pre-placed layout cell names a vegetable that is not in the catalogue.
+    UnknownVegetable { id: String },
+    /// The fill target is not a percentage.
+    InvalidFillTarget(u8),
+    /// A variety to place is not in the catalogue.
+    UnknownVariety { id: String },
+    /// No free block of the variety's span is left on the grid.
+    NoFreeBlock { id: String, span: u32 },
+    /// The cell to clear holds no plant, or lies outside the grid.
+    NoPlantAt { row: usize, col: usize },
+    /// The variety to clear is not planted anywhere in the layout.
+    NotInLayout { id: String },
+    /// The plants to size a bed for need more than [`MAX_GRID_CELLS`] cells.
+    BedTooLarge { cells: usize },
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyLayout => write!(f, "Layout must contain at least one row."),
+            Self::EmptyRow => write!(f, "Layout rows must not be empty."),
+            Self::RaggedRow { row, len, expected } => write!(
+                f,
+                "Layout row {row} has {len} cell(s); every row must have {expected}."
+            ),
+            Self::LayoutAndLayoutRle => write!(f, "Set either layout or layoutRle, not both."),
+            Self::EmptyRun { row } => {
+                write!(
+                    f,
+                    "layoutRle row {row} has a run of 0 cells; counts must be at least 1."
+                )
+            }
+            Self::GridTooLarge { rows, cols } => write!(
+                f,
+                "Grid of {rows}x{cols} cells exceeds the maximum of {MAX_GRID_CELLS} cells."
+            ),
+            Self::UnknownVegetable { id } => write!(f, "Unknown vegetable '{id}' in layout."),
+            Self::InvalidFillTarget(_) => {
+                write!(f, "Target fill percentage must be between 0 and 100.")
+            }
+            Self::UnknownVariety { id } => write!(f, "Unknown variety '{id}'."),
+            Self::NoFreeBlock { id, span } => {
+                write!(f, "No free {span}×{span} block is left for '{id}'.")
+            }
+            Self::NoPlantAt { row, col } => write!(f, "No plant to remove at [{row},{col}]."),
+            Self::NotInLayout { id } => write!(f, "No '{id}' in the layout to remove."),
+            Self::BedTooLarge { cells } => write!(
+                f,
+                "The plants need {cells} cells, more than the maximum of {MAX_GRID_CELLS} cells."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PlanError {}
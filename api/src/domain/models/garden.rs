@@ -1,7 +1,17 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::domain::models::{variety::Lifecycle, Coordinate, Matrix};
+use crate::domain::models::{
+    variety::{Category, Lifecycle},
+    Coordinate, Matrix,
+};
+
+/// [`PlacedVariety::placed_in_phase`] of plants taken from the submitted layout.
+pub const PHASE_PRE_PLACED: u8 = 0;
+/// [`PlacedVariety::placed_in_phase`] of plants placed for an explicit preference (Phase 1).
+pub const PHASE_EXPLICIT: u8 = 1;
+/// [`PlacedVariety::placed_in_phase`] of plants placed by the gap fill (Phase 2).
+pub const PHASE_FILL: u8 = 2;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -24,6 +34,10 @@ pub struct PlacedVariety {
     pub estimated_harvest_date: chrono::NaiveDate,
     /// Plant lifecycle — `Perennial` plants are never removed from the grid after harvest.
     pub lifecycle: Lifecycle,
+    /// Culinary category, used to spot neighbours competing for the same resources.
+    pub category: Category,
+    /// Planner phase that placed this plant (see [`PHASE_EXPLICIT`] and [`PHASE_FILL`]).
+    pub placed_in_phase: u8,
 }
 
 #[skip_serializing_none]
@@ -33,6 +47,10 @@ pub struct Cell {
     pub variety: Option<PlacedVariety>,
     /// True when the cell is a path, alley or other non-plantable zone.
     pub blocked: bool,
+    /// True when the cell is kept free for a later sowing: never planted now, but
+    /// neither blocked nor an unfilled gap.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reserved: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,6 +69,7 @@ impl GardenGrid {
                     .map(|_| Cell {
                         variety: None,
                         blocked: false,
+                        reserved: false,
                     })
                     .collect()
             })
@@ -75,13 +94,14 @@ impl GardenGrid {
 
     /// Returns true when every cell in the `span × span` block starting at `(row, col)` is free.
     pub fn is_block_free(&self, row: usize, col: usize, span: usize) -> bool {
-        if row + span > self.rows || col + span > self.cols {
+        if row >= self.rows || self.rows - row < span || col >= self.cols || self.cols - col < span
+        {
             return false;
         }
         for dr in 0..span {
             for dc in 0..span {
                 let cell = &self.cells[row + dr][col + dc];
-                if cell.variety.is_some() || cell.blocked {
+                if cell.variety.is_some() || cell.blocked || cell.reserved {
                     return false;
                 }
             }
@@ -90,6 +110,9 @@ impl GardenGrid {
     }
 
     /// Returns all distinct already-placed neighbours on the perimeter of a `span × span` block.
+    ///
+    /// The perimeter is the `4 × span` cells orthogonally adjacent to the block's edges
+    /// (diagonal corners are not included); each occupied cell contributes one entry.
     pub fn get_block_neighbors(&self, coordinate: Coordinate, span: usize) -> Vec<&PlacedVariety> {
         let mut seen: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
         let mut neighbors: Vec<&PlacedVariety> = Vec::new();
@@ -117,4 +140,18 @@ impl GardenGrid {
         }
         neighbors
     }
+
+    /// Returns one entry per plant — the cell holding its anchor — in row-major order.
+    pub fn anchored_plants(&self) -> Vec<&PlacedVariety> {
+        self.cells
+            .iter()
+            .enumerate()
+            .flat_map(|(r, row)| row.iter().enumerate().map(move |(c, cell)| (r, c, cell)))
+            .filter_map(|(r, c, cell)| {
+                cell.variety
+                    .as_ref()
+                    .filter(|v| v.anchor == Coordinate { row: r, col: c })
+            })
+            .collect()
+    }
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+pub mod error;
 pub mod garden;
 pub mod group;
 pub mod request;
@@ -1,9 +1,16 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::domain::models::{
+    response::PlannedCell,
     variety::{Region, Variety},
+    vegetable::Vegetable,
+    warnings::Language,
     Coordinate, Matrix,
 };
 
@@ -37,6 +44,8 @@ pub enum LayoutCell {
     Empty,
     /// Non-plantable zone (path, alley, obstacle).
     Blocked,
+    /// Cell kept free for a later sowing.
+    Reserved,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
@@ -46,6 +55,27 @@ pub enum Level {
     Expert,
 }
 
+impl Level {
+    /// Every variant, in declaration order.
+    pub fn all() -> &'static [Self] {
+        &[Level::Beginner, Level::Expert]
+    }
+}
+
+/// How `level: Beginner` treats varieties that are not beginner-friendly.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "PascalCase")]
+pub enum BeginnerMode {
+    /// Non-beginner-friendly varieties are never planned.
+    #[default]
+    #[serde(alias = "strict")]
+    Strict,
+    /// Non-beginner-friendly varieties stay candidates but are tried after every
+    /// beginner-friendly one.
+    #[serde(alias = "prefer")]
+    Prefer,
+}
+
 /// A single preference entry with an optional desired plant count.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -55,6 +85,63 @@ pub struct PreferenceEntry {
     /// Each plant may occupy more than one cell depending on its spacing.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quantity: Option<u32>,
+    /// Desired growing **area** in square meters, as an alternative to `quantity`
+    /// (e.g. `2.0` for 2 m² of lettuce). Converted to cells using the cell size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 2.0)]
+    pub area_m2: Option<f32>,
+    /// Ids of species this variety should be planted next to (e.g. `["tomato"]` for
+    /// basil): blocks beside one of them score higher. Unknown ids are ignored with a
+    /// warning.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schema(example = json!(["tomato"]))]
+    pub near: Vec<String>,
+    /// Plant this variety's several plants side by side rather than wherever each one
+    /// scores best: blocks next to a plant of the same variety score higher, at the cost
+    /// of a little companion score.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub keep_together: bool,
+}
+
+/// A rectangle of cells to mark as blocked (paths, raised-bed frames, a water butt…),
+/// as an alternative to listing `Blocked` cells one by one.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockedRegion {
+    /// Top-left cell of the rectangle.
+    pub top_left: Coordinate,
+    /// Width in cells (along a row).
+    pub width: usize,
+    /// Height in cells (along a column).
+    pub height: usize,
+}
+
+/// A rectangle a species is pinned to (e.g. climbing beans along the north edge): that
+/// species is only planted on blocks lying entirely inside one of its zones.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Zone {
+    /// Vegetable or variety id the zone is reserved to (e.g. `green-bean`).
+    #[schema(example = "green-bean")]
+    pub id: String,
+    /// Cells of the zone, as in `blockedRegions`.
+    pub region: BlockedRegion,
+}
+
+impl Zone {
+    /// `true` when the `span x span` block anchored at `coordinate` lies inside the zone.
+    /// A zone reaching past the grid (or past `usize::MAX`) is clipped to it.
+    pub fn contains_block(&self, coordinate: Coordinate, span: usize) -> bool {
+        let BlockedRegion {
+            top_left,
+            width,
+            height,
+        } = &self.region;
+        coordinate.row >= top_left.row
+            && coordinate.col >= top_left.col
+            && coordinate.row.saturating_add(span) <= top_left.row.saturating_add(*height)
+            && coordinate.col.saturating_add(span) <= top_left.col.saturating_add(*width)
+    }
 }
 
 /// The date range of the planning period.
@@ -82,12 +169,33 @@ pub struct SowingRecord {
     pub seeds_sown: u32,
 }
 
+/// One side of a plan comparison: a planned grid (as returned in `weeks[].grid`)
+/// and its companion score.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanSnapshot {
+    #[schema(value_type = Vec<Vec<PlannedCell>>)]
+    pub grid: Matrix<PlannedCell>,
+    /// Companion score of this grid (`weeks[].score`). Defaults to `0`.
+    #[serde(default)]
+    pub score: i32,
+}
+
 /// An enriched preference: carries the resolved variety instead of a bare ID.
 #[derive(Debug, Clone)]
 pub struct Preference {
     pub variety: Variety,
     /// Desired number of **plants** (placements) for this variety.
     pub quantity: Option<u32>,
+    /// Desired growing area in square meters, used when `quantity` is unset.
+    pub area_m2: Option<f32>,
+}
+
+impl Preference {
+    /// Whether the preference asks for a specific amount, as a plant count or an area.
+    pub fn is_explicit(&self) -> bool {
+        self.quantity.is_some() || self.area_m2.is_some()
+    }
 }
 
 /// An enriched sowing entry: carries the resolved variety together with its batches.
@@ -97,16 +205,228 @@ pub struct SownEntry {
     pub records: Vec<SowingRecord>,
 }
 
+/// Order in which the gap-filling phase tries candidates on each pass.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "PascalCase")]
+pub enum PackMode {
+    /// Candidates are tried in priority order (preferences, then French consumption rank).
+    #[default]
+    #[serde(alias = "priority")]
+    Priority,
+    /// Candidates that fill the largest remaining free square are tried first, so big
+    /// gaps go to large plants and small plants are kept for the leftover singletons.
+    #[serde(alias = "bestfit")]
+    BestFit,
+}
+
+/// What the placement phase optimises when choosing a block for each candidate.
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ToSchema,
+)]
+#[serde(rename_all = "PascalCase")]
+pub enum Objective {
+    /// Blocks are scored by companion score (good neighbours minus bad ones).
+    #[default]
+    #[serde(alias = "companionscore")]
+    CompanionScore,
+    /// Blocks are scored by the number of distinct other vegetables around them.
+    #[serde(alias = "diversity")]
+    Diversity,
+    /// Blocks are scored by their expected harvest, and the highest-yielding
+    /// candidates per cell are tried first.
+    #[serde(alias = "yield")]
+    Yield,
+    /// Beginner-friendly candidates are tried first and score a bonus that outweighs
+    /// companion effects; companion score breaks ties.
+    #[serde(alias = "lowmaintenance")]
+    LowMaintenance,
+    /// Blocks are scored by companion score, and equal scores go to the block with the
+    /// most distinct other vegetables around it. Cannot be weighted.
+    #[serde(alias = "companionthendiversity", alias = "companion_then_diversity")]
+    CompanionThenDiversity,
+}
+
+impl Objective {
+    /// Every objective that can be weighted, in the order weighted score breakdowns list
+    /// them.
+    pub const ALL: [Objective; 4] = [
+        Objective::CompanionScore,
+        Objective::Diversity,
+        Objective::Yield,
+        Objective::LowMaintenance,
+    ];
+}
+
+/// Planting density preset: scales every planned variety's spacing, trading yield for
+/// plant health.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "PascalCase")]
+pub enum Density {
+    /// Spacing reduced by 20%: more plants per cell, more competition between them.
+    #[serde(alias = "intensive")]
+    Intensive,
+    /// Catalogue spacing.
+    #[default]
+    #[serde(alias = "standard")]
+    Standard,
+    /// Spacing increased by 20%: fewer plants, more airflow.
+    #[serde(alias = "spacious")]
+    Spacious,
+}
+
+impl Density {
+    /// Factor applied to each variety's `spacing_cm`.
+    pub fn spacing_multiplier(&self) -> f32 {
+        match self {
+            Density::Intensive => 0.8,
+            Density::Standard => 1.0,
+            Density::Spacious => 1.2,
+        }
+    }
+
+    /// `spacing_cm` scaled by [`Self::spacing_multiplier`], rounded to the nearest
+    /// centimetre and never below 1.
+    pub fn scale_spacing(&self, spacing_cm: u32) -> u32 {
+        ((spacing_cm as f32 * self.spacing_multiplier()).round() as u32).max(1)
+    }
+}
+
+/// [`PlacementOptions::avoid_pair_penalty`] when the request sets none: more than a
+/// bad companion costs, so an avoided pair outweighs the catalogue's own advice.
+pub const DEFAULT_AVOID_PAIR_PENALTY: i32 = 5;
+
+/// Points a block earns for each neighbour named in the placed variety's `near` hints:
+/// more than a good companion is worth, so a hinted neighbour wins over an otherwise
+/// equal block.
+pub const NEAR_BONUS: i32 = 3;
+
+/// Points a block earns for each neighbouring cell of the same variety when the
+/// preference asks to keep its plants together: as much as a good companion, so
+/// contiguity wins ties and small companion differences only.
+pub const KEEP_TOGETHER_BONUS: i32 = 2;
+
+/// Tuning knobs for the placement phase.
+/// `Default` reproduces the classic planner behaviour.
+#[derive(Debug, Clone, Default)]
+pub struct PlacementOptions {
+    /// Candidate ordering used when filling the remaining cells.
+    pub pack: PackMode,
+    /// Points subtracted from a block's score for each neutral (neither good nor bad)
+    /// neighbour of the same [`Category`](crate::domain::models::variety::Category),
+    /// nudging the planner towards mixing categories. `0` disables it.
+    pub neutral_competitor_penalty: i32,
+    /// Id pairs (vegetable or variety ids, in either order) the user does not want side
+    /// by side, on top of the catalogue's bad companions.
+    pub avoid_pairs: Vec<(String, String)>,
+    /// Points subtracted from a block's score for each neighbour forming one of
+    /// `avoid_pairs` with the placed variety. Only steers block choice: the reported
+    /// companion score is unaffected.
+    pub avoid_pair_penalty: i32,
+    /// `(variety id, neighbour id)` hints from the preferences' `near` lists: a block of
+    /// the first next to the second earns [`NEAR_BONUS`] per such neighbour. Ids are
+    /// matched against variety and vegetable ids.
+    pub near: Vec<(String, String)>,
+    /// Variety ids whose plants are kept side by side: a block of one of them earns
+    /// [`KEEP_TOGETHER_BONUS`] per neighbouring cell of the same variety.
+    pub keep_together: Vec<String>,
+    /// Percentage (0–100) of plantable cells the fill phase stops at, leaving the rest
+    /// empty for airflow and access. `None` packs every cell. Explicit preference
+    /// quantities are still placed in full.
+    pub target_fill_pct: Option<u8>,
+    /// Most plants the planner may place over the whole period, counting every plant
+    /// of a block (see [`plants_per_cell`](crate::domain::services::helpers::plants_per_cell)).
+    /// Plants from the submitted layout are not counted. `None` sets no limit.
+    pub max_total_plants: Option<u32>,
+    /// Skip the fill phase: only preferences are placed and the remaining cells stay
+    /// empty (without an empty-cell warning). A preference without `quantity` gets one plant.
+    pub preferences_only: bool,
+    /// Scoring function used to pick the best block (see [`Objective`]).
+    pub objective: Objective,
+    /// Normalised weights (summing to 1) blending several objectives into one block
+    /// score; overrides `objective` when non-empty.
+    pub weights: BTreeMap<Objective, f32>,
+    /// Soft limit on planning time: once spent, the fill phase stops and the grid is
+    /// returned as it stands. `None` lets it run to completion.
+    pub time_budget: Option<Duration>,
+    /// Never place a variety next to one of its bad companions, even when no other
+    /// block is left for it.
+    pub strict_companions: bool,
+    /// Vegetables by id whose own companion lists also count towards the score of a
+    /// block they neighbour, so a pair listing each other outscores a one-sided one.
+    /// `None` scores only the placed variety's lists.
+    pub mutual_companions: Option<HashMap<String, Arc<Vegetable>>>,
+    /// Density preset already applied to the planned varieties' `spacing_cm`; kept
+    /// here to be echoed in the response.
+    pub density: Density,
+    /// Species pinned to parts of the grid: a variety matching a zone's id (by variety
+    /// or vegetable id) is only placed inside its zones; other varieties go anywhere.
+    pub zones: Vec<Zone>,
+}
+
 #[derive(Debug, Clone)]
 pub struct PlanParams {
     /// Planning period (start and end dates).
     /// When omitted, defaults to the current Monday-to-Sunday week.
     pub period: Option<Period>,
     pub region: Region,
+    /// Hours of direct sun the plot receives per day. Varieties whose
+    /// `min_sun_hours` exceeds it are not planned. `None` disables the check.
+    pub available_sun_hours: Option<u32>,
     /// Preferred varieties with optional per-variety plant count.
     pub preferences: Vec<Preference>,
+    /// Per-variety rank overriding the French consumption rank when ordering
+    /// non-preferred candidates (lower first).
+    pub ranking: HashMap<String, usize>,
+    /// Also plan varieties active only in the months bordering the planning month's
+    /// season (see [`shoulder_months`](crate::domain::services::filter::shoulder_months)).
+    pub season_overlap: bool,
+    /// Order non-preferred beginner-friendly candidates before the others.
+    pub prefer_beginner: bool,
     /// Varieties already sown from seed, enriched with resolved variety data.
     pub sown: Vec<SownEntry>,
     /// Combined grid layout — defines dimensions and pre-filled cells.
     pub layout: Matrix<LayoutCell>,
+    /// Rectangles blocked on top of the layout's own `Blocked` cells.
+    pub blocked_regions: Vec<BlockedRegion>,
+    /// Single cells blocked on top of the layout and `blocked_regions`.
+    pub blocked_cells: Vec<Coordinate>,
+    /// Cells that may be planted, same dimensions as `layout`; `false` cells are blocked.
+    pub plantable_mask: Option<Matrix<bool>>,
+    /// Placement tuning options.
+    pub placement: PlacementOptions,
+    /// Propose relocations for pre-placed plants sitting next to a bad companion.
+    pub suggest_fixes: bool,
+    /// Ids listed in a preference's `near` that match no variety, warned about once.
+    pub unknown_near: Vec<String>,
+    /// Preference ids that match no variety, warned about once each.
+    pub unknown_preferences: Vec<String>,
+    /// Language of the planner's own warnings.
+    pub language: Language,
+}
+
+impl PlanParams {
+    /// Plans `layout` in `region` for the current week: no preferences, sowings, extra
+    /// blocked cells nor mask, default placement options and English warnings. Set the
+    /// other fields with struct update syntax.
+    pub fn new(region: Region, layout: Matrix<LayoutCell>) -> Self {
+        Self {
+            period: None,
+            region,
+            available_sun_hours: None,
+            preferences: Vec::new(),
+            ranking: HashMap::new(),
+            season_overlap: false,
+            prefer_beginner: false,
+            sown: Vec::new(),
+            layout,
+            blocked_regions: Vec::new(),
+            blocked_cells: Vec::new(),
+            plantable_mask: None,
+            placement: PlacementOptions::default(),
+            suggest_fixes: false,
+            unknown_near: Vec::new(),
+            unknown_preferences: Vec::new(),
+            language: Language::default(),
+        }
+    }
 }
@@ -1,18 +1,26 @@
+use std::collections::BTreeMap;
+
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::domain::models::{request::Period, Coordinate, Matrix};
+use crate::domain::models::{
+    garden::GardenGrid,
+    request::{Density, Level, Objective, PackMode, Period},
+    variety::{Category, Lifecycle, Region, Season, SoilType, SunExposure},
+    Coordinate, Matrix,
+};
 
 /// A cell in the planned garden grid (response output).
 ///
-/// Three occupied variants, plus `Empty` and `Blocked`:
+/// Three occupied variants, plus `Empty`, `Blocked` and `Reserved`:
 /// - `SelfContained` - a plant whose spacing <= 30 cm; fits entirely in one cell.
 /// - `Overflowing`   - the anchor (top-left) cell of a plant that spans multiple cells.
 /// - `Overflowed`    - a continuation cell covered by a neighbouring anchor; carries only a
 ///   back-reference so clients can look up the full data from the anchor.
 /// - `Empty`         - free, unoccupied, non-blocked cell.
 /// - `Blocked`       - non-plantable zone (path, alley, obstacle).
+/// - `Reserved`      - free cell the layout keeps for a later sowing.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(tag = "type")]
 pub enum PlannedCell {
@@ -26,6 +34,11 @@ pub enum PlannedCell {
         /// Estimated date the plant will be ready to harvest.
         #[schema(value_type = String, format = Date, example = "2025-08-01")]
         estimated_harvest_date: NaiveDate,
+        /// Planner phase that placed this plant: `1` for an explicit preference, `2` for
+        /// the gap fill. Omitted for plants from the submitted layout.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[schema(example = 1)]
+        placed_in_phase: Option<u8>,
     },
     /// The anchor (top-left) cell of a plant that overflows into neighbouring cells.
     #[serde(rename_all = "camelCase")]
@@ -39,6 +52,11 @@ pub enum PlannedCell {
         /// Estimated date the plant will be ready to harvest.
         #[schema(value_type = String, format = Date, example = "2025-08-01")]
         estimated_harvest_date: NaiveDate,
+        /// Planner phase that placed this plant: `1` for an explicit preference, `2` for
+        /// the gap fill. Omitted for plants from the submitted layout.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[schema(example = 1)]
+        placed_in_phase: Option<u8>,
     },
     /// A continuation cell covered by a multi-cell plant's anchor.
     /// All plant data lives on the anchor cell; this cell only holds a back-reference.
@@ -48,6 +66,8 @@ pub enum PlannedCell {
     Empty,
     /// A non-plantable zone (path, alley, obstacle).
     Blocked,
+    /// A free cell kept for a later sowing; never planted by the planner.
+    Reserved,
 }
 
 impl PlannedCell {
@@ -61,7 +81,7 @@ impl PlannedCell {
 
     /// Returns `true` if this cell carries or is part of a plant placement.
     pub fn is_placed(&self) -> bool {
-        !matches!(self, Self::Empty | Self::Blocked)
+        !matches!(self, Self::Empty | Self::Blocked | Self::Reserved)
     }
 
     /// Returns `true` if this cell is a non-plantable zone.
@@ -119,7 +139,8 @@ pub struct WeeklyPlan {
     /// Full garden grid for this week (same dimensions as the request layout).
     #[schema(value_type = Vec<Vec<PlannedCell>>)]
     pub grid: Matrix<PlannedCell>,
-    /// Cumulative companion-planting score for plants placed **this week**.
+    /// Companion score of the garden as it stands after this week (see
+    /// `grid_companion_score`), summed over merged weeks.
     pub score: i32,
     /// Varieties to sow this week so they are ready to transplant during a
     /// future planning week.
@@ -129,11 +150,592 @@ pub struct WeeklyPlan {
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PlanResponse {
+    /// Identifier of the stored plan (see `GET /api/plan/{id}`); only present once the
+    /// plan has been stored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     pub rows: usize,
     pub cols: usize,
+    /// Cells that can hold a plant — neither blocked nor reserved — pre-placed plants
+    /// included.
+    #[serde(default)]
+    pub plantable_cells: usize,
+    /// Blocked cells, from the layout, `blockedRegions`, `blockedCells` and
+    /// `plantableMask`.
+    #[serde(default)]
+    pub blocked_cells: usize,
+    /// Area of the plantable cells, in square metres.
+    #[serde(default)]
+    #[schema(example = 1.26)]
+    pub usable_area_m2: f32,
     /// One entry per week in the requested planning period.
     pub weeks: Vec<WeeklyPlan>,
+    /// Sum of the weekly `score`s divided by the occupied cells summed over the same
+    /// weeks (`0` when nothing was planted) — comparable across grid sizes.
+    #[serde(default)]
+    pub normalized_score: f32,
+    pub warnings: Vec<String>,
+    /// Proposed moves for pre-placed plants next to a bad companion; only computed
+    /// when the request sets `suggestFixes`, and never applied to `weeks`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<RelocationSuggestion>,
+    /// The inputs the plan was actually computed from, after defaults and normalisation.
+    pub applied: AppliedRequest,
+    /// Contiguous groups of `Empty` cells in the last planned week, largest first.
+    #[serde(default)]
+    pub empty_regions: Vec<GridRegion>,
+    /// Dashboard-style sub-scores of the garden as it stands after the last week.
+    #[serde(default)]
+    pub health: PlanHealth,
+    /// Orthogonally neighbouring plants that are good or bad companions, after the
+    /// last week, each pair once; neutral pairs are left out.
+    #[serde(default)]
+    pub adjacencies: Vec<Adjacency>,
+    /// In-season candidates that passed every filter yet were never planted in any
+    /// week, with the reason; varieties excluded by season, soil, sun… are not listed.
+    #[serde(default)]
+    pub unplaced_candidates: Vec<UnplacedCandidate>,
+    /// Present when no catalogue variety passed the request's constraints for any
+    /// planned week: how many varieties each constraint rules out.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_candidates: Option<CandidateEliminations>,
+    /// Whether sending the same request again reproduces this plan: `false` when the
+    /// time budget cut planning short, or when `period` was omitted and so follows the
+    /// current date.
+    #[serde(default)]
+    pub deterministic: bool,
+}
+
+/// Catalogue varieties ruled out by each planning constraint, each counted on its own:
+/// a variety failing several constraints counts under each of them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CandidateEliminations {
+    /// Varieties never sown or planted in the region during the planned months.
+    pub season: usize,
+    /// Varieties not suited to the requested `soil`.
+    pub soil: usize,
+    /// Varieties not tolerating the requested `sun`, or needing more than
+    /// `availableSunHours`.
+    pub sun: usize,
+    /// Varieties without a calendar for the requested `region`.
+    pub region: usize,
+    /// Varieties too demanding for the requested `level`.
+    pub level: usize,
+    /// Varieties listed in `exclusions`.
+    pub excluded: usize,
+}
+
+/// Why an eligible candidate ended up with no cell.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum UnplacedReason {
+    /// Its `span x span` block is larger than the grid in either dimension.
+    SpanTooLarge,
+    /// No free cell was left for the fill phase in any week it was in season.
+    GridFull,
+    /// Free cells remained, but they went to better-scoring candidates or its block did
+    /// not fit them (companions, zones, sun, plant budget…).
+    Outcompeted,
+    /// The request set `fill: false`, so only preferences were placed.
+    FillDisabled,
+}
+
+/// A candidate that was considered by the planner but never placed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct UnplacedCandidate {
+    pub id: String,
+    pub name: String,
+    pub reason: UnplacedReason,
+}
+
+/// Whether two neighbouring plants help or hinder each other.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AdjacencyKind {
+    Good,
+    Bad,
+}
+
+/// A companion relationship between two neighbouring plants, drawn from one plant's
+/// anchor to the other's.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Adjacency {
+    pub from: Coordinate,
+    pub to: Coordinate,
+    pub kind: AdjacencyKind,
+}
+
+/// Sub-scores of a planned garden, each from 0 to 100, computed on the grid as it
+/// stands after the last planned week.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanHealth {
+    /// Neighbouring plant pairs that are good companions versus bad ones: `100` when
+    /// every pair helps, `50` when all are neutral, `0` when every pair is bad.
+    #[schema(example = 75)]
+    pub companion: u8,
+    /// Distinct species planted, relative to a sensible mix for the number of plants.
+    #[schema(example = 80)]
+    pub diversity: u8,
+    /// Occupied plantable cells.
+    #[schema(example = 100)]
+    pub fill: u8,
+    /// Share of plants that are beginner-friendly.
+    #[schema(example = 60)]
+    pub beginner: u8,
+    /// Weighted blend of the sub-scores.
+    #[schema(example = 80)]
+    pub overall: u8,
+    /// `overall` as a letter, from `A` (85 and above) to `E` (below 40).
+    #[schema(example = "B")]
+    pub grade: String,
+}
+
+/// A [`PlannedCell`] reduced to its type and variety id; names and reasons live in the
+/// [`CompactPlanResponse::species`] dictionary instead of on every cell.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type")]
+pub enum CompactCell {
+    SelfContained {
+        id: String,
+    },
+    Overflowing {
+        id: String,
+    },
+    /// A continuation cell; `id` is the variety of the anchor covering it.
+    Overflowed {
+        id: String,
+    },
+    Empty,
+    Blocked,
+    Reserved,
+}
+
+/// One week of a [`CompactPlanResponse`].
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactWeeklyPlan {
+    pub period: Period,
+    pub week_count: u16,
+    #[schema(value_type = Vec<Vec<CompactCell>>)]
+    pub grid: Matrix<CompactCell>,
+    pub score: i32,
+}
+
+/// Name and placement reason shared by every cell of one variety.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct SpeciesEntry {
+    pub name: String,
+    /// Reason given for the variety's first placement.
+    pub reason: String,
+}
+
+/// `?view=compact` projection of a [`PlanResponse`]: grids of `{type, id}` cells plus
+/// one dictionary resolving each id to its name and reason.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactPlanResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub rows: usize,
+    pub cols: usize,
+    pub weeks: Vec<CompactWeeklyPlan>,
+    pub normalized_score: f32,
+    /// Every variety id present in `weeks`, keyed by id.
+    pub species: BTreeMap<String, SpeciesEntry>,
+    pub warnings: Vec<String>,
+}
+
+/// A [`PlanResponse`] together with the planner's internal grid, for debugging placement.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DebugPlanResponse {
+    #[serde(flatten)]
+    pub plan: PlanResponse,
+    /// The internal grid after the last planned week: each cell's `blocked` flag and the
+    /// `variety` occupying it, whose `anchor` is the top-left cell of its block.
+    #[schema(value_type = Object)]
+    pub raw_grid: GardenGrid,
+}
+
+/// One placement decision of the planner, in the order it was made.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlacementStep {
+    /// Index of the planning week the plant was placed in, counted before identical
+    /// consecutive weeks are merged.
+    pub week: usize,
+    pub id: String,
+    pub name: String,
+    /// Top-left cell of the placed block.
+    pub anchor: Coordinate,
+    /// Side of the square block, in cells.
+    pub span: usize,
+    /// Companion score the block earned against its neighbours when it was placed.
+    pub score: i32,
+    /// `1` for explicit preferences, `2` for the fill phase.
+    pub phase: u8,
+}
+
+/// A [`PlanResponse`] together with every placement decision behind it.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanTraceResponse {
+    #[serde(flatten)]
+    pub plan: PlanResponse,
+    /// Placements in the order the planner made them, co-placed companions included.
+    pub trace: Vec<PlacementStep>,
+}
+
+/// Outcome of checking a layout without planning it.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutValidationResponse {
+    pub rows: usize,
+    pub cols: usize,
+    /// Cells that are not blocked, pre-placed plants included.
+    pub plantable_cells: usize,
+    /// Number of pre-placed plants (anchor cells).
+    pub pre_placed: usize,
+    /// Blocked cells, from the layout and from `blockedRegions`.
+    pub blocked: usize,
+    /// Cells the layout keeps free for a later sowing.
+    #[serde(default)]
+    pub reserved: usize,
+    /// Issues the planner would work around (clipped footprints, dangling continuation
+    /// cells, clamped blocked regions, …).
     pub warnings: Vec<String>,
+    /// Issues that make `POST /api/plan` reject the layout. Counts are zero when the
+    /// layout is malformed.
+    pub errors: Vec<String>,
+}
+
+/// A pre-placed plant next to which a previewed plant would be a bad companion.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlacementConflict {
+    /// Variety id of the neighbouring plant.
+    pub id: String,
+    /// Anchor (top-left cell) of the neighbouring plant.
+    pub anchor: Coordinate,
+}
+
+/// What placing a plant at a given anchor of a layout would do, without planning.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlacementPreviewResponse {
+    /// Canonical variety id of the previewed plant.
+    pub id: String,
+    /// Top-left cell of the previewed block.
+    pub anchor: Coordinate,
+    /// Side of the plant's block, in cells.
+    pub span: u32,
+    /// Whether every cell of the block is free (neither planted nor blocked).
+    pub free: bool,
+    /// Whether no neighbouring plant is a bad companion (in either direction).
+    pub compatible: bool,
+    /// Companion score the planner would credit for the block, as for a planned plant.
+    #[schema(example = -3)]
+    pub score_delta: i32,
+    /// Neighbouring plants that are bad companions, in row-major order of their anchors.
+    pub conflicts: Vec<PlacementConflict>,
+}
+
+/// A layout with one more plant added where the planner scores it best.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceOneResponse {
+    /// Canonical variety id of the placed plant.
+    pub id: String,
+    /// Top-left cell of the block it was placed on.
+    pub anchor: Coordinate,
+    /// Side of the plant's block, in cells.
+    pub span: u32,
+    /// Companion score credited for the block.
+    #[schema(example = 2)]
+    pub score: i32,
+    /// Why that block was chosen, as in a planned cell.
+    pub reason: String,
+    /// The submitted layout with the plant added; nothing else is moved or re-planned.
+    #[schema(value_type = Vec<Vec<PlannedCell>>)]
+    pub grid: Matrix<PlannedCell>,
+}
+
+/// Why a bulk-planted plant was not stamped in.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum BulkRejection {
+    /// The id matches no variety.
+    UnknownVariety,
+    /// Its `span x span` block runs past the grid edge.
+    OutOfBounds,
+    /// Its block covers a blocked or reserved cell.
+    Blocked,
+    /// Its block covers a cell already planted, by the layout or an earlier placement.
+    Occupied,
+    /// Its block touches a bad companion (only with `checkCompanions`).
+    BadCompanion,
+}
+
+/// Outcome of one plant of a bulk plant, in request order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkPlacementOutcome {
+    /// Canonical variety id, or the id as sent when it is unknown.
+    pub id: String,
+    /// Requested top-left cell.
+    pub anchor: Coordinate,
+    /// Whether the plant was stamped into the layout.
+    pub accepted: bool,
+    /// Why it was rejected; absent when accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<BulkRejection>,
+    /// Bad-companion neighbours that caused a `badCompanion` rejection, in row-major
+    /// order of their anchors.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conflicts: Vec<PlacementConflict>,
+}
+
+/// A layout with several plants stamped in at once, and what became of each.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkPlantResponse {
+    /// Number of plants stamped in.
+    pub accepted: usize,
+    /// Number of plants rejected.
+    pub rejected: usize,
+    /// One outcome per requested plant, in request order.
+    pub placements: Vec<BulkPlacementOutcome>,
+    /// The base layout with the accepted plants added.
+    #[schema(value_type = Vec<Vec<PlannedCell>>)]
+    pub grid: Matrix<PlannedCell>,
+}
+
+/// Room one preference of a bed-size request takes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BedSizeItem {
+    /// Canonical variety id.
+    pub id: String,
+    pub name: String,
+    /// Blocks to plant, as the planner counts a `quantity`.
+    #[schema(example = 4)]
+    pub blocks: usize,
+    /// Side of each block, in cells.
+    #[schema(example = 2)]
+    pub span: u32,
+    /// Cells the blocks cover (`blocks × span²`).
+    #[schema(example = 16)]
+    pub cells: usize,
+    /// Plants the blocks hold (`blocks × plantsPerCell` for one-cell blocks).
+    #[schema(example = 4)]
+    pub plants: u32,
+}
+
+/// Smallest square-ish bed holding every requested plant, with some room to spare.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BedSizeResponse {
+    /// Bed height in cells; never more than `cols`.
+    #[schema(example = 4)]
+    pub rows: usize,
+    /// Bed width in cells; at most one more than `rows`.
+    #[schema(example = 5)]
+    pub cols: usize,
+    /// `rows × cols`.
+    #[schema(example = 20)]
+    pub cells: usize,
+    /// Cells taken by the plants themselves; the rest is buffer.
+    #[schema(example = 16)]
+    pub plant_cells: usize,
+    /// Bed width in centimetres (`cols × 30`).
+    #[schema(example = 150)]
+    pub width_cm: u32,
+    /// Bed length in centimetres (`rows × 30`).
+    #[schema(example = 120)]
+    pub length_cm: u32,
+    /// One entry per preference, in request order.
+    pub items: Vec<BedSizeItem>,
+}
+
+/// A plant taken out of the layout by a what-if removal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovedPlant {
+    /// Canonical variety id.
+    pub id: String,
+    /// Top-left cell of its block.
+    pub anchor: Coordinate,
+}
+
+/// A remaining plant whose companion score changes once the removal is done.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NeighborImpact {
+    /// Canonical variety id.
+    pub id: String,
+    /// Top-left cell of its block.
+    pub anchor: Coordinate,
+    /// Companion score against its neighbours before the removal.
+    pub before: i32,
+    /// Companion score against its neighbours after the removal.
+    pub after: i32,
+}
+
+/// What pulling plants out of a layout does to its companion score, without re-planning.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WhatIfResponse {
+    /// Plants taken out, in row-major order of their anchors.
+    pub removed: Vec<RemovedPlant>,
+    /// Sum of every plant's companion score against its neighbours, before the removal.
+    #[schema(example = 4)]
+    pub score_before: i32,
+    /// The same sum once the plants are removed.
+    #[schema(example = 0)]
+    pub score_after: i32,
+    /// `scoreAfter - scoreBefore`.
+    #[schema(example = -4)]
+    pub score_delta: i32,
+    /// Remaining plants whose score changes, biggest loss first: those losing a good
+    /// companion go negative, those rid of a bad one go positive.
+    pub affected: Vec<NeighborImpact>,
+    /// The layout with the plants removed.
+    #[schema(value_type = Vec<Vec<PlannedCell>>)]
+    pub grid: Matrix<PlannedCell>,
+}
+
+/// A pre-placed plant considered by a season transition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TransitionPlant {
+    /// Canonical variety id.
+    pub id: String,
+    /// Top-left cell of its block.
+    pub anchor: Coordinate,
+    pub lifecycle: Lifecycle,
+}
+
+/// The next season's plan for a planted bed, built around the plants that stay.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TransitionResponse {
+    pub target_season: Season,
+    /// Perennials, and biennials still within two years of planting, left in place; in
+    /// row-major order of their anchors.
+    pub kept: Vec<TransitionPlant>,
+    /// Annuals, and biennials past their second year, cleared to free their cells.
+    pub cleared: Vec<TransitionPlant>,
+    /// Plan of the target season, filled around the kept plants.
+    pub plan: PlanResponse,
+}
+
+/// A tiny two-species plan illustrating how the planner arranges companions.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanionDemoResponse {
+    /// Canonical variety id of the first species.
+    pub a: String,
+    /// Canonical variety id of the second species.
+    pub b: String,
+    /// Week the demo was planned for: the first week of the first month in which both
+    /// species are in season.
+    pub period: Period,
+    #[schema(value_type = Vec<Vec<PlannedCell>>)]
+    pub grid: Matrix<PlannedCell>,
+    /// Companion score of the arrangement.
+    pub score: i32,
+}
+
+/// A set of orthogonally connected cells, described by its bounding box.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GridRegion {
+    /// Top-left cell of the bounding box.
+    pub top_left: Coordinate,
+    /// Bounding box width in cells.
+    pub width: usize,
+    /// Bounding box height in cells.
+    pub height: usize,
+    /// Number of cells actually in the region (at most `width × height`).
+    pub cell_count: usize,
+}
+
+/// Effective planning inputs echoed back with a plan: what the server planned against
+/// once defaults were filled in, the period snapped to weeks and aliases resolved.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AppliedRequest {
+    /// Planning period after snapping to full Monday–Sunday weeks; absent when no week
+    /// could be planned.
+    pub period: Option<Period>,
+    /// Seasons covered by the planned weeks, in chronological order.
+    pub seasons: Vec<Season>,
+    pub region: Region,
+    /// Grid height in cells.
+    pub rows: usize,
+    /// Grid width in cells.
+    pub cols: usize,
+    /// Side of one grid cell in centimetres.
+    pub cell_size_cm: u32,
+    /// Preferences with canonical variety ids and the quantities actually requested
+    /// from the planner (after capping; `null` lets the fill phase decide).
+    pub preferences: Vec<AppliedPreference>,
+    pub available_sun_hours: Option<u32>,
+    pub pack: PackMode,
+    pub objective: Objective,
+    /// Objective weights after normalisation (empty when not blending).
+    pub weights: BTreeMap<Objective, f32>,
+    pub target_fill_pct: Option<u8>,
+    pub max_total_plants: Option<u32>,
+    /// Id pairs kept apart, as requested.
+    #[serde(default)]
+    #[schema(value_type = Vec<Vec<String>>)]
+    pub avoid_pairs: Vec<(String, String)>,
+    #[serde(default)]
+    pub avoid_pair_penalty: i32,
+    #[serde(default)]
+    pub neutral_competitor_penalty: i32,
+    pub fill: bool,
+    pub suggest_fixes: bool,
+    pub strict_companions: bool,
+    #[serde(default)]
+    pub mutual_companions: bool,
+    pub density: Density,
+    pub season_overlap: bool,
+    /// Candidates admitted in at least one week only through `seasonOverlap`, ordered
+    /// by French consumption rank then id.
+    #[serde(default)]
+    pub shoulder_varieties: Vec<String>,
+    /// Factor the catalogue spacing of every planned variety was multiplied by.
+    #[schema(example = 1.0)]
+    pub spacing_multiplier: f32,
+}
+
+/// One preference as applied by the planner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AppliedPreference {
+    /// Canonical variety id (aliases resolved).
+    pub id: String,
+    pub quantity: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area_m2: Option<f32>,
+}
+
+/// Proposed relocation of a pre-placed plant away from a bad companion.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RelocationSuggestion {
+    /// Variety id of the plant to move.
+    pub id: String,
+    /// Current anchor (top-left cell) of the plant.
+    pub from: Coordinate,
+    /// Nearest free anchor where the plant has no bad companion as a neighbour.
+    pub to: Coordinate,
+    /// Variety id of the bad companion it currently touches.
+    pub conflicts_with: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -143,6 +745,9 @@ pub struct CompanionsResponse {
     pub name: String,
     pub good: Vec<CompanionInfo>,
     pub bad: Vec<CompanionInfo>,
+    /// Why documented pairs help or harm each other, e.g. "Basil repels whitefly and
+    /// aphids from tomato."; empty when none of its pairs is documented.
+    pub notes: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -151,3 +756,232 @@ pub struct CompanionInfo {
     pub id: String,
     pub name: String,
 }
+
+/// A follow-up crop proposed for a cell freed by an early harvest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessionPlanting {
+    /// Id of the base-plan variety this planting follows.
+    pub follows: String,
+    pub id: String,
+    pub name: String,
+    /// Date the follow-up goes in the ground (the base crop's harvest date).
+    #[schema(value_type = String, format = Date, example = "2025-07-15")]
+    pub planting_date: NaiveDate,
+    /// Estimated harvest date of the follow-up crop.
+    #[schema(value_type = String, format = Date, example = "2025-08-30")]
+    pub estimated_harvest_date: NaiveDate,
+}
+
+/// Outcome of one request of a batch, at the same position as the request.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "status")]
+pub enum BatchPlanItem {
+    /// The request was planned successfully.
+    Ok { plan: Box<PlanResponse> },
+    /// The request was rejected; the other requests of the batch are unaffected.
+    Error { error: String },
+}
+
+/// Results of `POST /api/plan/batch`, one item per submitted request, in order.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchPlanResponse {
+    pub items: Vec<BatchPlanItem>,
+    /// Number of `Ok` items.
+    pub succeeded: usize,
+    /// Number of `Error` items.
+    pub failed: usize,
+}
+
+/// A base plan together with a succession overlay of follow-up crops.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessionResponse {
+    pub rows: usize,
+    pub cols: usize,
+    /// Grid of the first planned week.
+    #[schema(value_type = Vec<Vec<PlannedCell>>)]
+    pub base: Matrix<PlannedCell>,
+    /// Same dimensions as `base`; anchor cells of early-maturing plants carry the
+    /// proposed follow-up, every other cell is `null`.
+    #[schema(value_type = Vec<Vec<Option<SuccessionPlanting>>>)]
+    pub succession: Matrix<Option<SuccessionPlanting>>,
+    pub warnings: Vec<String>,
+}
+
+/// How a single cell differs between two plans.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "PascalCase")]
+pub enum CellChangeKind {
+    /// A plant now occupies a previously free or blocked cell.
+    Added,
+    /// The cell's plant is gone.
+    Removed,
+    /// A different plant occupies the cell, or it switched between `Empty` and `Blocked`.
+    Changed,
+    /// Same plant (or same `Empty` / `Blocked` state) in both plans.
+    Unchanged,
+}
+
+/// Per-cell comparison result. `before` / `after` hold the id of the plant occupying
+/// the cell (resolved through the anchor for `Overflowed` cells).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CellDiff {
+    pub change: CellChangeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+}
+
+/// Number of cells per [`CellChangeKind`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffCounts {
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+    pub unchanged: usize,
+}
+
+/// Cell-by-cell comparison of two planned grids of equal dimensions.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanDiffResponse {
+    pub rows: usize,
+    pub cols: usize,
+    #[schema(value_type = Vec<Vec<CellDiff>>)]
+    pub cells: Matrix<CellDiff>,
+    pub counts: DiffCounts,
+    /// `after.score - before.score`, widened so that no pair of scores can overflow.
+    pub score_delta: i64,
+}
+
+/// A catalogue vegetable suggested as a neighbour, with the reasons for its rank.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanionSuggestion {
+    pub id: String,
+    pub name: String,
+    /// Companion score counted in both directions (e.g. `4` when both vegetables list
+    /// each other as good companions, `0` when neutral).
+    pub score: i32,
+    /// Seasons in which both vegetables can be sown or planted.
+    pub shared_seasons: Vec<Season>,
+}
+
+/// A catalogue vegetable worth adding to a partly planted layout.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlantingRecommendation {
+    pub id: String,
+    pub name: String,
+    /// Companion score summed over every vegetable already in the layout, counted in
+    /// both directions.
+    pub score: i32,
+    /// Vegetables already in the layout that are good companions of this one (in either
+    /// direction), sorted by id.
+    pub good_with: Vec<String>,
+}
+
+/// What to add to a partly planted layout, best first (bad companions of anything
+/// already present excluded).
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanRecommendationsResponse {
+    /// Vegetable ids found in the layout, sorted by id.
+    pub present: Vec<String>,
+    /// Season the recommendations are active in.
+    pub season: Season,
+    pub recommendations: Vec<PlantingRecommendation>,
+}
+
+/// Ranked neighbour suggestions for a vegetable (bad companions excluded).
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanionSuggestionsResponse {
+    pub id: String,
+    pub name: String,
+    pub suggestions: Vec<CompanionSuggestion>,
+}
+
+/// How a cell of a neighbours preview relates to the vegetable at its centre.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum NeighborRole {
+    Center,
+    Good,
+    Bad,
+}
+
+/// A planted cell of a neighbours preview.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NeighborPreviewCell {
+    pub id: String,
+    pub name: String,
+    pub role: NeighborRole,
+    /// Companion score the centre vegetable gets from this neighbour (`0` for the centre).
+    #[schema(example = 2)]
+    pub score: i32,
+}
+
+/// A 3×3 grid illustrating one vegetable's relationships: the vegetable in the centre,
+/// its top good companions and one bad companion orthogonally around it.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NeighborsPreviewResponse {
+    pub id: String,
+    pub name: String,
+    /// Row-major cells; `null` for empty cells (the corners, and slots left without a
+    /// companion).
+    #[schema(value_type = Vec<Vec<Option<NeighborPreviewCell>>>)]
+    pub grid: Matrix<Option<NeighborPreviewCell>>,
+    /// Companion score of the centre vegetable against its neighbours.
+    #[schema(example = 3)]
+    pub score: i32,
+}
+
+/// Pairwise companion scores between catalogue vegetables.
+///
+/// `scores[i][j]` is the score `vegetables[i]` gets from having `vegetables[j]` as a
+/// neighbour (`+2` good, `-3` bad, `0` neutral).
+#[derive(Debug, Clone)]
+pub struct CompanionMatrix {
+    pub vegetables: Vec<CompanionInfo>,
+    pub scores: Matrix<i32>,
+}
+
+/// Every accepted value of the enums used in requests and responses, as serialized.
+/// Rendering hints for one variety category.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryInfo {
+    pub category: Category,
+    /// Default colour, as `#RRGGBB`.
+    #[schema(example = "#E53935")]
+    pub color_hex: String,
+    /// Default emoji.
+    #[schema(example = "🍅")]
+    pub icon: String,
+}
+
+/// Every variety category with its rendering hints, in declaration order.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoriesResponse {
+    pub categories: Vec<CategoryInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EnumsResponse {
+    pub seasons: Vec<Season>,
+    pub soil_types: Vec<SoilType>,
+    pub sun_exposures: Vec<SunExposure>,
+    pub regions: Vec<Region>,
+    pub categories: Vec<Category>,
+    pub lifecycles: Vec<Lifecycle>,
+    pub levels: Vec<Level>,
+}
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::fmt;
 use std::sync::Arc;
 
@@ -15,6 +16,28 @@ pub enum Season {
     Winter,
 }
 
+impl Season {
+    /// Every variant, in declaration order.
+    pub fn all() -> &'static [Self] {
+        &[
+            Season::Spring,
+            Season::Summer,
+            Season::Autumn,
+            Season::Winter,
+        ]
+    }
+
+    /// First month (1–12) of the season, matching [`season_for_month`].
+    pub fn first_month(&self) -> u32 {
+        match self {
+            Season::Spring => 3,
+            Season::Summer => 6,
+            Season::Autumn => 9,
+            Season::Winter => 12,
+        }
+    }
+}
+
 /// Maps a calendar month (1–12) to the corresponding planting [`Season`].
 /// Spring: Mar–May, Summer: Jun–Aug, Autumn: Sep–Nov, Winter: Dec–Feb.
 pub fn season_for_month(month: u32) -> Season {
@@ -36,6 +59,19 @@ pub enum SoilType {
     Humus,
 }
 
+impl SoilType {
+    /// Every variant, in declaration order.
+    pub fn all() -> &'static [Self] {
+        &[
+            SoilType::Clay,
+            SoilType::Sandy,
+            SoilType::Loamy,
+            SoilType::Chalky,
+            SoilType::Humus,
+        ]
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "PascalCase")]
 pub enum SunExposure {
@@ -44,6 +80,17 @@ pub enum SunExposure {
     Shade,
 }
 
+impl SunExposure {
+    /// Every variant, in declaration order.
+    pub fn all() -> &'static [Self] {
+        &[
+            SunExposure::FullSun,
+            SunExposure::PartialShade,
+            SunExposure::Shade,
+        ]
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "PascalCase")]
 pub enum Region {
@@ -54,6 +101,19 @@ pub enum Region {
     Mountain,
 }
 
+impl Region {
+    /// Every variant, in declaration order.
+    pub fn all() -> &'static [Self] {
+        &[
+            Region::Temperate,
+            Region::Mediterranean,
+            Region::Oceanic,
+            Region::Continental,
+            Region::Mountain,
+        ]
+    }
+}
+
 /// Calendar month — used in sowing and planting windows.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "PascalCase")]
@@ -141,6 +201,47 @@ pub enum Category {
     Pod,
 }
 
+impl Category {
+    /// Every variant, in declaration order.
+    pub fn all() -> &'static [Self] {
+        &[
+            Category::Fruit,
+            Category::Produce,
+            Category::Herb,
+            Category::Root,
+            Category::Bulb,
+            Category::Leafy,
+            Category::Pod,
+        ]
+    }
+
+    /// Default colour clients should render this category with, as `#RRGGBB`.
+    pub fn color_hex(&self) -> &'static str {
+        match self {
+            Category::Fruit => "#E53935",
+            Category::Produce => "#FDD835",
+            Category::Herb => "#2E7D32",
+            Category::Root => "#FB8C00",
+            Category::Bulb => "#8E24AA",
+            Category::Leafy => "#7CB342",
+            Category::Pod => "#00897B",
+        }
+    }
+
+    /// Default emoji clients should render this category with.
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Category::Fruit => "🍅",
+            Category::Produce => "🥒",
+            Category::Herb => "🌿",
+            Category::Root => "🥕",
+            Category::Bulb => "🧅",
+            Category::Leafy => "🥬",
+            Category::Pod => "🫛",
+        }
+    }
+}
+
 impl fmt::Display for Category {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", format!("{:?}", self).to_lowercase())
@@ -159,6 +260,13 @@ pub enum Lifecycle {
     Perennial,
 }
 
+impl Lifecycle {
+    /// Every variant, in declaration order.
+    pub fn all() -> &'static [Self] {
+        &[Lifecycle::Annual, Lifecycle::Biennial, Lifecycle::Perennial]
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Variety {
@@ -171,6 +279,10 @@ pub struct Variety {
     /// the variety can be grown there.
     pub calendars: Vec<RegionCalendar>,
     pub sun_requirement: Vec<SunExposure>,
+    /// Minimum hours of direct sun per day the variety needs to crop well.
+    pub min_sun_hours: u32,
+    /// Typical harvest of one plant over its season, in grams.
+    pub yield_per_plant_g: u32,
     pub soil_types: Vec<SoilType>,
     pub spacing_cm: u32,
     /// Approximate number of days from planting/transplanting to first harvest.
@@ -181,3 +293,92 @@ pub struct Variety {
     pub beginner_friendly: bool,
     pub category: Category,
 }
+
+impl Variety {
+    /// Seasons in which this variety can be sown or planted in at least one region,
+    /// in `Spring`, `Summer`, `Autumn`, `Winter` order.
+    pub fn active_seasons(&self) -> Vec<Season> {
+        let mut seasons: Vec<Season> = self
+            .calendars
+            .iter()
+            .flat_map(|c| {
+                [
+                    &c.sowing.outdoor,
+                    &c.sowing.indoor,
+                    &c.planting.outdoor,
+                    &c.planting.indoor,
+                ]
+            })
+            .flatten()
+            .map(|m| season_for_month(m.to_u32()))
+            .collect();
+        seasons.sort_by_key(|s| s.clone() as u8);
+        seasons.dedup();
+        seasons
+    }
+
+    /// Checks the variety and its vegetable (see [`Vegetable::validate`]): non-empty id
+    /// and name, and a positive `spacing_cm`. Returns every problem found.
+    pub fn validate(&self, known_ids: &HashSet<String>) -> Result<(), Vec<String>> {
+        let mut errors = self.vegetable.validate(known_ids).err().unwrap_or_default();
+        if self.id.trim().is_empty() {
+            errors.push("Variety id must not be empty.".to_owned());
+        }
+        if self.name.trim().is_empty() {
+            errors.push(format!("Variety '{}' has an empty name.", self.id));
+        }
+        if self.spacing_cm == 0 {
+            errors.push(format!(
+                "Variety '{}' must have a spacingCm greater than 0.",
+                self.id
+            ));
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_fixtures::{get_all_vegetables, get_variety_by_id};
+
+    fn known_ids() -> HashSet<String> {
+        get_all_vegetables().into_iter().map(|v| v.id).collect()
+    }
+
+    #[test]
+    fn test_validate_accepts_fixture_variety() {
+        let tomato = get_variety_by_id("tomato").unwrap();
+        assert_eq!(tomato.validate(&known_ids()), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_spacing() {
+        let mut tomato = get_variety_by_id("tomato").unwrap();
+        tomato.spacing_cm = 0;
+        assert_eq!(
+            tomato.validate(&known_ids()),
+            Err(vec![
+                "Variety 'tomato' must have a spacingCm greater than 0.".to_owned()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_vegetable_problems() {
+        let mut tomato = get_variety_by_id("tomato").unwrap();
+        let mut vegetable = (*tomato.vegetable).clone();
+        vegetable.bad_companions.push("tomato".into());
+        tomato.vegetable = Arc::new(vegetable);
+        assert_eq!(
+            tomato.validate(&known_ids()),
+            Err(vec![
+                "Vegetable 'tomato' lists itself in badCompanions.".to_owned()
+            ])
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -8,6 +10,10 @@ use utoipa::ToSchema;
 pub struct Vegetable {
     pub id: String,
     pub name: String,
+    /// Alternative ids accepted wherever this vegetable's id is (e.g. `"courgette"` for
+    /// `"zucchini"`). Lookups by alias resolve to this vegetable and its namesake variety.
+    #[serde(default)]
+    pub aliases: Vec<String>,
     /// Identifier of the group this vegetable belongs to.
     pub group_id: String,
     pub variety_ids: Vec<String>,
@@ -15,4 +21,110 @@ pub struct Vegetable {
     pub good_companions: Vec<String>,
     /// Identifiers of vegetables that harm this vegetable when planted nearby.
     pub bad_companions: Vec<String>,
+    /// Identifiers of vegetables this vegetable cannot be planted without (e.g. the
+    /// support of a climbing bean): the planner only places it next to one of them.
+    #[serde(default)]
+    pub requires: Vec<String>,
+}
+
+impl Vegetable {
+    /// Checks the vegetable is consistent with a catalogue whose vegetable ids are
+    /// `known_ids`: non-empty id and name, and every good, bad or required companion
+    /// names another known vegetable. Returns every problem found.
+    pub fn validate(&self, known_ids: &HashSet<String>) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        if self.id.trim().is_empty() {
+            errors.push("Vegetable id must not be empty.".to_owned());
+        }
+        if self.name.trim().is_empty() {
+            errors.push(format!("Vegetable '{}' has an empty name.", self.id));
+        }
+        for (list, ids) in [
+            ("goodCompanions", &self.good_companions),
+            ("badCompanions", &self.bad_companions),
+            ("requires", &self.requires),
+        ] {
+            for id in ids {
+                if *id == self.id {
+                    errors.push(format!("Vegetable '{}' lists itself in {list}.", self.id));
+                } else if !known_ids.contains(id) {
+                    errors.push(format!(
+                        "Vegetable '{}' lists unknown vegetable '{id}' in {list}.",
+                        self.id
+                    ));
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vegetable(id: &str) -> Vegetable {
+        Vegetable {
+            id: id.into(),
+            name: id.into(),
+            aliases: vec![],
+            group_id: "solanaceae".into(),
+            variety_ids: vec![id.into()],
+            good_companions: vec![],
+            bad_companions: vec![],
+            requires: vec![],
+        }
+    }
+
+    fn known(ids: &[&str]) -> HashSet<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn test_validate_accepts_known_companions() {
+        let mut tomato = vegetable("tomato");
+        tomato.good_companions = vec!["basil".into()];
+        tomato.bad_companions = vec!["fennel".into()];
+        assert_eq!(
+            tomato.validate(&known(&["tomato", "basil", "fennel"])),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_id_and_name() {
+        let mut blank = vegetable(" ");
+        blank.name = String::new();
+        let errors = blank.validate(&known(&[])).unwrap_err();
+        assert_eq!(errors.len(), 2, "{errors:?}");
+    }
+
+    #[test]
+    fn test_validate_rejects_self_listed_companion() {
+        let mut tomato = vegetable("tomato");
+        tomato.good_companions = vec!["tomato".into()];
+        assert_eq!(
+            tomato.validate(&known(&["tomato"])),
+            Err(vec![
+                "Vegetable 'tomato' lists itself in goodCompanions.".to_owned()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_dangling_companion_id() {
+        let mut bean = vegetable("bean");
+        bean.requires = vec!["pole".into()];
+        bean.bad_companions = vec!["onion".into()];
+        assert_eq!(
+            bean.validate(&known(&["bean", "onion"])),
+            Err(vec![
+                "Vegetable 'bean' lists unknown vegetable 'pole' in requires.".to_owned()
+            ])
+        );
+    }
 }
@@ -1,10 +1,33 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+/// Language warning messages are written in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    English,
+    French,
+}
+
+impl Language {
+    /// French for the `fr` locale, English for every other one.
+    pub fn from_locale(locale: &str) -> Self {
+        match locale {
+            "fr" => Self::French,
+            _ => Self::English,
+        }
+    }
+}
+
 /// Accumulates planner warnings and provides helper methods for mutation.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
-pub struct Warnings(Vec<String>);
+pub struct Warnings {
+    messages: Vec<String>,
+    /// Language the helpers that know several write their message in.
+    #[serde(skip)]
+    language: Language,
+}
 
 impl Warnings {
     /// Creates an empty warning collection.
@@ -12,9 +35,22 @@ impl Warnings {
         Self::default()
     }
 
+    /// Creates an empty warning collection whose helpers write in `language`.
+    pub fn localized(language: Language) -> Self {
+        Self {
+            messages: Vec::new(),
+            language,
+        }
+    }
+
+    /// Language the localized helpers write in.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
     /// Adds one warning message.
     pub fn add(&mut self, warning: impl Into<String>) {
-        self.0.push(warning.into());
+        self.messages.push(warning.into());
     }
 
     /// Adds an optional warning message when present.
@@ -26,28 +62,28 @@ impl Warnings {
 
     /// Number of collected warning messages.
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.messages.len()
     }
 
     /// Returns `true` when no warnings have been collected.
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.messages.is_empty()
     }
 
     /// Returns collected warnings as a slice.
     pub fn as_slice(&self) -> &[String] {
-        &self.0
+        &self.messages
     }
 
     /// Consumes the collection and returns the underlying vector.
     pub fn into_vec(self) -> Vec<String> {
-        self.0
+        self.messages
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Warnings;
+    use super::{Language, Warnings};
 
     #[test]
     fn warnings_new_and_add() {
@@ -60,4 +96,12 @@ mod tests {
         assert_eq!(warnings.as_slice()[0], "first warning");
         assert_eq!(warnings.as_slice()[1], "second warning");
     }
+
+    #[test]
+    fn language_from_locale_defaults_to_english() {
+        assert_eq!(Language::from_locale("fr"), Language::French);
+        assert_eq!(Language::from_locale("en"), Language::English);
+        assert_eq!(Language::from_locale("de"), Language::English);
+        assert_eq!(Warnings::new().language(), Language::English);
+    }
 }
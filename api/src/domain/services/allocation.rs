@@ -1,9 +1,10 @@
 use std::collections::HashMap;
 
 use crate::domain::models::{request::Preference, variety::Variety};
-use crate::domain::services::helpers::cell_span;
+use crate::domain::services::helpers::{cell_span, cells_for_area};
 
-/// Distributes cells for varieties that have an explicit `quantity` preference.
+/// Distributes cells for varieties that have an explicit `quantity` or `area_m2`
+/// preference: a quantity needs `span^2` cells per plant, an area the cells covering it.
 /// Returns a map of `id -> cell count` only for those varieties; everything else
 /// (auto-fill candidates) is handled by a separate iterative fill phase.
 pub fn compute_explicit_allocation(
@@ -14,9 +15,13 @@ pub fn compute_explicit_allocation(
     let mut remaining = available;
 
     for pref in preferences {
-        if let Some(qty) = pref.quantity {
-            let cells_per_plant = (cell_span(pref.variety.spacing_cm) as usize).pow(2);
-            let cells_needed = (qty as usize).saturating_mul(cells_per_plant);
+        let cells_per_plant = (cell_span(pref.variety.spacing_cm) as usize).pow(2);
+        let cells_needed = match (pref.quantity, pref.area_m2) {
+            (Some(qty), _) => Some((qty as usize).saturating_mul(cells_per_plant)),
+            (None, Some(area)) => Some(cells_for_area(area)),
+            (None, None) => None,
+        };
+        if let Some(cells_needed) = cells_needed {
             let alloc = cells_needed.min(remaining);
             allocation.insert(pref.variety.id.clone(), alloc);
             remaining = remaining.saturating_sub(alloc);
@@ -77,14 +82,34 @@ mod tests {
             Preference {
                 variety: basil,
                 quantity: Some(2),
+                area_m2: None,
             },
             Preference {
                 variety: tomato,
                 quantity: Some(1),
+                area_m2: None,
             },
         ];
         let allocation = compute_explicit_allocation(&preferences, 20);
         assert_eq!(allocation["basil"], 2, "basil: 2 plants x 1 cell");
         assert_eq!(allocation["tomato"], 4, "tomato: 1 plant x 4 cells");
     }
+
+    #[test]
+    fn test_compute_explicit_allocation_converts_area_to_cells() {
+        let basil = get_variety_by_id("basil").unwrap();
+        let preferences = vec![Preference {
+            variety: basil,
+            quantity: None,
+            area_m2: Some(1.0),
+        }];
+        let allocation = compute_explicit_allocation(&preferences, 20);
+        assert_eq!(
+            allocation["basil"], 11,
+            "1 m² / 0.09 m² per cell ≈ 11 cells"
+        );
+        let (queue, placements) = build_placement_queue(&preferences, 20);
+        assert_eq!(placements["basil"], 11);
+        assert_eq!(queue.len(), 11);
+    }
 }
@@ -1,25 +1,219 @@
-use crate::domain::models::vegetable::Vegetable;
+use std::collections::HashMap;
+
+use crate::domain::models::{
+    response::{
+        CompanionInfo, CompanionMatrix, CompanionSuggestion, NeighborPreviewCell, NeighborRole,
+        NeighborsPreviewResponse, PlantingRecommendation,
+    },
+    variety::Season,
+    vegetable::Vegetable,
+};
+use crate::domain::services::filter::species_order;
 
 pub const GOOD_COMPANION_SCORE: i32 = 2;
 pub const BAD_COMPANION_SCORE: i32 = -3;
 
+/// Cells of a neighbours preview taking good companions (top, left, right) and the
+/// bad one (bottom), around the centre at `[1,1]`.
+const PREVIEW_GOOD_SLOTS: [(usize, usize); 3] = [(0, 1), (1, 0), (1, 2)];
+const PREVIEW_BAD_SLOT: (usize, usize) = (2, 1);
+
+/// Magnitude at which a companion score is clearly dominant (or clearly bad): scoring
+/// stops accumulating once it is reached. Far above what the catalogue's spans can
+/// produce (a 4×4 block has 16 perimeter cells, i.e. at most +32 / -48), so only
+/// synthetic huge blocks are affected.
+pub const COMPANION_SCORE_CAP: i32 = 64;
+
 /// Calculates the companion score of a vegetable against its neighbours.
-/// +2 per good companion, -3 per bad companion.
+/// +2 per good companion, -3 per bad companion, clamped to ±[`COMPANION_SCORE_CAP`].
 pub fn companion_score(vegetable: &Vegetable, neighbor_vegetable_ids: &[&str]) -> i32 {
+    capped_companion_score(vegetable, neighbor_vegetable_ids).0
+}
+
+/// [`companion_score`], also returning how many neighbours were examined before the
+/// cap was reached.
+fn capped_companion_score(vegetable: &Vegetable, neighbor_vegetable_ids: &[&str]) -> (i32, usize) {
     let mut score = 0;
-    for neighbor_id in neighbor_vegetable_ids {
+    for (examined, neighbor_id) in neighbor_vegetable_ids.iter().enumerate() {
         if vegetable.good_companions.iter().any(|c| c == neighbor_id) {
             score += GOOD_COMPANION_SCORE;
         }
         if vegetable.bad_companions.iter().any(|c| c == neighbor_id) {
             score += BAD_COMPANION_SCORE;
         }
+        if score.abs() >= COMPANION_SCORE_CAP {
+            return (
+                score.clamp(-COMPANION_SCORE_CAP, COMPANION_SCORE_CAP),
+                examined + 1,
+            );
+        }
+    }
+    (score, neighbor_vegetable_ids.len())
+}
+
+/// Builds the N×N companion matrix for `vegetables`, keeping their order on both axes.
+/// The diagonal is always scored like any other pair (normally `0`).
+pub fn companion_matrix(vegetables: &[Vegetable]) -> CompanionMatrix {
+    let scores = vegetables
+        .iter()
+        .map(|row| {
+            vegetables
+                .iter()
+                .map(|col| companion_score(row, &[col.id.as_str()]))
+                .collect()
+        })
+        .collect();
+    CompanionMatrix {
+        vegetables: vegetables
+            .iter()
+            .map(|v| CompanionInfo {
+                id: v.id.clone(),
+                name: v.name.clone(),
+            })
+            .collect(),
+        scores,
+    }
+}
+
+/// Ranks `catalogue` vegetables as neighbours for `target`.
+///
+/// Bad companions (in either direction) and `target` itself are excluded. The rest is
+/// ordered by mutual companion score (good companions first, then neutral), then by the
+/// number of seasons shared with `target`, then by id. `seasons` maps vegetable ids to
+/// their active seasons; with `season`, only vegetables active in it are kept.
+pub fn rank_suggestions(
+    target: &Vegetable,
+    catalogue: &[Vegetable],
+    seasons: &HashMap<String, Vec<Season>>,
+    season: Option<&Season>,
+) -> Vec<CompanionSuggestion> {
+    let no_seasons = Vec::new();
+    let target_seasons = seasons.get(&target.id).unwrap_or(&no_seasons);
+    let mut suggestions: Vec<CompanionSuggestion> = catalogue
+        .iter()
+        .filter(|v| v.id != target.id && is_compatible(target, v))
+        .filter_map(|v| {
+            let own = seasons.get(&v.id).unwrap_or(&no_seasons);
+            if season.is_some_and(|s| !own.contains(s)) {
+                return None;
+            }
+            Some(CompanionSuggestion {
+                id: v.id.clone(),
+                name: v.name.clone(),
+                score: companion_score(target, &[v.id.as_str()])
+                    + companion_score(v, &[target.id.as_str()]),
+                shared_seasons: own
+                    .iter()
+                    .filter(|s| target_seasons.contains(s))
+                    .cloned()
+                    .collect(),
+            })
+        })
+        .collect();
+    suggestions.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| b.shared_seasons.len().cmp(&a.shared_seasons.len()))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    suggestions
+}
+
+/// Ranks `catalogue` vegetables as additions to a layout already holding `present`.
+///
+/// Vegetables already present, bad companions (in either direction) of any of them and
+/// vegetables not active in `season` are excluded. The rest is ordered by mutual
+/// companion score summed over `present`, then by id. `seasons` maps vegetable ids to
+/// their active seasons.
+pub fn rank_recommendations(
+    present: &[Vegetable],
+    catalogue: &[Vegetable],
+    seasons: &HashMap<String, Vec<Season>>,
+    season: &Season,
+) -> Vec<PlantingRecommendation> {
+    let mut recommendations: Vec<PlantingRecommendation> = catalogue
+        .iter()
+        .filter(|v| !present.iter().any(|p| p.id == v.id))
+        .filter(|v| present.iter().all(|p| is_compatible(p, v)))
+        .filter(|v| seasons.get(&v.id).is_some_and(|s| s.contains(season)))
+        .map(|v| {
+            let mutual = |p: &Vegetable| {
+                companion_score(p, &[v.id.as_str()]) + companion_score(v, &[p.id.as_str()])
+            };
+            let mut good_with: Vec<String> = present
+                .iter()
+                .filter(|p| mutual(p) > 0)
+                .map(|p| p.id.clone())
+                .collect();
+            good_with.sort();
+            PlantingRecommendation {
+                id: v.id.clone(),
+                name: v.name.clone(),
+                score: present.iter().map(mutual).sum(),
+                good_with,
+            }
+        })
+        .collect();
+    recommendations.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+    recommendations
+}
+
+/// Builds a 3×3 grid with `target` in the centre, surrounded by its best good
+/// companions from `catalogue` (highest mutual score first, then French consumption
+/// rank) and its first bad companion (by French consumption rank). Companions missing
+/// from `catalogue` are skipped and their slots left empty.
+pub fn neighbors_preview(target: &Vegetable, catalogue: &[Vegetable]) -> NeighborsPreviewResponse {
+    let find = |id: &String| catalogue.iter().find(|v| &v.id == id);
+    let mutual = |v: &Vegetable| {
+        companion_score(target, &[v.id.as_str()]) + companion_score(v, &[target.id.as_str()])
+    };
+
+    let mut good: Vec<&Vegetable> = target.good_companions.iter().filter_map(find).collect();
+    good.sort_by(|a, b| {
+        mutual(b)
+            .cmp(&mutual(a))
+            .then_with(|| species_order(&a.id, &b.id))
+    });
+    let mut bad: Vec<&Vegetable> = target.bad_companions.iter().filter_map(find).collect();
+    bad.sort_by(|a, b| species_order(&a.id, &b.id));
+
+    let cell = |v: &Vegetable, role: NeighborRole| NeighborPreviewCell {
+        id: v.id.clone(),
+        name: v.name.clone(),
+        score: if role == NeighborRole::Center {
+            0
+        } else {
+            companion_score(target, &[v.id.as_str()])
+        },
+        role,
+    };
+    let mut grid: Vec<Vec<Option<NeighborPreviewCell>>> = vec![vec![None; 3]; 3];
+    grid[1][1] = Some(cell(target, NeighborRole::Center));
+    for (&(r, c), v) in PREVIEW_GOOD_SLOTS.iter().zip(&good) {
+        grid[r][c] = Some(cell(v, NeighborRole::Good));
+    }
+    if let Some(v) = bad.first() {
+        let (r, c) = PREVIEW_BAD_SLOT;
+        grid[r][c] = Some(cell(v, NeighborRole::Bad));
+    }
+
+    let neighbor_ids: Vec<&str> = grid
+        .iter()
+        .flatten()
+        .flatten()
+        .filter(|c| c.role != NeighborRole::Center)
+        .map(|c| c.id.as_str())
+        .collect();
+    let score = companion_score(target, &neighbor_ids);
+    NeighborsPreviewResponse {
+        id: target.id.clone(),
+        name: target.name.clone(),
+        grid,
+        score,
     }
-    score
 }
 
 /// Returns true if the two vegetables are compatible (neither appears in the other's bad_companions list).
-#[cfg(test)]
 pub fn is_compatible(a: &Vegetable, b: &Vegetable) -> bool {
     !a.bad_companions.iter().any(|c| c == &b.id) && !b.bad_companions.iter().any(|c| c == &a.id)
 }
@@ -78,6 +272,67 @@ mod tests {
         assert_eq!(score, 0);
     }
 
+    #[test]
+    fn test_score_cap_leaves_small_blocks_unchanged() {
+        let tomato = get("tomato");
+        // A 4×4 block surrounded by basil: 16 good neighbours, well under the cap.
+        let neighbors = ["basil"; 16];
+        assert_eq!(
+            capped_companion_score(&tomato, &neighbors),
+            (16 * GOOD_COMPANION_SCORE, 16)
+        );
+        let neighbors = ["fennel"; 16];
+        assert_eq!(
+            companion_score(&tomato, &neighbors),
+            16 * BAD_COMPANION_SCORE
+        );
+    }
+
+    #[test]
+    fn test_score_cap_stops_early_on_huge_blocks() {
+        let tomato = get("tomato");
+        // Perimeter of a synthetic 250×250 block full of basil.
+        let neighbors = vec!["basil"; 1000];
+        let (score, examined) = capped_companion_score(&tomato, &neighbors);
+        assert_eq!(score, COMPANION_SCORE_CAP);
+        assert_eq!(
+            examined,
+            (COMPANION_SCORE_CAP / GOOD_COMPANION_SCORE) as usize,
+            "scoring stops once the cap is reached"
+        );
+        let neighbors = vec!["fennel"; 1000];
+        assert_eq!(companion_score(&tomato, &neighbors), -COMPANION_SCORE_CAP);
+    }
+
+    #[test]
+    fn test_companion_matrix_scores_each_ordered_pair() {
+        let vegetables = vec![get("tomato"), get("basil"), get("fennel")];
+        let matrix = companion_matrix(&vegetables);
+
+        let ids: Vec<&str> = matrix.vegetables.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(ids, ["tomato", "basil", "fennel"]);
+        assert_eq!(matrix.scores.len(), 3);
+        assert_eq!(matrix.scores[0][1], GOOD_COMPANION_SCORE, "tomato <- basil");
+        assert_eq!(matrix.scores[0][2], BAD_COMPANION_SCORE, "tomato <- fennel");
+        assert_eq!(matrix.scores[0][0], 0);
+    }
+
+    #[test]
+    fn test_rank_suggestions_good_first_and_bad_excluded() {
+        let catalogue = vec![get("turnip"), get("fennel"), get("basil"), get("tomato")];
+        let seasons = HashMap::new();
+        let ranked = rank_suggestions(&get("tomato"), &catalogue, &seasons, None);
+
+        let ids: Vec<&str> = ranked.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            ["basil", "turnip"],
+            "fennel is bad, tomato is the target"
+        );
+        assert!(ranked[0].score > 0);
+        assert_eq!(ranked[1].score, 0);
+    }
+
     #[test]
     fn test_is_compatible_good_pair() {
         let tomato = get("tomato");
@@ -112,4 +367,46 @@ mod tests {
         // Lettuce and radish → compatible (good companions)
         assert!(is_compatible(&lettuce, &radish));
     }
+
+    #[test]
+    fn test_recommendations_exclude_present_and_bad_companions() {
+        let present = vec![get("tomato")];
+        let catalogue = vec![get("tomato"), get("basil"), get("fennel"), get("lettuce")];
+        let seasons: HashMap<String, Vec<Season>> = catalogue
+            .iter()
+            .map(|v| (v.id.clone(), vec![Season::Summer]))
+            .collect();
+        let ranked = rank_recommendations(&present, &catalogue, &seasons, &Season::Summer);
+        let ids: Vec<&str> = ranked.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids[0], "basil");
+        assert_eq!(ranked[0].good_with, vec!["tomato".to_string()]);
+        assert!(!ids.contains(&"tomato"));
+        assert!(!ids.contains(&"fennel"));
+
+        // Out-of-season vegetables are dropped.
+        assert!(rank_recommendations(&present, &catalogue, &seasons, &Season::Winter).is_empty());
+    }
+
+    #[test]
+    fn test_neighbors_preview_surrounds_tomato_with_top_companions() {
+        let catalogue = crate::domain::test_fixtures::get_all_vegetables();
+        let preview = neighbors_preview(&get("tomato"), &catalogue);
+        let at = |r: usize, c: usize| preview.grid[r][c].as_ref();
+
+        assert_eq!(at(1, 1).unwrap().id, "tomato");
+        assert_eq!(at(1, 1).unwrap().role, NeighborRole::Center);
+        // All of tomato's good companions like it back; French rank breaks the tie.
+        let good: Vec<&str> = PREVIEW_GOOD_SLOTS
+            .iter()
+            .map(|&(r, c)| at(r, c).unwrap().id.as_str())
+            .collect();
+        assert_eq!(good, vec!["carrot", "onion", "garlic"]);
+        let bad = at(2, 1).unwrap();
+        assert_eq!((bad.id.as_str(), &bad.role), ("fennel", &NeighborRole::Bad));
+        assert!(at(0, 0).is_none(), "corners stay empty");
+        assert_eq!(
+            preview.score,
+            3 * GOOD_COMPANION_SCORE + BAD_COMPANION_SCORE
+        );
+    }
 }
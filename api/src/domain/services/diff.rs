@@ -0,0 +1,145 @@
+use crate::domain::models::{
+    request::PlanSnapshot,
+    response::{CellChangeKind, CellDiff, DiffCounts, PlanDiffResponse, PlannedCell},
+    Matrix,
+};
+
+/// Id of the plant occupying `grid[row][col]`; `Overflowed` cells resolve through
+/// their anchor. `None` for `Empty` / `Blocked` cells and dangling back-references.
+fn occupant(grid: &Matrix<PlannedCell>, row: usize, col: usize) -> Option<String> {
+    let cell = &grid[row][col];
+    match cell.covered_by() {
+        Some(anchor) => grid
+            .get(anchor.row)
+            .and_then(|r| r.get(anchor.col))
+            .and_then(PlannedCell::id)
+            .map(str::to_owned),
+        None => cell.id().map(str::to_owned),
+    }
+}
+
+fn classify(
+    before: &PlannedCell,
+    after: &PlannedCell,
+    before_id: &Option<String>,
+    after_id: &Option<String>,
+) -> CellChangeKind {
+    match (before_id, after_id) {
+        (None, Some(_)) => CellChangeKind::Added,
+        (Some(_), None) => CellChangeKind::Removed,
+        (Some(a), Some(b)) if a != b => CellChangeKind::Changed,
+        (Some(_), Some(_)) => CellChangeKind::Unchanged,
+        (None, None) if before.is_blocked() != after.is_blocked() => CellChangeKind::Changed,
+        (None, None) => CellChangeKind::Unchanged,
+    }
+}
+
+/// Compares two planned grids cell by cell.
+/// Returns an error when the grids do not have the same dimensions.
+pub fn diff_plans(before: &PlanSnapshot, after: &PlanSnapshot) -> Result<PlanDiffResponse, String> {
+    let rows = before.grid.len();
+    let cols = before.grid.first().map_or(0, Vec::len);
+    let same_shape = after.grid.len() == rows
+        && before.grid.iter().all(|r| r.len() == cols)
+        && after.grid.iter().all(|r| r.len() == cols);
+    if !same_shape {
+        return Err("Both grids must have the same dimensions.".into());
+    }
+
+    let mut counts = DiffCounts::default();
+    let cells = (0..rows)
+        .map(|r| {
+            (0..cols)
+                .map(|c| {
+                    let before_id = occupant(&before.grid, r, c);
+                    let after_id = occupant(&after.grid, r, c);
+                    let change =
+                        classify(&before.grid[r][c], &after.grid[r][c], &before_id, &after_id);
+                    match change {
+                        CellChangeKind::Added => counts.added += 1,
+                        CellChangeKind::Removed => counts.removed += 1,
+                        CellChangeKind::Changed => counts.changed += 1,
+                        CellChangeKind::Unchanged => counts.unchanged += 1,
+                    }
+                    CellDiff {
+                        change,
+                        before: before_id,
+                        after: after_id,
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    Ok(PlanDiffResponse {
+        rows,
+        cols,
+        cells,
+        counts,
+        score_delta: i64::from(after.score) - i64::from(before.score),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::Coordinate;
+
+    fn plant(id: &str) -> PlannedCell {
+        PlannedCell::SelfContained {
+            id: id.into(),
+            name: id.into(),
+            reason: String::new(),
+            plants_per_cell: 1,
+            estimated_harvest_date: chrono::NaiveDate::from_ymd_opt(2025, 8, 1).unwrap(),
+            placed_in_phase: None,
+        }
+    }
+
+    #[test]
+    fn test_overflowed_cells_resolve_to_anchor_id() {
+        let grid = vec![vec![
+            PlannedCell::Overflowing {
+                id: "tomato".into(),
+                name: "Tomato".into(),
+                reason: String::new(),
+                plants_per_cell: 1,
+                width_cells: 2,
+                length_cells: 1,
+                estimated_harvest_date: chrono::NaiveDate::from_ymd_opt(2025, 8, 1).unwrap(),
+                placed_in_phase: None,
+            },
+            PlannedCell::Overflowed {
+                covered_by: Coordinate { row: 0, col: 0 },
+            },
+        ]];
+        assert_eq!(occupant(&grid, 0, 1).as_deref(), Some("tomato"));
+    }
+
+    #[test]
+    fn test_mismatched_dimensions_are_rejected() {
+        let before = PlanSnapshot {
+            grid: vec![vec![plant("basil")]],
+            score: 0,
+        };
+        let after = PlanSnapshot {
+            grid: vec![vec![plant("basil"), PlannedCell::Empty]],
+            score: 0,
+        };
+        assert!(diff_plans(&before, &after).is_err());
+    }
+
+    #[test]
+    fn test_score_delta_does_not_overflow_on_extreme_scores() {
+        let before = PlanSnapshot {
+            grid: vec![vec![PlannedCell::Empty]],
+            score: i32::MIN,
+        };
+        let after = PlanSnapshot {
+            grid: vec![vec![PlannedCell::Empty]],
+            score: i32::MAX,
+        };
+        let diff = diff_plans(&before, &after).unwrap();
+        assert_eq!(diff.score_delta, i64::from(i32::MAX) - i64::from(i32::MIN));
+    }
+}
@@ -1,48 +1,71 @@
+use std::collections::HashMap;
+
 use crate::domain::models::{
     request::PlanParams,
-    variety::{Month, RegionCalendar, Variety},
+    response::CandidateEliminations,
+    variety::{Month, Region, RegionCalendar, SoilType, SunExposure, Variety},
+    warnings::{Language, Warnings},
 };
 
+/// Variety ids by French household consumption, most consumed first: the default
+/// priority of non-preferred candidates. A request's `ranking` overrides it per id.
+pub const FRENCH_RANKING: &[&str] = &[
+    "tomato",
+    "carrot",
+    "leek",
+    "lettuce",
+    "green-bean",
+    "zucchini",
+    "cucumber",
+    "onion",
+    "cabbage",
+    "spinach",
+    "pepper",
+    "red-pepper",
+    "broccoli",
+    "eggplant",
+    "cauliflower",
+    "pea",
+    "beet",
+    "radish",
+    "potato",
+    "garlic",
+    "pumpkin",
+    "celery",
+    "fennel",
+    "turnip",
+    "asparagus",
+    "artichoke",
+    "strawberry",
+    "basil",
+    "parsley",
+    "chive",
+    "mint",
+    "thyme",
+    "rosemary",
+    "maïs",
+];
+
+/// Rank given to ids missing from the ranking table.
+pub const UNRANKED: usize = 999;
+
 /// Returns the French household consumption rank for a variety ID.
 /// Rank 1 = most consumed; unknown IDs get rank 999.
 pub fn french_rank(id: &str) -> usize {
-    match id {
-        "tomato" => 1,
-        "carrot" => 2,
-        "leek" => 3,
-        "lettuce" => 4,
-        "green-bean" => 5,
-        "zucchini" => 6,
-        "cucumber" => 7,
-        "onion" => 8,
-        "cabbage" => 9,
-        "spinach" => 10,
-        "pepper" => 11,
-        "red-pepper" => 12,
-        "broccoli" => 13,
-        "eggplant" => 14,
-        "cauliflower" => 15,
-        "pea" => 16,
-        "beet" => 17,
-        "radish" => 18,
-        "potato" => 19,
-        "garlic" => 20,
-        "pumpkin" => 21,
-        "celery" => 22,
-        "fennel" => 23,
-        "turnip" => 24,
-        "asparagus" => 25,
-        "artichoke" => 26,
-        "strawberry" => 27,
-        "basil" => 28,
-        "parsley" => 29,
-        "chive" => 30,
-        "mint" => 31,
-        "thyme" => 32,
-        "rosemary" => 33,
-        "maïs" => 34,
-        _ => 999,
-    }
+    FRENCH_RANKING
+        .iter()
+        .position(|ranked| *ranked == id)
+        .map_or(UNRANKED, |i| i + 1)
+}
+
+/// Rank of `id` in `ranking`, falling back to [`french_rank`] for ids it omits.
+pub fn popularity_rank(id: &str, ranking: &HashMap<String, usize>) -> usize {
+    ranking.get(id).copied().unwrap_or_else(|| french_rank(id))
+}
+
+/// Canonical ordering of species lists in responses: French consumption rank, then id.
+pub fn species_order(a: &str, b: &str) -> std::cmp::Ordering {
+    french_rank(a).cmp(&french_rank(b)).then_with(|| a.cmp(b))
 }
 
 /// Returns `true` when `month` is an active sowing or planting month
@@ -54,6 +77,28 @@ pub(crate) fn is_active_month(cal: &RegionCalendar, month: Month) -> bool {
         || cal.planting.indoor.contains(&month)
 }
 
+/// Months bordering the season of `month`: the last month of the previous season and
+/// the first month of the next one (e.g. February and June for April).
+pub fn shoulder_months(month: Month) -> [Month; 2] {
+    let m = month.to_u32();
+    // Seasons start in March, June, September and December.
+    let season_start = m - (m + 9) % 3;
+    let wrap = |m: u32| Month::from_u32((m + 11) % 12 + 1);
+    [wrap(season_start + 11), wrap(season_start + 3)]
+}
+
+/// `true` when `variety` is only a candidate for `month` in `region` through
+/// `seasonOverlap`: not active in `month` itself, but in one of its [`shoulder_months`].
+pub fn is_shoulder_candidate(variety: &Variety, region: &Region, month: Month) -> bool {
+    let active = |m: Month| {
+        variety
+            .calendars
+            .iter()
+            .any(|c| &c.region == region && is_active_month(c, m))
+    };
+    !active(month) && shoulder_months(month).into_iter().any(active)
+}
+
 /// Internal helper: filters and sorts candidates, optionally restricting to a given month.
 fn filter_and_sort_internal(
     db: &[Variety],
@@ -68,17 +113,27 @@ fn filter_and_sort_internal(
             // Filter by region and/or month via calendars.
             // sun / soil / level / exclusions are already handled at SQL level.
             match month_filter {
-                Some(month) => v
-                    .calendars
-                    .iter()
-                    .any(|c| c.region == request.region && is_active_month(c, month)),
+                Some(month) => {
+                    v.calendars
+                        .iter()
+                        .any(|c| c.region == request.region && is_active_month(c, month))
+                        || (request.season_overlap
+                            && is_shoulder_candidate(v, &request.region, month))
+                }
                 None => v.calendars.iter().any(|c| c.region == request.region),
             }
         })
+        .filter(|v| {
+            request
+                .available_sun_hours
+                .is_none_or(|hours| v.min_sun_hours <= hours)
+        })
         .cloned()
         .collect();
 
-    // Sort: preferences first (preserving preference order), then by French consumption rank
+    // Sort: preferences first (preserving preference order), then beginner-friendly varieties
+    // when `prefer_beginner` is set, then by the request's ranking (French consumption rank by
+    // default), then by id so that equally-ranked varieties are ordered deterministically.
     filtered.sort_by(|a, b| {
         let a_pos = preferences.iter().position(|p| p.variety.id == a.id);
         let b_pos = preferences.iter().position(|p| p.variety.id == b.id);
@@ -86,13 +141,79 @@ fn filter_and_sort_internal(
             (Some(ai), Some(bi)) => ai.cmp(&bi),
             (Some(_), None) => std::cmp::Ordering::Less,
             (None, Some(_)) => std::cmp::Ordering::Greater,
-            (None, None) => french_rank(&a.id).cmp(&french_rank(&b.id)),
+            (None, None) => (request.prefer_beginner && !a.beginner_friendly)
+                .cmp(&(request.prefer_beginner && !b.beginner_friendly))
+                .then_with(|| {
+                    popularity_rank(&a.id, &request.ranking)
+                        .cmp(&popularity_rank(&b.id, &request.ranking))
+                })
+                .then_with(|| a.id.cmp(&b.id)),
         }
     });
 
     filtered
 }
 
+/// Constraints a planning request narrows the catalogue with, as needed to explain an
+/// empty candidate list.
+pub struct CandidateCriteria<'a> {
+    pub region: &'a Region,
+    pub sun: Option<&'a SunExposure>,
+    pub available_sun_hours: Option<u32>,
+    pub soil: Option<&'a SoilType>,
+    pub beginner_only: bool,
+    pub exclusions: &'a [String],
+    pub season_overlap: bool,
+    /// Month of each planned week.
+    pub months: &'a [Month],
+}
+
+/// Counts, for each constraint of `criteria` on its own, the `catalogue` varieties it
+/// rules out.
+pub fn count_eliminations(
+    catalogue: &[Variety],
+    criteria: &CandidateCriteria,
+) -> CandidateEliminations {
+    let count = |fails: &dyn Fn(&Variety) -> bool| catalogue.iter().filter(|v| fails(v)).count();
+    let in_season = |v: &Variety| {
+        criteria.months.iter().any(|&month| {
+            v.calendars
+                .iter()
+                .any(|c| &c.region == criteria.region && is_active_month(c, month))
+                || (criteria.season_overlap && is_shoulder_candidate(v, criteria.region, month))
+        })
+    };
+    CandidateEliminations {
+        season: count(&|v| !in_season(v)),
+        soil: count(&|v| criteria.soil.is_some_and(|s| !v.soil_types.contains(s))),
+        sun: count(&|v| {
+            criteria.sun.is_some_and(|s| !v.sun_requirement.contains(s))
+                || criteria
+                    .available_sun_hours
+                    .is_some_and(|hours| v.min_sun_hours > hours)
+        }),
+        region: count(&|v| !v.calendars.iter().any(|c| &c.region == criteria.region)),
+        level: count(&|v| criteria.beginner_only && !v.beginner_friendly),
+        excluded: count(&|v| criteria.exclusions.contains(&v.id)),
+    }
+}
+
+impl Warnings {
+    /// Planner warning text when no variety passes the request's constraints.
+    pub fn no_candidates(language: Language, e: &CandidateEliminations) -> String {
+        match language {
+            Language::English => format!(
+                "No vegetables match the given season/soil/sun/region/level constraints; varieties ruled out by season: {}, soil: {}, sun: {}, region: {}, level: {}, exclusions: {}.",
+                e.season, e.soil, e.sun, e.region, e.level, e.excluded
+            ),
+            Language::French => format!(
+                "Aucun légume ne correspond aux contraintes de saison/sol/soleil/région/niveau ; variétés écartées par saison : {}, sol : {}, soleil : {}, région : {}, niveau : {}, exclusions : {}.",
+                e.season, e.soil, e.sun, e.region, e.level, e.excluded
+            ),
+        }
+    }
+}
+
 /// Filters varieties by all request constraints **including** the calendar month
 /// (used to check against per-region sowing/planting windows), then sorts by
 /// priority (preferences first, then French consumption rank).
@@ -111,7 +232,7 @@ pub fn filter_candidates_base(db: &[Variety], request: &PlanParams) -> Vec<Varie
 mod tests {
     use super::*;
     use crate::domain::models::{
-        request::{LayoutCell, Period, PlanParams, Preference},
+        request::{LayoutCell, Period, PlacementOptions, PlanParams, Preference},
         variety::{Month, Region},
     };
     use crate::domain::test_fixtures::{get_all_varieties, get_variety_by_id};
@@ -122,13 +243,25 @@ mod tests {
         PlanParams {
             // 2m × 3m → 7 cols × 10 rows
             layout: vec![vec![LayoutCell::Empty; 7]; 10],
+            blocked_regions: vec![],
+            blocked_cells: vec![],
+            plantable_mask: None,
             period: Some(Period {
                 start,
                 end: start + Duration::days(6),
             }),
             region: Region::Temperate,
+            available_sun_hours: None,
             preferences: vec![],
+            ranking: HashMap::new(),
+            season_overlap: false,
+            prefer_beginner: false,
             sown: vec![],
+            placement: PlacementOptions::default(),
+            suggest_fixes: false,
+            unknown_near: Vec::new(),
+            unknown_preferences: Vec::new(),
+            language: Default::default(),
         }
     }
 
@@ -166,6 +299,7 @@ mod tests {
             preferences: vec![Preference {
                 variety: basil,
                 quantity: None,
+                area_m2: None,
             }],
             ..make_request_for_month(6)
         };
@@ -175,6 +309,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_prefer_beginner_orders_advanced_varieties_last() {
+        let db = get_all_varieties();
+        let req = PlanParams {
+            prefer_beginner: true,
+            ..make_request_for_month(6)
+        };
+        let result = filter_varieties(&db, &req, Month::June);
+        let first_advanced = result
+            .iter()
+            .position(|v| !v.beginner_friendly)
+            .expect("June has advanced candidates");
+        assert!(first_advanced > 0);
+        assert!(result[first_advanced..]
+            .iter()
+            .all(|v| !v.beginner_friendly));
+    }
+
     #[test]
     fn test_filter_by_region() {
         let db = get_all_varieties();
@@ -192,12 +344,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_filter_by_available_sun_hours() {
+        let db = get_all_varieties();
+        let req = PlanParams {
+            available_sun_hours: Some(3),
+            ..make_request_for_month(5)
+        };
+        let result = filter_candidates_base(&db, &req);
+        assert!(
+            !result.iter().any(|v| v.id == "tomato"),
+            "Full-sun tomato must be excluded with 3 sun hours"
+        );
+        assert!(
+            result.iter().any(|v| v.id == "lettuce"),
+            "Shade-tolerant lettuce must be kept with 3 sun hours"
+        );
+        assert!(result.iter().all(|v| v.min_sun_hours <= 3));
+    }
+
     #[test]
     fn test_french_rank_known() {
         assert_eq!(super::french_rank("tomato"), 1);
         assert_eq!(super::french_rank("maïs"), 34);
     }
 
+    #[test]
+    fn test_corn_alias_resolves_to_ranked_canonical_id() {
+        use crate::domain::test_fixtures::get_vegetable_by_id;
+        let corn = get_vegetable_by_id("corn").expect("corn alias must resolve");
+        assert_eq!(corn, get_vegetable_by_id("maïs").unwrap());
+        assert_eq!(super::french_rank(&corn.id), 34);
+        for variety_id in &corn.variety_ids {
+            assert_ne!(super::french_rank(variety_id), 999);
+        }
+    }
+
     #[test]
     fn test_french_rank_unknown() {
         assert_eq!(super::french_rank("dragon"), 999);
@@ -219,4 +401,128 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_ranking_override_reorders_candidates() {
+        let db = get_all_varieties();
+        let position = |result: &[Variety], id: &str| result.iter().position(|v| v.id == id);
+        let default = filter_varieties(&db, &make_request_for_month(6), Month::June);
+        assert!(position(&default, "tomato") < position(&default, "basil"));
+
+        // Ranking basil first moves it ahead of tomato; unlisted ids keep the French rank.
+        let req = PlanParams {
+            ranking: HashMap::from([("basil".to_owned(), 0)]),
+            ..make_request_for_month(6)
+        };
+        let result = filter_varieties(&db, &req, Month::June);
+        assert_eq!(result[0].id, "basil");
+        assert!(position(&result, "tomato") < position(&result, "carrot"));
+    }
+
+    #[test]
+    fn test_shoulder_months_border_the_season() {
+        assert_eq!(
+            shoulder_months(Month::April),
+            [Month::February, Month::June]
+        );
+        assert_eq!(
+            shoulder_months(Month::March),
+            [Month::February, Month::June]
+        );
+        assert_eq!(
+            shoulder_months(Month::January),
+            [Month::November, Month::March]
+        );
+        assert_eq!(
+            shoulder_months(Month::December),
+            [Month::November, Month::March]
+        );
+    }
+
+    #[test]
+    fn test_season_overlap_includes_early_summer_variety_in_spring() {
+        // A variety sown outdoors only in June is out of season in May...
+        let mut early_summer = get_variety_by_id("zucchini").unwrap();
+        for cal in &mut early_summer.calendars {
+            cal.sowing.outdoor = vec![Month::June];
+            cal.sowing.indoor.clear();
+            cal.planting.outdoor.clear();
+            cal.planting.indoor.clear();
+        }
+        let db = vec![early_summer];
+        let req = make_request_for_month(5);
+        assert!(filter_varieties(&db, &req, Month::May).is_empty());
+
+        // ...but a shoulder candidate with `season_overlap`.
+        let req = PlanParams {
+            season_overlap: true,
+            ..req
+        };
+        let result = filter_varieties(&db, &req, Month::May);
+        assert_eq!(result.len(), 1);
+        assert!(is_shoulder_candidate(
+            &result[0],
+            &Region::Temperate,
+            Month::May
+        ));
+        // Shoulder months only reach the bordering seasons.
+        assert!(filter_varieties(&db, &req, Month::January).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_rank_ties_broken_by_id() {
+        // Two varieties with rank 999 are ordered by id regardless of input order.
+        let tomato = get_variety_by_id("tomato").unwrap();
+        let zeta = Variety {
+            id: "zeta-unknown".into(),
+            ..tomato.clone()
+        };
+        let alpha = Variety {
+            id: "alpha-unknown".into(),
+            ..tomato
+        };
+        let req = make_request_for_month(6);
+        for db in [
+            vec![zeta.clone(), alpha.clone()],
+            vec![alpha.clone(), zeta.clone()],
+        ] {
+            let ids: Vec<String> = filter_varieties(&db, &req, Month::June)
+                .into_iter()
+                .map(|v| v.id)
+                .collect();
+            assert_eq!(ids, vec!["alpha-unknown", "zeta-unknown"]);
+        }
+    }
+
+    #[test]
+    fn test_count_eliminations_counts_each_constraint_on_its_own() {
+        let db = get_all_varieties();
+        let exclusions = vec!["tomato".to_string()];
+        let criteria = CandidateCriteria {
+            region: &Region::Temperate,
+            sun: None,
+            available_sun_hours: Some(0),
+            soil: None,
+            beginner_only: true,
+            exclusions: &exclusions,
+            season_overlap: false,
+            months: &[Month::June],
+        };
+        let counts = count_eliminations(&db, &criteria);
+
+        assert_eq!(
+            counts.sun,
+            db.iter().filter(|v| v.min_sun_hours > 0).count()
+        );
+        assert_eq!(
+            counts.level,
+            db.iter().filter(|v| !v.beginner_friendly).count()
+        );
+        assert_eq!(counts.excluded, 1);
+        assert_eq!(counts.soil, 0);
+        assert_eq!(
+            counts.season,
+            db.len() - filter_varieties(&db, &make_request_for_month(6), Month::June).len()
+        );
+    }
 }
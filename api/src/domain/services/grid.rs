@@ -1,41 +1,198 @@
 use chrono::{Duration, NaiveDate};
 
 use crate::domain::models::{
-    garden::GardenGrid, request::LayoutCell, variety::Region, warnings::Warnings, Coordinate,
+    error::{PlanError, MAX_GRID_CELLS},
+    garden::GardenGrid,
+    request::{BlockedRegion, LayoutCell},
+    response::LayoutValidationResponse,
+    variety::Region,
+    warnings::{Language, Warnings},
+    Coordinate,
 };
 use crate::domain::services::helpers::{
     adjusted_days_to_harvest, cell_span, infer_planted_date, plants_per_cell,
 };
 
 /// Grid dimensions returned by layout validation: `(rows, cols)`.
+#[derive(Debug, PartialEq)]
 pub struct GridSize(pub usize, pub usize);
 
-/// Grid occupancy counts returned by the occupancy check: `(occupied, blocked)`.
-pub struct GridOccupancy(pub usize, pub usize);
+/// Grid occupancy counts returned by the occupancy check: `(occupied, blocked, reserved)`.
+#[derive(Clone, Copy)]
+pub struct GridOccupancy(pub usize, pub usize, pub usize);
+
+impl GridOccupancy {
+    /// Cells that can take a plant now or already hold one: neither blocked nor reserved.
+    pub fn plantable(&self, grid: &GardenGrid) -> usize {
+        let GridOccupancy(_, blocked, reserved) = *self;
+        grid.rows * grid.cols - blocked - reserved
+    }
+}
 
 /// A deferred continuation cell and its anchor coordinate: `(position, anchor)`.
 struct DeferredCell(Coordinate, Coordinate);
 
-/// Validates that the layout has at least one non-empty row.
-/// Returns `GridSize(rows, cols)` on success.
-pub fn validate_layout(layout: &[Vec<LayoutCell>]) -> Result<GridSize, String> {
+/// A pre-placed multi-cell plant's footprint: `(anchor, width, length)` in cells.
+struct Footprint(Coordinate, usize, usize);
+
+impl Warnings {
+    /// Adds grid warning for a blocked region reaching past the grid edge.
+    fn add_blocked_region_clamped(&mut self, region: &BlockedRegion, width: usize, height: usize) {
+        let BlockedRegion {
+            top_left: Coordinate { row, col },
+            width: w,
+            height: h,
+        } = region;
+        let outside = width == 0 || height == 0;
+        self.add(match (self.language(), outside) {
+            (Language::English, true) => format!(
+                "Blocked region at [{row},{col}] ({w}x{h}) lies outside the grid, ignored."
+            ),
+            (Language::English, false) => format!(
+                "Blocked region at [{row},{col}] ({w}x{h}) extends beyond the grid, clamped to {width}x{height}."
+            ),
+            (Language::French, true) => format!(
+                "La zone bloquée en [{row},{col}] ({w}x{h}) est hors de la grille, ignorée."
+            ),
+            (Language::French, false) => format!(
+                "La zone bloquée en [{row},{col}] ({w}x{h}) dépasse de la grille, ramenée à {width}x{height}."
+            ),
+        });
+    }
+
+    /// Adds grid warning for a pre-placed plant whose footprint could not be fully marked.
+    fn add_footprint_clipped(&mut self, id: &str, anchor: Coordinate, lost: usize) {
+        let Coordinate { row, col } = anchor;
+        self.add(match self.language() {
+            Language::English => format!(
+                "Pre-placed '{id}' at [{row},{col}] overlaps {lost} cell(s) that are off the grid, blocked or taken; its footprint was clipped."
+            ),
+            Language::French => format!(
+                "'{id}' déjà en place en [{row},{col}] déborde sur {lost} case(s) hors de la grille, bloquée(s) ou occupée(s) ; son emprise a été réduite."
+            ),
+        });
+    }
+
+    /// Adds grid warning for pre-placed plants left in place inside a blocked region.
+    fn add_blocked_region_over_plants(&mut self, region: &BlockedRegion, occupied: usize) {
+        let Coordinate { row, col } = region.top_left;
+        self.add(match self.language() {
+            Language::English => format!(
+                "Blocked region at [{row},{col}] covers {occupied} pre-placed cell(s), which were kept."
+            ),
+            Language::French => format!(
+                "La zone bloquée en [{row},{col}] couvre {occupied} case(s) déjà plantée(s), conservée(s)."
+            ),
+        });
+    }
+
+    /// Adds grid warning for a blocked cell outside the grid.
+    fn add_blocked_cell_out_of_bounds(&mut self, cell: Coordinate) {
+        let Coordinate { row, col } = cell;
+        self.add(match self.language() {
+            Language::English => {
+                format!("Blocked cell [{row},{col}] lies outside the grid, ignored.")
+            }
+            Language::French => {
+                format!("La case bloquée [{row},{col}] est hors de la grille, ignorée.")
+            }
+        });
+    }
+
+    /// Adds grid warning for a blocked cell holding a pre-placed plant.
+    fn add_blocked_cell_over_plant(&mut self, cell: Coordinate) {
+        let Coordinate { row, col } = cell;
+        self.add(match self.language() {
+            Language::English => {
+                format!("Blocked cell [{row},{col}] holds a pre-placed plant, which was kept.")
+            }
+            Language::French => {
+                format!(
+                    "La case bloquée [{row},{col}] contient une plante déjà en place, conservée."
+                )
+            }
+        });
+    }
+
+    /// Adds grid warning for pre-placed plants left in place outside the plantable mask.
+    fn add_mask_over_plants(&mut self, occupied: usize) {
+        self.add(match self.language() {
+            Language::English => {
+                format!("Plantable mask excludes {occupied} pre-placed cell(s), which were kept.")
+            }
+            Language::French => format!(
+                "Le masque plantable exclut {occupied} case(s) déjà plantée(s), conservée(s)."
+            ),
+        });
+    }
+
+    /// Adds grid warning for a continuation cell whose anchor holds no plant.
+    fn add_unplanted_anchor(&mut self, pos: Coordinate, anchor: Coordinate) {
+        let (Coordinate { row, col }, Coordinate { row: ar, col: ac }) = (pos, anchor);
+        self.add(match self.language() {
+            Language::English => format!(
+                "Continuation cell [{row},{col}] references an unplanted anchor [{ar},{ac}], skipped."
+            ),
+            Language::French => format!(
+                "La case de continuation [{row},{col}] renvoie à une ancre non plantée [{ar},{ac}], ignorée."
+            ),
+        });
+    }
+
+    /// Adds grid warning for a continuation cell whose anchor is off the grid.
+    fn add_out_of_bounds_anchor(&mut self, pos: Coordinate, anchor: Coordinate) {
+        let (Coordinate { row, col }, Coordinate { row: ar, col: ac }) = (pos, anchor);
+        self.add(match self.language() {
+            Language::English => format!(
+                "Continuation cell [{row},{col}] references out-of-bounds anchor [{ar},{ac}], skipped."
+            ),
+            Language::French => format!(
+                "La case de continuation [{row},{col}] renvoie à une ancre hors de la grille [{ar},{ac}], ignorée."
+            ),
+        });
+    }
+}
+
+/// Validates that the layout has at least one non-empty row, rows of equal length and at most
+/// [`MAX_GRID_CELLS`] cells. Returns `GridSize(rows, cols)` on success.
+pub fn validate_layout(layout: &[Vec<LayoutCell>]) -> Result<GridSize, PlanError> {
     if layout.is_empty() {
-        return Err("Layout must contain at least one row.".into());
+        return Err(PlanError::EmptyLayout);
     }
-    let cols = layout[0].len();
+    let (rows, cols) = (layout.len(), layout[0].len());
     if cols == 0 {
-        return Err("Layout rows must not be empty.".into());
+        return Err(PlanError::EmptyRow);
+    }
+    if let Some((row, r)) = layout.iter().enumerate().find(|(_, r)| r.len() != cols) {
+        return Err(PlanError::RaggedRow {
+            row,
+            len: r.len(),
+            expected: cols,
+        });
+    }
+    if rows * cols > MAX_GRID_CELLS {
+        return Err(PlanError::GridTooLarge { rows, cols });
+    }
+    Ok(GridSize(rows, cols))
+}
+
+/// Validates that a fill target is a percentage.
+pub fn validate_fill_target(target_fill_pct: Option<u8>) -> Result<(), PlanError> {
+    match target_fill_pct {
+        Some(pct) if pct > 100 => Err(PlanError::InvalidFillTarget(pct)),
+        _ => Ok(()),
     }
-    Ok(GridSize(layout.len(), cols))
 }
 
 /// Creates a blank grid and pre-fills it from the unified layout array:
-/// blocked zones (`true`) and pre-placed varieties (enriched `Variety` objects).
+/// blocked zones (`true`) and pre-placed varieties (enriched `Variety` objects),
+/// then blocks the cells of `blocked_regions`.
 /// Returns the grid and any warnings produced (e.g. out-of-bounds continuation cells).
 pub fn initialize_grid(
     rows: usize,
     cols: usize,
     layout: &[Vec<LayoutCell>],
+    blocked_regions: &[BlockedRegion],
     planning_start: NaiveDate,
     region: &Region,
     warnings: &mut Warnings,
@@ -43,6 +200,8 @@ pub fn initialize_grid(
     let mut grid = GardenGrid::new(rows, cols);
     // Continuation cells are collected here and resolved after all anchors are placed.
     let mut deferred: Vec<DeferredCell> = Vec::new();
+    // Overflowing anchors whose whole block is marked once every cell has been read.
+    let mut footprints: Vec<Footprint> = Vec::new();
 
     for (r, row) in layout.iter().enumerate() {
         for (c, cell) in row.iter().enumerate() {
@@ -50,6 +209,9 @@ pub fn initialize_grid(
                 LayoutCell::Blocked => {
                     grid.cells[r][c].blocked = true;
                 }
+                LayoutCell::Reserved => {
+                    grid.cells[r][c].reserved = true;
+                }
                 LayoutCell::SelfContained {
                     variety,
                     plants_per_cell: ppc_input,
@@ -76,6 +238,8 @@ pub fn initialize_grid(
                         estimated_harvest_date: effective_date
                             + Duration::days(adjusted_days as i64),
                         lifecycle: variety.lifecycle.clone(),
+                        category: variety.category.clone(),
+                        placed_in_phase: crate::domain::models::garden::PHASE_PRE_PLACED,
                     });
                 }
                 LayoutCell::Overflowed { covered_by } => {
@@ -105,20 +269,60 @@ pub fn initialize_grid(
                         name: variety.name.clone(),
                         reason: "Present in the existing layout.".into(),
                         plants_per_cell: ppc,
-                        span: w.max(l),
+                        // Clipped to the grid like the footprint, so neighbour walks
+                        // stay bounded whatever size the layout claims.
+                        span: w.max(l).min(rows.max(cols) as u32),
                         anchor: Coordinate { row: r, col: c },
                         planted_week: 0,
                         days_to_harvest: adjusted_days,
                         estimated_harvest_date: effective_date
                             + Duration::days(adjusted_days as i64),
                         lifecycle: variety.lifecycle.clone(),
+                        category: variety.category.clone(),
+                        placed_in_phase: crate::domain::models::garden::PHASE_PRE_PLACED,
                     });
+                    footprints.push(Footprint(
+                        Coordinate { row: r, col: c },
+                        w as usize,
+                        l as usize,
+                    ));
                 }
                 LayoutCell::Empty => {}
             }
         }
     }
 
+    // Cover the block of each multi-cell anchor, so its real footprint takes part in
+    // neighbour scoring even when the layout omits the `Overflowed` cells.
+    for Footprint(anchor, width, length) in footprints {
+        let Some(placed) = grid.cells[anchor.row][anchor.col].variety.clone() else {
+            continue;
+        };
+        // Only the on-grid part of the block is walked: the client's width and length
+        // are unbounded, so the off-grid remainder is counted without visiting it.
+        let row_end = anchor.row.saturating_add(length).min(grid.rows);
+        let col_end = anchor.col.saturating_add(width).min(grid.cols);
+        let on_grid = (row_end - anchor.row) * (col_end - anchor.col);
+        let mut lost = width.saturating_mul(length) - on_grid;
+        for r in anchor.row..row_end {
+            for c in anchor.col..col_end {
+                let cell = &mut grid.cells[r][c];
+                if cell.blocked || cell.reserved {
+                    lost += 1;
+                    continue;
+                }
+                match &cell.variety {
+                    None => cell.variety = Some(placed.clone()),
+                    Some(v) if v.anchor == anchor => {}
+                    Some(_) => lost += 1,
+                }
+            }
+        }
+        if lost > 0 {
+            warnings.add_footprint_clipped(&placed.id, anchor, lost);
+        }
+    }
+
     // Resolve continuation cells now that all anchors are in the grid.
     for DeferredCell(pos, covered_by) in deferred {
         let Coordinate {
@@ -133,26 +337,158 @@ pub fn initialize_grid(
             if let Some(anchor_veg) = grid.cells[covered_by_row][covered_by_col].variety.clone() {
                 grid.cells[position_row][position_col].variety = Some(anchor_veg);
             } else {
-                warnings.add(format!(
-                    "Continuation cell [{position_row},{position_col}] references an unplanted anchor [{covered_by_row},{covered_by_col}], skipped."
-                ));
+                warnings.add_unplanted_anchor(pos, covered_by);
             }
         } else {
-            warnings.add(format!(
-                "Continuation cell [{position_row},{position_col}] references out-of-bounds anchor [{covered_by_row},{covered_by_col}], skipped."
-            ));
+            warnings.add_out_of_bounds_anchor(pos, covered_by);
         }
     }
 
+    apply_blocked_regions(&mut grid, blocked_regions, warnings);
     grid
 }
 
-/// Returns `GridOccupancy(occupied, blocked)` cell counts for the given grid.
+/// Marks every cell of each region blocked. Regions reaching past the grid edge are
+/// clamped (or ignored when entirely outside) with a warning; cells holding a
+/// pre-placed plant are left as they are.
+fn apply_blocked_regions(
+    grid: &mut GardenGrid,
+    regions: &[BlockedRegion],
+    warnings: &mut Warnings,
+) {
+    for region in regions {
+        let Coordinate { row, col } = region.top_left;
+        let height = region.height.min(grid.rows.saturating_sub(row));
+        let width = region.width.min(grid.cols.saturating_sub(col));
+        if (width, height) != (region.width, region.height) {
+            warnings.add_blocked_region_clamped(region, width, height);
+        }
+        let mut occupied = 0;
+        for r in row..row + height {
+            for c in col..col + width {
+                let cell = &mut grid.cells[r][c];
+                if cell.variety.is_some() {
+                    occupied += 1;
+                } else {
+                    cell.blocked = true;
+                }
+            }
+        }
+        if occupied > 0 {
+            warnings.add_blocked_region_over_plants(region, occupied);
+        }
+    }
+}
+
+/// Blocks each of `cells`. Cells outside the grid are ignored and cells holding a
+/// pre-placed plant left as they are, each with a warning.
+pub fn apply_blocked_cells(grid: &mut GardenGrid, cells: &[Coordinate], warnings: &mut Warnings) {
+    for &coordinate in cells {
+        match grid
+            .cells
+            .get_mut(coordinate.row)
+            .and_then(|row| row.get_mut(coordinate.col))
+        {
+            None => warnings.add_blocked_cell_out_of_bounds(coordinate),
+            Some(cell) if cell.variety.is_some() => {
+                warnings.add_blocked_cell_over_plant(coordinate)
+            }
+            Some(cell) => cell.blocked = true,
+        }
+    }
+}
+
+/// Blocks every cell whose `mask` entry is `false`. Cells holding a pre-placed plant are
+/// left as they are; the mask is expected to match the grid's dimensions.
+pub fn apply_plantable_mask(grid: &mut GardenGrid, mask: &[Vec<bool>], warnings: &mut Warnings) {
+    let mut occupied = 0;
+    for (row, mask_row) in grid.cells.iter_mut().zip(mask) {
+        for (cell, _) in row
+            .iter_mut()
+            .zip(mask_row)
+            .filter(|(_, plantable)| !**plantable)
+        {
+            if cell.variety.is_some() {
+                occupied += 1;
+            } else {
+                cell.blocked = true;
+            }
+        }
+    }
+    if occupied > 0 {
+        warnings.add_mask_over_plants(occupied);
+    }
+}
+
+/// Checks `layout` and lays it out on a grid without planning anything, reporting its
+/// dimensions, cell counts and the warnings raised while reading it. A malformed layout
+/// yields its error and zero counts.
+pub fn dry_run_layout(
+    layout: &[Vec<LayoutCell>],
+    blocked_regions: &[BlockedRegion],
+    planning_start: NaiveDate,
+    region: &Region,
+) -> LayoutValidationResponse {
+    let (rows, cols) = (layout.len(), layout.first().map_or(0, Vec::len));
+    let mut report = LayoutValidationResponse {
+        rows,
+        cols,
+        plantable_cells: 0,
+        pre_placed: 0,
+        blocked: 0,
+        reserved: 0,
+        warnings: Vec::new(),
+        errors: Vec::new(),
+    };
+    if let Err(e) = validate_layout(layout) {
+        report.errors.push(e.to_string());
+        return report;
+    }
+    let mut warnings = Warnings::new();
+    let grid = initialize_grid(
+        rows,
+        cols,
+        layout,
+        blocked_regions,
+        planning_start,
+        region,
+        &mut warnings,
+    );
+    let occupancy = count_grid_occupancy(&grid);
+    report.plantable_cells = occupancy.plantable(&grid);
+    report.blocked = occupancy.1;
+    report.reserved = occupancy.2;
+    report.pre_placed = grid
+        .cells
+        .iter()
+        .enumerate()
+        .flat_map(|(r, row)| row.iter().enumerate().map(move |(c, cell)| (r, c, cell)))
+        .filter(|(r, c, cell)| {
+            cell.variety
+                .as_ref()
+                .is_some_and(|v| v.anchor == Coordinate { row: *r, col: *c })
+        })
+        .count();
+    report.warnings = warnings.into_vec();
+    report
+}
+
+/// Returns `GridOccupancy(occupied, blocked, reserved)` cell counts for the given grid.
+/// A cell both blocked and reserved counts as blocked.
 pub fn count_grid_occupancy(grid: &GardenGrid) -> GridOccupancy {
     let flat = || grid.cells.iter().flat_map(|r| r.iter());
     let occupied = flat().filter(|c| c.variety.is_some()).count();
     let blocked = flat().filter(|c| c.blocked).count();
-    GridOccupancy(occupied, blocked)
+    let reserved = flat().filter(|c| c.reserved && !c.blocked).count();
+    GridOccupancy(occupied, blocked, reserved)
+}
+
+/// Number of occupied cells the fill phase aims for given a `target_fill_pct`,
+/// rounded up; `None` when every plantable cell should be filled.
+pub fn fill_target(grid: &GardenGrid, target_fill_pct: Option<u8>) -> Option<usize> {
+    let pct = target_fill_pct?;
+    let plantable = count_grid_occupancy(grid).plantable(grid);
+    Some((plantable * pct as usize).div_ceil(100))
 }
 
 #[cfg(test)]
@@ -162,7 +498,92 @@ mod tests {
     #[test]
     fn test_invalid_zero_width_returns_error() {
         let layout: Vec<Vec<LayoutCell>> = vec![];
-        let result = validate_layout(&layout);
-        assert!(result.is_err());
+        assert_eq!(validate_layout(&layout), Err(PlanError::EmptyLayout));
+        assert_eq!(validate_layout(&[vec![]]), Err(PlanError::EmptyRow));
+    }
+
+    #[test]
+    fn test_ragged_layout_returns_error() {
+        let layout = vec![vec![LayoutCell::Empty; 3], vec![LayoutCell::Empty; 4]];
+        assert_eq!(
+            validate_layout(&layout),
+            Err(PlanError::RaggedRow {
+                row: 1,
+                len: 4,
+                expected: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_oversized_grid_returns_error() {
+        let layout = vec![vec![LayoutCell::Empty; 101]; 100];
+        assert_eq!(
+            validate_layout(&layout),
+            Err(PlanError::GridTooLarge {
+                rows: 100,
+                cols: 101
+            })
+        );
+    }
+
+    #[test]
+    fn test_blocked_region_past_the_edge_is_clamped() {
+        let mut grid = GardenGrid::new(3, 3);
+        let mut warnings = Warnings::new();
+        let region = BlockedRegion {
+            top_left: Coordinate { row: 1, col: 2 },
+            width: 4,
+            height: 1,
+        };
+        apply_blocked_regions(&mut grid, &[region], &mut warnings);
+
+        let blocked: Vec<(usize, usize)> = (0..3)
+            .flat_map(|r| (0..3).map(move |c| (r, c)))
+            .filter(|&(r, c)| grid.cells[r][c].blocked)
+            .collect();
+        assert_eq!(blocked, vec![(1, 2)]);
+        assert_eq!(
+            warnings.as_slice(),
+            ["Blocked region at [1,2] (4x1) extends beyond the grid, clamped to 1x1."]
+        );
+    }
+
+    #[test]
+    fn test_huge_pre_placed_footprint_is_clipped_to_the_grid() {
+        use crate::domain::test_fixtures::get_variety_by_id;
+
+        let mut layout = vec![vec![LayoutCell::Empty; 3]; 2];
+        layout[1][1] = LayoutCell::Overflowing {
+            variety: get_variety_by_id("pumpkin").unwrap(),
+            plants_per_cell: None,
+            width_cells: Some(u32::MAX),
+            length_cells: Some(u32::MAX),
+            planted_date: None,
+        };
+        let mut warnings = Warnings::new();
+        let grid = initialize_grid(
+            2,
+            3,
+            &layout,
+            &[],
+            NaiveDate::from_ymd_opt(2025, 6, 2).unwrap(),
+            &Region::Temperate,
+            &mut warnings,
+        );
+
+        let covered: Vec<(usize, usize)> = (0..2)
+            .flat_map(|r| (0..3).map(move |c| (r, c)))
+            .filter(|&(r, c)| grid.cells[r][c].variety.is_some())
+            .collect();
+        assert_eq!(covered, vec![(1, 1), (1, 2)]);
+        assert_eq!(grid.cells[1][1].variety.as_ref().unwrap().span, 3);
+        let lost = (u32::MAX as usize) * (u32::MAX as usize) - 2;
+        assert_eq!(
+            warnings.as_slice(),
+            [format!(
+                "Pre-placed 'pumpkin' at [1,1] overlaps {lost} cell(s) that are off the grid, blocked or taken; its footprint was clipped."
+            )]
+        );
     }
 }
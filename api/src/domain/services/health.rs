@@ -0,0 +1,264 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::domain::models::{
+    garden::{GardenGrid, PlacedVariety},
+    response::{Adjacency, AdjacencyKind, PlanHealth},
+    variety::Variety,
+};
+use crate::domain::services::companion::{
+    companion_score, BAD_COMPANION_SCORE, GOOD_COMPANION_SCORE,
+};
+
+/// Distinct species a garden needs for a full diversity sub-score (fewer when it holds
+/// fewer plants).
+const DIVERSITY_TARGET_SPECIES: usize = 6;
+
+/// Weights of the companion, diversity, fill and beginner sub-scores in `overall`.
+const HEALTH_WEIGHTS: [f32; 4] = [0.4, 0.2, 0.25, 0.15];
+
+/// Lowest `overall` earning each grade, best first; anything lower is `E`.
+const GRADE_THRESHOLDS: [(u8, &str); 4] = [(85, "A"), (70, "B"), (55, "C"), (40, "D")];
+
+/// `part / whole` as a 0–100 score, `0` when `whole` is zero.
+fn percent(part: usize, whole: usize) -> u8 {
+    if whole == 0 {
+        0
+    } else {
+        (100 * part.min(whole) as u64 / whole as u64) as u8
+    }
+}
+
+/// Orthogonally adjacent pairs of distinct plants, each pair once.
+fn neighbouring_pairs(grid: &GardenGrid) -> Vec<(&PlacedVariety, &PlacedVariety)> {
+    let mut seen = HashSet::new();
+    let mut pairs = Vec::new();
+    for (r, row) in grid.cells.iter().enumerate() {
+        for (c, cell) in row.iter().enumerate() {
+            let Some(a) = &cell.variety else { continue };
+            let right = grid.cells[r].get(c + 1);
+            let below = grid.cells.get(r + 1).map(|row| &row[c]);
+            for b in [right, below].into_iter().flatten() {
+                let Some(b) = &b.variety else { continue };
+                let key = ((a.anchor.row, a.anchor.col), (b.anchor.row, b.anchor.col));
+                let reversed = (key.1, key.0);
+                if a.anchor != b.anchor && !seen.contains(&reversed) && seen.insert(key) {
+                    pairs.push((a, b));
+                }
+            }
+        }
+    }
+    pairs
+}
+
+/// Sign of how much `a` and `b` help each other: positive when either counts the
+/// other as a good companion more than as a bad one.
+fn pair_balance(a: &PlacedVariety, b: &PlacedVariety, varieties: &HashMap<&str, &Variety>) -> i32 {
+    let score = |from: &PlacedVariety, to: &PlacedVariety| {
+        varieties.get(from.id.as_str()).map_or(0, |v| {
+            companion_score(&v.vegetable, &[to.vegetable_id.as_str()])
+        })
+    };
+    (score(a, b) + score(b, a)).signum()
+}
+
+/// `50 + 50 × (good − bad) / pairs`, where a pair is good (or bad) when either plant
+/// counts the other as a good (or bad) companion; `50` without neighbours.
+fn companion_quality(grid: &GardenGrid, varieties: &HashMap<&str, &Variety>) -> u8 {
+    let pairs = neighbouring_pairs(grid);
+    if pairs.is_empty() {
+        return 50;
+    }
+    let balance: i64 = pairs
+        .iter()
+        .map(|(a, b)| i64::from(pair_balance(a, b, varieties)))
+        .sum();
+    (50 + 50 * balance / pairs.len() as i64) as u8
+}
+
+/// Companion score of the whole garden, independent of placement order: every border
+/// between two cells of different plants counts once, `GOOD_COMPANION_SCORE` when the
+/// plants are good companions and `BAD_COMPANION_SCORE` when they are bad ones (see
+/// [`pair_balance`]). A plant spanning several cells touches its neighbour once per
+/// shared cell border.
+pub fn grid_companion_score(grid: &GardenGrid, varieties: &HashMap<&str, &Variety>) -> i32 {
+    let mut score = 0;
+    for (r, row) in grid.cells.iter().enumerate() {
+        for (c, cell) in row.iter().enumerate() {
+            let Some(a) = &cell.variety else { continue };
+            let right = grid.cells[r].get(c + 1);
+            let below = grid.cells.get(r + 1).map(|row| &row[c]);
+            for b in [right, below].into_iter().flatten() {
+                let Some(b) = &b.variety else { continue };
+                if a.anchor == b.anchor {
+                    continue;
+                }
+                score += match pair_balance(a, b, varieties) {
+                    1 => GOOD_COMPANION_SCORE,
+                    -1 => BAD_COMPANION_SCORE,
+                    _ => 0,
+                };
+            }
+        }
+    }
+    score
+}
+
+/// Good and bad companion edges between neighbouring plants of `grid`, anchor to
+/// anchor, each pair once in row-major order of its first cell; neutral pairs are
+/// skipped.
+pub fn companion_adjacencies(
+    grid: &GardenGrid,
+    varieties: &HashMap<&str, &Variety>,
+) -> Vec<Adjacency> {
+    neighbouring_pairs(grid)
+        .into_iter()
+        .filter_map(|(a, b)| {
+            let kind = match pair_balance(a, b, varieties) {
+                1 => AdjacencyKind::Good,
+                -1 => AdjacencyKind::Bad,
+                _ => return None,
+            };
+            Some(Adjacency {
+                from: a.anchor,
+                to: b.anchor,
+                kind,
+            })
+        })
+        .collect()
+}
+
+/// Health sub-scores of `grid`; `varieties` resolves placed variety ids to their
+/// companions and `beginner_friendly` flag.
+pub fn plan_health(grid: &GardenGrid, varieties: &HashMap<&str, &Variety>) -> PlanHealth {
+    let plants = grid.anchored_plants();
+    let species: HashSet<&str> = plants.iter().map(|p| p.vegetable_id.as_str()).collect();
+    let occupied = grid.cells.iter().flatten().filter(|c| c.variety.is_some());
+    let plantable = grid
+        .cells
+        .iter()
+        .flatten()
+        .filter(|c| !c.blocked && !c.reserved)
+        .count();
+    let beginner = plants
+        .iter()
+        .filter(|p| {
+            varieties
+                .get(p.id.as_str())
+                .is_some_and(|v| v.beginner_friendly)
+        })
+        .count();
+
+    let companion = companion_quality(grid, varieties);
+    let diversity = percent(species.len(), plants.len().min(DIVERSITY_TARGET_SPECIES));
+    let fill = percent(occupied.count(), plantable);
+    let beginner = percent(beginner, plants.len());
+    let overall = [companion, diversity, fill, beginner]
+        .iter()
+        .zip(HEALTH_WEIGHTS)
+        .map(|(score, weight)| f32::from(*score) * weight)
+        .sum::<f32>()
+        .round() as u8;
+    let grade = GRADE_THRESHOLDS
+        .iter()
+        .find(|(min, _)| overall >= *min)
+        .map_or("E", |(_, grade)| grade);
+    PlanHealth {
+        companion,
+        diversity,
+        fill,
+        beginner,
+        overall,
+        grade: grade.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{garden::PHASE_FILL, Coordinate};
+    use crate::domain::test_fixtures::get_variety_by_id;
+
+    fn placed(variety: &Variety, anchor: Coordinate) -> PlacedVariety {
+        PlacedVariety {
+            id: variety.id.clone(),
+            vegetable_id: variety.vegetable.id.clone(),
+            name: variety.name.clone(),
+            reason: "Test".into(),
+            plants_per_cell: 1,
+            span: 1,
+            anchor,
+            planted_week: 0,
+            days_to_harvest: variety.days_to_harvest,
+            estimated_harvest_date: chrono::NaiveDate::from_ymd_opt(2025, 6, 8).unwrap(),
+            lifecycle: variety.lifecycle.clone(),
+            category: variety.category.clone(),
+            placed_in_phase: PHASE_FILL,
+        }
+    }
+
+    #[test]
+    fn test_health_scores_good_and_bad_neighbours() {
+        let [tomato, basil, fennel] =
+            ["tomato", "basil", "fennel"].map(|id| get_variety_by_id(id).unwrap());
+        let varieties: HashMap<&str, &Variety> = [&tomato, &basil, &fennel]
+            .into_iter()
+            .map(|v| (v.id.as_str(), v))
+            .collect();
+        // Tomato between basil (good) and fennel (bad); the last cell is blocked.
+        let mut grid = GardenGrid::new(1, 4);
+        for (c, variety) in [&basil, &tomato, &fennel].into_iter().enumerate() {
+            grid.cells[0][c].variety = Some(placed(variety, Coordinate { row: 0, col: c }));
+        }
+        grid.cells[0][3].blocked = true;
+
+        let health = plan_health(&grid, &varieties);
+        assert_eq!(health.companion, 50, "one good and one bad pair cancel out");
+        assert_eq!(health.diversity, 100);
+        assert_eq!(health.fill, 100);
+
+        grid.cells[0][2].variety = None;
+        let health = plan_health(&grid, &varieties);
+        assert_eq!(health.companion, 100);
+        assert_eq!(health.fill, 66);
+    }
+
+    #[test]
+    fn test_tomato_basil_adjacency_is_one_good_edge() {
+        let [tomato, basil, fennel] =
+            ["tomato", "basil", "fennel"].map(|id| get_variety_by_id(id).unwrap());
+        let varieties: HashMap<&str, &Variety> = [&tomato, &basil, &fennel]
+            .into_iter()
+            .map(|v| (v.id.as_str(), v))
+            .collect();
+        // A 2×2 tomato with basil to its right on both of its rows, fennel below.
+        let mut grid = GardenGrid::new(3, 3);
+        let mut big_tomato = placed(&tomato, Coordinate { row: 0, col: 0 });
+        big_tomato.span = 2;
+        for (r, c) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            grid.cells[r][c].variety = Some(big_tomato.clone());
+        }
+        let basil_plant = placed(&basil, Coordinate { row: 0, col: 2 });
+        grid.cells[0][2].variety = Some(basil_plant.clone());
+        grid.cells[1][2].variety = Some(basil_plant);
+        grid.cells[2][0].variety = Some(placed(&fennel, Coordinate { row: 2, col: 0 }));
+
+        let edges = companion_adjacencies(&grid, &varieties);
+        let tomato_basil: Vec<_> = edges
+            .iter()
+            .filter(|e| e.to == Coordinate { row: 0, col: 2 })
+            .collect();
+        assert_eq!(
+            tomato_basil.len(),
+            1,
+            "symmetric and repeated edges collapse"
+        );
+        assert_eq!(tomato_basil[0].from, Coordinate { row: 0, col: 0 });
+        assert_eq!(tomato_basil[0].kind, AdjacencyKind::Good);
+        assert!(edges.contains(&Adjacency {
+            from: Coordinate { row: 0, col: 0 },
+            to: Coordinate { row: 2, col: 0 },
+            kind: AdjacencyKind::Bad,
+        }));
+        assert_eq!(edges.len(), 2);
+    }
+}
@@ -12,17 +12,30 @@ pub fn cell_span(spacing_cm: u32) -> u32 {
 }
 
 /// Plants per cell:
-/// - span == 1 (spacing <= 30 cm): `floor(30 / spacing)^2`
+/// - span == 1 (spacing <= 30 cm): `round(30 / spacing)^2`, minimum 1
 /// - span  > 1 (spacing  > 30 cm): 1 plant occupies the whole spanxspan block.
+///
+/// Rounding (rather than flooring) lets edge plants share their half-spacing margin with
+/// the neighbouring cell, matching square-foot gardening densities:
+/// 8 cm -> 16 (radish, chive), 10 cm -> 9, 15-20 cm -> 4 (thyme, basil, parsley),
+/// 21-30 cm -> 1.
 pub fn plants_per_cell(spacing_cm: u32) -> u32 {
     if cell_span(spacing_cm) > 1 {
         1
     } else {
-        let per_axis = (CELL_SIZE_CM / spacing_cm.max(1)).max(1);
+        let spacing = spacing_cm.max(1);
+        let per_axis = ((CELL_SIZE_CM + spacing / 2) / spacing).max(1);
         per_axis * per_axis
     }
 }
 
+/// Whole cells covering `area_m2` square meters, rounded to the nearest cell, minimum 1.
+/// Examples with 30 cm cells (0.09 m²): 0.09 -> 1, 1.0 -> 11, 2.0 -> 22.
+pub fn cells_for_area(area_m2: f32) -> usize {
+    let cell_m2 = (CELL_SIZE_CM as f32 / 100.0).powi(2);
+    ((area_m2 / cell_m2).round() as usize).max(1)
+}
+
 /// Adjusts `days_to_harvest` for pre-placed varieties based on user-provided
 /// planting date and planning start.
 ///
@@ -92,6 +105,35 @@ mod tests {
         assert_eq!(cell_span(90), 3, "90 cm needs 3 cells");
     }
 
+    #[test]
+    fn test_plants_per_cell_values() {
+        assert_eq!(plants_per_cell(8), 16);
+        assert_eq!(plants_per_cell(10), 9);
+        assert_eq!(plants_per_cell(15), 4);
+        assert_eq!(plants_per_cell(20), 4);
+        assert_eq!(plants_per_cell(25), 1);
+        assert_eq!(plants_per_cell(30), 1);
+        assert_eq!(
+            plants_per_cell(60),
+            1,
+            "multi-cell plants hold one plant per block"
+        );
+    }
+
+    #[test]
+    fn test_plants_per_cell_pins_dense_crop_yields() {
+        use crate::domain::test_fixtures::get_variety_by_id;
+        for (id, expected) in [("basil", 4), ("parsley", 4), ("chive", 16), ("radish", 16)] {
+            let variety = get_variety_by_id(id).unwrap();
+            assert_eq!(
+                plants_per_cell(variety.spacing_cm),
+                expected,
+                "{id} ({} cm)",
+                variety.spacing_cm
+            );
+        }
+    }
+
     #[test]
     fn test_infer_planted_date_picks_most_recent_before_planning_start() {
         use crate::domain::models::variety::{
@@ -105,9 +147,11 @@ mod tests {
             vegetable: Arc::new(Vegetable {
                 id: "tomato".into(),
                 name: "Tomato".into(),
+                aliases: vec![],
                 group_id: "legumes-fruits".into(),
                 variety_ids: vec!["tomato".into()],
                 good_companions: vec![],
+                requires: vec![],
                 bad_companions: vec![],
             }),
             name: "Tomato".into(),
@@ -124,6 +168,8 @@ mod tests {
                 },
             }],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 1000,
             soil_types: vec![SoilType::Loamy],
             spacing_cm: 50,
             days_to_harvest: 80,
@@ -152,9 +198,11 @@ mod tests {
             vegetable: Arc::new(Vegetable {
                 id: "tomato".into(),
                 name: "Tomato".into(),
+                aliases: vec![],
                 group_id: "legumes-fruits".into(),
                 variety_ids: vec!["tomato".into()],
                 good_companions: vec![],
+                requires: vec![],
                 bad_companions: vec![],
             }),
             name: "Tomato".into(),
@@ -171,6 +219,8 @@ mod tests {
                 },
             }],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 1000,
             soil_types: vec![SoilType::Loamy],
             spacing_cm: 50,
             days_to_harvest: 80,
@@ -200,9 +250,11 @@ mod tests {
             vegetable: Arc::new(Vegetable {
                 id: "tomato".into(),
                 name: "Tomato".into(),
+                aliases: vec![],
                 group_id: "legumes-fruits".into(),
                 variety_ids: vec!["tomato".into()],
                 good_companions: vec![],
+                requires: vec![],
                 bad_companions: vec![],
             }),
             name: "Tomato".into(),
@@ -219,6 +271,8 @@ mod tests {
                 },
             }],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 1000,
             soil_types: vec![SoilType::Loamy],
             spacing_cm: 50,
             days_to_harvest: 80,
@@ -1,9 +1,18 @@
 mod allocation;
-mod companion;
+pub mod companion;
+pub mod diff;
 pub mod filter;
-mod grid;
-mod helpers;
-mod placement;
+pub mod grid;
+mod health;
+pub mod helpers;
+pub mod placement;
 pub mod planner;
-mod response;
+mod regions;
+pub mod removal;
+pub mod repair;
+mod requirements;
+pub mod response;
 mod schedule;
+pub mod sizing;
+pub mod succession;
+pub mod transition;
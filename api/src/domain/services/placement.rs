@@ -1,46 +1,412 @@
-use std::collections::HashMap;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
 
 use chrono::NaiveDate;
 
-use crate::domain::models::{garden::GardenGrid, variety::Variety, Coordinate};
+use crate::domain::models::{
+    garden::{GardenGrid, PlacedVariety, PHASE_EXPLICIT, PHASE_FILL},
+    request::{Objective, PackMode, PlacementOptions, KEEP_TOGETHER_BONUS, NEAR_BONUS},
+    response::PlacementStep,
+    variety::Variety,
+    vegetable::Vegetable,
+    Coordinate,
+};
 use crate::domain::services::companion::companion_score;
+use crate::domain::services::grid::{count_grid_occupancy, fill_target, GridOccupancy};
 use crate::domain::services::helpers::{cell_span, plants_per_cell};
+use crate::domain::services::requirements::{required_companion_block, requirement_met};
 
-/// Scans the grid for the free `span x span` block that maximises the companion score
-/// for `variety`. Returns `Some((coordinate, score))` or `None` when no valid block exists.
+/// Points a beginner-friendly variety gains under [`Objective::LowMaintenance`] — more
+/// than any realistic companion score, which is then left to break ties.
+const LOW_MAINTENANCE_BONUS: i32 = 100;
+
+/// `true` when a neighbour of the block is one of `variety`'s bad companions.
+fn has_bad_neighbor(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+) -> bool {
+    let bad = &variety.vegetable.bad_companions;
+    grid.get_block_neighbors(coordinate, span)
+        .iter()
+        .any(|n| bad.contains(&n.vegetable_id))
+}
+
+/// Companion score of a `span x span` block anchored at `coordinate` for `variety`.
+///
+/// Cells considered: the block perimeter (see [`GardenGrid::get_block_neighbors`]).
+///
+/// With `options.mutual_companions`, each neighbour's own companion lists are scored
+/// against the variety too. Each neutral neighbour sharing the variety's category
+/// additionally costs `options.neutral_competitor_penalty` points.
+pub fn block_score(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+    options: &PlacementOptions,
+) -> i32 {
+    let neighbors = grid.get_block_neighbors(coordinate, span);
+    let vegetable: &Vegetable = &variety.vegetable;
+    let neighbor_veg_ids: Vec<&str> = neighbors.iter().map(|v| v.vegetable_id.as_str()).collect();
+    let mut score = companion_score(vegetable, &neighbor_veg_ids);
+    if let Some(lists) = &options.mutual_companions {
+        score += neighbor_veg_ids
+            .iter()
+            .filter_map(|id| lists.get(*id))
+            .map(|neighbor| companion_score(neighbor, &[vegetable.id.as_str()]))
+            .sum::<i32>();
+    }
+    if options.neutral_competitor_penalty != 0 {
+        let competitors = neighbors
+            .iter()
+            .filter(|n| n.category == variety.category)
+            .filter(|n| companion_score(vegetable, &[n.vegetable_id.as_str()]) == 0)
+            .count() as i32;
+        score =
+            score.saturating_sub(competitors.saturating_mul(options.neutral_competitor_penalty));
+    }
+    score
+}
+
+/// `true` when `a` or its vegetable and `b` or its vegetable form one of `pairs`, in
+/// either order.
+fn is_avoided_pair(pairs: &[(String, String)], a: [&str; 2], b: [&str; 2]) -> bool {
+    pairs.iter().any(|(x, y)| {
+        (a.contains(&x.as_str()) && b.contains(&y.as_str()))
+            || (a.contains(&y.as_str()) && b.contains(&x.as_str()))
+    })
+}
+
+/// Points `find_best_block` takes off a block for each neighbour forming one of
+/// `options.avoid_pairs` with `variety`, on the scale of [`objective_score`].
+fn avoid_pair_penalty(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+    options: &PlacementOptions,
+) -> i32 {
+    if options.avoid_pairs.is_empty() || options.avoid_pair_penalty == 0 {
+        return 0;
+    }
+    let own = [variety.id.as_str(), variety.vegetable.id.as_str()];
+    let avoided = grid
+        .get_block_neighbors(coordinate, span)
+        .iter()
+        .filter(|n| {
+            is_avoided_pair(
+                &options.avoid_pairs,
+                own,
+                [n.id.as_str(), n.vegetable_id.as_str()],
+            )
+        })
+        .count() as i32;
+    avoided
+        .saturating_mul(options.avoid_pair_penalty)
+        .saturating_mul(tuning_scale(options))
+}
+
+/// Points `find_best_block` adds to a block for each neighbour named in one of
+/// `options.near` hints for `variety`, on the scale of [`objective_score`].
+fn near_bonus(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+    options: &PlacementOptions,
+) -> i32 {
+    let own = [variety.id.as_str(), variety.vegetable.id.as_str()];
+    let wanted: Vec<&str> = options
+        .near
+        .iter()
+        .filter(|(id, _)| own.contains(&id.as_str()))
+        .map(|(_, neighbor)| neighbor.as_str())
+        .collect();
+    if wanted.is_empty() {
+        return 0;
+    }
+    let hinted = grid
+        .get_block_neighbors(coordinate, span)
+        .iter()
+        .filter(|n| wanted.contains(&n.id.as_str()) || wanted.contains(&n.vegetable_id.as_str()))
+        .count() as i32;
+    hinted * NEAR_BONUS * tuning_scale(options)
+}
+
+/// Points `find_best_block` adds to a block for each neighbouring cell holding the same
+/// variety, when `options.keep_together` lists it, on the scale of [`objective_score`].
+fn keep_together_bonus(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+    options: &PlacementOptions,
+) -> i32 {
+    if !options.keep_together.contains(&variety.id) {
+        return 0;
+    }
+    let same = grid
+        .get_block_neighbors(coordinate, span)
+        .iter()
+        .filter(|n| n.id == variety.id)
+        .count() as i32;
+    same * KEEP_TOGETHER_BONUS * tuning_scale(options)
+}
+
+/// Factor bringing flat tuning points onto the scale of [`objective_score`], whose
+/// weighted scores are multiplied by [`WEIGHT_SCALE`].
+fn tuning_scale(options: &PlacementOptions) -> i32 {
+    if options.weights.is_empty() {
+        1
+    } else {
+        WEIGHT_SCALE as i32
+    }
+}
+
+/// Expected harvest of one block of `variety`, in grams.
+fn block_yield_g(variety: &Variety) -> i32 {
+    (variety.yield_per_plant_g * plants_per_cell(variety.spacing_cm)) as i32
+}
+
+/// Weighted scores are multiplied by this before rounding to `i32`, keeping two decimals.
+const WEIGHT_SCALE: f32 = 100.0;
+
+/// Number of distinct vegetables, other than the variety's own, around the block.
+fn block_diversity(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+) -> i32 {
+    let distinct: HashSet<&str> = grid
+        .get_block_neighbors(coordinate, span)
+        .into_iter()
+        .map(|n| n.vegetable_id.as_str())
+        .filter(|id| *id != variety.vegetable.id)
+        .collect();
+    distinct.len() as i32
+}
+
+/// Value of one objective for a block, on roughly comparable scales so weights are
+/// meaningful: companion points, distinct neighbours, kilograms per block, and `1`
+/// for a beginner-friendly variety.
+fn component(
+    objective: &Objective,
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+    options: &PlacementOptions,
+) -> f32 {
+    match objective {
+        Objective::CompanionScore | Objective::CompanionThenDiversity => {
+            block_score(grid, variety, coordinate, span, options) as f32
+        }
+        Objective::Diversity => block_diversity(grid, variety, coordinate, span) as f32,
+        Objective::Yield => block_yield_g(variety) as f32 / 1000.0,
+        Objective::LowMaintenance => f32::from(u8::from(variety.beginner_friendly)),
+    }
+}
+
+/// `(objective, component, weight)` for every weighted objective, in [`Objective::ALL`] order.
+fn weighted_components(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+    options: &PlacementOptions,
+) -> Vec<(Objective, f32, f32)> {
+    Objective::ALL
+        .into_iter()
+        .filter_map(|objective| {
+            let weight = *options.weights.get(&objective)?;
+            let value = component(&objective, grid, variety, coordinate, span, options);
+            Some((objective, value, weight))
+        })
+        .collect()
+}
+
+/// Human-readable breakdown of the weighted score of a block, e.g.
+/// `" [companion 2.00 × 0.70 + diversity 1.00 × 0.30 = 1.70]"`; empty without weights.
+pub fn weighted_breakdown(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+    options: &PlacementOptions,
+) -> String {
+    let components = weighted_components(grid, variety, coordinate, span, options);
+    if components.is_empty() {
+        return String::new();
+    }
+    let terms: Vec<String> = components
+        .iter()
+        .map(|(objective, value, weight)| {
+            let label = match objective {
+                Objective::CompanionScore | Objective::CompanionThenDiversity => "companion",
+                Objective::Diversity => "diversity",
+                Objective::Yield => "yield",
+                Objective::LowMaintenance => "low maintenance",
+            };
+            format!("{label} {value:.2} × {weight:.2}")
+        })
+        .collect();
+    let total: f32 = components.iter().map(|(_, v, w)| v * w).sum();
+    format!(" [{} = {total:.2}]", terms.join(" + "))
+}
+
+/// Score of a block; higher is better.
+///
+/// With `options.weights`, the weighted sum of each objective's [`component`], scaled
+/// by [`WEIGHT_SCALE`] and rounded. Otherwise, by `options.objective`:
+/// - `CompanionScore` and `CompanionThenDiversity`: [`block_score`]; the latter breaks
+///   ties with [`tie_break`].
+/// - `Diversity`: number of distinct vegetables among the neighbours, other than the
+///   variety's own.
+/// - `Yield`: [`block_yield_g`] (the same for every block of a variety).
+/// - `LowMaintenance`: [`block_score`] plus [`LOW_MAINTENANCE_BONUS`] for
+///   beginner-friendly varieties.
+pub fn objective_score(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+    options: &PlacementOptions,
+) -> i32 {
+    if !options.weights.is_empty() {
+        let total: f32 = weighted_components(grid, variety, coordinate, span, options)
+            .iter()
+            .map(|(_, value, weight)| value * weight)
+            .sum();
+        return (total * WEIGHT_SCALE).round() as i32;
+    }
+    match options.objective {
+        Objective::CompanionScore | Objective::CompanionThenDiversity => {
+            block_score(grid, variety, coordinate, span, options)
+        }
+        Objective::Diversity => block_diversity(grid, variety, coordinate, span),
+        Objective::Yield => block_yield_g(variety),
+        Objective::LowMaintenance => {
+            let bonus = if variety.beginner_friendly {
+                LOW_MAINTENANCE_BONUS
+            } else {
+                0
+            };
+            block_score(grid, variety, coordinate, span, options) + bonus
+        }
+    }
+}
+
+/// Secondary score compared only between blocks of equal [`placement_score`]: the
+/// distinct neighbouring vegetables under `CompanionThenDiversity` without weights, `0`
+/// otherwise.
+fn tie_break(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+    options: &PlacementOptions,
+) -> i32 {
+    if options.weights.is_empty() && options.objective == Objective::CompanionThenDiversity {
+        block_diversity(grid, variety, coordinate, span)
+    } else {
+        0
+    }
+}
+
+/// `true` when the block lies inside one of `variety`'s zones, or the variety has none.
+fn in_zone(
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+    options: &PlacementOptions,
+) -> bool {
+    let mut zones = options
+        .zones
+        .iter()
+        .filter(|z| z.id == variety.id || z.id == variety.vegetable.id)
+        .peekable();
+    zones.peek().is_none() || zones.any(|z| z.contains_block(coordinate, span))
+}
+
+/// `true` when `variety` may be placed on the `span x span` block anchored at
+/// `coordinate`: the block is free and inside the variety's zones if it has any, the
+/// variety's `requires` is met there (or one of `companions` can be co-placed next to
+/// it), and with `options.strict_companions` no neighbour is a bad companion.
+fn block_fits(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+    options: &PlacementOptions,
+    companions: &[Variety],
+) -> bool {
+    grid.is_block_free(coordinate.row, coordinate.col, span)
+        && in_zone(variety, coordinate, span, options)
+        && (requirement_met(grid, variety, coordinate, span)
+            || required_companion_block(grid, variety, coordinate, span, companions).is_some())
+        && !(options.strict_companions && has_bad_neighbor(grid, variety, coordinate, span))
+}
+
+/// [`objective_score`] of a block less its [`avoid_pair_penalty`], plus its [`near_bonus`]
+/// and [`keep_together_bonus`].
+fn placement_score(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+    options: &PlacementOptions,
+) -> i32 {
+    objective_score(grid, variety, coordinate, span, options)
+        .saturating_sub(avoid_pair_penalty(grid, variety, coordinate, span, options))
+        .saturating_add(near_bonus(grid, variety, coordinate, span, options))
+        .saturating_add(keep_together_bonus(
+            grid, variety, coordinate, span, options,
+        ))
+}
+
+/// Scans the grid for the free `span x span` block that maximises
+/// [`objective_score`] for `variety`, less its [`avoid_pair_penalty`], plus its
+/// [`near_bonus`], with [`tie_break`] deciding between equal scores. Returns
+/// `Some((coordinate, score))` or `None` when no valid block exists.
+///
+/// When the variety's vegetable `requires` others, only blocks next to one of them, or
+/// next to a free block where one of `companions` can be co-placed, are valid.
 pub fn find_best_block(
     grid: &GardenGrid,
     variety: &Variety,
     rows: usize,
     cols: usize,
+    options: &PlacementOptions,
+    companions: &[Variety],
 ) -> Option<(Coordinate, i32)> {
     let span = cell_span(variety.spacing_cm) as usize;
-    let vegetable = &variety.vegetable;
-    let mut best: Option<(Coordinate, i32)> = None;
+    let mut best: Option<(Coordinate, (i32, i32))> = None;
 
     for r in 0..=rows.saturating_sub(span) {
         for c in 0..=cols.saturating_sub(span) {
-            if !grid.is_block_free(r, c, span) {
+            let coordinate = Coordinate { row: r, col: c };
+            if !block_fits(grid, variety, coordinate, span, options, companions) {
                 continue;
             }
-            let score = {
-                let neighbor_veg_ids: Vec<&str> = grid
-                    .get_block_neighbors(Coordinate { row: r, col: c }, span)
-                    .iter()
-                    .map(|v| v.vegetable_id.as_str())
-                    .collect();
-                companion_score(vegetable, &neighbor_veg_ids)
-            };
+            let score = (
+                placement_score(grid, variety, coordinate, span, options),
+                tie_break(grid, variety, coordinate, span, options),
+            );
             if best.is_none_or(|(_, s)| score > s) {
-                best = Some((Coordinate { row: r, col: c }, score));
+                best = Some((coordinate, score));
             }
         }
     }
 
-    best
+    best.map(|(coordinate, (score, _))| (coordinate, score))
 }
 
-/// Fills a single `span x span` block starting at `(row, col)` with `variety`.
+/// Fills a single `span x span` block starting at `(row, col)` with `variety`, placed
+/// during planner `phase`.
 pub fn fill_block(
     grid: &mut GardenGrid,
     variety: &Variety,
@@ -48,40 +414,204 @@ pub fn fill_block(
     reason: &str,
     week_idx: usize,
     week_start: NaiveDate,
+    phase: u8,
 ) {
     let span = cell_span(variety.spacing_cm) as usize;
     let ppc = plants_per_cell(variety.spacing_cm);
     for dr in 0..span {
         for dc in 0..span {
-            grid.cells[coordinate.row + dr][coordinate.col + dc].variety =
-                Some(crate::domain::models::garden::PlacedVariety {
-                    id: variety.id.clone(),
-                    vegetable_id: variety.vegetable.id.clone(),
-                    name: variety.name.clone(),
-                    reason: reason.to_owned(),
-                    plants_per_cell: ppc,
-                    span: span as u32,
-                    anchor: coordinate,
-                    planted_week: week_idx,
-                    days_to_harvest: variety.days_to_harvest,
-                    estimated_harvest_date: week_start
-                        + chrono::Duration::days(variety.days_to_harvest as i64),
-                    lifecycle: variety.lifecycle.clone(),
-                });
+            grid.cells[coordinate.row + dr][coordinate.col + dc].variety = Some(PlacedVariety {
+                id: variety.id.clone(),
+                vegetable_id: variety.vegetable.id.clone(),
+                name: variety.name.clone(),
+                reason: reason.to_owned(),
+                plants_per_cell: ppc,
+                span: span as u32,
+                anchor: coordinate,
+                planted_week: week_idx,
+                days_to_harvest: variety.days_to_harvest,
+                estimated_harvest_date: week_start
+                    + chrono::Duration::days(variety.days_to_harvest as i64),
+                lifecycle: variety.lifecycle.clone(),
+                category: variety.category.clone(),
+                placed_in_phase: phase,
+            });
+        }
+    }
+}
+
+/// Plants the planner may still place over the whole planning period
+/// ([`PlacementOptions::max_total_plants`]), shared by every week.
+#[derive(Debug)]
+pub struct PlantBudget {
+    left: Cell<u32>,
+    exhausted: Cell<bool>,
+}
+
+impl PlantBudget {
+    pub fn new(max_total_plants: u32) -> Self {
+        Self {
+            left: Cell::new(max_total_plants),
+            exhausted: Cell::new(false),
+        }
+    }
+
+    /// `true` when `variety`'s block fits in what is left; records the refusal otherwise.
+    fn affords(&self, variety: &Variety) -> bool {
+        let fits = plants_per_cell(variety.spacing_cm) <= self.left.get();
+        if !fits {
+            self.exhausted.set(true);
         }
+        fits
+    }
+
+    /// Takes `variety`'s block off the budget, down to `0` at most: callers check
+    /// [`Self::affords`] first.
+    fn spend(&self, variety: &Variety) {
+        self.left.set(
+            self.left
+                .get()
+                .saturating_sub(plants_per_cell(variety.spacing_cm)),
+        );
+    }
+
+    /// `true` once the budget turned down a placement.
+    pub fn exhausted(&self) -> bool {
+        self.exhausted.get()
+    }
+}
+
+/// Collects every placement decision, in order, for `POST /api/plan/trace`.
+#[derive(Default)]
+pub struct PlacementTrace {
+    steps: RefCell<Vec<PlacementStep>>,
+}
+
+impl PlacementTrace {
+    fn record(&self, step: PlacementStep) {
+        self.steps.borrow_mut().push(step);
+    }
+
+    /// The recorded placements, in the order they were made.
+    pub fn into_steps(self) -> Vec<PlacementStep> {
+        self.steps.into_inner()
     }
 }
 
 /// Shared context for a single planning week passed to placement functions.
-pub struct PlacementWeek {
+pub struct PlacementWeek<'a> {
     pub rows: usize,
     pub cols: usize,
     pub week_idx: usize,
     pub week_start: NaiveDate,
+    pub options: &'a PlacementOptions,
+    /// Varieties that may be co-placed to satisfy a vegetable's `requires`.
+    pub companions: &'a [Variety],
+    /// Instant after which the fill phase stops placing plants.
+    pub deadline: Option<Instant>,
+    /// Plants left to place over the period; `None` when the request sets no limit.
+    pub plant_budget: Option<&'a PlantBudget>,
+    /// Records each placement when set.
+    pub trace: Option<&'a PlacementTrace>,
+}
+
+impl PlacementWeek<'_> {
+    /// `true` once the planning deadline, if any, has passed.
+    pub fn past_deadline(&self) -> bool {
+        self.deadline.is_some_and(|d| Instant::now() >= d)
+    }
+
+    /// `true` when the plant budget, if any, still covers a block of `variety`.
+    fn affords(&self, variety: &Variety) -> bool {
+        self.plant_budget.is_none_or(|b| b.affords(variety))
+    }
+}
+
+/// Places `variety` on the block at `coordinate` and, when none of its neighbours is a
+/// vegetable it requires, co-places the first fitting required companion next to it
+/// (unless the plant budget no longer covers it). Both are recorded as placed in
+/// `phase`. Returns the companion score of the placed block(s).
+fn place_block(
+    grid: &mut GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    week: &PlacementWeek,
+    phase: u8,
+    build_reason_fn: &impl Fn(&Variety, &[String], i32) -> String,
+) -> i32 {
+    let span = cell_span(variety.spacing_cm) as usize;
+    let score = block_score(grid, variety, coordinate, span, week.options);
+    let neighbor_names: Vec<String> = grid
+        .get_block_neighbors(coordinate, span)
+        .iter()
+        .map(|v| v.name.clone())
+        .collect();
+    let reason = build_reason_fn(variety, &neighbor_names, score)
+        + &weighted_breakdown(grid, variety, coordinate, span, week.options);
+    fill_block(
+        grid,
+        variety,
+        coordinate,
+        &reason,
+        week.week_idx,
+        week.week_start,
+        phase,
+    );
+    if let Some(budget) = week.plant_budget {
+        budget.spend(variety);
+    }
+    if let Some(trace) = week.trace {
+        trace.record(PlacementStep {
+            week: week.week_idx,
+            id: variety.id.clone(),
+            name: variety.name.clone(),
+            anchor: coordinate,
+            span,
+            score,
+            phase,
+        });
+    }
+    if requirement_met(grid, variety, coordinate, span) {
+        return score;
+    }
+    match required_companion_block(grid, variety, coordinate, span, week.companions) {
+        Some((companion, anchor)) if week.affords(companion) => {
+            score + place_block(grid, companion, anchor, week, phase, build_reason_fn)
+        }
+        _ => score,
+    }
+}
+
+/// Places a single `variety` on its best free block of `grid` (see [`find_best_block`])
+/// as an explicit placement, leaving every other plant where it is. Returns the chosen
+/// anchor and the companion score of the placed block(s), or `None` when no block fits.
+pub fn place_one(
+    grid: &mut GardenGrid,
+    variety: &Variety,
+    week: &PlacementWeek,
+    build_reason_fn: impl Fn(&Variety, &[String], i32) -> String,
+) -> Option<(Coordinate, i32)> {
+    let (coordinate, _) = find_best_block(
+        grid,
+        variety,
+        week.rows,
+        week.cols,
+        week.options,
+        week.companions,
+    )?;
+    let score = place_block(
+        grid,
+        variety,
+        coordinate,
+        week,
+        PHASE_EXPLICIT,
+        &build_reason_fn,
+    );
+    Some((coordinate, score))
 }
 
 /// Iterates over the placement queue and greedily places each variety on the grid.
-/// Returns the cumulative companion score.
+/// Blocks are chosen by [`objective_score`]; returns the cumulative companion score.
 pub fn place_candidates(
     grid: &mut GardenGrid,
     queue: &[Variety],
@@ -104,52 +634,149 @@ pub fn place_candidates(
 
     'outer: for variety in queue {
         let max_count = placements_map.get(&variety.id).copied().unwrap_or(0);
-        if placed_counts.get(&variety.id).copied().unwrap_or(0) >= max_count {
+        if placed_counts.get(&variety.id).copied().unwrap_or(0) >= max_count
+            || !week.affords(variety)
+        {
             continue;
         }
 
         let span = cell_span(variety.spacing_cm) as usize;
 
-        match find_best_block(grid, variety, week.rows, week.cols) {
-            None if span == 1 => {
+        match find_best_block(
+            grid,
+            variety,
+            week.rows,
+            week.cols,
+            week.options,
+            week.companions,
+        ) {
+            None if span == 1 && variety.vegetable.requires.is_empty() => {
                 break 'outer; // no free single cell - grid is full
             }
             None => {
-                continue; // no spanxspan block; smaller plants may still fit
+                continue; // no spanxspan block (or none meeting `requires`); others may fit
             }
-            Some((coordinate, score)) => {
-                let neighbor_names: Vec<String> = grid
-                    .get_block_neighbors(coordinate, span)
-                    .iter()
-                    .map(|v| v.name.clone())
-                    .collect();
-                let reason = build_reason_fn(variety, &neighbor_names, score);
-                fill_block(
+            Some((coordinate, _)) => {
+                global_score += place_block(
                     grid,
                     variety,
                     coordinate,
-                    &reason,
-                    week.week_idx,
-                    week.week_start,
+                    week,
+                    PHASE_EXPLICIT,
+                    &build_reason_fn,
                 );
                 placed_counts
                     .entry(variety.id.clone())
                     .and_modify(|n| *n += 1)
                     .or_insert(1);
-                global_score += score;
             }
         }
     }
     global_score
 }
 
+/// Side of the largest free square block currently available on the grid (0 when full).
+fn largest_free_square(grid: &GardenGrid) -> usize {
+    let max_span = grid.rows.min(grid.cols);
+    (1..=max_span)
+        .rev()
+        .find(|&span| {
+            (0..=grid.rows - span)
+                .any(|r| (0..=grid.cols - span).any(|c| grid.is_block_free(r, c, span)))
+        })
+        .unwrap_or(0)
+}
+
+/// Per-variety part of `objective`, higher first: expected grams per cell for `Yield`,
+/// `1` for beginner-friendly varieties under `LowMaintenance`, `0` otherwise.
+fn candidate_rank(objective: &Objective, variety: &Variety) -> i32 {
+    match objective {
+        Objective::Yield => {
+            let span = cell_span(variety.spacing_cm) as i32;
+            block_yield_g(variety) / (span * span)
+        }
+        Objective::LowMaintenance => variety.beginner_friendly as i32,
+        Objective::CompanionScore | Objective::Diversity | Objective::CompanionThenDiversity => 0,
+    }
+}
+
+/// Candidate order for one gap-filling pass.
+///
+/// Without `options.weights`, candidates are first ranked by [`candidate_rank`] for
+/// `options.objective` (a no-op for block-only objectives), then:
+/// - `Priority`: kept in that order.
+/// - `BestFit`: candidates whose block fits the largest free square first, largest span
+///   first; ties keep the previous order. Candidates too big for any gap are dropped.
+fn pass_order<'v>(
+    grid: &GardenGrid,
+    candidates: &'v [Variety],
+    options: &PlacementOptions,
+) -> Vec<&'v Variety> {
+    let mut ordered: Vec<&Variety> = candidates.iter().collect();
+    if options.weights.is_empty() {
+        ordered.sort_by_key(|v| std::cmp::Reverse(candidate_rank(&options.objective, v)));
+    }
+    match options.pack {
+        PackMode::Priority => ordered,
+        PackMode::BestFit => {
+            let free = largest_free_square(grid);
+            ordered.retain(|v| cell_span(v.spacing_cm) as usize <= free);
+            ordered.sort_by_key(|v| std::cmp::Reverse(cell_span(v.spacing_cm)));
+            ordered
+        }
+    }
+}
+
+/// The candidate to plant on the free block anchored at `coordinate`: among those
+/// whose block fits there (see [`block_fits`]) and the plant budget still covers, the
+/// one with the highest [`placement_score`], then [`tie_break`]. Earlier candidates win
+/// remaining ties.
+fn best_candidate_at<'v>(
+    grid: &GardenGrid,
+    ordered: &[&'v Variety],
+    coordinate: Coordinate,
+    week: &PlacementWeek,
+) -> Option<&'v Variety> {
+    let mut best: Option<(&Variety, (i32, i32))> = None;
+    for &variety in ordered {
+        let span = cell_span(variety.spacing_cm) as usize;
+        if coordinate.row + span > week.rows
+            || coordinate.col + span > week.cols
+            || !block_fits(
+                grid,
+                variety,
+                coordinate,
+                span,
+                week.options,
+                week.companions,
+            )
+            || !week.affords(variety)
+        {
+            continue;
+        }
+        let score = (
+            placement_score(grid, variety, coordinate, span, week.options),
+            tie_break(grid, variety, coordinate, span, week.options),
+        );
+        if best.is_none_or(|(_, s)| score > s) {
+            best = Some((variety, score));
+        }
+    }
+    best.map(|(variety, _)| variety)
+}
+
 /// Phase 2 - iterative greedy fill.
 ///
-/// After explicit preferences have been placed, tries every candidate in priority
-/// order and places the best available block for each. Repeats until a full pass
-/// over all candidates produces zero new placements (grid is genuinely full or no
-/// candidate fits anywhere). This ensures that cells left vacant by large-span
-/// plants that could not find a free block are filled by smaller alternatives.
+/// After explicit preferences have been placed, walks the free cells in row-major
+/// order and plants on each the best-scoring candidate whose block fits there (see
+/// [`best_candidate_at`]); the order given by `week.options.objective` and
+/// `week.options.pack` (see [`pass_order`]) only breaks ties. Choosing per block rather
+/// than per candidate keeps the top-ranked variety from taking every gap and lets each
+/// gap go to the plant that suits its neighbours best. Repeats until a full pass
+/// produces zero new placements (grid is genuinely full or no candidate fits anywhere),
+/// so blocks whose `requires` became satisfiable are revisited. With
+/// `week.options.target_fill_pct`, stops as soon as that share of plantable cells is
+/// occupied; candidates the plant budget no longer covers are skipped.
 pub fn fill_remaining_cells(
     grid: &mut GardenGrid,
     candidates: &[Variety],
@@ -157,32 +784,39 @@ pub fn fill_remaining_cells(
     build_reason_fn: impl Fn(&Variety, &[String], i32) -> String,
 ) -> i32 {
     let mut total_score: i32 = 0;
+    let target = fill_target(grid, week.options.target_fill_pct);
+    let target_reached = |grid: &GardenGrid| {
+        target.is_some_and(|t| {
+            let GridOccupancy(occupied, ..) = count_grid_occupancy(grid);
+            occupied >= t
+        })
+    };
 
     loop {
         let mut placements_this_pass = 0usize;
+        let ordered = pass_order(grid, candidates, week.options);
 
-        for variety in candidates {
-            match find_best_block(grid, variety, week.rows, week.cols) {
-                None => continue,
-                Some((coordinate, score)) => {
-                    let span = cell_span(variety.spacing_cm) as usize;
-                    let neighbor_names: Vec<String> = grid
-                        .get_block_neighbors(coordinate, span)
-                        .iter()
-                        .map(|v| v.name.clone())
-                        .collect();
-                    let reason = build_reason_fn(variety, &neighbor_names, score);
-                    fill_block(
-                        grid,
-                        variety,
-                        coordinate,
-                        &reason,
-                        week.week_idx,
-                        week.week_start,
-                    );
-                    total_score += score;
-                    placements_this_pass += 1;
+        for row in 0..week.rows {
+            for col in 0..week.cols {
+                if target_reached(grid) || week.past_deadline() {
+                    return total_score;
                 }
+                let coordinate = Coordinate { row, col };
+                if !grid.is_block_free(row, col, 1) {
+                    continue;
+                }
+                let Some(variety) = best_candidate_at(grid, &ordered, coordinate, week) else {
+                    continue;
+                };
+                total_score += place_block(
+                    grid,
+                    variety,
+                    coordinate,
+                    week,
+                    PHASE_FILL,
+                    &build_reason_fn,
+                );
+                placements_this_pass += 1;
             }
         }
 
@@ -215,6 +849,587 @@ pub fn harvest_plants(grid: &mut GardenGrid, current_week_idx: usize) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::models::garden::PlacedVariety;
+    use crate::domain::test_fixtures::get_variety_by_id;
+
+    fn placed(variety: &Variety, anchor: Coordinate) -> PlacedVariety {
+        PlacedVariety {
+            id: variety.id.clone(),
+            vegetable_id: variety.vegetable.id.clone(),
+            name: variety.name.clone(),
+            reason: "Test".into(),
+            plants_per_cell: 1,
+            span: 1,
+            anchor,
+            planted_week: 0,
+            days_to_harvest: variety.days_to_harvest,
+            estimated_harvest_date: chrono::NaiveDate::from_ymd_opt(2025, 6, 8).unwrap(),
+            lifecycle: variety.lifecycle.clone(),
+            category: variety.category.clone(),
+            placed_in_phase: PHASE_FILL,
+        }
+    }
+
+    fn empty_cell_count(grid: &GardenGrid) -> usize {
+        grid.cells
+            .iter()
+            .flatten()
+            .filter(|c| !c.blocked && c.variety.is_none())
+            .count()
+    }
+
+    fn fill_with(pack: PackMode) -> GardenGrid {
+        // 3x4 grid with the bottom-right corner blocked: a 3x3 block fits on the left,
+        // but two 2x2 blocks placed first would leave the bottom row unusable.
+        let mut grid = GardenGrid::new(3, 4);
+        grid.cells[2][3].blocked = true;
+        let candidates = vec![
+            get_variety_by_id("tomato").unwrap(),   // span 2
+            get_variety_by_id("zucchini").unwrap(), // span 3
+        ];
+        let options = PlacementOptions {
+            pack,
+            ..PlacementOptions::default()
+        };
+        let week = PlacementWeek {
+            rows: 3,
+            cols: 4,
+            week_idx: 0,
+            week_start: chrono::NaiveDate::from_ymd_opt(2025, 6, 2).unwrap(),
+            options: &options,
+            companions: &[],
+            deadline: None,
+            plant_budget: None,
+            trace: None,
+        };
+        fill_remaining_cells(&mut grid, &candidates, &week, |_, _, _| String::new());
+        grid
+    }
+
+    #[test]
+    fn test_strict_companions_rejects_blocks_next_to_a_bad_companion() {
+        let tomato = get_variety_by_id("tomato").unwrap();
+        let fennel = get_variety_by_id("fennel").unwrap();
+        let mut grid = GardenGrid::new(1, 2);
+        grid.cells[0][0].variety = Some(placed(&tomato, Coordinate { row: 0, col: 0 }));
+        let find = |strict_companions: bool| {
+            let options = PlacementOptions {
+                strict_companions,
+                ..PlacementOptions::default()
+            };
+            find_best_block(&grid, &fennel, 1, 2, &options, &[])
+        };
+        assert!(find(false).is_some(), "the bad neighbour only costs points");
+        assert_eq!(find(true), None, "strict placement leaves the cell empty");
+    }
+
+    /// The fill as it was before it went block-centric: each candidate in turn takes its
+    /// best free block, pass after pass.
+    fn candidate_centric_fill(
+        grid: &mut GardenGrid,
+        candidates: &[Variety],
+        week: &PlacementWeek,
+    ) -> i32 {
+        let mut total_score = 0;
+        loop {
+            let mut placements_this_pass = 0;
+            for variety in pass_order(grid, candidates, week.options) {
+                let Some((coordinate, _)) = find_best_block(
+                    grid,
+                    variety,
+                    week.rows,
+                    week.cols,
+                    week.options,
+                    week.companions,
+                ) else {
+                    continue;
+                };
+                total_score +=
+                    place_block(grid, variety, coordinate, week, PHASE_FILL, &|_, _, _| {
+                        String::new()
+                    });
+                placements_this_pass += 1;
+            }
+            if placements_this_pass == 0 {
+                return total_score;
+            }
+        }
+    }
+
+    #[test]
+    fn test_block_centric_fill_scores_at_least_candidate_centric() {
+        let candidates: Vec<Variety> = [
+            "tomato",
+            "carrot",
+            "lettuce",
+            "basil",
+            "onion",
+            "green-bean",
+            "radish",
+            "fennel",
+        ]
+        .into_iter()
+        .map(|id| get_variety_by_id(id).unwrap())
+        .collect();
+        let options = PlacementOptions::default();
+        let week = PlacementWeek {
+            rows: 6,
+            cols: 6,
+            week_idx: 0,
+            week_start: chrono::NaiveDate::from_ymd_opt(2025, 6, 2).unwrap(),
+            options: &options,
+            companions: &[],
+            deadline: None,
+            plant_budget: None,
+            trace: None,
+        };
+
+        let mut grid = GardenGrid::new(6, 6);
+        let before = candidate_centric_fill(&mut grid, &candidates, &week);
+        let mut grid = GardenGrid::new(6, 6);
+        let after = fill_remaining_cells(&mut grid, &candidates, &week, |_, _, _| String::new());
+        assert!(
+            after >= before,
+            "block-centric {after} < candidate-centric {before}"
+        );
+        assert_eq!(empty_cell_count(&grid), 0);
+    }
+
+    #[test]
+    fn test_bestfit_leaves_fewer_empty_cells_than_priority() {
+        let priority = empty_cell_count(&fill_with(PackMode::Priority));
+        let bestfit = empty_cell_count(&fill_with(PackMode::BestFit));
+        assert_eq!(priority, 3, "two tomatoes strand the bottom row");
+        assert_eq!(bestfit, 2, "zucchini first only strands the right column");
+        assert!(bestfit < priority);
+    }
+
+    #[test]
+    fn test_plant_budget_leaves_cells_empty_once_spent() {
+        let chive = get_variety_by_id("chive").unwrap();
+        assert_eq!(plants_per_cell(chive.spacing_cm), 16);
+        let mut grid = GardenGrid::new(3, 3);
+        let options = PlacementOptions::default();
+        let budget = PlantBudget::new(40);
+        let week = PlacementWeek {
+            rows: 3,
+            cols: 3,
+            week_idx: 0,
+            week_start: chrono::NaiveDate::from_ymd_opt(2025, 6, 2).unwrap(),
+            options: &options,
+            companions: &[],
+            deadline: None,
+            plant_budget: Some(&budget),
+            trace: None,
+        };
+        fill_remaining_cells(&mut grid, &[chive], &week, |_, _, _| String::new());
+
+        // Two cells use 32 plants; a third would need 48 > 40.
+        assert_eq!(empty_cell_count(&grid), 7);
+        assert!(budget.exhausted());
+    }
+
+    #[test]
+    fn test_plant_budget_spend_stops_at_zero() {
+        let chive = get_variety_by_id("chive").unwrap();
+        let budget = PlantBudget::new(10);
+        budget.spend(&chive);
+        assert_eq!(budget.left.get(), 0);
+        assert!(!budget.affords(&chive));
+    }
+
+    #[test]
+    fn test_avoid_pair_keeps_neutral_species_apart() {
+        let mint = get_variety_by_id("mint").unwrap();
+        let lettuce = get_variety_by_id("lettuce").unwrap();
+        assert_eq!(companion_score(&lettuce.vegetable, &["mint"]), 0);
+        let place = |avoid_pairs: Vec<(String, String)>| {
+            let mut grid = GardenGrid::new(4, 4);
+            let options = PlacementOptions {
+                avoid_pairs,
+                avoid_pair_penalty: crate::domain::models::request::DEFAULT_AVOID_PAIR_PENALTY,
+                ..PlacementOptions::default()
+            };
+            let week = PlacementWeek {
+                rows: 4,
+                cols: 4,
+                week_idx: 0,
+                week_start: chrono::NaiveDate::from_ymd_opt(2025, 6, 2).unwrap(),
+                options: &options,
+                companions: &[],
+                deadline: None,
+                plant_budget: None,
+                trace: None,
+            };
+            let queue = [mint.clone(), lettuce.clone()];
+            let counts = HashMap::from([("mint".to_owned(), 1), ("lettuce".to_owned(), 1)]);
+            place_candidates(&mut grid, &queue, &counts, &week, |_, _, _| String::new());
+            let lettuce_at = grid
+                .cells
+                .iter()
+                .flatten()
+                .find_map(|c| c.variety.as_ref().filter(|v| v.id == "lettuce"))
+                .unwrap()
+                .anchor;
+            grid.get_block_neighbors(lettuce_at, 1)
+                .iter()
+                .any(|n| n.id == "mint")
+        };
+        assert!(place(Vec::new()), "neutral species end up side by side");
+        assert!(
+            !place(vec![("lettuce".into(), "mint".into())]),
+            "the avoided pair is kept apart, whatever the order"
+        );
+    }
+
+    #[test]
+    fn test_near_hint_places_basil_beside_tomato() {
+        // Basil scores +2 at [0,1] next to a pepper and at [0,3] next to a tomato; the
+        // first equal block wins unless a hint names the tomato.
+        let basil = get_variety_by_id("basil").unwrap();
+        let mut grid = GardenGrid::new(1, 5);
+        for (col, id) in [(0, "pepper"), (4, "tomato")] {
+            let v = get_variety_by_id(id).unwrap();
+            grid.cells[0][col].variety = Some(placed(&v, Coordinate { row: 0, col }));
+        }
+        let best = |near: Vec<(String, String)>| {
+            let options = PlacementOptions {
+                near,
+                ..PlacementOptions::default()
+            };
+            find_best_block(&grid, &basil, 1, 5, &options, &[]).unwrap()
+        };
+
+        assert_eq!(best(Vec::new()), (Coordinate { row: 0, col: 1 }, 2));
+        assert_eq!(
+            best(vec![("basil".into(), "tomato".into())]),
+            (Coordinate { row: 0, col: 3 }, 2 + NEAR_BONUS)
+        );
+        // Hints for other varieties leave basil's choice alone.
+        assert_eq!(best(vec![("tomato".into(), "basil".into())]).0.col, 1);
+    }
+
+    #[test]
+    fn test_keep_together_clusters_explicit_plants() {
+        // Four basil plants between two tomatoes: each basil scores best beside a tomato,
+        // so they scatter around both unless asked to stay together.
+        let tomato = get_variety_by_id("tomato").unwrap();
+        let basil = get_variety_by_id("basil").unwrap();
+        let place = |keep_together: Vec<String>| {
+            let mut grid = GardenGrid::new(3, 7);
+            for col in [0, 6] {
+                grid.cells[1][col].variety = Some(placed(&tomato, Coordinate { row: 1, col }));
+            }
+            let options = PlacementOptions {
+                keep_together,
+                ..PlacementOptions::default()
+            };
+            let week = PlacementWeek {
+                rows: 3,
+                cols: 7,
+                week_idx: 0,
+                week_start: chrono::NaiveDate::from_ymd_opt(2025, 6, 2).unwrap(),
+                options: &options,
+                companions: &[],
+                deadline: None,
+                plant_budget: None,
+                trace: None,
+            };
+            let queue = vec![basil.clone(); 4];
+            let placements = HashMap::from([("basil".to_owned(), 4)]);
+            place_candidates(&mut grid, &queue, &placements, &week, |_, _, _| {
+                String::new()
+            });
+            let mut cells: Vec<(usize, usize)> = Vec::new();
+            for (r, row) in grid.cells.iter().enumerate() {
+                for (c, cell) in row.iter().enumerate() {
+                    if cell.variety.as_ref().is_some_and(|v| v.id == "basil") {
+                        cells.push((r, c));
+                    }
+                }
+            }
+            cells
+        };
+        // `true` when every cell can be reached from the first through side neighbours.
+        let contiguous = |cells: &[(usize, usize)]| {
+            let mut reached = vec![cells[0]];
+            let mut i = 0;
+            while i < reached.len() {
+                let (r, c) = reached[i];
+                for &other in cells {
+                    if r.abs_diff(other.0) + c.abs_diff(other.1) == 1 && !reached.contains(&other) {
+                        reached.push(other);
+                    }
+                }
+                i += 1;
+            }
+            reached.len() == cells.len()
+        };
+
+        let scattered = place(Vec::new());
+        assert_eq!(scattered.len(), 4);
+        assert!(!contiguous(&scattered), "{scattered:?}");
+
+        let clustered = place(vec!["basil".into()]);
+        assert_eq!(clustered.len(), 4);
+        assert!(contiguous(&clustered), "{clustered:?}");
+    }
+
+    #[test]
+    fn test_yield_objective_places_higher_yield_species() {
+        // One free cell next to a tomato: basil is its good companion and comes first,
+        // onion crops more per cell (9 x 100 g against 4 x 150 g).
+        let tomato = get_variety_by_id("tomato").unwrap();
+        let candidates = vec![
+            get_variety_by_id("basil").unwrap(),
+            get_variety_by_id("onion").unwrap(),
+        ];
+        let fill = |objective: Objective| {
+            let mut grid = GardenGrid::new(1, 2);
+            grid.cells[0][0].variety = Some(placed(&tomato, Coordinate { row: 0, col: 0 }));
+            let options = PlacementOptions {
+                objective,
+                ..PlacementOptions::default()
+            };
+            let week = PlacementWeek {
+                rows: 1,
+                cols: 2,
+                week_idx: 0,
+                week_start: chrono::NaiveDate::from_ymd_opt(2025, 6, 2).unwrap(),
+                options: &options,
+                companions: &[],
+                deadline: None,
+                plant_budget: None,
+                trace: None,
+            };
+            fill_remaining_cells(&mut grid, &candidates, &week, |_, _, _| String::new());
+            grid.cells[0][1].variety.as_ref().unwrap().id.clone()
+        };
+
+        assert_eq!(fill(Objective::CompanionScore), "basil");
+        assert_eq!(fill(Objective::Yield), "onion");
+    }
+
+    #[test]
+    fn test_diversity_objective_counts_distinct_neighbours() {
+        let lettuce = get_variety_by_id("lettuce").unwrap();
+        let radish = get_variety_by_id("radish").unwrap();
+        let carrot = get_variety_by_id("carrot").unwrap();
+        let mut grid = GardenGrid::new(3, 3);
+        grid.cells[0][1].variety = Some(placed(&radish, Coordinate { row: 0, col: 1 }));
+        grid.cells[1][0].variety = Some(placed(&radish, Coordinate { row: 1, col: 0 }));
+        grid.cells[1][2].variety = Some(placed(&carrot, Coordinate { row: 1, col: 2 }));
+        grid.cells[2][1].variety = Some(placed(&lettuce, Coordinate { row: 2, col: 1 }));
+        let options = PlacementOptions {
+            objective: Objective::Diversity,
+            ..PlacementOptions::default()
+        };
+        // Radish and carrot; the second radish and the lettuce's own kind do not count.
+        let centre = Coordinate { row: 1, col: 1 };
+        assert_eq!(objective_score(&grid, &lettuce, centre, 1, &options), 2);
+    }
+
+    #[test]
+    fn test_weights_shift_placement_between_blocks() {
+        // Basil fits at [0,1] next to a tomato (good companion, one distinct neighbour)
+        // or at [0,4] between a carrot and a radish (neutral, two distinct neighbours).
+        let basil = get_variety_by_id("basil").unwrap();
+        let mut grid = GardenGrid::new(1, 6);
+        grid.cells[0][2].blocked = true;
+        for (col, id) in [(0, "tomato"), (3, "carrot"), (5, "radish")] {
+            let v = get_variety_by_id(id).unwrap();
+            grid.cells[0][col].variety = Some(placed(&v, Coordinate { row: 0, col }));
+        }
+        let best_col = |companion: f32, diversity: f32| {
+            let options = PlacementOptions {
+                weights: std::collections::BTreeMap::from([
+                    (Objective::CompanionScore, companion),
+                    (Objective::Diversity, diversity),
+                ]),
+                ..PlacementOptions::default()
+            };
+            find_best_block(&grid, &basil, 1, 6, &options, &[])
+                .unwrap()
+                .0
+                .col
+        };
+
+        // 0.7 × 2 + 0.3 × 1 = 1.7 against 0.3 × 2 = 0.6
+        assert_eq!(best_col(0.7, 0.3), 1);
+        // 0.3 × 2 + 0.7 × 1 = 1.3 against 0.7 × 2 = 1.4
+        assert_eq!(best_col(0.3, 0.7), 4);
+    }
+
+    #[test]
+    fn test_companion_then_diversity_breaks_ties_by_distinct_neighbours() {
+        // Basil is neutral next to carrots and radishes: [0,1] sits between two carrots,
+        // [0,5] between a carrot and a radish.
+        let basil = get_variety_by_id("basil").unwrap();
+        let mut grid = GardenGrid::new(1, 7);
+        grid.cells[0][3].blocked = true;
+        for (col, id) in [(0, "carrot"), (2, "carrot"), (4, "carrot"), (6, "radish")] {
+            let v = get_variety_by_id(id).unwrap();
+            grid.cells[0][col].variety = Some(placed(&v, Coordinate { row: 0, col }));
+        }
+        let best = |objective: Objective| {
+            let options = PlacementOptions {
+                objective,
+                ..PlacementOptions::default()
+            };
+            find_best_block(&grid, &basil, 1, 7, &options, &[]).unwrap()
+        };
+
+        assert_eq!(best(Objective::CompanionScore).0.col, 1);
+        let (coordinate, score) = best(Objective::CompanionThenDiversity);
+        assert_eq!(coordinate.col, 5);
+        assert_eq!(score, best(Objective::CompanionScore).1);
+    }
+
+    #[test]
+    fn test_mutual_companions_prefer_a_pair_listing_each_other() {
+        // Basil lists both carrot and tomato as good companions; only tomato lists basil
+        // back. [0,1] sits next to the carrot, [0,3] next to the tomato.
+        let with_good = |id: &str, good: &[&str]| {
+            let mut variety = get_variety_by_id(id).unwrap();
+            let mut vegetable = (*variety.vegetable).clone();
+            vegetable.good_companions = good.iter().map(|g| g.to_string()).collect();
+            vegetable.bad_companions = Vec::new();
+            variety.vegetable = std::sync::Arc::new(vegetable);
+            variety
+        };
+        let basil = with_good("basil", &["carrot", "tomato"]);
+        let carrot = with_good("carrot", &[]);
+        let tomato = with_good("tomato", &["basil"]);
+        let mut grid = GardenGrid::new(1, 5);
+        grid.cells[0][2].blocked = true;
+        grid.cells[0][0].variety = Some(placed(&carrot, Coordinate { row: 0, col: 0 }));
+        grid.cells[0][4].variety = Some(placed(&tomato, Coordinate { row: 0, col: 4 }));
+
+        let one_way = PlacementOptions::default();
+        let mutual = PlacementOptions {
+            mutual_companions: Some(
+                [&carrot, &tomato]
+                    .into_iter()
+                    .map(|v| (v.vegetable.id.clone(), v.vegetable.clone()))
+                    .collect(),
+            ),
+            ..PlacementOptions::default()
+        };
+        let next_to_tomato = Coordinate { row: 0, col: 3 };
+        assert_eq!(block_score(&grid, &basil, next_to_tomato, 1, &one_way), 2);
+        assert_eq!(block_score(&grid, &basil, next_to_tomato, 1, &mutual), 4);
+
+        let best_col = |options: &PlacementOptions| {
+            find_best_block(&grid, &basil, 1, 5, options, &[])
+                .unwrap()
+                .0
+                .col
+        };
+        assert_eq!(best_col(&one_way), 1);
+        assert_eq!(best_col(&mutual), 3);
+    }
+
+    #[test]
+    fn test_required_companion_is_co_placed_or_neither_is_placed() {
+        // A bean that cannot grow without a radish next to it.
+        let mut bean = get_variety_by_id("green-bean").unwrap();
+        let mut vegetable = (*bean.vegetable).clone();
+        vegetable.requires = vec!["radish".into()];
+        bean.vegetable = std::sync::Arc::new(vegetable);
+        let companions = vec![bean.clone(), get_variety_by_id("radish").unwrap()];
+
+        let fill = |cols: usize| {
+            let mut grid = GardenGrid::new(1, cols);
+            let options = PlacementOptions::default();
+            let week = PlacementWeek {
+                rows: 1,
+                cols,
+                week_idx: 0,
+                week_start: chrono::NaiveDate::from_ymd_opt(2025, 6, 2).unwrap(),
+                options: &options,
+                companions: &companions,
+                deadline: None,
+                plant_budget: None,
+                trace: None,
+            };
+            fill_remaining_cells(&mut grid, std::slice::from_ref(&bean), &week, |_, _, _| {
+                String::new()
+            });
+            grid.cells[0]
+                .iter()
+                .map(|c| c.variety.as_ref().map(|v| v.id.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            fill(2),
+            vec![Some("green-bean".to_string()), Some("radish".to_string())]
+        );
+        assert_eq!(
+            fill(1),
+            vec![None],
+            "no room for the radish: no bean either"
+        );
+    }
+
+    /// Orthogonally adjacent pairs of plants sharing a category.
+    fn same_category_adjacent_pairs(grid: &GardenGrid) -> usize {
+        let category = |r: usize, c: usize| grid.cells[r][c].variety.as_ref().map(|v| &v.category);
+        let mut pairs = 0;
+        for r in 0..grid.rows {
+            for c in 0..grid.cols {
+                let Some(here) = category(r, c) else { continue };
+                if c + 1 < grid.cols && category(r, c + 1) == Some(here) {
+                    pairs += 1;
+                }
+                if r + 1 < grid.rows && category(r + 1, c) == Some(here) {
+                    pairs += 1;
+                }
+            }
+        }
+        pairs
+    }
+
+    #[test]
+    fn test_neutral_competitor_penalty_mixes_categories() {
+        // Spinach and celery (both leafy) and turnip (root) are mutually neutral.
+        let candidates = vec![
+            get_variety_by_id("spinach").unwrap(),
+            get_variety_by_id("celery").unwrap(),
+            get_variety_by_id("turnip").unwrap(),
+        ];
+        let fill = |penalty: i32| {
+            let mut grid = GardenGrid::new(1, 3);
+            let options = PlacementOptions {
+                neutral_competitor_penalty: penalty,
+                ..PlacementOptions::default()
+            };
+            let week = PlacementWeek {
+                rows: 1,
+                cols: 3,
+                week_idx: 0,
+                week_start: chrono::NaiveDate::from_ymd_opt(2025, 6, 2).unwrap(),
+                options: &options,
+                companions: &[],
+                deadline: None,
+                plant_budget: None,
+                trace: None,
+            };
+            fill_remaining_cells(&mut grid, &candidates, &week, |_, _, _| String::new());
+            grid
+        };
+
+        assert_eq!(
+            same_category_adjacent_pairs(&fill(0)),
+            2,
+            "every block ties, so top-priority spinach takes all three"
+        );
+        assert_eq!(
+            same_category_adjacent_pairs(&fill(1)),
+            0,
+            "turnip separates the two leafy plants"
+        );
+    }
 
     #[test]
     fn test_harvest_frees_cells_for_replanting() {
@@ -231,6 +1446,8 @@ mod tests {
             days_to_harvest: 7,
             estimated_harvest_date: chrono::NaiveDate::from_ymd_opt(2025, 6, 8).unwrap(),
             lifecycle: crate::domain::models::variety::Lifecycle::Annual,
+            category: crate::domain::models::variety::Category::Leafy,
+            placed_in_phase: PHASE_FILL,
         });
 
         harvest_plants(&mut grid, 1);
@@ -252,6 +1469,8 @@ mod tests {
             days_to_harvest: 7,
             estimated_harvest_date: chrono::NaiveDate::from_ymd_opt(2025, 6, 8).unwrap(),
             lifecycle: crate::domain::models::variety::Lifecycle::Perennial,
+            category: crate::domain::models::variety::Category::Leafy,
+            placed_in_phase: PHASE_FILL,
         });
 
         harvest_plants(&mut grid, 100);
@@ -1,23 +1,32 @@
 use chrono::{Datelike, Duration, NaiveDate};
 use std::collections::{HashMap, HashSet};
+use std::time::Instant;
 
 use crate::domain::models::{
+    error::PlanError,
     garden::GardenGrid,
-    request::{PlanParams, Preference, SownEntry},
-    response::{PlanResponse, SowingTask},
-    variety::{Month, Variety},
-    warnings::Warnings,
+    request::{LayoutCell, Period, PlanParams, Preference, SownEntry},
+    response::{PlanResponse, PlannedCell, SowingTask, UnplacedCandidate, UnplacedReason},
+    variety::{season_for_month, Month, Season, Variety},
+    warnings::{Language, Warnings},
 };
 use crate::domain::services::allocation::build_placement_queue;
-use crate::domain::services::filter::filter_varieties;
+use crate::domain::services::filter::{filter_varieties, is_shoulder_candidate, species_order};
 use crate::domain::services::grid::{
-    count_grid_occupancy, initialize_grid, validate_layout, GridOccupancy, GridSize,
+    apply_blocked_cells, apply_plantable_mask, count_grid_occupancy, fill_target, initialize_grid,
+    validate_fill_target, validate_layout, GridOccupancy, GridSize,
 };
-pub use crate::domain::services::helpers::{cell_span, CELL_SIZE_CM};
+use crate::domain::services::health::{companion_adjacencies, grid_companion_score, plan_health};
+pub use crate::domain::services::helpers::{cell_span, plants_per_cell, CELL_SIZE_CM};
 use crate::domain::services::placement::{
-    fill_remaining_cells, harvest_plants, place_candidates, PlacementWeek,
+    fill_remaining_cells, harvest_plants, place_candidates, PlacementTrace, PlacementWeek,
+    PlantBudget,
+};
+use crate::domain::services::regions::connected_regions;
+use crate::domain::services::repair::check_pre_placed_companions;
+use crate::domain::services::response::{
+    build_applied_request, build_reason, build_weekly_plan, merge_consecutive_plans,
 };
-use crate::domain::services::response::{build_reason, build_weekly_plan, merge_consecutive_plans};
 use crate::domain::services::schedule::weeks_for_period;
 
 /// One pre-germinated batch of a single variety ready to transplant on `plant_date`.
@@ -51,7 +60,7 @@ fn compute_sown_batches(sown: &[SownEntry], planning_start: NaiveDate) -> Vec<So
 /// For each planning week, returns the list of varieties to sow that week
 /// so they will be ready to transplant during a future planning week.
 fn compute_sowing_tasks_by_week(
-    weeks: &[crate::domain::models::request::Period],
+    weeks: &[Period],
     base_candidates: &[Variety],
     request: &PlanParams,
 ) -> Vec<Vec<SowingTask>> {
@@ -85,45 +94,396 @@ fn compute_sowing_tasks_by_week(
         .collect()
 }
 
+/// Season name as written in warnings.
+fn season_name(season: &Season, language: Language) -> String {
+    match language {
+        Language::English => format!("{season:?}"),
+        Language::French => match season {
+            Season::Spring => "printemps",
+            Season::Summer => "été",
+            Season::Autumn => "automne",
+            Season::Winter => "hiver",
+        }
+        .to_string(),
+    }
+}
+
 impl Warnings {
     /// Planner warning text when no week can be generated for the period.
-    fn no_weeks_to_plan() -> String {
-        "No weeks to plan in the provided date range.".to_string()
+    fn no_weeks_to_plan(language: Language) -> String {
+        match language {
+            Language::English => "No weeks to plan in the provided date range.".to_string(),
+            Language::French => "Aucune semaine à planifier sur la période indiquée.".to_string(),
+        }
     }
 
     /// Adds planner warning for an empty planning period.
     fn add_no_weeks_to_plan(&mut self) {
-        self.add(Self::no_weeks_to_plan());
+        self.add(Self::no_weeks_to_plan(self.language()));
     }
 
     /// Planner warning text when non-blocked cells remain empty.
-    fn empty_cells_not_filled(empty_cells: usize) -> String {
-        format!(
-            "{empty_cells} empty cell(s): not enough compatible varieties to fill the entire grid."
-        )
+    fn empty_cells_not_filled(language: Language, empty_cells: usize) -> String {
+        match language {
+            Language::English => format!(
+                "{empty_cells} empty cell(s): not enough compatible varieties to fill the entire grid."
+            ),
+            Language::French => format!(
+                "{empty_cells} case(s) vide(s) : pas assez de variétés compatibles pour remplir toute la grille."
+            ),
+        }
+    }
+
+    /// Adds planner warning for a preferred variety that no planning week's calendar allows.
+    fn add_preference_out_of_season(&mut self, id: &str, seasons: &[Season]) {
+        let seasons = seasons
+            .iter()
+            .map(|s| season_name(s, self.language()))
+            .collect::<Vec<_>>()
+            .join("/");
+        self.add(match self.language() {
+            Language::English => format!("Preferred variety '{id}' is not available in {seasons}."),
+            Language::French => {
+                format!("La variété préférée '{id}' n'est pas disponible en {seasons}.")
+            }
+        });
+    }
+
+    /// Adds planner warning for a preference id that matches no variety.
+    fn add_unknown_preference(&mut self, id: &str) {
+        self.add(match self.language() {
+            Language::English => {
+                format!("Unknown vegetable '{id}' in preferences; it was ignored.")
+            }
+            Language::French => {
+                format!("Légume inconnu '{id}' dans les préférences ; il a été ignoré.")
+            }
+        });
+    }
+
+    /// Adds planner warning for a preferred variety ruled out by the sun, soil, region,
+    /// level or exclusion filters and never planted.
+    fn add_preference_unavailable(&mut self, id: &str) {
+        self.add(match self.language() {
+            Language::English => {
+                format!("Preferred '{id}' is not available for these constraints.")
+            }
+            Language::French => {
+                format!("La préférence '{id}' n'est pas disponible avec ces contraintes.")
+            }
+        });
+    }
+
+    /// Adds planner warning for a fill phase cut short by the planning time budget.
+    fn add_stopped_early(&mut self, budget: std::time::Duration) {
+        let ms = budget.as_millis();
+        self.add(match self.language() {
+            Language::English => {
+                format!("Planning stopped early after {ms}ms; the grid may be partially filled.")
+            }
+            Language::French => format!(
+                "Planification interrompue après {ms}ms ; la grille peut être partiellement remplie."
+            ),
+        });
+    }
+
+    /// Adds planner warning for placements turned down by `max_total_plants`.
+    fn add_plant_budget_reached(&mut self, max_total_plants: u32) {
+        self.add(match self.language() {
+            Language::English => format!(
+                "Plant budget of {max_total_plants} reached; remaining cells were left empty."
+            ),
+            Language::French => format!(
+                "Budget de {max_total_plants} plant(s) atteint ; les cases restantes sont laissées vides."
+            ),
+        });
+    }
+
+    /// Adds planner warning for explicit quantities cut short because the grid filled up.
+    fn add_quantities_truncated(&mut self, ids: &[String]) {
+        let ids = ids
+            .iter()
+            .map(|id| format!("'{id}'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.add(match self.language() {
+            Language::English => {
+                format!("Grid is full: requested quantities could not be placed for {ids}.")
+            }
+            Language::French => format!(
+                "Grille pleine : les quantités demandées n'ont pas pu être placées pour {ids}."
+            ),
+        });
+    }
+
+    /// Adds planner warning for an explicit quantity larger than the grid can ever hold.
+    fn add_quantity_capped(&mut self, id: &str, requested: u32, capacity: u32) {
+        self.add(match self.language() {
+            Language::English => format!(
+                "Requested quantity {requested} for '{id}' exceeds the grid capacity; capped at {capacity}."
+            ),
+            Language::French => format!(
+                "La quantité demandée {requested} pour '{id}' dépasse la capacité de la grille ; ramenée à {capacity}."
+            ),
+        });
+    }
+
+    /// Adds planner warning for preferences dropped because every cell is blocked.
+    fn add_no_plantable_cells(&mut self, ignored: usize) {
+        self.add(match self.language() {
+            Language::English => {
+                format!("No plantable cells available; {ignored} preference(s) ignored.")
+            }
+            Language::French => {
+                format!("Aucune case plantable disponible ; {ignored} préférence(s) ignorée(s).")
+            }
+        });
+    }
+
+    /// Adds planner warning for an explicit preference whose block can never fit the grid.
+    fn add_block_exceeds_grid(&mut self, id: &str, span: usize, rows: usize, cols: usize) {
+        self.add(match self.language() {
+            Language::English => format!(
+                "'{id}' needs a {span}×{span} block but the grid is {rows}×{cols}; it was not placed."
+            ),
+            Language::French => format!(
+                "'{id}' demande un bloc de {span}×{span} mais la grille fait {rows}×{cols} ; il n'a pas été placé."
+            ),
+        });
+    }
+
+    /// Adds planner warning for `near` hints naming no known variety.
+    fn add_unknown_near(&mut self, ids: &[String]) {
+        let ids = ids
+            .iter()
+            .map(|id| format!("'{id}'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.add(match self.language() {
+            Language::English => format!("Unknown id(s) in 'near' ignored: {ids}."),
+            Language::French => {
+                format!("Identifiant(s) inconnu(s) dans 'near' ignoré(s) : {ids}.")
+            }
+        });
     }
 }
 
-fn empty_cells_warning(grid: &GardenGrid) -> Option<String> {
-    let empty = grid
+/// Drops the explicit preferences whose `span x span` block is larger than the grid in
+/// either dimension — no placement could ever succeed — warning for each of them.
+fn drop_oversized(
+    preferences: &[Preference],
+    rows: usize,
+    cols: usize,
+    warnings: &mut Warnings,
+) -> Vec<Preference> {
+    preferences
+        .iter()
+        .filter(|p| {
+            let span = cell_span(p.variety.spacing_cm) as usize;
+            let fits = span <= rows && span <= cols;
+            if !fits && p.is_explicit() {
+                warnings.add_block_exceeds_grid(&p.variety.id, span, rows, cols);
+            }
+            fits || !p.is_explicit()
+        })
+        .cloned()
+        .collect()
+}
+
+/// Caps each explicit quantity at the grid's `plantable` cell count — no grid can hold
+/// more plants than it has cells — warning for every capped preference. Quantities that
+/// fit the grid size but not the free space are left to the truncation warning.
+fn cap_quantities(
+    preferences: &[Preference],
+    plantable: usize,
+    warnings: &mut Warnings,
+) -> Vec<Preference> {
+    let capacity = u32::try_from(plantable).unwrap_or(u32::MAX).max(1);
+    preferences
+        .iter()
+        .map(|p| {
+            let quantity = p.quantity.map(|q| {
+                if q > capacity {
+                    warnings.add_quantity_capped(&p.variety.id, q, capacity);
+                }
+                q.min(capacity)
+            });
+            Preference {
+                variety: p.variety.clone(),
+                quantity,
+                area_m2: p.area_m2,
+            }
+        })
+        .collect()
+}
+
+/// Preferences as placed by Phase 1. Without a fill phase a preference lacking an
+/// explicit `quantity` or `area_m2` would never be placed, so it gets one plant instead.
+fn explicit_preferences(request: &PlanParams) -> Vec<Preference> {
+    request
+        .preferences
+        .iter()
+        .map(|p| Preference {
+            variety: p.variety.clone(),
+            quantity: p
+                .quantity
+                .or((request.placement.preferences_only && p.area_m2.is_none()).then_some(1)),
+            area_m2: p.area_m2,
+        })
+        .collect()
+}
+
+/// Ids of preferences with an explicit quantity that have fewer plants on the grid
+/// than requested while no free cell is left. Empty when the grid still has room.
+fn truncated_preferences<'p>(grid: &GardenGrid, preferences: &'p [Preference]) -> Vec<&'p str> {
+    let has_free_cell = grid
         .cells
         .iter()
-        .flat_map(|r| r.iter())
-        .filter(|c| c.variety.is_none() && !c.blocked)
-        .count();
-    (empty > 0).then(|| Warnings::empty_cells_not_filled(empty))
+        .flatten()
+        .any(|c| c.variety.is_none() && !c.blocked && !c.reserved);
+    if has_free_cell {
+        return Vec::new();
+    }
+    preferences
+        .iter()
+        .filter_map(|p| p.quantity.map(|q| (p, q as usize)))
+        .filter(|(p, quantity)| {
+            let planted = grid
+                .cells
+                .iter()
+                .enumerate()
+                .flat_map(|(r, row)| row.iter().enumerate().map(move |(c, cell)| (r, c, cell)))
+                .filter(|(r, c, cell)| {
+                    cell.variety.as_ref().is_some_and(|v| {
+                        v.id == p.variety.id && v.anchor.row == *r && v.anchor.col == *c
+                    })
+                })
+                .count();
+            planted < *quantity
+        })
+        .map(|(p, _)| p.variety.id.as_str())
+        .collect()
+}
+
+/// Considered candidates that were never planted, in species order, each with the most
+/// specific reason: a block too large for the grid, `fill: false`, no free cell left
+/// in any week it was in season, or otherwise losing the free cells to other plants.
+fn unplaced_candidates(
+    considered: &[Variety],
+    placed_ids: &HashSet<String>,
+    had_room: &HashSet<String>,
+    GridSize(rows, cols): GridSize,
+    preferences_only: bool,
+) -> Vec<UnplacedCandidate> {
+    let mut unplaced: Vec<UnplacedCandidate> = considered
+        .iter()
+        .filter(|v| !placed_ids.contains(&v.id))
+        .map(|v| {
+            let span = cell_span(v.spacing_cm) as usize;
+            let reason = if span > rows || span > cols {
+                UnplacedReason::SpanTooLarge
+            } else if preferences_only {
+                UnplacedReason::FillDisabled
+            } else if !had_room.contains(&v.id) {
+                UnplacedReason::GridFull
+            } else {
+                UnplacedReason::Outcompeted
+            };
+            UnplacedCandidate {
+                id: v.id.clone(),
+                name: v.name.clone(),
+                reason,
+            }
+        })
+        .collect();
+    unplaced.sort_by(|a, b| species_order(&a.id, &b.id));
+    unplaced
+}
+
+/// Every variety the planner may have put on the grid, by id: the candidates, the
+/// layout's pre-placed plants and the sown batches.
+fn known_varieties<'v>(
+    candidates: &'v [Variety],
+    request: &'v PlanParams,
+) -> HashMap<&'v str, &'v Variety> {
+    let layout = request
+        .layout
+        .iter()
+        .flatten()
+        .filter_map(|cell| match cell {
+            LayoutCell::SelfContained { variety, .. } | LayoutCell::Overflowing { variety, .. } => {
+                Some(variety)
+            }
+            _ => None,
+        });
+    let sown = request.sown.iter().map(|s| &s.variety);
+    candidates
+        .iter()
+        .chain(layout)
+        .chain(sown)
+        .map(|v| (v.id.as_str(), v))
+        .collect()
+}
+
+/// Companion points per occupied cell and week, `0` when nothing was ever planted.
+fn normalized_score(total_score: i64, occupied_cell_weeks: usize) -> f32 {
+    if occupied_cell_weeks == 0 {
+        0.0
+    } else {
+        total_score as f32 / occupied_cell_weeks as f32
+    }
+}
+
+/// Empty cells beyond those intentionally left free by `target_fill_pct` are reported.
+fn empty_cells_warning(
+    grid: &GardenGrid,
+    target_fill_pct: Option<u8>,
+    language: Language,
+) -> Option<String> {
+    let occupancy = count_grid_occupancy(grid);
+    let GridOccupancy(occupied, ..) = occupancy;
+    let plantable = occupancy.plantable(grid);
+    let intended = fill_target(grid, target_fill_pct).unwrap_or(plantable);
+    let empty = intended.saturating_sub(occupied);
+    (empty > 0).then(|| Warnings::empty_cells_not_filled(language, empty))
 }
 
 /// Returns a warning string when non-blocked cells remain unplanted, otherwise `None`.
 pub fn plan_garden(
     base_candidates: Vec<Variety>,
     request: &PlanParams,
-) -> Result<PlanResponse, String> {
-    let mut warnings = Warnings::new();
+) -> Result<PlanResponse, PlanError> {
+    plan_garden_with_grid(base_candidates, request).map(|(plan, _)| plan)
+}
+
+/// [`plan_garden`], also returning the internal grid as it stands after the last week.
+pub fn plan_garden_with_grid(
+    base_candidates: Vec<Variety>,
+    request: &PlanParams,
+) -> Result<(PlanResponse, GardenGrid), PlanError> {
+    plan_garden_traced(base_candidates, request, None)
+}
+
+/// [`plan_garden_with_grid`], recording every placement decision into `trace` when set.
+pub fn plan_garden_traced(
+    base_candidates: Vec<Variety>,
+    request: &PlanParams,
+    trace: Option<&PlacementTrace>,
+) -> Result<(PlanResponse, GardenGrid), PlanError> {
+    let mut warnings = Warnings::localized(request.language);
+    let deadline = request
+        .placement
+        .time_budget
+        .map(|budget| Instant::now() + budget);
 
     let weeks = weeks_for_period(&request.period, &mut warnings);
+    let applied_period = weeks.first().zip(weeks.last()).map(|(first, last)| Period {
+        start: first.start,
+        end: last.end,
+    });
 
     let GridSize(rows, cols) = validate_layout(&request.layout)?;
+    validate_fill_target(request.placement.target_fill_pct)?;
 
     let planning_start = weeks
         .first()
@@ -138,12 +498,52 @@ pub fn plan_garden(
         rows,
         cols,
         &request.layout,
+        &request.blocked_regions,
         planning_start,
         &request.region,
         &mut warnings,
     );
-    let preferences = &request.preferences;
+    apply_blocked_cells(&mut grid, &request.blocked_cells, &mut warnings);
+    if let Some(mask) = &request.plantable_mask {
+        apply_plantable_mask(&mut grid, mask, &mut warnings);
+    }
+    for id in &request.unknown_preferences {
+        warnings.add_unknown_preference(id);
+    }
+    if !request.unknown_near.is_empty() {
+        warnings.add_unknown_near(&request.unknown_near);
+    }
+    let suggestions =
+        check_pre_placed_companions(&grid, &request.layout, request.suggest_fixes, &mut warnings);
+    let plantable = count_grid_occupancy(&grid).plantable(&grid);
+    let preferences = &if plantable == 0 {
+        if !request.preferences.is_empty() {
+            warnings.add_no_plantable_cells(request.preferences.len());
+        }
+        Vec::new()
+    } else {
+        cap_quantities(&explicit_preferences(request), plantable, &mut warnings)
+    };
+    let placeable = drop_oversized(preferences, rows, cols, &mut warnings);
     let mut weekly_plans = Vec::with_capacity(weeks.len());
+    // Seasons covered by the period and ids that were in season for at least one week,
+    // used to explain preferences that were silently filtered out by the calendar.
+    let mut seasons: Vec<Season> = Vec::new();
+    let mut in_season_ids: HashSet<String> = HashSet::new();
+    // Candidates admitted only through `season_overlap`.
+    let mut shoulder_ids: Vec<String> = Vec::new();
+    // Preferences whose explicit quantity was cut short by a full grid, in first-seen order.
+    let mut truncated_ids: Vec<String> = Vec::new();
+    // Candidates in season for at least one week, in first-seen order, the ones that had
+    // free cells to compete for, and every species planted in any week.
+    let mut considered: Vec<Variety> = Vec::new();
+    let mut had_room: HashSet<String> = HashSet::new();
+    let mut placed_ids: HashSet<String> = HashSet::new();
+    // Weekly garden scores and occupied cells, summed over the whole period.
+    let (mut total_score, mut occupied_cell_weeks) = (0i64, 0usize);
+    let known = known_varieties(&base_candidates, request);
+    let mut stopped_early = false;
+    let plant_budget = request.placement.max_total_plants.map(PlantBudget::new);
 
     for (week_idx, (week, sowing_tasks)) in weeks.into_iter().zip(sowing_tasks_by_week).enumerate()
     {
@@ -151,15 +551,30 @@ pub fn plan_garden(
         harvest_plants(&mut grid, week_idx);
 
         // Filter candidates for the current week's month.
-        let week_candidates = filter_varieties(
-            &base_candidates,
-            request,
-            Month::from_u32(week.start.month()),
-        );
+        let month = Month::from_u32(week.start.month());
+        let week_candidates = filter_varieties(&base_candidates, request, month);
+        if request.season_overlap {
+            for v in &week_candidates {
+                if is_shoulder_candidate(v, &request.region, month) && !shoulder_ids.contains(&v.id)
+                {
+                    shoulder_ids.push(v.id.clone());
+                }
+            }
+        }
+        let season = season_for_month(week.start.month());
+        if !seasons.contains(&season) {
+            seasons.push(season);
+        }
+        in_season_ids.extend(week_candidates.iter().map(|v| v.id.clone()));
+        for v in &week_candidates {
+            if !considered.iter().any(|c| c.id == v.id) {
+                considered.push(v.clone());
+            }
+        }
 
-        let GridOccupancy(occupied, blocked_count) = count_grid_occupancy(&grid);
-        let available_cells = (rows * cols).saturating_sub(blocked_count);
-        let free_cells = available_cells.saturating_sub(occupied);
+        let occupancy = count_grid_occupancy(&grid);
+        let GridOccupancy(occupied, ..) = occupancy;
+        let free_cells = occupancy.plantable(&grid).saturating_sub(occupied);
 
         // Aggregate all sown batches whose plant_date has arrived by this week.
         let mut active_sown_counts: HashMap<String, u32> = HashMap::new();
@@ -175,16 +590,19 @@ pub fn plan_garden(
             }
         }
 
-        // Sown preferences come before regular preferences for placement priority.
-        let sown_prefs: Vec<Preference> = active_sown_counts
+        // Sown preferences come before regular preferences for placement priority,
+        // ordered like any species list (French consumption rank, then id).
+        let mut sown_prefs: Vec<Preference> = active_sown_counts
             .into_iter()
             .filter_map(|(id, count)| {
                 sown_variety_map.remove(&id).map(|variety| Preference {
                     variety,
                     quantity: Some(count),
+                    area_m2: None,
                 })
             })
             .collect();
+        sown_prefs.sort_by(|a, b| species_order(&a.variety.id, &b.variety.id));
 
         // Sown varieties ready this week that didn't pass the calendar filter — bypass it.
         let sown_extra: Vec<Variety> = sown_prefs
@@ -197,10 +615,10 @@ pub fn plan_garden(
             week_candidates.iter().cloned().chain(sown_extra).collect();
         let combined_prefs: Vec<Preference> = sown_prefs
             .into_iter()
-            .chain(preferences.iter().cloned())
+            .chain(placeable.iter().cloned())
             .collect();
 
-        let week_score = if free_cells > 0 && !extended_candidates.is_empty() {
+        if free_cells > 0 && !extended_candidates.is_empty() {
             // Phase 1: place varieties with an explicit quantity (in preference order).
             let (queue, placements_map) = build_placement_queue(&combined_prefs, free_cells);
             let pw = PlacementWeek {
@@ -208,32 +626,136 @@ pub fn plan_garden(
                 cols,
                 week_idx,
                 week_start: week.start,
+                options: &request.placement,
+                companions: &extended_candidates,
+                deadline,
+                plant_budget: plant_budget.as_ref(),
+                trace,
             };
             let score_p1 = place_candidates(&mut grid, &queue, &placements_map, &pw, build_reason);
+            for id in truncated_preferences(&grid, &combined_prefs) {
+                if !truncated_ids.iter().any(|t| t == id) {
+                    truncated_ids.push(id.to_owned());
+                }
+            }
+
+            let GridOccupancy(occupied_p1, ..) = count_grid_occupancy(&grid);
+            if occupancy.plantable(&grid) > occupied_p1 {
+                had_room.extend(week_candidates.iter().map(|v| v.id.clone()));
+            }
 
             // Phase 2: iteratively fill every remaining free cell.
-            let score_p2 = fill_remaining_cells(&mut grid, &extended_candidates, &pw, build_reason);
+            let score_p2 = if request.placement.preferences_only {
+                0
+            } else {
+                let score =
+                    fill_remaining_cells(&mut grid, &extended_candidates, &pw, build_reason);
+                stopped_early |= pw.past_deadline();
+                score
+            };
 
-            score_p1 + score_p2
-        } else {
-            0
-        };
+            log::debug!(
+                "Week {week_idx}: phase 1 placements scored {score_p1}, phase 2 {score_p2}"
+            );
+        }
+
+        // The placement sums above depend on placement order; the reported score is
+        // recomputed from the finished grid.
+        let week_score = grid_companion_score(&grid, &known);
+        placed_ids.extend(
+            grid.cells
+                .iter()
+                .flatten()
+                .filter_map(|c| c.variety.as_ref().map(|v| v.id.clone())),
+        );
+        let GridOccupancy(occupied_after, ..) = count_grid_occupancy(&grid);
+        occupied_cell_weeks += occupied_after;
+        total_score += i64::from(week_score);
 
         weekly_plans.push(build_weekly_plan(week, &grid, week_score, sowing_tasks));
     }
 
+    for pref in preferences {
+        let id = &pref.variety.id;
+        let passes_other_filters = base_candidates.iter().any(|v| &v.id == id);
+        if passes_other_filters && !seasons.is_empty() && !in_season_ids.contains(id) {
+            warnings.add_preference_out_of_season(id, &seasons);
+        } else if !passes_other_filters && !placed_ids.contains(id) {
+            warnings.add_preference_unavailable(id);
+        }
+    }
+
+    if let Some(budget) = request.placement.time_budget.filter(|_| stopped_early) {
+        warnings.add_stopped_early(budget);
+    }
+
+    if !truncated_ids.is_empty() {
+        warnings.add_quantities_truncated(&truncated_ids);
+    }
+
+    let budget_exhausted = plant_budget.as_ref().is_some_and(PlantBudget::exhausted);
+    if let Some(max) = request
+        .placement
+        .max_total_plants
+        .filter(|_| budget_exhausted)
+    {
+        warnings.add_plant_budget_reached(max);
+    }
+
     if weekly_plans.is_empty() {
         warnings.add_no_weeks_to_plan();
-    } else {
-        warnings.add_optional(empty_cells_warning(&grid));
+    } else if !request.placement.preferences_only && !budget_exhausted {
+        // Cells left empty by an exhausted plant budget are explained by its own warning.
+        warnings.add_optional(empty_cells_warning(
+            &grid,
+            request.placement.target_fill_pct,
+            request.language,
+        ));
     }
 
     let weekly_plans = merge_consecutive_plans(weekly_plans);
+    let empty_regions = weekly_plans.last().map_or_else(Vec::new, |plan| {
+        connected_regions(&plan.grid, |cell| *cell == PlannedCell::Empty)
+    });
 
-    Ok(PlanResponse {
+    shoulder_ids.sort_by(|a, b| species_order(a, b));
+    let health = plan_health(&grid, &known);
+    let adjacencies = companion_adjacencies(&grid, &known);
+    let unplaced_candidates = unplaced_candidates(
+        &considered,
+        &placed_ids,
+        &had_room,
+        GridSize(rows, cols),
+        request.placement.preferences_only,
+    );
+    let occupancy = count_grid_occupancy(&grid);
+    let GridOccupancy(_, blocked_cells, _) = occupancy;
+    let plantable_cells = occupancy.plantable(&grid);
+    let plan = PlanResponse {
+        id: None,
         rows,
         cols,
+        plantable_cells,
+        blocked_cells,
+        usable_area_m2: (plantable_cells as u32 * CELL_SIZE_CM.pow(2)) as f32 / 10_000.0,
         weeks: weekly_plans,
+        normalized_score: normalized_score(total_score, occupied_cell_weeks),
         warnings: warnings.into_vec(),
-    })
+        suggestions,
+        applied: build_applied_request(
+            request,
+            (rows, cols),
+            applied_period,
+            seasons,
+            preferences,
+            shoulder_ids,
+        ),
+        empty_regions,
+        health,
+        adjacencies,
+        unplaced_candidates,
+        no_candidates: None,
+        deterministic: !stopped_early && request.period.is_some(),
+    };
+    Ok((plan, grid))
 }
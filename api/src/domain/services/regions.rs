@@ -0,0 +1,101 @@
+use std::collections::VecDeque;
+
+use crate::domain::models::{response::GridRegion, Coordinate, Matrix};
+
+/// Groups the cells of `grid` matching `include` into orthogonally connected regions
+/// (flood fill), sorted by decreasing cell count, then by top-left cell in row-major order.
+pub fn connected_regions<T>(grid: &Matrix<T>, include: impl Fn(&T) -> bool) -> Vec<GridRegion> {
+    let mut seen: Vec<Vec<bool>> = grid.iter().map(|row| vec![false; row.len()]).collect();
+    let mut regions = Vec::new();
+
+    for (r, row) in grid.iter().enumerate() {
+        for (c, cell) in row.iter().enumerate() {
+            if seen[r][c] || !include(cell) {
+                continue;
+            }
+            seen[r][c] = true;
+            let (mut min_r, mut max_r, mut min_c, mut max_c) = (r, r, c, c);
+            let mut cell_count = 0;
+            let mut queue = VecDeque::from([(r, c)]);
+            while let Some((cr, cc)) = queue.pop_front() {
+                cell_count += 1;
+                min_r = min_r.min(cr);
+                max_r = max_r.max(cr);
+                min_c = min_c.min(cc);
+                max_c = max_c.max(cc);
+                let neighbours = [
+                    cr.checked_sub(1).map(|nr| (nr, cc)),
+                    Some((cr + 1, cc)),
+                    cc.checked_sub(1).map(|nc| (cr, nc)),
+                    Some((cr, cc + 1)),
+                ];
+                for (nr, nc) in neighbours.into_iter().flatten() {
+                    let Some(neighbour) = grid.get(nr).and_then(|row| row.get(nc)) else {
+                        continue;
+                    };
+                    if !seen[nr][nc] && include(neighbour) {
+                        seen[nr][nc] = true;
+                        queue.push_back((nr, nc));
+                    }
+                }
+            }
+            regions.push(GridRegion {
+                top_left: Coordinate {
+                    row: min_r,
+                    col: min_c,
+                },
+                width: max_c - min_c + 1,
+                height: max_r - min_r + 1,
+                cell_count,
+            });
+        }
+    }
+
+    regions.sort_by(|a, b| {
+        b.cell_count
+            .cmp(&a.cell_count)
+            .then_with(|| (a.top_left.row, a.top_left.col).cmp(&(b.top_left.row, b.top_left.col)))
+    });
+    regions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isolated_pockets_are_separate_regions() {
+        // `true` = empty. An L-shaped pocket top-left and a single cell bottom-right,
+        // separated by a diagonal-only contact.
+        let grid = vec![
+            vec![true, true, false, false],
+            vec![true, false, false, false],
+            vec![false, false, false, false],
+            vec![false, false, false, true],
+        ];
+        let regions = connected_regions(&grid, |empty| *empty);
+        assert_eq!(
+            regions,
+            vec![
+                GridRegion {
+                    top_left: Coordinate { row: 0, col: 0 },
+                    width: 2,
+                    height: 2,
+                    cell_count: 3,
+                },
+                GridRegion {
+                    top_left: Coordinate { row: 3, col: 3 },
+                    width: 1,
+                    height: 1,
+                    cell_count: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diagonal_cells_are_not_connected() {
+        let grid = vec![vec![true, false], vec![false, true]];
+        assert_eq!(connected_regions(&grid, |empty| *empty).len(), 2);
+    }
+}
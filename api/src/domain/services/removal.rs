@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use crate::domain::models::{
+    garden::{GardenGrid, PlacedVariety},
+    response::{NeighborImpact, RemovedPlant, WhatIfResponse},
+    variety::Variety,
+    Coordinate,
+};
+use crate::domain::services::{companion::companion_score, response::build_grid_cells};
+
+/// Companion score of `plant` against the plants around its block; `0` when its variety
+/// is not in `varieties`.
+fn plant_score(
+    grid: &GardenGrid,
+    plant: &PlacedVariety,
+    varieties: &HashMap<String, Variety>,
+) -> i32 {
+    let Some(variety) = varieties.get(&plant.id) else {
+        return 0;
+    };
+    let neighbors: Vec<&str> = grid
+        .get_block_neighbors(plant.anchor, plant.span as usize)
+        .iter()
+        .map(|n| n.vegetable_id.as_str())
+        .collect();
+    companion_score(&variety.vegetable, &neighbors)
+}
+
+/// Every plant's anchor, variety id and companion score.
+fn plant_scores(
+    grid: &GardenGrid,
+    varieties: &HashMap<String, Variety>,
+) -> Vec<(Coordinate, String, i32)> {
+    grid.anchored_plants()
+        .into_iter()
+        .map(|p| (p.anchor, p.id.clone(), plant_score(grid, p, varieties)))
+        .collect()
+}
+
+/// Anchor of the plant covering `cell`, if any.
+pub fn anchor_at(grid: &GardenGrid, cell: Coordinate) -> Option<Coordinate> {
+    grid.cells
+        .get(cell.row)?
+        .get(cell.col)?
+        .variety
+        .as_ref()
+        .map(|v| v.anchor)
+}
+
+/// Anchors of every plant of variety `id`, in row-major order.
+pub fn anchors_of(grid: &GardenGrid, id: &str) -> Vec<Coordinate> {
+    grid.anchored_plants()
+        .into_iter()
+        .filter(|p| p.id == id)
+        .map(|p| p.anchor)
+        .collect()
+}
+
+/// Removes the plants anchored at `anchors` and reports how the layout's companion score
+/// — every plant's [`companion_score`] against its block neighbours, summed — changes,
+/// along with the remaining plants whose own score moves. Nothing is re-planned.
+pub fn simulate_removal(
+    grid: &mut GardenGrid,
+    anchors: &[Coordinate],
+    varieties: &HashMap<String, Variety>,
+) -> WhatIfResponse {
+    let before = plant_scores(grid, varieties);
+    let removed = grid
+        .anchored_plants()
+        .into_iter()
+        .filter(|p| anchors.contains(&p.anchor))
+        .map(|p| RemovedPlant {
+            id: p.id.clone(),
+            anchor: p.anchor,
+        })
+        .collect();
+    for cell in grid.cells.iter_mut().flatten() {
+        if cell
+            .variety
+            .as_ref()
+            .is_some_and(|v| anchors.contains(&v.anchor))
+        {
+            cell.variety = None;
+        }
+    }
+    let after = plant_scores(grid, varieties);
+
+    let mut affected: Vec<NeighborImpact> = after
+        .iter()
+        .filter_map(|(anchor, id, score)| {
+            let (_, _, was) = before.iter().find(|(a, _, _)| a == anchor)?;
+            (was != score).then(|| NeighborImpact {
+                id: id.clone(),
+                anchor: *anchor,
+                before: *was,
+                after: *score,
+            })
+        })
+        .collect();
+    affected.sort_by_key(|impact| impact.after - impact.before);
+
+    let score_before = before.iter().map(|(_, _, s)| s).sum();
+    let score_after = after.iter().map(|(_, _, s)| s).sum();
+    WhatIfResponse {
+        removed,
+        score_before,
+        score_after,
+        score_delta: score_after - score_before,
+        affected,
+        grid: build_grid_cells(grid),
+    }
+}
@@ -0,0 +1,408 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+
+use crate::domain::models::{
+    garden::{GardenGrid, PHASE_PRE_PLACED},
+    request::LayoutCell,
+    response::{
+        BulkPlacementOutcome, BulkRejection, PlacementConflict, PlacementPreviewResponse,
+        RelocationSuggestion,
+    },
+    variety::Variety,
+    warnings::{Language, Warnings},
+    Coordinate,
+};
+use crate::domain::services::companion::{companion_score, is_compatible};
+use crate::domain::services::helpers::cell_span;
+use crate::domain::services::placement::fill_block;
+use crate::domain::services::requirements::blocks_touch;
+
+/// A plant present in the submitted layout, identified by its anchor cell.
+struct PrePlaced<'a> {
+    anchor: Coordinate,
+    span: usize,
+    variety: &'a Variety,
+}
+
+impl Warnings {
+    /// Adds planner warning for two pre-placed plants that are bad companions.
+    fn add_bad_adjacency(&mut self, a: &PrePlaced, b: &PrePlaced) {
+        let (a_id, ar, ac) = (&a.variety.id, a.anchor.row, a.anchor.col);
+        let (b_id, br, bc) = (&b.variety.id, b.anchor.row, b.anchor.col);
+        self.add(match self.language() {
+            Language::English => format!(
+                "Pre-placed '{a_id}' at [{ar},{ac}] is next to its bad companion '{b_id}' at [{br},{bc}]."
+            ),
+            Language::French => format!(
+                "'{a_id}' déjà en place en [{ar},{ac}] est voisin de son mauvais compagnon '{b_id}' en [{br},{bc}]."
+            ),
+        });
+    }
+}
+
+/// Anchors of the layout's pre-placed plants, with the span they were given in `grid`.
+fn pre_placed<'a>(grid: &GardenGrid, layout: &'a [Vec<LayoutCell>]) -> Vec<PrePlaced<'a>> {
+    let mut plants = Vec::new();
+    for (r, row) in layout.iter().enumerate() {
+        for (c, cell) in row.iter().enumerate() {
+            let variety = match cell {
+                LayoutCell::SelfContained { variety, .. }
+                | LayoutCell::Overflowing { variety, .. } => variety,
+                _ => continue,
+            };
+            let span = grid.cells[r][c].variety.as_ref().map_or(1, |v| v.span) as usize;
+            plants.push(PrePlaced {
+                anchor: Coordinate { row: r, col: c },
+                span,
+                variety,
+            });
+        }
+    }
+    plants
+}
+
+/// Index pairs of pre-placed plants that touch while being bad companions, in layout order.
+fn bad_adjacencies(plants: &[PrePlaced]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for (i, a) in plants.iter().enumerate() {
+        for (j, b) in plants.iter().enumerate().skip(i + 1) {
+            if blocks_touch(a.anchor, a.span, b.anchor, b.span)
+                && !is_compatible(&a.variety.vegetable, &b.variety.vegetable)
+            {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
+
+/// Nearest (Manhattan distance between anchors, then row-major) free block for `plant`
+/// whose pre-placed neighbours are all compatible with it and which does not overlap
+/// a block already proposed in `reserved`.
+fn nearest_safe_block(
+    grid: &GardenGrid,
+    plants: &[PrePlaced],
+    plant: &PrePlaced,
+    reserved: &[(Coordinate, usize)],
+) -> Option<Coordinate> {
+    let span = plant.span;
+    let mut best: Option<(usize, Coordinate)> = None;
+    for r in 0..=grid.rows.saturating_sub(span) {
+        for c in 0..=grid.cols.saturating_sub(span) {
+            let coordinate = Coordinate { row: r, col: c };
+            if !grid.is_block_free(r, c, span) {
+                continue;
+            }
+            let clashes_reserved = reserved.iter().any(|&(anchor, s)| {
+                r < anchor.row + s
+                    && anchor.row < r + span
+                    && c < anchor.col + s
+                    && anchor.col < c + span
+            });
+            let safe = grid
+                .get_block_neighbors(coordinate, span)
+                .into_iter()
+                .filter(|n| n.anchor != plant.anchor)
+                .filter_map(|n| plants.iter().find(|p| p.anchor == n.anchor))
+                .all(|n| is_compatible(&plant.variety.vegetable, &n.variety.vegetable));
+            if clashes_reserved || !safe {
+                continue;
+            }
+            let distance = r.abs_diff(plant.anchor.row) + c.abs_diff(plant.anchor.col);
+            if best.is_none_or(|(d, _)| distance < d) {
+                best = Some((distance, coordinate));
+            }
+        }
+    }
+    best.map(|(_, coordinate)| coordinate)
+}
+
+/// Warns about every pair of pre-placed bad companions in `layout` and, when `suggest`
+/// is set, proposes for each pair a free block to move one of them to.
+///
+/// `grid` must be the freshly initialised grid (only pre-placed plants on it). The
+/// smaller plant is moved (the later one on a tie), each plant at most once, and
+/// suggestions never target the same cells. Nothing is applied to the grid.
+pub fn check_pre_placed_companions(
+    grid: &GardenGrid,
+    layout: &[Vec<LayoutCell>],
+    suggest: bool,
+    warnings: &mut Warnings,
+) -> Vec<RelocationSuggestion> {
+    let plants = pre_placed(grid, layout);
+    let mut moved: Vec<usize> = Vec::new();
+    let mut reserved: Vec<(Coordinate, usize)> = Vec::new();
+    let mut suggestions = Vec::new();
+
+    for (i, j) in bad_adjacencies(&plants) {
+        warnings.add_bad_adjacency(&plants[i], &plants[j]);
+        if !suggest || moved.contains(&i) || moved.contains(&j) {
+            continue;
+        }
+        let (mover, other) = if plants[i].span < plants[j].span {
+            (i, j)
+        } else {
+            (j, i)
+        };
+        if let Some(to) = nearest_safe_block(grid, &plants, &plants[mover], &reserved) {
+            moved.push(mover);
+            reserved.push((to, plants[mover].span));
+            suggestions.push(RelocationSuggestion {
+                id: plants[mover].variety.id.clone(),
+                from: plants[mover].anchor,
+                to,
+                conflicts_with: plants[other].variety.id.clone(),
+            });
+        }
+    }
+    suggestions
+}
+
+/// Previews placing `variety` with its top-left cell at `anchor`, next to the plants of
+/// `layout` already on `grid` (the freshly initialised grid). The block must lie
+/// inside the grid; nothing is applied to it.
+pub fn preview_placement(
+    grid: &GardenGrid,
+    layout: &[Vec<LayoutCell>],
+    variety: &Variety,
+    anchor: Coordinate,
+) -> PlacementPreviewResponse {
+    let span = cell_span(variety.spacing_cm);
+    let neighbors = grid.get_block_neighbors(anchor, span as usize);
+    let neighbor_ids: Vec<&str> = neighbors.iter().map(|n| n.vegetable_id.as_str()).collect();
+    let score_delta = companion_score(&variety.vegetable, &neighbor_ids);
+
+    let plants = pre_placed(grid, layout);
+    let mut conflicts: Vec<PlacementConflict> = Vec::new();
+    for neighbor in &neighbors {
+        let Some(plant) = plants.iter().find(|p| p.anchor == neighbor.anchor) else {
+            continue;
+        };
+        if !is_compatible(&variety.vegetable, &plant.variety.vegetable)
+            && !conflicts.iter().any(|c| c.anchor == plant.anchor)
+        {
+            conflicts.push(PlacementConflict {
+                id: plant.variety.id.clone(),
+                anchor: plant.anchor,
+            });
+        }
+    }
+    conflicts.sort_by_key(|c| (c.anchor.row, c.anchor.col));
+
+    PlacementPreviewResponse {
+        id: variety.id.clone(),
+        anchor,
+        span,
+        free: grid.is_block_free(anchor.row, anchor.col, span as usize),
+        compatible: conflicts.is_empty(),
+        score_delta,
+        conflicts,
+    }
+}
+
+/// Stamps plants into `grid` one after the other, as if they had been part of the
+/// submitted layout: each `(id, anchor)` is looked up in `lookup` and accepted when its
+/// block lies inside the grid on free cells and, with `check_companions`, touches no
+/// bad companion of it. Later plants see the earlier accepted ones.
+pub fn bulk_plant<'a>(
+    grid: &mut GardenGrid,
+    placements: impl IntoIterator<Item = (&'a str, Coordinate)>,
+    lookup: &HashMap<String, Variety>,
+    check_companions: bool,
+    today: NaiveDate,
+) -> Vec<BulkPlacementOutcome> {
+    let mut outcomes = Vec::new();
+    for (id, anchor) in placements {
+        let rejected = |id: &str, reason, conflicts| BulkPlacementOutcome {
+            id: id.to_owned(),
+            anchor,
+            accepted: false,
+            reason: Some(reason),
+            conflicts,
+        };
+        let Some(variety) = lookup.get(id) else {
+            outcomes.push(rejected(id, BulkRejection::UnknownVariety, Vec::new()));
+            continue;
+        };
+        let span = cell_span(variety.spacing_cm) as usize;
+        // Subtract rather than add: the anchor comes from the client unbounded.
+        let fits = |at: usize, len: usize| at < len && len - at >= span;
+        if !fits(anchor.row, grid.rows) || !fits(anchor.col, grid.cols) {
+            outcomes.push(rejected(
+                &variety.id,
+                BulkRejection::OutOfBounds,
+                Vec::new(),
+            ));
+            continue;
+        }
+        let block = || {
+            grid.cells[anchor.row..anchor.row + span]
+                .iter()
+                .flat_map(|row| &row[anchor.col..anchor.col + span])
+        };
+        if block().any(|cell| cell.blocked || cell.reserved) {
+            outcomes.push(rejected(&variety.id, BulkRejection::Blocked, Vec::new()));
+            continue;
+        }
+        if block().any(|cell| cell.variety.is_some()) {
+            outcomes.push(rejected(&variety.id, BulkRejection::Occupied, Vec::new()));
+            continue;
+        }
+        if check_companions {
+            let mut conflicts: Vec<PlacementConflict> = Vec::new();
+            for neighbor in grid.get_block_neighbors(anchor, span) {
+                let bad = lookup
+                    .get(&neighbor.id)
+                    .is_some_and(|n| !is_compatible(&variety.vegetable, &n.vegetable));
+                if bad && !conflicts.iter().any(|c| c.anchor == neighbor.anchor) {
+                    conflicts.push(PlacementConflict {
+                        id: neighbor.id.clone(),
+                        anchor: neighbor.anchor,
+                    });
+                }
+            }
+            if !conflicts.is_empty() {
+                conflicts.sort_by_key(|c| (c.anchor.row, c.anchor.col));
+                outcomes.push(rejected(
+                    &variety.id,
+                    BulkRejection::BadCompanion,
+                    conflicts,
+                ));
+                continue;
+            }
+        }
+        fill_block(
+            grid,
+            variety,
+            anchor,
+            "Present in the existing layout.",
+            0,
+            today,
+            PHASE_PRE_PLACED,
+        );
+        outcomes.push(BulkPlacementOutcome {
+            id: variety.id.clone(),
+            anchor,
+            accepted: true,
+            reason: None,
+            conflicts: Vec::new(),
+        });
+    }
+    outcomes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_fixtures::get_variety_by_id;
+
+    fn plant(id: &str) -> LayoutCell {
+        LayoutCell::SelfContained {
+            variety: get_variety_by_id(id).unwrap(),
+            plants_per_cell: None,
+            planted_date: None,
+        }
+    }
+
+    fn grid_for(layout: &[Vec<LayoutCell>]) -> GardenGrid {
+        let mut grid = GardenGrid::new(layout.len(), layout[0].len());
+        for (r, row) in layout.iter().enumerate() {
+            for (c, cell) in row.iter().enumerate() {
+                if let LayoutCell::SelfContained { variety, .. } = cell {
+                    grid.cells[r][c].variety = Some(crate::domain::models::garden::PlacedVariety {
+                        id: variety.id.clone(),
+                        vegetable_id: variety.vegetable.id.clone(),
+                        name: variety.name.clone(),
+                        reason: String::new(),
+                        plants_per_cell: 1,
+                        span: 1,
+                        anchor: Coordinate { row: r, col: c },
+                        planted_week: 0,
+                        days_to_harvest: variety.days_to_harvest,
+                        estimated_harvest_date: chrono::NaiveDate::from_ymd_opt(2025, 8, 1)
+                            .unwrap(),
+                        lifecycle: variety.lifecycle.clone(),
+                        category: variety.category.clone(),
+                        placed_in_phase: crate::domain::models::garden::PHASE_PRE_PLACED,
+                    });
+                }
+            }
+        }
+        grid
+    }
+
+    #[test]
+    fn test_tomato_next_to_fennel_gets_relocation_suggestion() {
+        let layout = vec![
+            vec![plant("tomato"), plant("fennel"), LayoutCell::Empty],
+            vec![LayoutCell::Empty, LayoutCell::Empty, LayoutCell::Empty],
+        ];
+        let grid = grid_for(&layout);
+        let mut warnings = Warnings::new();
+        let suggestions = check_pre_placed_companions(&grid, &layout, true, &mut warnings);
+
+        assert_eq!(warnings.len(), 1, "{:?}", warnings.as_slice());
+        assert_eq!(suggestions.len(), 1);
+        let s = &suggestions[0];
+        assert_eq!(s.id, "fennel");
+        assert_eq!(s.conflicts_with, "tomato");
+        assert_eq!(s.from, Coordinate { row: 0, col: 1 });
+        // [0,2] and [1,1] are both one step away and clear of the tomato; row-major wins.
+        assert_eq!(s.to, Coordinate { row: 0, col: 2 });
+        assert!(
+            grid.cells[0][2].variety.is_none(),
+            "suggestions are not applied"
+        );
+    }
+
+    #[test]
+    fn test_no_suggestions_unless_requested() {
+        let layout = vec![vec![plant("tomato"), plant("fennel"), LayoutCell::Empty]];
+        let grid = grid_for(&layout);
+        let mut warnings = Warnings::new();
+        assert!(check_pre_placed_companions(&grid, &layout, false, &mut warnings).is_empty());
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_preview_fennel_next_to_tomato_is_incompatible() {
+        let layout = vec![vec![plant("tomato"), LayoutCell::Empty, LayoutCell::Empty]];
+        let grid = grid_for(&layout);
+        let fennel = get_variety_by_id("fennel").unwrap();
+        let preview = preview_placement(&grid, &layout, &fennel, Coordinate { row: 0, col: 1 });
+
+        assert!(preview.free);
+        assert!(!preview.compatible);
+        assert_eq!(preview.conflicts.len(), 1);
+        assert_eq!(preview.conflicts[0].id, "tomato");
+        assert!(preview.score_delta < 0);
+
+        // One cell further, the tomato is no longer a neighbour.
+        let preview = preview_placement(&grid, &layout, &fennel, Coordinate { row: 0, col: 2 });
+        assert!(preview.compatible);
+        assert_eq!(preview.score_delta, 0);
+    }
+
+    #[test]
+    fn test_bulk_plant_rejects_bad_companion_only_when_checked() {
+        let layout = vec![vec![plant("tomato"), LayoutCell::Empty, LayoutCell::Empty]];
+        let lookup: HashMap<String, Variety> = ["tomato", "fennel"]
+            .map(|id| (id.to_owned(), get_variety_by_id(id).unwrap()))
+            .into();
+        let today = NaiveDate::from_ymd_opt(2025, 6, 2).unwrap();
+        let beside = [("fennel", Coordinate { row: 0, col: 1 })];
+
+        let mut grid = grid_for(&layout);
+        let outcomes = bulk_plant(&mut grid, beside, &lookup, true, today);
+        assert!(!outcomes[0].accepted);
+        assert_eq!(outcomes[0].reason, Some(BulkRejection::BadCompanion));
+        assert_eq!(outcomes[0].conflicts[0].id, "tomato");
+        assert!(grid.cells[0][1].variety.is_none());
+
+        let mut grid = grid_for(&layout);
+        let outcomes = bulk_plant(&mut grid, beside, &lookup, false, today);
+        assert!(outcomes[0].accepted);
+        assert_eq!(grid.cells[0][1].variety.as_ref().unwrap().id, "fennel");
+    }
+}
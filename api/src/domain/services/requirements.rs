@@ -0,0 +1,79 @@
+use crate::domain::models::{garden::GardenGrid, variety::Variety, Coordinate};
+use crate::domain::services::helpers::cell_span;
+
+/// `true` when the square blocks anchored at `a` and `b` share an edge (diagonal contact
+/// does not count, neither does overlap).
+pub fn blocks_touch(a: Coordinate, a_span: usize, b: Coordinate, b_span: usize) -> bool {
+    let overlaps =
+        |a0: usize, a_len: usize, b0: usize, b_len: usize| a0 < b0 + b_len && b0 < a0 + a_len;
+    let rows_overlap = overlaps(a.row, a_span, b.row, b_span);
+    let cols_overlap = overlaps(a.col, a_span, b.col, b_span);
+    (rows_overlap && (a.col + a_span == b.col || b.col + b_span == a.col))
+        || (cols_overlap && (a.row + a_span == b.row || b.row + b_span == a.row))
+}
+
+/// `true` when `variety` requires nothing, or a plant around its `span x span` block at
+/// `coordinate` already is one of the vegetables it requires.
+pub fn requirement_met(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+) -> bool {
+    let requires = &variety.vegetable.requires;
+    requires.is_empty()
+        || grid
+            .get_block_neighbors(coordinate, span)
+            .iter()
+            .any(|n| requires.contains(&n.vegetable_id))
+}
+
+/// First free block (row-major, companions in the given order) touching the
+/// `span x span` block at `coordinate` where a variety of one of the vegetables
+/// `variety` requires fits. Companions with requirements of their own are only used
+/// when `variety` satisfies them.
+pub fn required_companion_block<'v>(
+    grid: &GardenGrid,
+    variety: &Variety,
+    coordinate: Coordinate,
+    span: usize,
+    companions: &'v [Variety],
+) -> Option<(&'v Variety, Coordinate)> {
+    let requires = &variety.vegetable.requires;
+    companions
+        .iter()
+        .filter(|c| requires.contains(&c.vegetable.id))
+        .filter(|c| {
+            c.vegetable.requires.is_empty() || c.vegetable.requires.contains(&variety.vegetable.id)
+        })
+        .find_map(|companion| {
+            let c_span = cell_span(companion.spacing_cm) as usize;
+            let rows = coordinate.row.saturating_sub(c_span)
+                ..=(coordinate.row + span).min(grid.rows.saturating_sub(c_span));
+            rows.flat_map(|r| {
+                let cols = coordinate.col.saturating_sub(c_span)
+                    ..=(coordinate.col + span).min(grid.cols.saturating_sub(c_span));
+                cols.map(move |c| Coordinate { row: r, col: c })
+            })
+            .find(|&anchor| {
+                blocks_touch(coordinate, span, anchor, c_span)
+                    && grid.is_block_free(anchor.row, anchor.col, c_span)
+            })
+            .map(|anchor| (companion, anchor))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks_touch_on_edges_only() {
+        let origin = Coordinate { row: 1, col: 1 };
+        let at = |row, col| Coordinate { row, col };
+        assert!(blocks_touch(origin, 2, at(1, 3), 1), "right edge");
+        assert!(blocks_touch(origin, 2, at(0, 2), 1), "top edge");
+        assert!(!blocks_touch(origin, 2, at(0, 0), 1), "diagonal corner");
+        assert!(!blocks_touch(origin, 2, at(2, 2), 1), "inside the block");
+    }
+}
@@ -1,10 +1,63 @@
+use std::collections::BTreeMap;
+
 use crate::domain::models::{
-    garden::GardenGrid,
-    request::Period,
-    response::{PlannedCell, SowingTask, WeeklyPlan},
-    variety::Variety,
+    garden::{GardenGrid, PlacedVariety, PHASE_PRE_PLACED},
+    request::{Period, PlanParams, Preference},
+    response::{
+        AppliedPreference, AppliedRequest, CompactCell, CompactPlanResponse, CompactWeeklyPlan,
+        PlanResponse, PlannedCell, SowingTask, SpeciesEntry, WeeklyPlan,
+    },
+    variety::{Season, Variety},
     Matrix,
 };
+use crate::domain::services::helpers::CELL_SIZE_CM;
+
+/// Echo of the inputs `plan_garden` actually worked from: the week-aligned `period`,
+/// the covered `seasons`, the grid size, the capped, alias-resolved `preferences` and
+/// the `shoulder_varieties` admitted through `season_overlap`.
+pub fn build_applied_request(
+    request: &PlanParams,
+    (rows, cols): (usize, usize),
+    period: Option<Period>,
+    seasons: Vec<Season>,
+    preferences: &[Preference],
+    shoulder_varieties: Vec<String>,
+) -> AppliedRequest {
+    let placement = &request.placement;
+    AppliedRequest {
+        period,
+        seasons,
+        region: request.region.clone(),
+        rows,
+        cols,
+        cell_size_cm: CELL_SIZE_CM,
+        preferences: preferences
+            .iter()
+            .map(|p| AppliedPreference {
+                id: p.variety.id.clone(),
+                quantity: p.quantity,
+                area_m2: p.area_m2,
+            })
+            .collect(),
+        available_sun_hours: request.available_sun_hours,
+        pack: placement.pack.clone(),
+        objective: placement.objective.clone(),
+        weights: placement.weights.clone(),
+        target_fill_pct: placement.target_fill_pct,
+        max_total_plants: placement.max_total_plants,
+        avoid_pairs: placement.avoid_pairs.clone(),
+        avoid_pair_penalty: placement.avoid_pair_penalty,
+        neutral_competitor_penalty: placement.neutral_competitor_penalty,
+        fill: !placement.preferences_only,
+        suggest_fixes: request.suggest_fixes,
+        strict_companions: placement.strict_companions,
+        mutual_companions: placement.mutual_companions.is_some(),
+        density: placement.density.clone(),
+        season_overlap: request.season_overlap,
+        shoulder_varieties,
+        spacing_multiplier: placement.density.spacing_multiplier(),
+    }
+}
 
 /// Merges consecutive [`WeeklyPlan`]s that have identical grids.
 ///
@@ -44,6 +97,11 @@ pub fn build_weekly_plan(
     }
 }
 
+/// Phase reported on a planned cell; `None` for plants from the submitted layout.
+fn placed_in_phase(v: &PlacedVariety) -> Option<u8> {
+    (v.placed_in_phase != PHASE_PRE_PLACED).then_some(v.placed_in_phase)
+}
+
 /// Converts a [`GardenGrid`] into the `Matrix<PlannedCell>` used in API responses.
 pub fn build_grid_cells(grid: &GardenGrid) -> Matrix<PlannedCell> {
     grid.cells
@@ -62,6 +120,7 @@ pub fn build_grid_cells(grid: &GardenGrid) -> Matrix<PlannedCell> {
                             reason: v.reason.clone(),
                             plants_per_cell: v.plants_per_cell,
                             estimated_harvest_date: v.estimated_harvest_date,
+                            placed_in_phase: placed_in_phase(v),
                         }
                     }
                     Some(v) if (row_idx, col_idx) == (v.anchor.row, v.anchor.col) => {
@@ -73,12 +132,14 @@ pub fn build_grid_cells(grid: &GardenGrid) -> Matrix<PlannedCell> {
                             width_cells: v.span,
                             length_cells: v.span,
                             estimated_harvest_date: v.estimated_harvest_date,
+                            placed_in_phase: placed_in_phase(v),
                         }
                     }
                     Some(v) => PlannedCell::Overflowed {
                         covered_by: v.anchor,
                     },
                     None if cell.blocked => PlannedCell::Blocked,
+                    None if cell.reserved => PlannedCell::Reserved,
                     None => PlannedCell::Empty,
                 })
                 .collect()
@@ -86,8 +147,82 @@ pub fn build_grid_cells(grid: &GardenGrid) -> Matrix<PlannedCell> {
         .collect()
 }
 
+/// `cell` reduced to its type and variety id; continuation cells take the id of the
+/// anchor covering them in `grid`.
+fn compact_cell(cell: &PlannedCell, grid: &Matrix<PlannedCell>) -> CompactCell {
+    match cell {
+        PlannedCell::SelfContained { id, .. } => CompactCell::SelfContained { id: id.clone() },
+        PlannedCell::Overflowing { id, .. } => CompactCell::Overflowing { id: id.clone() },
+        PlannedCell::Overflowed { covered_by } => CompactCell::Overflowed {
+            id: grid[covered_by.row][covered_by.col]
+                .id()
+                .unwrap_or_default()
+                .to_owned(),
+        },
+        PlannedCell::Empty => CompactCell::Empty,
+        PlannedCell::Blocked => CompactCell::Blocked,
+        PlannedCell::Reserved => CompactCell::Reserved,
+    }
+}
+
+/// Projects `plan` onto its compact view: cells keep only their type and variety id,
+/// and each variety's name and first reason move to the `species` dictionary.
+pub fn compact_plan(plan: PlanResponse) -> CompactPlanResponse {
+    let mut species = BTreeMap::new();
+    for cell in plan.weeks.iter().flat_map(|w| w.grid.iter().flatten()) {
+        if let PlannedCell::SelfContained {
+            id, name, reason, ..
+        }
+        | PlannedCell::Overflowing {
+            id, name, reason, ..
+        } = cell
+        {
+            species.entry(id.clone()).or_insert_with(|| SpeciesEntry {
+                name: name.clone(),
+                reason: reason.clone(),
+            });
+        }
+    }
+    let weeks = plan
+        .weeks
+        .iter()
+        .map(|week| CompactWeeklyPlan {
+            period: week.period.clone(),
+            week_count: week.week_count,
+            grid: week
+                .grid
+                .iter()
+                .map(|row| row.iter().map(|c| compact_cell(c, &week.grid)).collect())
+                .collect(),
+            score: week.score,
+        })
+        .collect();
+    CompactPlanResponse {
+        id: plan.id,
+        rows: plan.rows,
+        cols: plan.cols,
+        weeks,
+        normalized_score: plan.normalized_score,
+        species,
+        warnings: plan.warnings,
+    }
+}
+
+/// Note appended to the reason of a variety whose vegetable has no good nor bad
+/// companions: it scores neutrally everywhere, so its spot is essentially arbitrary.
+const NO_COMPANION_DATA: &str = " (no companion data available)";
+
 /// Generates a descriptive reason string for a planted variety.
 pub fn build_reason(variety: &Variety, neighbor_names: &[String], score: i32) -> String {
+    let mut reason = describe_placement(variety, neighbor_names, score);
+    if variety.vegetable.good_companions.is_empty() && variety.vegetable.bad_companions.is_empty() {
+        reason.truncate(reason.trim_end().len());
+        reason.push_str(NO_COMPANION_DATA);
+    }
+    reason
+}
+
+fn describe_placement(variety: &Variety, neighbor_names: &[String], score: i32) -> String {
     if neighbor_names.is_empty() {
         return format!(
             "First placed ({}{}) ",
@@ -158,4 +293,27 @@ mod tests {
         assert_eq!(merged[0].week_count, 2);
         assert_eq!(merged[0].score, 20);
     }
+
+    #[test]
+    fn test_build_reason_notes_missing_companion_data() {
+        use crate::domain::test_fixtures::get_variety_by_id;
+        use std::sync::Arc;
+
+        let tomato = get_variety_by_id("tomato").unwrap();
+        let neighbors = vec!["Basil".to_owned()];
+        assert!(!build_reason(&tomato, &neighbors, 2).contains("no companion data"));
+
+        let mut loner = tomato.clone();
+        let mut vegetable = (*loner.vegetable).clone();
+        vegetable.good_companions.clear();
+        vegetable.bad_companions.clear();
+        loner.vegetable = Arc::new(vegetable);
+        loner.beginner_friendly = false;
+        assert_eq!(
+            build_reason(&loner, &neighbors, 0),
+            "Tomato neutral with Basil (no companion data available)"
+        );
+        assert!(build_reason(&loner, &[], 0).ends_with("(no companion data available)"));
+        assert!(!build_reason(&loner, &[], 0).contains("  "));
+    }
 }
@@ -1,6 +1,9 @@
 use chrono::{Datelike, Duration, Local};
 
-use crate::domain::models::{request::Period, warnings::Warnings};
+use crate::domain::models::{
+    request::Period,
+    warnings::{Language, Warnings},
+};
 
 impl Warnings {
     /// Adds schedule warning when the requested period is normalized to full weeks.
@@ -9,9 +12,14 @@ impl Warnings {
         start: chrono::NaiveDate,
         end: chrono::NaiveDate,
     ) {
-        self.add(format!(
-            "Planning period adjusted to full weeks: {start} (Monday) → {end} (Sunday)."
-        ));
+        self.add(match self.language() {
+            Language::English => format!(
+                "Planning period adjusted to full weeks: {start} (Monday) → {end} (Sunday)."
+            ),
+            Language::French => format!(
+                "Période de planification ajustée à des semaines complètes : {start} (lundi) → {end} (dimanche)."
+            ),
+        });
     }
 }
 
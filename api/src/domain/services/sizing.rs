@@ -0,0 +1,156 @@
+use crate::domain::models::{
+    error::{PlanError, MAX_GRID_CELLS},
+    garden::GardenGrid,
+    request::Preference,
+    response::{BedSizeItem, BedSizeResponse},
+};
+use crate::domain::services::helpers::{cell_span, cells_for_area, plants_per_cell, CELL_SIZE_CM};
+
+/// Extra cells, as a percentage of the plants' own, that [`bed_size_for`] leaves free so
+/// companions can be arranged rather than packed edge to edge.
+pub const BED_BUFFER_PCT: usize = 10;
+
+/// Blocks a preference asks for: its `quantity`, the blocks covering its `area_m2`, or
+/// one when it sets neither.
+fn blocks_for(preference: &Preference, span: usize) -> usize {
+    match (preference.quantity, preference.area_m2) {
+        (Some(quantity), _) => quantity as usize,
+        (None, Some(area)) => cells_for_area(area).div_ceil(span * span),
+        (None, None) => 1,
+    }
+}
+
+/// `true` when every `span` in `spans` fits on a `rows × cols` grid, placing the largest
+/// first on the first free block in row-major order.
+fn packs(rows: usize, cols: usize, spans: &[usize]) -> bool {
+    let mut grid = GardenGrid::new(rows, cols);
+    // Cells only ever fill up, so a block of the same span never fits before the last
+    // one placed: the scan resumes there instead of from the top-left corner.
+    let mut resume = (0, 0);
+    spans.iter().all(|&span| {
+        if resume.0 != span {
+            resume = (span, 0);
+        }
+        let width = cols.saturating_sub(span - 1);
+        let free = (resume.1..rows.saturating_sub(span - 1) * width)
+            .map(|i| (i / width, i % width))
+            .find(|&(r, c)| grid.is_block_free(r, c, span));
+        let Some((row, col)) = free else {
+            return false;
+        };
+        resume.1 = row * width + col;
+        for cells in &mut grid.cells[row..row + span] {
+            for cell in &mut cells[col..col + span] {
+                cell.blocked = true;
+            }
+        }
+        true
+    })
+}
+
+/// The smallest bed, by area, among `n × n` and `n × (n + 1)` grids that holds every
+/// block `preferences` ask for plus [`BED_BUFFER_PCT`] spare cells, checked by packing
+/// the blocks largest first. Fails when the blocks alone need more than
+/// [`MAX_GRID_CELLS`] cells, before any of them is laid out.
+pub fn bed_size_for(preferences: &[Preference]) -> Result<BedSizeResponse, PlanError> {
+    let sized: Vec<(usize, usize)> = preferences
+        .iter()
+        .map(|p| {
+            let span = cell_span(p.variety.spacing_cm) as usize;
+            (span, blocks_for(p, span))
+        })
+        .collect();
+    let plant_cells = sized.iter().fold(0usize, |total, &(span, blocks)| {
+        total.saturating_add(blocks.saturating_mul(span * span))
+    });
+    if plant_cells > MAX_GRID_CELLS {
+        return Err(PlanError::BedTooLarge { cells: plant_cells });
+    }
+
+    let mut items = Vec::new();
+    let mut spans: Vec<usize> = Vec::new();
+    for (preference, &(span, blocks)) in preferences.iter().zip(&sized) {
+        let variety = &preference.variety;
+        spans.extend(std::iter::repeat_n(span, blocks));
+        items.push(BedSizeItem {
+            id: variety.id.clone(),
+            name: variety.name.clone(),
+            blocks,
+            span: span as u32,
+            cells: blocks * span * span,
+            plants: blocks as u32 * plants_per_cell(variety.spacing_cm),
+        });
+    }
+    spans.sort_unstable_by(|a, b| b.cmp(a));
+    let wanted = plant_cells + (plant_cells * BED_BUFFER_PCT).div_ceil(100);
+
+    let (rows, cols) = (1..)
+        .flat_map(|n| [(n, n), (n, n + 1)])
+        .find(|&(rows, cols)| rows * cols >= wanted && packs(rows, cols, &spans))
+        .expect("a large enough bed holds any set of blocks");
+    Ok(BedSizeResponse {
+        rows,
+        cols,
+        cells: rows * cols,
+        plant_cells,
+        width_cm: cols as u32 * CELL_SIZE_CM,
+        length_cm: rows as u32 * CELL_SIZE_CM,
+        items,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_fixtures::get_variety_by_id;
+
+    fn preference(id: &str, quantity: Option<u32>) -> Preference {
+        Preference {
+            variety: get_variety_by_id(id).unwrap(),
+            quantity,
+            area_m2: None,
+        }
+    }
+
+    #[test]
+    fn test_four_tomatoes_need_at_least_sixteen_cells() {
+        let size = bed_size_for(&[preference("tomato", Some(4))]).unwrap();
+        assert_eq!(size.plant_cells, 16);
+        assert!(size.cells >= 16 + 2, "{size:?}");
+        assert!(size.rows >= 4 && size.cols >= 4, "{size:?}");
+        assert!(size.cols - size.rows <= 1);
+    }
+
+    #[test]
+    fn test_bed_holds_mixed_spans() {
+        let size = bed_size_for(&[
+            preference("pumpkin", Some(1)),
+            preference("radish", Some(3)),
+            preference("basil", None),
+        ])
+        .unwrap();
+        let pumpkin = cell_span(get_variety_by_id("pumpkin").unwrap().spacing_cm) as usize;
+        assert!(size.rows >= pumpkin, "{size:?}");
+        assert_eq!(size.items[2].blocks, 1);
+        assert!(packs(size.rows, size.cols, &[pumpkin, 1, 1, 1, 1]));
+    }
+
+    #[test]
+    fn test_bed_over_the_largest_grid_is_rejected() {
+        assert_eq!(
+            bed_size_for(&[preference("tomato", Some(2_501))]).unwrap_err(),
+            PlanError::BedTooLarge { cells: 10_004 }
+        );
+        assert!(matches!(
+            bed_size_for(&[preference("radish", Some(u32::MAX))]),
+            Err(PlanError::BedTooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn test_largest_bed_is_sized_quickly() {
+        let size = bed_size_for(&[preference("radish", Some(9_000))]).unwrap();
+        assert_eq!(size.plant_cells, 9_000);
+        assert!(size.cells >= 9_900, "{size:?}");
+    }
+}
@@ -0,0 +1,195 @@
+use chrono::{Datelike, Duration, NaiveDate};
+
+use crate::domain::models::{
+    request::PlanParams,
+    response::{PlanResponse, PlannedCell, SuccessionPlanting, SuccessionResponse},
+    variety::{Month, Variety},
+    warnings::{Language, Warnings},
+    Matrix,
+};
+use crate::domain::services::companion::{companion_score, is_compatible};
+use crate::domain::services::filter::is_active_month;
+use crate::domain::services::helpers::cell_span;
+
+/// Vegetable ids of the plants orthogonally adjacent to `(row, col)` in `grid`.
+/// Varieties missing from `catalogue` fall back to their own id.
+fn neighbor_vegetable_ids(
+    grid: &Matrix<PlannedCell>,
+    row: usize,
+    col: usize,
+    catalogue: &[Variety],
+) -> Vec<String> {
+    let mut ids = Vec::new();
+    let offsets: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+    for (dr, dc) in offsets {
+        let (Some(r), Some(c)) = (row.checked_add_signed(dr), col.checked_add_signed(dc)) else {
+            continue;
+        };
+        let Some(cell) = grid.get(r).and_then(|cells| cells.get(c)) else {
+            continue;
+        };
+        let anchor = cell
+            .covered_by()
+            .and_then(|a| grid.get(a.row).and_then(|cells| cells.get(a.col)))
+            .unwrap_or(cell);
+        if let Some(id) = anchor.id() {
+            ids.push(vegetable_id(id, catalogue));
+        }
+    }
+    ids
+}
+
+impl Warnings {
+    /// Adds succession warning when no plant of the base plan gets a follow-up crop.
+    fn add_no_follow_up(&mut self) {
+        self.add(match self.language() {
+            Language::English => {
+                "No early-maturing plant leaves room for a compatible in-season follow-up crop."
+            }
+            Language::French => {
+                "Aucune plante précoce ne laisse la place à une culture de relais compatible et de saison."
+            }
+        });
+    }
+}
+
+fn vegetable_id(variety_id: &str, catalogue: &[Variety]) -> String {
+    catalogue
+        .iter()
+        .find(|v| v.id == variety_id)
+        .map_or_else(|| variety_id.to_owned(), |v| v.vegetable.id.clone())
+}
+
+/// Best follow-up for a plant harvested on `harvest` in a `span`-wide block.
+///
+/// Candidates must be a different vegetable that is compatible with the harvested
+/// crop and every neighbour, fit in the block, be in season (active in the region's
+/// calendar) in the harvest month, and mature before `period_end`. Among
+/// them, the highest positive companion score wins; ties go to the fastest crop,
+/// then to candidate priority order.
+fn best_follow_up<'c>(
+    previous: &Variety,
+    neighbors: &[String],
+    harvest: NaiveDate,
+    span: u32,
+    period_end: NaiveDate,
+    candidates: &'c [Variety],
+    params: &PlanParams,
+) -> Option<&'c Variety> {
+    let month = Month::from_u32(harvest.month());
+    let neighbor_refs: Vec<&str> = neighbors.iter().map(String::as_str).collect();
+    let previous_id = [previous.vegetable.id.as_str()];
+    candidates
+        .iter()
+        .filter(|v| v.vegetable.id != previous.vegetable.id)
+        .filter(|v| cell_span(v.spacing_cm) <= span)
+        .filter(|v| harvest + Duration::days(v.days_to_harvest as i64) <= period_end)
+        .filter(|v| {
+            v.calendars
+                .iter()
+                .any(|c| c.region == params.region && is_active_month(c, month))
+        })
+        .filter(|v| is_compatible(&v.vegetable, &previous.vegetable))
+        .filter(|v| {
+            !neighbor_refs
+                .iter()
+                .any(|n| v.vegetable.bad_companions.iter().any(|b| b == n))
+        })
+        .map(|v| {
+            let score = companion_score(&v.vegetable, &neighbor_refs)
+                + companion_score(&v.vegetable, &previous_id);
+            (v, score)
+        })
+        .filter(|(_, score)| *score > 0)
+        .fold(None::<(&Variety, i32)>, |best, (v, score)| match best {
+            Some((b, s)) if s > score || (s == score && b.days_to_harvest <= v.days_to_harvest) => {
+                Some((b, s))
+            }
+            _ => Some((v, score)),
+        })
+        .map(|(v, _)| v)
+}
+
+/// Proposes a succession crop for each plant of the first planned week that is
+/// harvested early enough for a fast follow-up to mature within the period.
+///
+/// `candidates` are the region/sun/soil-filtered varieties used for the base plan,
+/// in priority order.
+pub fn plan_succession(
+    plan: PlanResponse,
+    candidates: &[Variety],
+    params: &PlanParams,
+) -> SuccessionResponse {
+    let mut warnings = plan.warnings;
+    let (Some(first), Some(last)) = (plan.weeks.first(), plan.weeks.last()) else {
+        return SuccessionResponse {
+            rows: plan.rows,
+            cols: plan.cols,
+            base: Vec::new(),
+            succession: Vec::new(),
+            warnings,
+        };
+    };
+    let period_end = last.period.end;
+    let base = first.grid.clone();
+
+    let succession: Matrix<Option<SuccessionPlanting>> = base
+        .iter()
+        .enumerate()
+        .map(|(r, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(c, cell)| {
+                    let (id, harvest, span) = match cell {
+                        PlannedCell::SelfContained {
+                            id,
+                            estimated_harvest_date,
+                            ..
+                        } => (id, *estimated_harvest_date, 1),
+                        PlannedCell::Overflowing {
+                            id,
+                            estimated_harvest_date,
+                            width_cells,
+                            length_cells,
+                            ..
+                        } => (
+                            id,
+                            *estimated_harvest_date,
+                            (*width_cells).min(*length_cells),
+                        ),
+                        _ => return None,
+                    };
+                    if harvest >= period_end {
+                        return None;
+                    }
+                    let previous = candidates.iter().find(|v| &v.id == id)?;
+                    let neighbors = neighbor_vegetable_ids(&base, r, c, candidates);
+                    best_follow_up(
+                        previous, &neighbors, harvest, span, period_end, candidates, params,
+                    )
+                    .map(|v| SuccessionPlanting {
+                        follows: id.clone(),
+                        id: v.id.clone(),
+                        name: v.name.clone(),
+                        planting_date: harvest,
+                        estimated_harvest_date: harvest + Duration::days(v.days_to_harvest as i64),
+                    })
+                })
+                .collect()
+        })
+        .collect();
+
+    if succession.iter().flatten().all(Option::is_none) {
+        let mut localized = Warnings::localized(params.language);
+        localized.add_no_follow_up();
+        warnings.extend(localized.into_vec());
+    }
+
+    SuccessionResponse {
+        rows: plan.rows,
+        cols: plan.cols,
+        base,
+        succession,
+        warnings,
+    }
+}
@@ -0,0 +1,136 @@
+use chrono::{Months, NaiveDate};
+
+use crate::domain::models::{
+    request::LayoutCell, response::TransitionPlant, variety::Lifecycle, Coordinate, Matrix,
+};
+
+/// How long a biennial lives after planting.
+const BIENNIAL_LIFESPAN: Months = Months::new(24);
+
+/// Whether a plant of `lifecycle` planted on `planted_date` still occupies its cells on
+/// `season_start`: perennials always do, biennials until their second year is over
+/// (forever when the planting date is unknown), annuals never.
+fn stays(lifecycle: &Lifecycle, planted_date: Option<NaiveDate>, season_start: NaiveDate) -> bool {
+    match lifecycle {
+        Lifecycle::Perennial => true,
+        Lifecycle::Biennial => planted_date
+            .and_then(|d| d.checked_add_months(BIENNIAL_LIFESPAN))
+            .is_none_or(|end| end > season_start),
+        Lifecycle::Annual => false,
+    }
+}
+
+/// Turns the current season's `layout` into the starting layout of the season beginning
+/// on `season_start`: spent plants (see [`stays`]) are cleared to `Empty`, together with
+/// the cells their blocks cover, and the others are left in place.
+///
+/// Returns the kept and the cleared plants, each in row-major order of their anchors.
+pub fn clear_spent_plants(
+    layout: &mut Matrix<LayoutCell>,
+    season_start: NaiveDate,
+) -> (Vec<TransitionPlant>, Vec<TransitionPlant>) {
+    let mut kept = Vec::new();
+    let mut cleared = Vec::new();
+    for row in 0..layout.len() {
+        for col in 0..layout[row].len() {
+            let (variety, planted_date) = match &layout[row][col] {
+                LayoutCell::SelfContained {
+                    variety,
+                    planted_date,
+                    ..
+                }
+                | LayoutCell::Overflowing {
+                    variety,
+                    planted_date,
+                    ..
+                } => (variety, *planted_date),
+                _ => continue,
+            };
+            let anchor = Coordinate { row, col };
+            let plant = TransitionPlant {
+                id: variety.id.clone(),
+                anchor,
+                lifecycle: variety.lifecycle.clone(),
+            };
+            if stays(&variety.lifecycle, planted_date, season_start) {
+                kept.push(plant);
+                continue;
+            }
+            cleared.push(plant);
+            for cell in layout.iter_mut().flatten() {
+                if matches!(cell, LayoutCell::Overflowed { covered_by } if *covered_by == anchor) {
+                    *cell = LayoutCell::Empty;
+                }
+            }
+            layout[row][col] = LayoutCell::Empty;
+        }
+    }
+    (kept, cleared)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_fixtures::get_variety_by_id;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn planted(id: &str, lifecycle: Lifecycle, planted_date: Option<NaiveDate>) -> LayoutCell {
+        let mut variety = get_variety_by_id(id).unwrap();
+        variety.lifecycle = lifecycle;
+        LayoutCell::SelfContained {
+            variety,
+            plants_per_cell: None,
+            planted_date,
+        }
+    }
+
+    #[test]
+    fn test_clear_spent_plants_keeps_perennials_and_young_biennials() {
+        let start = date(2025, 6, 1);
+        let mut layout = vec![vec![
+            planted("strawberry", Lifecycle::Perennial, Some(date(2020, 4, 1))),
+            planted("carrot", Lifecycle::Biennial, Some(date(2024, 7, 1))),
+            planted("parsley", Lifecycle::Biennial, Some(date(2023, 5, 1))),
+            planted("lettuce", Lifecycle::Annual, None),
+        ]];
+        let (kept, cleared) = clear_spent_plants(&mut layout, start);
+        let ids = |plants: &[TransitionPlant]| -> Vec<String> {
+            plants.iter().map(|p| p.id.clone()).collect()
+        };
+        assert_eq!(ids(&kept), ["strawberry", "carrot"]);
+        assert_eq!(ids(&cleared), ["parsley", "lettuce"]);
+        assert!(matches!(layout[0][0], LayoutCell::SelfContained { .. }));
+        assert!(matches!(layout[0][1], LayoutCell::SelfContained { .. }));
+        assert!(matches!(layout[0][2], LayoutCell::Empty));
+        assert!(matches!(layout[0][3], LayoutCell::Empty));
+    }
+
+    #[test]
+    fn test_clear_spent_plants_clears_whole_blocks() {
+        let anchor = Coordinate { row: 0, col: 0 };
+        let covered = || LayoutCell::Overflowed { covered_by: anchor };
+        let mut layout = vec![
+            vec![
+                LayoutCell::Overflowing {
+                    variety: get_variety_by_id("tomato").unwrap(),
+                    plants_per_cell: None,
+                    width_cells: Some(2),
+                    length_cells: Some(2),
+                    planted_date: None,
+                },
+                covered(),
+            ],
+            vec![covered(), covered()],
+        ];
+        let (kept, cleared) = clear_spent_plants(&mut layout, date(2025, 9, 1));
+        assert!(kept.is_empty());
+        assert_eq!(cleared.len(), 1);
+        assert!(layout
+            .iter()
+            .flatten()
+            .all(|c| matches!(c, LayoutCell::Empty)));
+    }
+}
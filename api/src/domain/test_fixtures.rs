@@ -15,8 +15,11 @@ pub fn get_variety_by_id(id: &str) -> Option<Variety> {
     get_all_varieties().into_iter().find(|v| v.id == id)
 }
 
+/// Looks a vegetable up by its id or one of its aliases.
 pub fn get_vegetable_by_id(id: &str) -> Option<Vegetable> {
-    get_all_vegetables().into_iter().find(|v| v.id == id)
+    get_all_vegetables()
+        .into_iter()
+        .find(|v| v.id == id || v.aliases.iter().any(|a| a == id))
 }
 
 pub fn get_all_varieties() -> Vec<Variety> {
@@ -73,6 +76,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 8,
+            yield_per_plant_g: 4000,
             soil_types: vec![SoilType::Loamy, SoilType::Humus],
             spacing_cm: 60,
             days_to_harvest: 75,
@@ -133,6 +138,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 8,
+            yield_per_plant_g: 5000,
             soil_types: vec![SoilType::Loamy, SoilType::Humus, SoilType::Clay],
             spacing_cm: 90,
             days_to_harvest: 55,
@@ -193,6 +200,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun, SunExposure::PartialShade],
+            min_sun_hours: 4,
+            yield_per_plant_g: 80,
             soil_types: vec![SoilType::Sandy, SoilType::Loamy],
             spacing_cm: 10,
             days_to_harvest: 75,
@@ -242,6 +251,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 8,
+            yield_per_plant_g: 150,
             soil_types: vec![SoilType::Loamy, SoilType::Humus],
             spacing_cm: 20,
             days_to_harvest: 30,
@@ -313,6 +324,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::PartialShade, SunExposure::FullSun],
+            min_sun_hours: 3,
+            yield_per_plant_g: 300,
             soil_types: vec![SoilType::Loamy, SoilType::Humus, SoilType::Clay],
             spacing_cm: 30,
             days_to_harvest: 45,
@@ -384,8 +397,10 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun, SunExposure::PartialShade],
+            min_sun_hours: 4,
+            yield_per_plant_g: 25,
             soil_types: vec![SoilType::Sandy, SoilType::Loamy],
-            spacing_cm: 5,
+            spacing_cm: 8,
             days_to_harvest: 25,
             days_to_plant: 0,
             lifecycle: Lifecycle::Annual,
@@ -444,6 +459,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 100,
             soil_types: vec![SoilType::Loamy, SoilType::Sandy],
             spacing_cm: 10,
             days_to_harvest: 100,
@@ -504,6 +521,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 50,
             soil_types: vec![SoilType::Loamy, SoilType::Sandy],
             spacing_cm: 10,
             days_to_harvest: 240,
@@ -564,6 +583,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 200,
             soil_types: vec![SoilType::Loamy, SoilType::Clay, SoilType::Humus],
             spacing_cm: 15,
             days_to_harvest: 120,
@@ -624,6 +645,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 150,
             soil_types: vec![SoilType::Loamy, SoilType::Sandy, SoilType::Humus],
             spacing_cm: 15,
             days_to_harvest: 55,
@@ -684,6 +707,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 8,
+            yield_per_plant_g: 3000,
             soil_types: vec![SoilType::Loamy, SoilType::Humus],
             spacing_cm: 60,
             days_to_harvest: 55,
@@ -733,6 +758,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 8,
+            yield_per_plant_g: 1000,
             soil_types: vec![SoilType::Loamy, SoilType::Humus],
             spacing_cm: 50,
             days_to_harvest: 70,
@@ -793,6 +820,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun, SunExposure::PartialShade],
+            min_sun_hours: 4,
+            yield_per_plant_g: 50,
             soil_types: vec![SoilType::Loamy, SoilType::Clay, SoilType::Chalky],
             spacing_cm: 10,
             days_to_harvest: 60,
@@ -853,6 +882,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 1500,
             soil_types: vec![SoilType::Loamy, SoilType::Clay, SoilType::Chalky],
             spacing_cm: 50,
             days_to_harvest: 90,
@@ -913,6 +944,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 400,
             soil_types: vec![SoilType::Loamy, SoilType::Clay, SoilType::Chalky],
             spacing_cm: 50,
             days_to_harvest: 80,
@@ -975,6 +1008,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun, SunExposure::PartialShade],
+            min_sun_hours: 4,
+            yield_per_plant_g: 150,
             soil_types: vec![SoilType::Loamy, SoilType::Humus],
             spacing_cm: 20,
             days_to_harvest: 75,
@@ -1035,8 +1070,10 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 50,
             soil_types: vec![SoilType::Sandy, SoilType::Chalky, SoilType::Loamy],
-            spacing_cm: 30,
+            spacing_cm: 15,
             days_to_harvest: 90,
             days_to_plant: 28,
             lifecycle: Lifecycle::Perennial,
@@ -1086,6 +1123,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 200,
             soil_types: vec![SoilType::Sandy, SoilType::Chalky],
             spacing_cm: 40,
             days_to_harvest: 90,
@@ -1146,6 +1185,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun, SunExposure::PartialShade],
+            min_sun_hours: 4,
+            yield_per_plant_g: 150,
             soil_types: vec![SoilType::Loamy, SoilType::Sandy, SoilType::Clay],
             spacing_cm: 15,
             days_to_harvest: 60,
@@ -1208,6 +1249,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::PartialShade, SunExposure::FullSun],
+            min_sun_hours: 3,
+            yield_per_plant_g: 100,
             soil_types: vec![SoilType::Loamy, SoilType::Humus, SoilType::Clay],
             spacing_cm: 15,
             days_to_harvest: 40,
@@ -1257,6 +1300,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 300,
             soil_types: vec![SoilType::Loamy, SoilType::Sandy],
             spacing_cm: 30,
             days_to_harvest: 90,
@@ -1295,6 +1340,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 8,
+            yield_per_plant_g: 2000,
             soil_types: vec![SoilType::Loamy, SoilType::Humus],
             spacing_cm: 60,
             days_to_harvest: 75,
@@ -1344,6 +1391,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun, SunExposure::PartialShade],
+            min_sun_hours: 4,
+            yield_per_plant_g: 500,
             soil_types: vec![SoilType::Loamy, SoilType::Humus, SoilType::Clay],
             spacing_cm: 30,
             days_to_harvest: 100,
@@ -1404,6 +1453,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 1000,
             soil_types: vec![SoilType::Loamy, SoilType::Sandy, SoilType::Humus],
             spacing_cm: 35,
             days_to_harvest: 90,
@@ -1464,6 +1515,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 8,
+            yield_per_plant_g: 300,
             soil_types: vec![SoilType::Loamy, SoilType::Humus, SoilType::Clay],
             spacing_cm: 40,
             days_to_harvest: 80,
@@ -1524,6 +1577,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 8,
+            yield_per_plant_g: 5000,
             soil_types: vec![SoilType::Loamy, SoilType::Humus],
             spacing_cm: 120,
             days_to_harvest: 100,
@@ -1595,8 +1650,10 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun, SunExposure::PartialShade],
+            min_sun_hours: 4,
+            yield_per_plant_g: 30,
             soil_types: vec![SoilType::Loamy, SoilType::Humus],
-            spacing_cm: 20,
+            spacing_cm: 8,
             days_to_harvest: 60,
             days_to_plant: 21,
             lifecycle: Lifecycle::Perennial,
@@ -1655,6 +1712,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::PartialShade, SunExposure::FullSun],
+            min_sun_hours: 3,
+            yield_per_plant_g: 200,
             soil_types: vec![SoilType::Loamy, SoilType::Humus, SoilType::Clay],
             spacing_cm: 30,
             days_to_harvest: 60,
@@ -1715,6 +1774,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun, SunExposure::PartialShade],
+            min_sun_hours: 4,
+            yield_per_plant_g: 300,
             soil_types: vec![SoilType::Loamy, SoilType::Sandy, SoilType::Humus],
             spacing_cm: 30,
             days_to_harvest: 90,
@@ -1777,6 +1838,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun, SunExposure::PartialShade],
+            min_sun_hours: 4,
+            yield_per_plant_g: 200,
             soil_types: vec![SoilType::Loamy, SoilType::Clay, SoilType::Chalky],
             spacing_cm: 20,
             days_to_harvest: 40,
@@ -1837,6 +1900,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 800,
             soil_types: vec![SoilType::Loamy, SoilType::Clay, SoilType::Chalky],
             spacing_cm: 60,
             days_to_harvest: 80,
@@ -1875,6 +1940,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 8,
+            yield_per_plant_g: 1000,
             soil_types: vec![SoilType::Loamy, SoilType::Humus],
             spacing_cm: 50,
             days_to_harvest: 70,
@@ -1935,6 +2002,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 250,
             soil_types: vec![SoilType::Sandy, SoilType::Loamy],
             spacing_cm: 45,
             days_to_harvest: 730,
@@ -1973,6 +2042,8 @@ pub fn get_all_varieties() -> Vec<Variety> {
                 },
             ],
             sun_requirement: vec![SunExposure::FullSun],
+            min_sun_hours: 6,
+            yield_per_plant_g: 1500,
             soil_types: vec![SoilType::Loamy, SoilType::Humus],
             spacing_cm: 80,
             days_to_harvest: 365,
@@ -1989,6 +2060,7 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
         Vegetable {
             id: "tomato".into(),
             name: "Tomato".into(),
+            aliases: vec![],
             group_id: "legumes-fruits".into(),
             variety_ids: vec!["tomato".into()],
             good_companions: vec![
@@ -1998,19 +2070,23 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
                 "garlic".into(),
                 "onion".into(),
             ],
+            requires: vec![],
             bad_companions: vec!["fennel".into(), "brassica".into()],
         },
         Vegetable {
             id: "zucchini".into(),
             name: "Zucchini".into(),
+            aliases: vec!["courgette".into()],
             group_id: "legumes-fruits".into(),
             variety_ids: vec!["zucchini".into()],
             good_companions: vec!["green-bean".into(), "maïs".into(), "radish".into()],
+            requires: vec![],
             bad_companions: vec!["potato".into()],
         },
         Vegetable {
             id: "carrot".into(),
             name: "Carrot".into(),
+            aliases: vec![],
             group_id: "legumes-racines".into(),
             variety_ids: vec!["carrot".into()],
             good_companions: vec![
@@ -2020,19 +2096,23 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
                 "lettuce".into(),
                 "radish".into(),
             ],
+            requires: vec![],
             bad_companions: vec!["dill".into(), "fennel".into()],
         },
         Vegetable {
             id: "basil".into(),
             name: "Basil".into(),
+            aliases: vec![],
             group_id: "legumes-feuilles".into(),
             variety_ids: vec!["basil".into()],
             good_companions: vec!["tomato".into(), "pepper".into(), "asparagus".into()],
+            requires: vec![],
             bad_companions: vec!["sage".into(), "thyme".into()],
         },
         Vegetable {
             id: "lettuce".into(),
             name: "Lettuce".into(),
+            aliases: vec![],
             group_id: "legumes-feuilles".into(),
             variety_ids: vec!["lettuce".into()],
             good_companions: vec![
@@ -2041,11 +2121,13 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
                 "strawberry".into(),
                 "cucumber".into(),
             ],
+            requires: vec![],
             bad_companions: vec!["parsley".into(), "celery".into()],
         },
         Vegetable {
             id: "radish".into(),
             name: "Radish".into(),
+            aliases: vec![],
             group_id: "legumes-racines".into(),
             variety_ids: vec!["radish".into()],
             good_companions: vec![
@@ -2054,11 +2136,13 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
                 "tomato".into(),
                 "cucumber".into(),
             ],
+            requires: vec![],
             bad_companions: vec!["hyssop".into()],
         },
         Vegetable {
             id: "onion".into(),
             name: "Onion".into(),
+            aliases: vec![],
             group_id: "bulbes".into(),
             variety_ids: vec!["onion".into()],
             good_companions: vec![
@@ -2067,11 +2151,13 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
                 "beet".into(),
                 "lettuce".into(),
             ],
+            requires: vec![],
             bad_companions: vec!["green-bean".into(), "pea".into(), "garlic".into()],
         },
         Vegetable {
             id: "garlic".into(),
             name: "Garlic".into(),
+            aliases: vec![],
             group_id: "bulbes".into(),
             variety_ids: vec!["garlic".into()],
             good_companions: vec![
@@ -2080,19 +2166,23 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
                 "strawberry".into(),
                 "carrot".into(),
             ],
+            requires: vec![],
             bad_companions: vec!["onion".into(), "green-bean".into(), "pea".into()],
         },
         Vegetable {
             id: "leek".into(),
             name: "Leek".into(),
+            aliases: vec![],
             group_id: "bulbes".into(),
             variety_ids: vec!["leek".into()],
             good_companions: vec!["carrot".into(), "celery".into(), "lettuce".into()],
+            requires: vec![],
             bad_companions: vec!["green-bean".into(), "pea".into()],
         },
         Vegetable {
             id: "green-bean".into(),
             name: "Green Bean".into(),
+            aliases: vec![],
             group_id: "legumes-fruits".into(),
             variety_ids: vec!["green-bean".into()],
             good_companions: vec![
@@ -2101,6 +2191,7 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
                 "potato".into(),
                 "radish".into(),
             ],
+            requires: vec![],
             bad_companions: vec![
                 "onion".into(),
                 "garlic".into(),
@@ -2111,6 +2202,7 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
         Vegetable {
             id: "cucumber".into(),
             name: "Cucumber".into(),
+            aliases: vec![],
             group_id: "legumes-fruits".into(),
             variety_ids: vec!["cucumber".into()],
             good_companions: vec![
@@ -2119,19 +2211,23 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
                 "green-bean".into(),
                 "maïs".into(),
             ],
+            requires: vec![],
             bad_companions: vec!["tomato".into(), "potato".into(), "fennel".into()],
         },
         Vegetable {
             id: "pepper".into(),
             name: "Pepper".into(),
+            aliases: vec!["capsicum".into()],
             group_id: "legumes-fruits".into(),
             variety_ids: vec!["pepper".into(), "red-pepper".into()],
             good_companions: vec!["basil".into(), "tomato".into(), "carrot".into()],
+            requires: vec![],
             bad_companions: vec!["fennel".into(), "brassica".into()],
         },
         Vegetable {
             id: "pea".into(),
             name: "Pea".into(),
+            aliases: vec![],
             group_id: "legumes-fruits".into(),
             variety_ids: vec!["pea".into()],
             good_companions: vec![
@@ -2140,62 +2236,77 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
                 "lettuce".into(),
                 "brassica".into(),
             ],
+            requires: vec![],
             bad_companions: vec!["onion".into(), "garlic".into(), "fennel".into()],
         },
         Vegetable {
             id: "brassica".into(),
             name: "Brassica".into(),
+            aliases: vec![],
             group_id: "legumes-feuilles".into(),
             variety_ids: vec!["cabbage".into(), "broccoli".into(), "cauliflower".into()],
             good_companions: vec!["celery".into(), "onion".into(), "pea".into()],
+            requires: vec![],
             bad_companions: vec!["tomato".into(), "strawberry".into(), "fennel".into()],
         },
         Vegetable {
             id: "parsley".into(),
             name: "Parsley".into(),
+            aliases: vec![],
             group_id: "legumes-feuilles".into(),
             variety_ids: vec!["parsley".into()],
             good_companions: vec!["tomato".into(), "asparagus".into(), "rose".into()],
+            requires: vec![],
             bad_companions: vec!["lettuce".into()],
         },
         Vegetable {
             id: "thyme".into(),
             name: "Thyme".into(),
+            aliases: vec![],
             group_id: "legumes-feuilles".into(),
             variety_ids: vec!["thyme".into()],
             good_companions: vec!["brassica".into(), "tomato".into(), "eggplant".into()],
+            requires: vec![],
             bad_companions: vec!["basil".into()],
         },
         Vegetable {
             id: "rosemary".into(),
             name: "Rosemary".into(),
+            aliases: vec![],
             group_id: "legumes-feuilles".into(),
             variety_ids: vec!["rosemary".into()],
             good_companions: vec!["brassica".into(), "green-bean".into(), "sage".into()],
+            requires: vec![],
             bad_companions: vec!["cucumber".into(), "pumpkin".into()],
         },
         Vegetable {
             id: "beet".into(),
             name: "Beet".into(),
+            aliases: vec![],
             group_id: "legumes-racines".into(),
             variety_ids: vec!["beet".into()],
             good_companions: vec!["onion".into(), "lettuce".into(), "radish".into()],
+            requires: vec![],
             bad_companions: vec!["green-bean".into(), "mustard".into()],
         },
         Vegetable {
             id: "spinach".into(),
             name: "Spinach".into(),
+            aliases: vec![],
             group_id: "legumes-feuilles".into(),
             variety_ids: vec!["spinach".into()],
             good_companions: vec!["strawberry".into(), "tomato".into(), "radish".into()],
+            requires: vec![],
             bad_companions: vec!["beet".into(), "sorrel".into()],
         },
         Vegetable {
             id: "fennel".into(),
             name: "Fennel".into(),
+            aliases: vec![],
             group_id: "legumes-feuilles".into(),
             variety_ids: vec!["fennel".into()],
             good_companions: vec![],
+            requires: vec![],
             bad_companions: vec![
                 "tomato".into(),
                 "green-bean".into(),
@@ -2209,46 +2320,57 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
         Vegetable {
             id: "eggplant".into(),
             name: "Eggplant".into(),
+            aliases: vec![],
             group_id: "legumes-fruits".into(),
             variety_ids: vec!["eggplant".into()],
             good_companions: vec!["basil".into(), "thyme".into(), "pepper".into()],
+            requires: vec![],
             bad_companions: vec!["fennel".into()],
         },
         Vegetable {
             id: "celery".into(),
             name: "Celery".into(),
+            aliases: vec![],
             group_id: "legumes-feuilles".into(),
             variety_ids: vec!["celery".into()],
             good_companions: vec!["leek".into(), "brassica".into(), "tomato".into()],
+            requires: vec![],
             bad_companions: vec!["lettuce".into(), "garlic".into()],
         },
         Vegetable {
             id: "potato".into(),
             name: "Potato".into(),
+            aliases: vec![],
             group_id: "legumes-racines".into(),
             variety_ids: vec!["potato".into()],
             good_companions: vec!["green-bean".into(), "brassica".into(), "maïs".into()],
+            requires: vec![],
             bad_companions: vec!["tomato".into(), "cucumber".into(), "zucchini".into()],
         },
         Vegetable {
             id: "maïs".into(),
             name: "Corn".into(),
+            aliases: vec!["corn".into()],
             group_id: "legumes-fruits".into(),
             variety_ids: vec!["maïs".into()],
             good_companions: vec!["green-bean".into(), "zucchini".into(), "potato".into()],
+            requires: vec![],
             bad_companions: vec!["tomato".into(), "celery".into()],
         },
         Vegetable {
             id: "pumpkin".into(),
             name: "Pumpkin".into(),
+            aliases: vec![],
             group_id: "legumes-fruits".into(),
             variety_ids: vec!["pumpkin".into()],
             good_companions: vec!["maïs".into(), "green-bean".into(), "onion".into()],
+            requires: vec![],
             bad_companions: vec!["potato".into(), "rosemary".into()],
         },
         Vegetable {
             id: "chive".into(),
             name: "Chive".into(),
+            aliases: vec![],
             group_id: "bulbes".into(),
             variety_ids: vec!["chive".into()],
             good_companions: vec![
@@ -2257,19 +2379,23 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
                 "rose".into(),
                 "strawberry".into(),
             ],
+            requires: vec![],
             bad_companions: vec!["green-bean".into(), "pea".into()],
         },
         Vegetable {
             id: "mint".into(),
             name: "Mint".into(),
+            aliases: vec![],
             group_id: "legumes-feuilles".into(),
             variety_ids: vec!["mint".into()],
             good_companions: vec!["brassica".into(), "tomato".into(), "pea".into()],
+            requires: vec![],
             bad_companions: vec!["parsley".into()],
         },
         Vegetable {
             id: "strawberry".into(),
             name: "Strawberry".into(),
+            aliases: vec![],
             group_id: "legumes-fruits".into(),
             variety_ids: vec!["strawberry".into()],
             good_companions: vec![
@@ -2278,30 +2404,37 @@ pub fn get_all_vegetables() -> Vec<Vegetable> {
                 "garlic".into(),
                 "onion".into(),
             ],
+            requires: vec![],
             bad_companions: vec!["brassica".into(), "fennel".into()],
         },
         Vegetable {
             id: "turnip".into(),
             name: "Turnip".into(),
+            aliases: vec![],
             group_id: "legumes-racines".into(),
             variety_ids: vec!["turnip".into()],
             good_companions: vec!["pea".into(), "green-bean".into()],
+            requires: vec![],
             bad_companions: vec!["mustard".into(), "radish".into()],
         },
         Vegetable {
             id: "asparagus".into(),
             name: "Asparagus".into(),
+            aliases: vec![],
             group_id: "legumes-feuilles".into(),
             variety_ids: vec!["asparagus".into()],
             good_companions: vec!["tomato".into(), "parsley".into(), "basil".into()],
+            requires: vec![],
             bad_companions: vec!["onion".into(), "garlic".into()],
         },
         Vegetable {
             id: "artichoke".into(),
             name: "Artichoke".into(),
+            aliases: vec![],
             group_id: "legumes-feuilles".into(),
             variety_ids: vec!["artichoke".into()],
             good_companions: vec![],
+            requires: vec![],
             bad_companions: vec![],
         },
     ]
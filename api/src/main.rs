@@ -3,20 +3,23 @@ use actix_web::{http, web, App, HttpServer};
 use deadpool_postgres::{Manager, ManagerConfig, Pool, RecyclingMethod};
 use tokio_postgres::NoTls;
 
+use garden::adapters::inbound::http::dto::{SharedStartupStatus, StartupStatus};
+use garden::adapters::inbound::http::idempotency::{IDEMPOTENCY_KEY, IDEMPOTENT_REPLAYED};
+use garden::adapters::inbound::http::request_id::X_REQUEST_ID;
+use garden::adapters::outbound::memory::plan_store::InMemoryPlanStore;
 use garden::adapters::outbound::postgres::group_repository::PostgresGroupRepository;
+use garden::adapters::outbound::postgres::startup::{
+    load_catalogue, reload_until_loaded, RetryPolicy,
+};
 use garden::adapters::outbound::postgres::variety_repository::PostgresVarietyRepository;
 use garden::adapters::outbound::postgres::variety_response_repository::PostgresVarietyResponseRepository;
 use garden::adapters::outbound::postgres::vegetable_repository::PostgresVegetableRepository;
 use garden::application::ports::group_repository::GroupRepository;
+use garden::application::ports::plan_store::PlanStore;
 use garden::application::ports::variety_repository::VarietyRepository;
 use garden::application::ports::variety_response_repository::VarietyResponseRepository;
 use garden::application::ports::vegetable_repository::VegetableRepository;
 
-mod embedded {
-    use refinery::embed_migrations;
-    embed_migrations!("migrations");
-}
-
 fn build_pool(database_url: &str) -> Pool {
     let pg_config: tokio_postgres::Config = database_url.parse().expect("Invalid DATABASE_URL");
     let mgr_config = ManagerConfig {
@@ -32,35 +35,46 @@ fn build_pool(database_url: &str) -> Pool {
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     dotenvy::dotenv().ok();
-    env_logger::init();
+    garden::adapters::inbound::http::logging::init();
 
     let database_url =
         std::env::var("DATABASE_URL").expect("DATABASE_URL environment variable must be set");
 
     let pool = build_pool(&database_url);
 
-    // Run pending migrations at startup.
-    {
-        let mut client = pool
-            .get()
-            .await
-            .expect("Failed to acquire DB connection for migrations");
-        embedded::migrations::runner()
-            .run_async(&mut **client)
-            .await
-            .expect("Database migration failed");
+    // Run pending migrations and load the catalogue at startup, retrying while the
+    // database comes up. When it never does, the server still starts, in degraded mode
+    // (see `GET /api/health`), and keeps loading in the background until it succeeds.
+    let policy = RetryPolicy::from_env();
+    let loaded = load_catalogue(&pool, &policy).await;
+    match &loaded {
+        Ok(problems) => problems.iter().for_each(|p| log::warn!("Catalogue: {p}")),
+        Err(e) => log::error!("Starting in degraded mode: {e}"),
+    }
+    let startup_data = web::Data::new(SharedStartupStatus::new(StartupStatus::from_load(&loaded)));
+    if loaded.is_err() {
+        let (pool, startup) = (pool.clone(), startup_data.clone());
+        actix_web::rt::spawn(async move {
+            let problems = reload_until_loaded(&pool, &policy).await;
+            problems.iter().for_each(|p| log::warn!("Catalogue: {p}"));
+            log::info!("Catalogue loaded, leaving degraded mode");
+            *startup.write().unwrap_or_else(|e| e.into_inner()) =
+                StartupStatus::from_load(&Ok(problems));
+        });
     }
 
     let repo: Box<dyn VarietyRepository> = Box::new(PostgresVarietyRepository::new(pool.clone()));
+    let vegetable_repo: Box<dyn VegetableRepository> =
+        Box::new(PostgresVegetableRepository::new(pool.clone()));
     let repo_data = web::Data::new(repo);
     let variety_response_repo: Box<dyn VarietyResponseRepository> =
         Box::new(PostgresVarietyResponseRepository::new(pool.clone()));
     let variety_response_repo_data = web::Data::new(variety_response_repo);
-    let vegetable_repo: Box<dyn VegetableRepository> =
-        Box::new(PostgresVegetableRepository::new(pool.clone()));
     let vegetable_repo_data = web::Data::new(vegetable_repo);
     let group_repo: Box<dyn GroupRepository> = Box::new(PostgresGroupRepository::new(pool));
     let group_repo_data = web::Data::new(group_repo);
+    let plan_store: Box<dyn PlanStore> = Box::new(InMemoryPlanStore::default());
+    let plan_store_data = web::Data::new(plan_store);
 
     let bind_addr = "0.0.0.0:8080";
     log::info!("Starting server on {bind_addr}");
@@ -69,8 +83,20 @@ async fn main() -> std::io::Result<()> {
         let cors = Cors::default()
             .allowed_origin("http://localhost:5173")
             .allowed_origin("http://127.0.0.1:5173")
-            .allowed_methods(vec!["GET", "POST"])
-            .allowed_headers(vec![http::header::CONTENT_TYPE, http::header::ACCEPT])
+            .allowed_methods(vec!["GET", "POST", "PUT", "DELETE"])
+            .allowed_headers(vec![
+                http::header::CONTENT_TYPE,
+                http::header::ACCEPT,
+                http::header::IF_MATCH,
+                X_REQUEST_ID,
+                IDEMPOTENCY_KEY,
+            ])
+            .expose_headers(vec![
+                X_REQUEST_ID,
+                http::header::LOCATION,
+                http::header::ETAG,
+                IDEMPOTENT_REPLAYED,
+            ])
             .max_age(3600);
         App::new()
             .wrap(cors)
@@ -78,6 +104,8 @@ async fn main() -> std::io::Result<()> {
             .app_data(variety_response_repo_data.clone())
             .app_data(vegetable_repo_data.clone())
             .app_data(group_repo_data.clone())
+            .app_data(plan_store_data.clone())
+            .app_data(startup_data.clone())
             .configure(garden::adapters::inbound::http::routes::configure)
             .app_data(web::JsonConfig::default().error_handler(|err, _req| {
                 let message = format!("JSON deserialization error: {err}");
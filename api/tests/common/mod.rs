@@ -1,12 +1,15 @@
 #![allow(dead_code)]
 
 use actix_web::{web, App};
+use garden::adapters::inbound::http::dto::{SharedStartupStatus, StartupStatus};
 use garden::adapters::inbound::http::routes::configure;
+use garden::adapters::outbound::memory::plan_store::InMemoryPlanStore;
 use garden::adapters::outbound::postgres::group_repository::PostgresGroupRepository;
 use garden::adapters::outbound::postgres::variety_repository::PostgresVarietyRepository;
 use garden::adapters::outbound::postgres::variety_response_repository::PostgresVarietyResponseRepository;
 use garden::adapters::outbound::postgres::vegetable_repository::PostgresVegetableRepository;
 use garden::application::ports::group_repository::GroupRepository;
+use garden::application::ports::plan_store::PlanStore;
 use garden::application::ports::variety_repository::VarietyRepository;
 use garden::application::ports::variety_response_repository::VarietyResponseRepository;
 use garden::application::ports::vegetable_repository::VegetableRepository;
@@ -26,6 +29,25 @@ pub async fn build_app_postgres() -> actix_web::App<
         Error = actix_web::Error,
         InitError = (),
     >,
+> {
+    build_app_with_startup(web::Data::new(SharedStartupStatus::new(
+        StartupStatus::default(),
+    )))
+    .await
+}
+
+/// [`build_app_postgres`], reporting `startup` as its health (shared, so a test can
+/// change it as a background reload would).
+pub async fn build_app_with_startup(
+    startup: web::Data<SharedStartupStatus>,
+) -> actix_web::App<
+    impl actix_web::dev::ServiceFactory<
+        actix_web::dev::ServiceRequest,
+        Config = (),
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+        InitError = (),
+    >,
 > {
     let pool = migrated_pool().await;
     let repo: Box<dyn VarietyRepository> = Box::new(PostgresVarietyRepository::new(pool.clone()));
@@ -34,11 +56,14 @@ pub async fn build_app_postgres() -> actix_web::App<
     let vegetable_repo: Box<dyn VegetableRepository> =
         Box::new(PostgresVegetableRepository::new(pool.clone()));
     let group_repo: Box<dyn GroupRepository> = Box::new(PostgresGroupRepository::new(pool));
+    let plan_store: Box<dyn PlanStore> = Box::new(InMemoryPlanStore::default());
     App::new()
         .app_data(web::Data::new(repo))
         .app_data(web::Data::new(variety_response_repo))
         .app_data(web::Data::new(vegetable_repo))
         .app_data(web::Data::new(group_repo))
+        .app_data(web::Data::new(plan_store))
+        .app_data(startup)
         .configure(configure)
         .app_data(web::JsonConfig::default().error_handler(|err, _req| {
             let message = format!("{err}");
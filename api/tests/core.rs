@@ -0,0 +1,115 @@
+//! Drives the planning engine through `garden::domain` alone: hand-built catalogue, no
+//! HTTP server and no database.
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use garden::domain::models::{
+    request::{LayoutCell, Period, PlanParams, Preference},
+    response::PlannedCell,
+    variety::{
+        CalendarWindow, Category, Lifecycle, Month, Region, RegionCalendar, SoilType, SunExposure,
+        Variety,
+    },
+    vegetable::Vegetable,
+};
+use garden::domain::{
+    companion_score, filter_candidates_base, is_compatible, plan_garden, PlanError,
+};
+
+fn vegetable(id: &str, good: &[&str]) -> Vegetable {
+    Vegetable {
+        id: id.into(),
+        name: id.into(),
+        aliases: vec![],
+        group_id: "garden".into(),
+        variety_ids: vec![id.into()],
+        good_companions: good.iter().map(|g| g.to_string()).collect(),
+        bad_companions: vec![],
+        requires: vec![],
+    }
+}
+
+/// A summer variety planted outdoors in June in temperate gardens.
+fn variety(vegetable: Vegetable, spacing_cm: u32) -> Variety {
+    Variety {
+        id: vegetable.id.clone(),
+        name: vegetable.name.clone(),
+        vegetable: Arc::new(vegetable),
+        latin_name: String::new(),
+        calendars: vec![RegionCalendar {
+            region: Region::Temperate,
+            sowing: CalendarWindow {
+                outdoor: vec![],
+                indoor: vec![],
+            },
+            planting: CalendarWindow {
+                outdoor: vec![Month::June],
+                indoor: vec![],
+            },
+        }],
+        sun_requirement: vec![SunExposure::FullSun],
+        min_sun_hours: 6,
+        yield_per_plant_g: 500,
+        soil_types: vec![SoilType::Loamy],
+        spacing_cm,
+        days_to_harvest: 60,
+        days_to_plant: 0,
+        lifecycle: Lifecycle::Annual,
+        beginner_friendly: true,
+        category: Category::Fruit,
+    }
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn test_core_plans_companions_next_to_each_other() {
+    let tomato = variety(vegetable("tomato", &["basil"]), 30);
+    let basil = variety(vegetable("basil", &["tomato"]), 30);
+    assert!(is_compatible(&tomato.vegetable, &basil.vegetable));
+    assert_eq!(companion_score(&tomato.vegetable, &["basil"]), 2);
+
+    let params = PlanParams {
+        period: Some(Period {
+            start: date(2025, 6, 2),
+            end: date(2025, 6, 8),
+        }),
+        preferences: [&tomato, &basil]
+            .into_iter()
+            .map(|v| Preference {
+                variety: v.clone(),
+                quantity: Some(1),
+                area_m2: None,
+            })
+            .collect(),
+        ..PlanParams::new(Region::Temperate, vec![vec![LayoutCell::Empty; 2]; 1])
+    };
+    let catalogue = [tomato, basil];
+    let candidates = filter_candidates_base(&catalogue, &params);
+    assert_eq!(candidates.len(), 2);
+
+    let plan = plan_garden(candidates, &params).expect("a 1×2 bed can be planned");
+    assert_eq!((plan.rows, plan.cols), (1, 2));
+    let mut ids: Vec<_> = plan.weeks[0].grid[0]
+        .iter()
+        .map(|cell| match cell {
+            PlannedCell::SelfContained { id, .. } => id.as_str(),
+            other => panic!("expected a planted cell, got {other:?}"),
+        })
+        .collect();
+    ids.sort();
+    assert_eq!(ids, ["basil", "tomato"]);
+    assert!(plan.weeks[0].score > 0, "good companions score");
+}
+
+#[test]
+fn test_core_reports_plan_errors() {
+    let params = PlanParams::new(Region::Temperate, Vec::new());
+    assert_eq!(
+        plan_garden(Vec::new(), &params).unwrap_err(),
+        PlanError::EmptyLayout
+    );
+}
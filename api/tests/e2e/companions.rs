@@ -38,6 +38,46 @@ async fn test_get_companions_returns_good_and_bad() {
     );
 }
 
+#[actix_web::test]
+async fn test_get_companions_tomato_has_note_about_basil() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/tomato/companions")
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let notes: Vec<&str> = body["payload"]["notes"]
+        .as_array()
+        .expect("Response must contain a 'notes' array")
+        .iter()
+        .map(|n| n.as_str().unwrap())
+        .collect();
+    assert!(
+        notes.iter().any(|n| n.contains("Basil")),
+        "tomato's notes should mention basil: {notes:?}"
+    );
+    assert!(notes.iter().any(|n| n.contains("Fennel")));
+}
+
+#[actix_web::test]
+async fn test_get_companions_notes_are_localised_and_empty_when_undocumented() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/basil/companions")
+        .insert_header(("Accept-Language", "fr"))
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(
+        body["payload"]["notes"],
+        serde_json::json!(["Le basilic éloigne les aleurodes et les pucerons de la tomate."])
+    );
+
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/asparagus/companions")
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["payload"]["notes"], serde_json::json!([]));
+}
+
 #[actix_web::test]
 async fn test_get_companions_unknown_id_returns_404() {
     let app = test::init_service(build_app_postgres().await).await;
@@ -83,3 +123,296 @@ async fn test_get_companions_returns_links() {
     );
     assert_eq!(links["vegetable"]["method"].as_str().unwrap(), "GET");
 }
+
+// ---------------------------------------------------------------------------
+// GET /api/companions/matrix.csv
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_companion_matrix_csv_has_id_header_row_and_column() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/companions/matrix.csv")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert!(content_type.starts_with("text/csv"), "got {content_type}");
+
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    let rows: Vec<Vec<&str>> = body.lines().map(|l| l.split(',').collect()).collect();
+    let header = &rows[0];
+    assert_eq!(header[0], "id");
+    let column: Vec<&str> = rows[1..].iter().map(|r| r[0]).collect();
+    assert_eq!(
+        &header[1..],
+        column.as_slice(),
+        "header row and column must list the same ids"
+    );
+    assert!(
+        rows.iter().all(|r| r.len() == header.len()),
+        "matrix must be square"
+    );
+
+    let tomato_row = rows.iter().find(|r| r[0] == "tomato").unwrap();
+    let basil_col = header.iter().position(|id| *id == "basil").unwrap();
+    let fennel_col = header.iter().position(|id| *id == "fennel").unwrap();
+    assert_eq!(tomato_row[basil_col], "2");
+    assert_eq!(tomato_row[fennel_col], "-3");
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/companions/graph.dot
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_companion_graph_dot_contains_known_edges() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/companions/graph.dot")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    assert!(body.starts_with("digraph companions {"));
+    assert!(body.trim_end().ends_with('}'));
+    assert!(body.contains(r#""tomato" [label="Tomato"];"#));
+    assert!(body.contains(r#""tomato" -> "basil" [color=green];"#));
+    assert!(body.contains(r#""tomato" -> "fennel" [color=red];"#));
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/vegetables/{id}/suggestions
+// ---------------------------------------------------------------------------
+
+fn suggestion_ids(body: &serde_json::Value) -> Vec<String> {
+    body["payload"]["suggestions"]
+        .as_array()
+        .expect("suggestions must be an array")
+        .iter()
+        .map(|s| s["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[actix_web::test]
+async fn test_suggestions_rank_good_companion_above_neutral() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/tomato/suggestions")
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let ids = suggestion_ids(&body);
+
+    let basil = ids
+        .iter()
+        .position(|id| id == "basil")
+        .expect("basil suggested");
+    let turnip = ids
+        .iter()
+        .position(|id| id == "turnip")
+        .expect("turnip suggested");
+    assert!(
+        basil < turnip,
+        "good companion basil must rank above neutral turnip"
+    );
+    assert!(
+        !ids.contains(&"fennel".to_string()),
+        "bad companions are excluded"
+    );
+    assert!(
+        !ids.contains(&"tomato".to_string()),
+        "the vegetable itself is excluded"
+    );
+}
+
+#[actix_web::test]
+async fn test_suggestions_season_filter_keeps_only_active_vegetables() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/tomato/suggestions")
+        .to_request();
+    let all: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/tomato/suggestions?season=Winter")
+        .to_request();
+    let winter: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let all_ids = suggestion_ids(&all);
+    let winter_ids = suggestion_ids(&winter);
+
+    assert!(winter_ids.iter().all(|id| all_ids.contains(id)));
+    assert!(
+        winter_ids.len() < all_ids.len(),
+        "summer-only vegetables must be filtered out in winter"
+    );
+}
+
+#[actix_web::test]
+async fn test_suggestions_unknown_id_returns_404() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/nonexistent-vegetable/suggestions")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 404);
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/companions/demo
+// ---------------------------------------------------------------------------
+
+// ---------------------------------------------------------------------------
+// GET /api/vegetables/{id}/neighbors-preview
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_neighbors_preview_centres_vegetable_next_to_good_companion() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/tomato/neighbors-preview")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let grid = &body["payload"]["grid"];
+    assert_eq!(grid[1][1]["id"], "tomato");
+    assert_eq!(grid[1][1]["role"], "Center");
+
+    let adjacent = [&grid[0][1], &grid[1][0], &grid[1][2], &grid[2][1]];
+    assert!(
+        adjacent
+            .iter()
+            .any(|c| c["role"] == "Good" && c["score"] == 2),
+        "a good companion must touch the centre: {grid}"
+    );
+    let bad: Vec<_> = adjacent.iter().filter(|c| c["role"] == "Bad").collect();
+    assert_eq!(bad.len(), 1, "exactly one bad companion is shown: {grid}");
+    assert!(grid[0][0].is_null());
+    assert_eq!(body["payload"]["score"], 3);
+}
+
+#[actix_web::test]
+async fn test_neighbors_preview_unknown_id_returns_404() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/mandrake/neighbors-preview")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 404);
+}
+
+#[actix_web::test]
+async fn test_companion_demo_plans_only_the_two_species() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/companions/demo?a=tomato&b=basil&rows=3&cols=3")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let payload = &body["payload"];
+    assert_eq!(payload["a"], "tomato");
+    assert_eq!(payload["b"], "basil");
+    let grid = payload["grid"].as_array().expect("grid must be an array");
+    assert_eq!(grid.len(), 3);
+    let ids: std::collections::HashSet<&str> = grid
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .filter_map(|cell| cell.get("id").and_then(|v| v.as_str()))
+        .collect();
+    assert!(ids.contains("tomato") && ids.contains("basil"), "{ids:?}");
+    assert!(
+        ids.iter().all(|id| *id == "tomato" || *id == "basil"),
+        "{ids:?}"
+    );
+    assert!(
+        payload["score"].as_i64().unwrap() > 0,
+        "tomato and basil are good companions"
+    );
+}
+
+#[actix_web::test]
+async fn test_companion_demo_unknown_id_returns_400() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/companions/demo?a=tomato&b=triffid")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert!(body["error"].as_str().unwrap().contains("triffid"));
+}
+
+#[actix_web::test]
+async fn test_companion_demo_rejects_out_of_range_dimensions() {
+    let app = test::init_service(build_app_postgres().await).await;
+    for query in ["rows=0", "cols=11", "rows=-1"] {
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/companions/demo?a=tomato&b=basil&{query}"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 400, "{query}");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/vegetables/{id}/plan-preview
+// ---------------------------------------------------------------------------
+
+async fn plan_preview_grid(id: &str) -> Vec<serde_json::Value> {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/vegetables/{id}/plan-preview"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    body["payload"]["weeks"][0]["grid"]
+        .as_array()
+        .expect("grid must be an array")
+        .iter()
+        .flat_map(|row| row.as_array().unwrap().clone())
+        .collect()
+}
+
+#[actix_web::test]
+async fn test_plan_preview_pumpkin_shows_span_4_blocks() {
+    let cells = plan_preview_grid("pumpkin").await;
+    assert_eq!(cells.len(), 32);
+    let anchors: Vec<_> = cells
+        .iter()
+        .filter(|c| c["type"] == "Overflowing")
+        .collect();
+    assert_eq!(anchors.len(), 2, "two 4×4 pumpkins fit a 4×8 bed");
+    assert!(anchors
+        .iter()
+        .all(|c| c["id"] == "pumpkin" && c["widthCells"] == 4 && c["lengthCells"] == 4));
+    assert_eq!(
+        cells.iter().filter(|c| c["type"] == "Overflowed").count(),
+        30
+    );
+}
+
+#[actix_web::test]
+async fn test_plan_preview_radish_fills_dense_self_contained_cells() {
+    let cells = plan_preview_grid("radish").await;
+    assert_eq!(cells.len(), 32);
+    assert!(cells.iter().all(|c| c["type"] == "SelfContained"
+        && c["id"] == "radish"
+        && c["plantsPerCell"].as_u64().unwrap() > 1));
+}
+
+#[actix_web::test]
+async fn test_plan_preview_unknown_id_returns_404() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/mandrake/plan-preview")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 404);
+}
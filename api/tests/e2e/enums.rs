@@ -0,0 +1,91 @@
+use crate::common::build_app_postgres;
+use actix_web::test;
+
+// ---------------------------------------------------------------------------
+// GET /api/enums
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_get_enums_lists_summer_under_seasons() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get().uri("/api/enums").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let seasons = body["payload"]["seasons"].as_array().unwrap();
+    assert!(
+        seasons.iter().any(|s| s == "Summer"),
+        "seasons must list \"Summer\": {seasons:?}"
+    );
+}
+
+#[actix_web::test]
+async fn test_get_enums_returns_every_enum() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get().uri("/api/enums").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    for key in [
+        "seasons",
+        "soilTypes",
+        "sunExposures",
+        "regions",
+        "categories",
+        "lifecycles",
+        "levels",
+    ] {
+        assert!(
+            !body["payload"][key].as_array().unwrap().is_empty(),
+            "'{key}' must be a non-empty array"
+        );
+    }
+    assert_eq!(
+        body["payload"]["levels"],
+        serde_json::json!(["Beginner", "Expert"])
+    );
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/categories
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_get_categories_gives_every_category_a_distinct_color() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get().uri("/api/enums").to_request();
+    let enums: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let req = test::TestRequest::get().uri("/api/categories").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let categories = body["payload"]["categories"].as_array().unwrap();
+
+    let listed: Vec<&serde_json::Value> = categories.iter().map(|c| &c["category"]).collect();
+    let expected: Vec<&serde_json::Value> = enums["payload"]["categories"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .collect();
+    assert_eq!(listed, expected, "every category must have rendering hints");
+
+    let mut colors: Vec<&str> = Vec::new();
+    for c in categories {
+        let color = c["colorHex"].as_str().unwrap();
+        assert!(
+            color.len() == 7
+                && color.starts_with('#')
+                && color[1..].chars().all(|ch| ch.is_ascii_hexdigit()),
+            "'{color}' is not #RRGGBB"
+        );
+        assert!(!c["icon"].as_str().unwrap().is_empty());
+        assert!(!colors.contains(&color), "'{color}' is used twice");
+        colors.push(color);
+    }
+}
+
+#[actix_web::test]
+async fn test_variety_carries_its_category_color() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/varieties/tomato")
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["payload"]["category"], "Fruit");
+    assert_eq!(body["payload"]["colorHex"], "#E53935");
+    assert_eq!(body["payload"]["icon"], "🍅");
+}
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use crate::common::{build_app_postgres, build_app_with_startup, db::unreachable_pool, test_pool};
+use actix_web::{test, web};
+use garden::adapters::inbound::http::dto::{SharedStartupStatus, StartupStatus};
+use garden::adapters::outbound::postgres::startup::{
+    load_catalogue, reload_until_loaded, RetryPolicy,
+};
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy {
+        attempts: 2,
+        initial_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(2),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/health
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_health_is_ok_after_a_normal_startup() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get().uri("/api/health").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["payload"]["status"], "Ok");
+    assert!(body["payload"].get("error").is_none());
+    assert_eq!(body["_links"]["self"]["href"], "/api/health");
+}
+
+#[actix_web::test]
+async fn test_startup_load_succeeds_against_the_database() {
+    let loaded = load_catalogue(&test_pool().await, &fast_policy()).await;
+    let status = StartupStatus::from_load(&loaded);
+    assert!(loaded.is_ok(), "{loaded:?}");
+    assert!(status.degraded_reason.is_none());
+}
+
+#[actix_web::test]
+async fn test_health_reports_degraded_mode_after_failed_startup_load() {
+    // Nothing listens on the database port: the migrations fail on every attempt, then
+    // the startup gives up with the last error instead of hanging.
+    let loaded = tokio::time::timeout(
+        Duration::from_secs(30),
+        load_catalogue(&unreachable_pool().await, &fast_policy()),
+    )
+    .await
+    .expect("the startup load gives up after its attempts");
+    let error = loaded.clone().unwrap_err();
+
+    let startup = web::Data::new(SharedStartupStatus::new(StartupStatus::from_load(&loaded)));
+    let app = test::init_service(build_app_with_startup(startup).await).await;
+    let req = test::TestRequest::get().uri("/api/health").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 503);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["payload"]["status"], "Degraded");
+    assert_eq!(body["payload"]["error"], error);
+}
+
+#[actix_web::test]
+async fn test_health_recovers_once_the_catalogue_loads() {
+    let startup = web::Data::new(SharedStartupStatus::new(StartupStatus::degraded(
+        "connection refused",
+    )));
+    let app = test::init_service(build_app_with_startup(startup.clone()).await).await;
+    let req = test::TestRequest::get().uri("/api/health").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 503);
+
+    // The background reload a degraded server runs, now that the database answers.
+    let problems = reload_until_loaded(&test_pool().await, &fast_policy()).await;
+    *startup.write().unwrap() = StartupStatus::from_load(&Ok(problems));
+
+    let req = test::TestRequest::get().uri("/api/health").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["payload"]["status"], "Ok");
+}
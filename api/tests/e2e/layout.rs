@@ -0,0 +1,254 @@
+use crate::common::{build_app_postgres, null_layout};
+use actix_web::test;
+
+// ---------------------------------------------------------------------------
+// POST /api/layout/validate
+// ---------------------------------------------------------------------------
+
+async fn validate(payload: serde_json::Value) -> (u16, serde_json::Value) {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/layout/validate")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    let status = resp.status().as_u16();
+    (status, test::read_body_json(resp).await)
+}
+
+#[actix_web::test]
+async fn test_validate_layout_reports_counts_for_a_valid_grid() {
+    let mut layout = null_layout(2, 3);
+    layout[0][0] = serde_json::json!({"type": "SelfContained", "id": "basil"});
+    layout[1][2] = serde_json::json!({"type": "Blocked"});
+    let (status, body) = validate(serde_json::json!({
+        "layout": layout,
+        "blockedRegions": [{"topLeft": {"row": 0, "col": 2}, "width": 1, "height": 1}]
+    }))
+    .await;
+
+    assert_eq!(status, 200, "{body}");
+    let report = &body["payload"];
+    assert_eq!(report["rows"], 2);
+    assert_eq!(report["cols"], 3);
+    assert_eq!(report["blocked"], 2);
+    assert_eq!(report["plantableCells"], 4);
+    assert_eq!(report["prePlaced"], 1);
+    assert_eq!(report["errors"], serde_json::json!([]));
+}
+
+#[actix_web::test]
+async fn test_validate_layout_empty_grid_returns_400_with_error() {
+    let (status, body) = validate(serde_json::json!({ "layout": [] })).await;
+    assert_eq!(status, 400);
+    let errors = body["payload"]["errors"].as_array().unwrap();
+    assert_eq!(
+        errors,
+        &vec![serde_json::json!("Layout must contain at least one row.")]
+    );
+}
+
+#[actix_web::test]
+async fn test_validate_layout_ragged_grid_returns_400_with_error() {
+    let (status, body) = validate(serde_json::json!({
+        "layout": [[{"type": "Empty"}, {"type": "Empty"}], [{"type": "Empty"}]]
+    }))
+    .await;
+    assert_eq!(status, 400);
+    assert_eq!(
+        body["payload"]["errors"][0],
+        "Layout row 1 has 1 cell(s); every row must have 2."
+    );
+}
+
+#[actix_web::test]
+async fn test_validate_layout_reports_unknown_vegetable() {
+    let mut layout = null_layout(2, 2);
+    layout[0][0] = serde_json::json!({"type": "SelfContained", "id": "triffid"});
+    layout[1][1] = serde_json::json!({"type": "SelfContained", "id": "tomato"});
+    let (status, body) = validate(serde_json::json!({ "layout": layout })).await;
+
+    assert_eq!(status, 400);
+    let report = &body["payload"];
+    assert_eq!(
+        report["errors"],
+        serde_json::json!(["Unknown vegetable 'triffid' in layout."])
+    );
+    assert_eq!(report["prePlaced"], 1, "The known plant is still counted");
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/layout/can-place
+// ---------------------------------------------------------------------------
+
+async fn can_place(payload: serde_json::Value) -> (u16, serde_json::Value) {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/layout/can-place")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    let status = resp.status().as_u16();
+    (status, test::read_body_json(resp).await)
+}
+
+#[actix_web::test]
+async fn test_can_place_fennel_next_to_tomato_reports_incompatibility() {
+    let mut layout = null_layout(3, 3);
+    layout[1][0] = serde_json::json!({"type": "SelfContained", "id": "tomato"});
+    let (status, body) = can_place(serde_json::json!({
+        "id": "fennel", "row": 1, "col": 1, "layout": layout
+    }))
+    .await;
+
+    assert_eq!(status, 200, "{body}");
+    let preview = &body["payload"];
+    assert_eq!(preview["free"], true);
+    assert_eq!(preview["compatible"], false);
+    assert_eq!(preview["scoreDelta"], -3);
+    assert_eq!(
+        preview["conflicts"],
+        serde_json::json!([{"id": "tomato", "anchor": {"row": 1, "col": 0}}])
+    );
+}
+
+#[actix_web::test]
+async fn test_can_place_basil_next_to_tomato_is_compatible() {
+    let mut layout = null_layout(3, 3);
+    layout[1][0] = serde_json::json!({"type": "SelfContained", "id": "tomato"});
+    let (status, body) = can_place(serde_json::json!({
+        "id": "basil", "row": 1, "col": 1, "layout": layout
+    }))
+    .await;
+
+    assert_eq!(status, 200, "{body}");
+    let preview = &body["payload"];
+    assert_eq!(preview["compatible"], true);
+    assert_eq!(preview["scoreDelta"], 2);
+    assert_eq!(preview["conflicts"], serde_json::json!([]));
+}
+
+#[actix_web::test]
+async fn test_can_place_reports_occupied_block_as_not_free() {
+    let mut layout = null_layout(2, 2);
+    layout[0][0] = serde_json::json!({"type": "SelfContained", "id": "basil"});
+    let (status, body) = can_place(serde_json::json!({
+        "id": "basil", "row": 0, "col": 0, "layout": layout
+    }))
+    .await;
+    assert_eq!(status, 200, "{body}");
+    assert_eq!(body["payload"]["free"], false);
+}
+
+#[actix_web::test]
+async fn test_can_place_block_past_the_grid_returns_400() {
+    let (status, body) = can_place(serde_json::json!({
+        "id": "tomato", "row": 1, "col": 1, "layout": null_layout(2, 2)
+    }))
+    .await;
+    assert_eq!(status, 400, "{body}");
+    assert_eq!(
+        body["error"],
+        "'tomato' needs a 2×2 block at [1,1], which runs past the 2×2 grid."
+    );
+}
+
+#[actix_web::test]
+async fn test_can_place_huge_anchor_returns_400() {
+    let (status, body) = can_place(serde_json::json!({
+        "id": "basil", "row": u64::MAX, "col": 0, "layout": null_layout(2, 2)
+    }))
+    .await;
+    assert_eq!(status, 400, "{body}");
+    assert_eq!(
+        body["error"],
+        format!(
+            "'basil' needs a 1×1 block at [{},0], which runs past the 2×2 grid.",
+            u64::MAX
+        )
+    );
+}
+
+#[actix_web::test]
+async fn test_can_place_unknown_variety_returns_422() {
+    let (status, _) = can_place(serde_json::json!({
+        "id": "mandrake", "row": 0, "col": 0, "layout": null_layout(2, 2)
+    }))
+    .await;
+    assert_eq!(status, 422);
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/layout/bulk-plant
+// ---------------------------------------------------------------------------
+
+async fn bulk_plant(payload: serde_json::Value) -> (u16, serde_json::Value) {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/layout/bulk-plant")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    let status = resp.status().as_u16();
+    (status, test::read_body_json(resp).await)
+}
+
+#[actix_web::test]
+async fn test_bulk_plant_rejects_a_plant_overlapping_a_blocked_cell() {
+    let mut layout = null_layout(4, 4);
+    layout[0][3] = serde_json::json!({"type": "Blocked"});
+    let (status, body) = bulk_plant(serde_json::json!({
+        "layout": layout,
+        "placements": [
+            {"id": "lettuce", "row": 0, "col": 0},
+            {"id": "radish", "row": 3, "col": 3},
+            {"id": "carrot", "row": 0, "col": 3}
+        ]
+    }))
+    .await;
+
+    assert_eq!(status, 200, "{body}");
+    let payload = &body["payload"];
+    assert_eq!(payload["accepted"], 2);
+    assert_eq!(payload["rejected"], 1);
+    let outcomes = payload["placements"].as_array().unwrap();
+    assert_eq!(outcomes[0]["accepted"], true);
+    assert_eq!(outcomes[1]["accepted"], true);
+    assert_eq!(outcomes[2]["accepted"], false);
+    assert_eq!(outcomes[2]["reason"], "blocked");
+
+    let grid = &payload["grid"];
+    assert_eq!(grid[0][0]["id"], "lettuce");
+    assert_eq!(grid[3][3]["id"], "radish");
+    assert_eq!(grid[0][3]["type"], "Blocked");
+}
+
+#[actix_web::test]
+async fn test_bulk_plant_rejects_an_anchor_far_outside_the_grid() {
+    let (status, body) = bulk_plant(serde_json::json!({
+        "layout": null_layout(2, 2),
+        "placements": [
+            {"id": "lettuce", "row": u64::MAX, "col": 0},
+            {"id": "lettuce", "row": 0, "col": u64::MAX},
+            {"id": "lettuce", "row": 1, "col": 1}
+        ]
+    }))
+    .await;
+
+    assert_eq!(status, 200, "{body}");
+    let outcomes = body["payload"]["placements"].as_array().unwrap();
+    assert_eq!(outcomes[0]["accepted"], false);
+    assert_eq!(outcomes[0]["reason"], "outOfBounds");
+    assert_eq!(outcomes[1]["reason"], "outOfBounds");
+    assert_eq!(outcomes[2]["accepted"], true);
+}
+
+#[actix_web::test]
+async fn test_bulk_plant_without_placements_returns_400() {
+    let (status, _) = bulk_plant(serde_json::json!({
+        "layout": null_layout(2, 2),
+        "placements": []
+    }))
+    .await;
+    assert_eq!(status, 400);
+}
@@ -2,7 +2,10 @@
 mod common;
 
 mod companions;
+mod enums;
 mod groups;
+mod health;
+mod layout;
 mod plan;
 mod scenarios;
 mod varieties;
@@ -54,6 +54,37 @@ async fn test_post_plan_minimal_has_grid_and_score() {
     );
 }
 
+#[actix_web::test]
+async fn test_post_plan_with_period_is_deterministic() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-01", "end": "2025-06-30"},
+        "region": "Temperate",
+        "layout": null_layout(4, 4)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["payload"]["deterministic"], true);
+}
+
+#[actix_web::test]
+async fn test_post_plan_without_period_is_not_deterministic() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "region": "Temperate",
+        "layout": null_layout(4, 4)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["payload"]["deterministic"], false);
+}
+
 #[actix_web::test]
 async fn test_post_plan_full_request_returns_200() {
     let app = test::init_service(build_app_postgres().await).await;
@@ -100,10 +131,12 @@ async fn test_post_plan_score_is_non_negative_for_compatible_garden() {
 
 // ---------------------------------------------------------------------------
 // POST /api/plan — validation errors
+//
+// Malformed JSON is `400`; a well-formed request the planner rejects is `422`.
 // ---------------------------------------------------------------------------
 
 #[actix_web::test]
-async fn test_post_plan_invalid_zero_dimensions_returns_400() {
+async fn test_post_plan_invalid_zero_dimensions_returns_422() {
     let app = test::init_service(build_app_postgres().await).await;
     let payload = serde_json::json!({
         "period": {"start": "2025-06-01", "end": "2025-08-31"},
@@ -115,7 +148,7 @@ async fn test_post_plan_invalid_zero_dimensions_returns_400() {
         .set_json(&payload)
         .to_request();
     let resp = test::call_service(&app, req).await;
-    assert_eq!(resp.status(), 400);
+    assert_eq!(resp.status(), 422);
 }
 
 #[actix_web::test]
@@ -179,6 +212,39 @@ async fn test_post_plan_with_existing_layout_preserved() {
     );
 }
 
+#[actix_web::test]
+async fn test_post_plan_pre_placed_overflowing_anchor_keeps_its_footprint() {
+    let app = test::init_service(build_app_postgres().await).await;
+    // Only the anchor is given; the tomato's three other cells are left `Empty`.
+    let mut layout = null_layout(3, 3);
+    layout[0][0] = serde_json::json!({"type": "Overflowing", "id": "tomato"});
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "fill": false,
+        "layout": layout
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let grid = &body["payload"]["weeks"][0]["grid"];
+
+    assert_eq!(grid[0][0]["type"], "Overflowing", "{body}");
+    assert_eq!(grid[0][0]["widthCells"], 2);
+    assert_eq!(grid[0][0]["lengthCells"], 2);
+    for (r, c) in [(0, 1), (1, 0), (1, 1)] {
+        assert_eq!(
+            grid[r][c],
+            serde_json::json!({"type": "Overflowed", "coveredBy": {"row": 0, "col": 0}}),
+            "cell [{r},{c}]"
+        );
+    }
+    assert_eq!(grid[0][2]["type"], "Empty");
+    assert_eq!(grid[2][0]["type"], "Empty");
+}
+
 #[actix_web::test]
 async fn test_post_plan_existing_layout_planted_date_sets_estimated_harvest_date() {
     let app = test::init_service(build_app_postgres().await).await;
@@ -311,3 +377,3063 @@ async fn test_post_plan_returns_links() {
     );
     assert_eq!(links["varieties"]["method"].as_str().unwrap(), "GET");
 }
+
+#[actix_web::test]
+async fn test_post_plan_warns_when_grid_fills_before_requested_quantity() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "preferences": [{"id": "tomato", "quantity": 3}],
+        "layout": null_layout(2, 2)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    // A tomato spans 2x2 cells: only one of the three fits.
+    assert_eq!(body["payload"]["weeks"][0]["grid"][0][0]["id"], "tomato");
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert!(
+        warnings.iter().any(|w| w.as_str()
+            == Some("Grid is full: requested quantities could not be placed for 'tomato'.")),
+        "Expected a truncation warning for tomato, got {warnings:?}"
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_target_fill_pct_leaves_half_the_grid_empty() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "targetFillPct": 50,
+        "layout": null_layout(4, 4)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    let empty = body["payload"]["weeks"][0]["grid"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .filter(|cell| cell["type"] == "Empty")
+        .count();
+    // Filling stops at 8 of 16 cells; the last block placed may overshoot by a few cells.
+    assert!(
+        (5..=8).contains(&empty),
+        "Expected roughly half of the 16 cells to stay empty, got {empty}"
+    );
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert!(
+        !warnings
+            .iter()
+            .any(|w| w.as_str().unwrap_or_default().contains("empty cell")),
+        "Intentionally empty cells must not be reported, got {warnings:?}"
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_target_fill_pct_above_100_returns_422() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "region": "Temperate",
+        "targetFillPct": 120,
+        "layout": null_layout(2, 2)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+}
+
+#[actix_web::test]
+async fn test_post_plan_without_fill_places_only_preferences() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "fill": false,
+        "preferences": [{"id": "basil", "quantity": 2}, {"id": "lettuce"}],
+        "layout": null_layout(6, 6)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    let cells: Vec<&serde_json::Value> = body["payload"]["weeks"][0]["grid"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .collect();
+    let count = |id: &str| cells.iter().filter(|c| c["id"] == id).count();
+    assert_eq!(count("basil"), 2);
+    // A preference without quantity gets a single plant.
+    assert_eq!(count("lettuce"), 1);
+    let empty = cells.iter().filter(|c| c["type"] == "Empty").count();
+    assert_eq!(empty, 36 - 3, "every other cell must stay empty");
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert!(
+        !warnings
+            .iter()
+            .any(|w| w.as_str().unwrap_or_default().contains("empty cell")),
+        "No empty-cell warning without fill, got {warnings:?}"
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_area_preference_allocates_the_covering_cells() {
+    let app = test::init_service(build_app_postgres().await).await;
+    // 0.54 m² / 0.09 m² per 30 cm cell = 6 basil cells.
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "fill": false,
+        "preferences": [{"id": "basil", "areaM2": 0.54}],
+        "layout": null_layout(4, 4)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    let basil = body["payload"]["weeks"][0]["grid"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .filter(|c| c["id"] == "basil")
+        .count();
+    assert_eq!(basil, 6, "{body}");
+    assert_eq!(
+        body["payload"]["applied"]["preferences"][0]["areaM2"],
+        serde_json::json!(0.54)
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_out_of_range_area_preference_returns_400() {
+    let app = test::init_service(build_app_postgres().await).await;
+    // Zero, negative and a 2 km × 2 km area; NaN and Infinity are not valid JSON.
+    for area in ["0", "-1.5", "4000000", "NaN", "Infinity"] {
+        let body = format!(
+            r#"{{"period": {{"start": "2025-06-02", "end": "2025-06-08"}}, "region": "Temperate",
+                "preferences": [{{"id": "basil", "areaM2": {area}}}], "layout": [[{{"type": "Empty"}}]]}}"#
+        );
+        let req = test::TestRequest::post()
+            .uri("/api/plan")
+            .insert_header(("Content-Type", "application/json"))
+            .set_payload(body)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 400, "areaM2 = {area}");
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        let error = body["error"].as_str().unwrap();
+        if area.parse::<f64>().is_ok_and(f64::is_finite) {
+            assert!(
+                error.starts_with("Preference areaM2 for 'basil'"),
+                "{error}"
+            );
+        }
+    }
+}
+
+#[actix_web::test]
+async fn test_post_plan_preference_with_quantity_and_area_returns_400() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "preferences": [{"id": "basil", "quantity": 2, "areaM2": 1.0}],
+        "layout": null_layout(4, 4)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert!(body["error"].as_str().unwrap().contains("areaM2"), "{body}");
+}
+
+#[actix_web::test]
+async fn test_post_plan_unknown_near_id_is_warned_about() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "preferences": [{"id": "basil", "near": ["tomato", "dragonfruit"]}],
+        "layout": null_layout(3, 3)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert!(
+        warnings.contains(&serde_json::json!(
+            "Unknown id(s) in 'near' ignored: 'dragonfruit'."
+        )),
+        "{warnings:?}"
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_available_sun_hours_excludes_sun_hungry_varieties() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "availableSunHours": 3,
+        "layout": null_layout(4, 4)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    let ids: Vec<&str> = body["payload"]["weeks"][0]["grid"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .filter_map(|cell| cell["id"].as_str())
+        .collect();
+    assert!(
+        !ids.is_empty(),
+        "Shade-tolerant crops must still be planned"
+    );
+    for id in ids {
+        assert!(
+            ["lettuce", "spinach", "mint"].contains(&id),
+            "Only crops needing at most 3 sun hours may be planned, got {id}"
+        );
+    }
+}
+
+#[actix_web::test]
+async fn test_post_plan_applied_echoes_resolved_inputs() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-04", "end": "2025-06-10"},
+        "region": "Temperate",
+        "preferences": [{"id": "courgette", "quantity": 1}, {"id": "basil"}],
+        "layout": null_layout(3, 4)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    let applied = &body["payload"]["applied"];
+    assert_eq!(
+        applied["preferences"],
+        serde_json::json!([
+            {"id": "zucchini", "quantity": 1},
+            {"id": "basil", "quantity": null}
+        ]),
+        "The aliased preference must be echoed with its canonical id"
+    );
+    // The period is snapped to full weeks.
+    assert_eq!(
+        applied["period"],
+        serde_json::json!({"start": "2025-06-02", "end": "2025-06-15"})
+    );
+    assert_eq!(applied["seasons"], serde_json::json!(["Summer"]));
+    assert_eq!(applied["rows"], 3);
+    assert_eq!(applied["cols"], 4);
+    assert_eq!(applied["cellSizeCm"], 30);
+    assert_eq!(applied["pack"], "Priority");
+    assert_eq!(applied["fill"], true);
+    assert_eq!(applied["suggestFixes"], false);
+}
+
+#[actix_web::test]
+async fn test_post_plan_preference_alias_places_canonical_variety() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "preferences": [{"id": "courgette", "quantity": 1}],
+        "layout": null_layout(3, 3)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    // A zucchini spans 3x3 cells: the whole grid is the canonical variety.
+    assert_eq!(body["payload"]["weeks"][0]["grid"][0][0]["id"], "zucchini");
+}
+
+#[actix_web::test]
+async fn test_post_plan_zero_quantity_returns_400() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "region": "Temperate",
+        "preferences": [{"id": "basil", "quantity": 0}],
+        "layout": null_layout(2, 2)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert!(body["error"].as_str().unwrap().contains("'basil'"));
+}
+
+#[actix_web::test]
+async fn test_post_plan_caps_quantity_far_exceeding_grid_capacity() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "preferences": [{"id": "basil", "quantity": 4_000_000_000u32}],
+        "layout": null_layout(2, 3)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+
+    let basil_cells = body["payload"]["weeks"][0]["grid"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .filter(|cell| cell["id"] == "basil")
+        .count();
+    assert_eq!(basil_cells, 6, "Every cell should hold basil");
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert!(
+        warnings.iter().any(|w| w.as_str()
+            == Some(
+                "Requested quantity 4000000000 for 'basil' exceeds the grid capacity; capped at 6."
+            )),
+        "Expected a capped-quantity warning, got {warnings:?}"
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_warns_when_required_plant_block_exceeds_grid() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "preferences": [{"id": "pumpkin", "quantity": 1}],
+        "layout": null_layout(3, 3)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert!(
+        warnings.iter().any(|w| w.as_str()
+            == Some("'pumpkin' needs a 4×4 block but the grid is 3×3; it was not placed.")),
+        "Expected an oversized-block warning, got {warnings:?}"
+    );
+    let cells: Vec<&serde_json::Value> = body["payload"]["weeks"][0]["grid"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .collect();
+    assert!(cells.iter().all(|cell| cell["id"] != "pumpkin"));
+    assert!(
+        cells.iter().all(|cell| cell["type"] != "Empty"),
+        "The fill phase still covers the grid"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/plan/diff
+// ---------------------------------------------------------------------------
+
+fn planted(id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "type": "SelfContained",
+        "id": id,
+        "name": id,
+        "reason": "",
+        "plantsPerCell": 1,
+        "estimatedHarvestDate": "2025-08-01"
+    })
+}
+
+#[actix_web::test]
+async fn test_plan_diff_reports_single_changed_cell() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "before": {
+            "grid": [[planted("basil"), planted("lettuce")], [{"type": "Empty"}, {"type": "Blocked"}]],
+            "score": 4
+        },
+        "after": {
+            "grid": [[planted("basil"), planted("carrot")], [{"type": "Empty"}, {"type": "Blocked"}]],
+            "score": 6
+        }
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan/diff")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let diff = &body["payload"];
+
+    assert_eq!(diff["rows"], 2);
+    assert_eq!(diff["cols"], 2);
+    assert_eq!(
+        diff["counts"],
+        serde_json::json!({"added": 0, "removed": 0, "changed": 1, "unchanged": 3})
+    );
+    assert_eq!(diff["scoreDelta"], 2);
+    assert_eq!(
+        diff["cells"][0][1],
+        serde_json::json!({"change": "Changed", "before": "lettuce", "after": "carrot"})
+    );
+    assert_eq!(diff["cells"][0][0]["change"], "Unchanged");
+    assert_eq!(body["_links"]["self"]["href"], "/api/plan/diff");
+}
+
+#[actix_web::test]
+async fn test_plan_diff_mismatched_dimensions_returns_400() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "before": {"grid": [[{"type": "Empty"}]]},
+        "after": {"grid": [[{"type": "Empty"}, {"type": "Empty"}]]}
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan/diff")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert!(body["error"].as_str().is_some_and(|e| !e.is_empty()));
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/plan/succession
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_plan_succession_follows_early_harvest_with_in_period_crop() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-05-05", "end": "2025-08-31"},
+        "region": "Temperate",
+        "preferences": [{"id": "radish", "quantity": 2}],
+        "layout": null_layout(3, 3)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan/succession")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let base = body["payload"]["base"].as_array().unwrap();
+    let succession = body["payload"]["succession"].as_array().unwrap();
+    assert_eq!(base.len(), 3);
+    assert_eq!(succession.len(), 3);
+
+    // Radish matures in a few weeks: its cells must get a follow-up crop planted on
+    // the radish harvest date and harvested before the period ends.
+    let mut followed = 0;
+    for (base_row, overlay_row) in base.iter().zip(succession) {
+        for (cell, next) in base_row
+            .as_array()
+            .unwrap()
+            .iter()
+            .zip(overlay_row.as_array().unwrap())
+        {
+            if cell["id"] != "radish" {
+                continue;
+            }
+            assert_eq!(next["follows"], "radish");
+            assert_ne!(next["id"], "radish");
+            assert_eq!(next["plantingDate"], cell["estimatedHarvestDate"]);
+            assert!(next["estimatedHarvestDate"].as_str().unwrap() <= "2025-08-31");
+            followed += 1;
+        }
+    }
+    assert!(followed >= 2, "every radish plant gets a follow-up");
+    assert_eq!(body["_links"]["self"]["href"], "/api/plan/succession");
+}
+
+#[actix_web::test]
+async fn test_plan_succession_warning_follows_accept_language() {
+    let app = test::init_service(build_app_postgres().await).await;
+    // One week: nothing planted in it is harvested in time for a follow-up.
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(2, 2)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan/succession")
+        .insert_header(("Accept-Language", "fr"))
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert!(
+        warnings.contains(&serde_json::json!(
+            "Aucune plante précoce ne laisse la place à une culture de relais compatible et de saison."
+        )),
+        "{warnings:?}"
+    );
+    assert!(
+        !warnings
+            .iter()
+            .any(|w| w.as_str().unwrap().starts_with("No early-maturing")),
+        "{warnings:?}"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/plan/transition
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_plan_transition_keeps_perennial_and_fills_summer_around_it() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let mut layout = null_layout(3, 3);
+    layout[1][1] =
+        serde_json::json!({"type": "SelfContained", "id": "chive", "plantedDate": "2024-04-01"});
+    layout[0][0] =
+        serde_json::json!({"type": "SelfContained", "id": "radish", "plantedDate": "2025-04-07"});
+    layout[2][2] =
+        serde_json::json!({"type": "SelfContained", "id": "lettuce", "plantedDate": "2025-04-07"});
+    let payload = serde_json::json!({
+        "targetSeason": "Summer",
+        "period": {"start": "2025-04-07", "end": "2025-05-25"},
+        "region": "Temperate",
+        "layout": layout
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan/transition")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let payload = &body["payload"];
+    assert_eq!(payload["targetSeason"], "Summer");
+    assert_eq!(
+        payload["kept"],
+        serde_json::json!([{"id": "chive", "anchor": {"row": 1, "col": 1}, "lifecycle": "Perennial"}])
+    );
+    let cleared: Vec<_> = payload["cleared"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(cleared, ["radish", "lettuce"]);
+
+    let plan = &payload["plan"];
+    assert_eq!(plan["applied"]["period"]["start"], "2025-06-02");
+    assert_eq!(plan["applied"]["period"]["end"], "2025-08-31");
+    let grid = plan["weeks"][0]["grid"].as_array().unwrap();
+    assert_eq!(grid[1][1]["id"], "chive", "the perennial stays in place");
+    let planted = grid
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .filter(|cell| cell["type"] != "Empty")
+        .count();
+    assert!(planted > 1, "summer crops fill around the perennial");
+    assert_eq!(body["_links"]["self"]["href"], "/api/plan/transition");
+}
+
+// ---------------------------------------------------------------------------
+// Stored plans: POST /api/plan?store=true, GET / PUT / DELETE /api/plan/{id}
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_stored_plan_create_fetch_delete_lifecycle() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(2, 2)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan?store=true")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 201);
+    let location = resp
+        .headers()
+        .get("location")
+        .expect("Stored plan must carry a Location header")
+        .to_str()
+        .unwrap()
+        .to_owned();
+    let created: serde_json::Value = test::read_body_json(resp).await;
+    let id = created["payload"]["id"].as_str().unwrap();
+    assert_eq!(location, format!("/api/plan/{id}"));
+
+    let req = test::TestRequest::get().uri(&location).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let fetched: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(fetched["payload"], created["payload"]);
+
+    let req = test::TestRequest::delete().uri(&location).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 204);
+
+    let req = test::TestRequest::get().uri(&location).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 404);
+}
+
+#[actix_web::test]
+async fn test_plan_accept_xml_keeps_float_precision() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(2, 3)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .insert_header(("Accept", "application/xml"))
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    assert!(resp
+        .headers()
+        .get("content-type")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .starts_with("application/xml"));
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    assert!(
+        body.contains("<usableAreaM2>0.54</usableAreaM2>"),
+        "f32 fields must not be widened: {body}"
+    );
+    assert!(body.contains("<rows>2</rows>"));
+}
+
+#[actix_web::test]
+async fn test_stored_plan_update_requires_current_etag() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(2, 2)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan?store=true")
+        .set_json(&payload)
+        .to_request();
+    let created: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let id = created["payload"]["id"].as_str().unwrap();
+    let uri = format!("/api/plan/{id}");
+
+    let req = test::TestRequest::get().uri(&uri).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.headers().get("etag").unwrap(), "\"v1\"");
+    let fetched: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(fetched["_links"]["update"]["method"], "PUT");
+    let mut edited = fetched["payload"].clone();
+    edited["weeks"][0]["grid"][0][0] = serde_json::json!({"type": "Empty"});
+
+    let req = test::TestRequest::put()
+        .uri(&uri)
+        .set_json(&edited)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 428, "an update without If-Match is refused");
+
+    let req = test::TestRequest::put()
+        .uri(&uri)
+        .insert_header(("If-Match", "\"v1\""))
+        .set_json(&edited)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(resp.headers().get("etag").unwrap(), "\"v2\"");
+    let updated: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(updated["payload"]["id"], id);
+    assert_eq!(
+        updated["payload"]["weeks"][0]["grid"][0][0]["type"],
+        "Empty"
+    );
+
+    let req = test::TestRequest::put()
+        .uri(&uri)
+        .insert_header(("If-Match", "\"v1\""))
+        .set_json(&edited)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 412, "a stale ETag is refused");
+    assert_eq!(resp.headers().get("etag").unwrap(), "\"v2\"");
+
+    let req = test::TestRequest::get().uri(&uri).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.headers().get("etag").unwrap(), "\"v2\"");
+    let fetched: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(fetched["payload"], updated["payload"]);
+
+    let req = test::TestRequest::put()
+        .uri("/api/plan/00000000-0000-0000-0000-000000000000")
+        .insert_header(("If-Match", "*"))
+        .set_json(&edited)
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 404);
+}
+
+#[actix_web::test]
+async fn test_stored_plan_markdown_guide_lists_every_placed_species() {
+    use std::collections::BTreeSet;
+
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-22"},
+        "region": "Temperate",
+        "layout": null_layout(3, 4)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan?store=true")
+        .set_json(&payload)
+        .to_request();
+    let created: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let id = created["payload"]["id"].as_str().unwrap();
+    let placed: BTreeSet<&str> = created["payload"]["weeks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|w| w["grid"].as_array().unwrap())
+        .flat_map(|row| row.as_array().unwrap())
+        .filter_map(|cell| cell["name"].as_str())
+        .collect();
+    assert!(!placed.is_empty(), "the plan places at least one species");
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/plan/{id}"))
+        .insert_header(("Accept", "text/markdown"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        "text/markdown; charset=utf-8"
+    );
+    let body = test::read_body(resp).await;
+    let guide = std::str::from_utf8(&body).unwrap();
+
+    assert!(guide.starts_with("# Planting guide\n"));
+    assert!(guide.contains("\n```\n"), "the layout is drawn as a grid");
+    assert!(
+        guide.contains("| Species | Symbol | Plants | Cells | Spacing (cm) | Days to harvest |")
+    );
+    for name in &placed {
+        assert!(
+            guide.contains(&format!("\n### {name}\n")),
+            "no heading for {name}"
+        );
+        assert!(
+            guide.lines().any(|l| l.starts_with(&format!("| {name} |"))),
+            "no quantities row for {name}"
+        );
+    }
+
+    // Spacing comes from the catalogue, not from the cell occupancy.
+    let first = created["payload"]["weeks"][0]["grid"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .find(|cell| cell["id"].is_string())
+        .unwrap();
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/varieties/{}", first["id"].as_str().unwrap()))
+        .to_request();
+    let variety: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let spacing = &variety["payload"]["spacingCm"];
+    assert!(
+        guide.contains(&format!("- Spacing: {spacing} cm")),
+        "no catalogue spacing of {spacing} cm in\n{guide}"
+    );
+
+    // A spacious plan was laid out at 1.2× the catalogue spacing; its guide says so.
+    let req = test::TestRequest::post()
+        .uri("/api/plan?store=true")
+        .set_json(serde_json::json!({
+            "period": {"start": "2025-06-02", "end": "2025-06-08"},
+            "region": "Temperate",
+            "density": "Spacious",
+            "preferences": [{"id": "basil", "quantity": 1}],
+            "layout": null_layout(2, 2)
+        }))
+        .to_request();
+    let created: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/plan/{}",
+            created["payload"]["id"].as_str().unwrap()
+        ))
+        .insert_header(("Accept", "text/markdown"))
+        .to_request();
+    let body = test::call_and_read_body(&app, req).await;
+    let guide = std::str::from_utf8(&body).unwrap();
+    let req = test::TestRequest::get()
+        .uri("/api/varieties/basil")
+        .to_request();
+    let basil: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let spacious = (basil["payload"]["spacingCm"].as_f64().unwrap() * 1.2).round();
+    let section = guide
+        .split("\n### ")
+        .find(|s| s.starts_with("Basil\n"))
+        .unwrap();
+    assert!(
+        section.contains(&format!("- Spacing: {spacious} cm")),
+        "no spacious spacing of {spacious} cm in\n{section}"
+    );
+}
+
+#[actix_web::test]
+async fn test_stored_plan_cells_stream_matches_json_grid() {
+    use std::collections::BTreeMap;
+
+    let app = test::init_service(build_app_postgres().await).await;
+    let mut layout = null_layout(5, 6);
+    layout[0][5] = serde_json::json!({"type": "Blocked"});
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-22"},
+        "region": "Temperate",
+        "layout": layout
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan?store=true")
+        .set_json(&payload)
+        .to_request();
+    let created: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let id = created["payload"]["id"].as_str().unwrap();
+    assert_eq!(
+        created["_links"]["cells"]["href"],
+        format!("/api/plan/{id}/cells")
+    );
+
+    let mut expected = BTreeMap::new();
+    for (w, week) in created["payload"]["weeks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .enumerate()
+    {
+        for (r, row) in week["grid"].as_array().unwrap().iter().enumerate() {
+            for (c, cell) in row.as_array().unwrap().iter().enumerate() {
+                if !["Empty", "Blocked", "Reserved"].contains(&cell["type"].as_str().unwrap()) {
+                    expected.insert((w, r, c), cell.clone());
+                }
+            }
+        }
+    }
+    assert!(!expected.is_empty());
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/plan/{id}/cells"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        "application/x-ndjson"
+    );
+    let body = test::read_body(resp).await;
+    let text = std::str::from_utf8(&body).unwrap();
+    assert!(text.ends_with('\n'));
+    let streamed: BTreeMap<_, _> = text
+        .lines()
+        .map(|line| {
+            let mut cell: serde_json::Map<String, serde_json::Value> =
+                serde_json::from_str(line).unwrap();
+            let at = |key: &str, cell: &mut serde_json::Map<_, _>| {
+                cell.remove(key).unwrap().as_u64().unwrap() as usize
+            };
+            let (w, r, c) = (
+                at("week", &mut cell),
+                at("row", &mut cell),
+                at("col", &mut cell),
+            );
+            ((w, r, c), serde_json::Value::Object(cell))
+        })
+        .collect();
+    assert_eq!(streamed.len(), text.lines().count(), "no duplicate lines");
+    assert_eq!(streamed, expected);
+
+    let req = test::TestRequest::get()
+        .uri("/api/plan/00000000-0000-0000-0000-000000000000/cells")
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 404);
+}
+
+#[actix_web::test]
+async fn test_post_plan_without_store_has_no_id() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "region": "Temperate",
+        "layout": null_layout(1, 1)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    assert!(resp.headers().get("location").is_none());
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert!(body["payload"].get("id").is_none());
+}
+
+#[actix_web::test]
+async fn test_get_unknown_stored_plan_returns_404() {
+    let app = test::init_service(build_app_postgres().await).await;
+    for uri in [
+        "/api/plan/00000000-0000-0000-0000-000000000000",
+        "/api/plan/not-a-uuid",
+    ] {
+        let req = test::TestRequest::get().uri(uri).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 404, "GET {uri}");
+    }
+}
+
+#[actix_web::test]
+async fn test_delete_unknown_stored_plan_returns_404() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::delete()
+        .uri("/api/plan/00000000-0000-0000-0000-000000000000")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 404);
+}
+
+// ---------------------------------------------------------------------------
+// Idempotency-Key on POST /api/plan
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_post_plan_same_idempotency_key_returns_same_plan() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(2, 2)
+    });
+    let mut responses = Vec::new();
+    for _ in 0..2 {
+        let req = test::TestRequest::post()
+            .uri("/api/plan")
+            .insert_header(("Idempotency-Key", "retry-abc-123"))
+            .set_json(&payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 201);
+        let replayed = resp.headers().contains_key("idempotent-replayed");
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        responses.push((replayed, body));
+    }
+    let (first_replayed, first) = &responses[0];
+    let (second_replayed, second) = &responses[1];
+    assert!(!first_replayed);
+    assert!(second_replayed);
+    assert!(first["payload"]["id"].is_string());
+    assert_eq!(first["payload"]["id"], second["payload"]["id"]);
+    assert_eq!(first["payload"], second["payload"]);
+}
+
+#[actix_web::test]
+async fn test_post_plan_different_idempotency_keys_store_distinct_plans() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(2, 2)
+    });
+    let mut ids = Vec::new();
+    for key in ["key-one", "key-two"] {
+        let req = test::TestRequest::post()
+            .uri("/api/plan")
+            .insert_header(("Idempotency-Key", key))
+            .set_json(&payload)
+            .to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        ids.push(body["payload"]["id"].clone());
+    }
+    assert_ne!(ids[0], ids[1]);
+}
+
+#[actix_web::test]
+async fn test_post_plan_invalid_idempotency_key_returns_400() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "region": "Temperate",
+        "layout": null_layout(2, 2)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .insert_header(("Idempotency-Key", "x".repeat(300)))
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+}
+
+// ---------------------------------------------------------------------------
+// suggestFixes: relocation suggestions for pre-placed bad companions
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_post_plan_suggest_fixes_proposes_relocation_for_bad_neighbours() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let mut layout = null_layout(3, 3);
+    layout[0][0] = serde_json::json!({"type": "SelfContained", "id": "tomato"});
+    layout[0][1] = serde_json::json!({"type": "SelfContained", "id": "fennel"});
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "suggestFixes": true,
+        "layout": layout
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert!(
+        warnings.iter().any(|w| w.as_str()
+            == Some(
+                "Pre-placed 'tomato' at [0,0] is next to its bad companion 'fennel' at [0,1]."
+            )),
+        "Expected a bad adjacency warning, got {warnings:?}"
+    );
+    let suggestions = body["payload"]["suggestions"].as_array().unwrap();
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0]["id"], "fennel");
+    assert_eq!(suggestions[0]["conflictsWith"], "tomato");
+    assert_eq!(
+        suggestions[0]["from"],
+        serde_json::json!({"row": 0, "col": 1})
+    );
+    assert_eq!(
+        suggestions[0]["to"],
+        serde_json::json!({"row": 0, "col": 2})
+    );
+    // Suggestions are not applied: fennel stays where it was submitted.
+    assert_eq!(body["payload"]["weeks"][0]["grid"][0][1]["id"], "fennel");
+}
+
+#[actix_web::test]
+async fn test_post_plan_without_suggest_fixes_omits_suggestions() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let mut layout = null_layout(2, 2);
+    layout[0][0] = serde_json::json!({"type": "SelfContained", "id": "tomato"});
+    layout[0][1] = serde_json::json!({"type": "SelfContained", "id": "fennel"});
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": layout
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert!(body["payload"].get("suggestions").is_none());
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/plan/batch
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_post_plan_batch_reports_per_item_errors_in_order() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!([
+        {
+            "period": {"start": "2025-06-02", "end": "2025-06-08"},
+            "region": "Temperate",
+            "layout": null_layout(2, 2)
+        },
+        {
+            "region": "Temperate",
+            "preferences": [{"id": "tomato", "quantity": 0}],
+            "layout": null_layout(2, 2)
+        },
+        {"region": "Atlantis", "layout": null_layout(2, 2)}
+    ]);
+    let req = test::TestRequest::post()
+        .uri("/api/plan/batch")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+
+    let items = body["payload"]["items"].as_array().unwrap();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0]["status"], "Ok");
+    assert_eq!(items[0]["plan"]["rows"], 2);
+    assert_eq!(items[1]["status"], "Error");
+    assert!(items[1]["error"].as_str().unwrap().contains("'tomato'"));
+    assert_eq!(items[2]["status"], "Error", "a malformed item fails alone");
+    assert_eq!(body["payload"]["succeeded"], 1);
+    assert_eq!(body["payload"]["failed"], 2);
+}
+
+#[actix_web::test]
+async fn test_plan_batch_hides_storage_errors_behind_a_generic_item_error() {
+    use garden::adapters::outbound::postgres::variety_repository::PostgresVarietyRepository;
+    use garden::application::use_cases::plan_batch::PlanBatchUseCase;
+
+    let repo = PostgresVarietyRepository::new(crate::common::db::unreachable_pool().await);
+    let request = serde_json::from_value(serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(2, 2)
+    }))
+    .unwrap();
+    let response = PlanBatchUseCase::new(&repo)
+        .execute(vec![Ok(request)], "en")
+        .await
+        .unwrap();
+    let items = serde_json::to_value(&response.items).unwrap();
+    assert_eq!(items[0]["status"], "Error");
+    assert_eq!(items[0]["error"], "Internal server error");
+}
+
+#[actix_web::test]
+async fn test_post_plan_batch_rejects_oversized_batch() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let item = serde_json::json!({"region": "Temperate", "layout": null_layout(1, 1)});
+    let payload = serde_json::Value::Array(vec![item; 21]);
+    let req = test::TestRequest::post()
+        .uri("/api/plan/batch")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_post_plan_batch_rejects_empty_batch() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/plan/batch")
+        .set_json(serde_json::json!([]))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_post_plan_blocked_region_keeps_central_cells_free() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(4, 4),
+        "blockedRegions": [{"topLeft": {"row": 1, "col": 1}, "width": 2, "height": 2}]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    let grid = &body["payload"]["weeks"][0]["grid"];
+    for (r, c) in [(1, 1), (1, 2), (2, 1), (2, 2)] {
+        let cell = &grid[r][c];
+        assert_eq!(cell["type"], "Blocked", "cell [{r},{c}] must be blocked");
+    }
+    let plants = grid
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .filter(|cell| cell["id"].is_string() || cell["coveredBy"].is_object())
+        .count();
+    assert_eq!(plants, 12, "the 12 border cells are still planted");
+}
+
+#[actix_web::test]
+async fn test_post_plan_reports_plantable_cells_and_usable_area() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let mut layout = null_layout(4, 4);
+    layout[3][3] = serde_json::json!({"type": "Blocked"});
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": layout,
+        "blockedRegions": [{"topLeft": {"row": 1, "col": 1}, "width": 2, "height": 2}],
+        "blockedCells": [{"row": 0, "col": 0}]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    let plan = &body["payload"];
+    assert_eq!(plan["blockedCells"], 6);
+    assert_eq!(plan["plantableCells"], 10);
+    assert_eq!(plan["usableAreaM2"], 0.9);
+}
+
+#[actix_web::test]
+async fn test_post_plan_blocked_cells_stay_unplanted_while_neighbours_fill() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(4, 4),
+        "blockedCells": [{"row": 0, "col": 3}, {"row": 2, "col": 1}, {"row": 9, "col": 0}]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    let grid = &body["payload"]["weeks"][0]["grid"];
+    assert_eq!(grid[0][3]["type"], "Blocked");
+    assert_eq!(grid[2][1]["type"], "Blocked");
+    let plants = grid
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .filter(|cell| cell["id"].is_string() || cell["coveredBy"].is_object())
+        .count();
+    assert_eq!(plants, 14, "every other cell is still planted");
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert!(
+        warnings
+            .iter()
+            .any(|w| w == "Blocked cell [9,0] lies outside the grid, ignored."),
+        "{warnings:?}"
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_reports_empty_regions_by_size() {
+    let app = test::init_service(build_app_postgres().await).await;
+    // A blocked path down column 1 isolates a 1-wide pocket on the left.
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "fill": false,
+        "layout": null_layout(4, 4),
+        "blockedRegions": [{"topLeft": {"row": 0, "col": 1}, "width": 1, "height": 4}]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(
+        body["payload"]["emptyRegions"],
+        serde_json::json!([
+            {"topLeft": {"row": 0, "col": 2}, "width": 2, "height": 4, "cellCount": 8},
+            {"topLeft": {"row": 0, "col": 0}, "width": 1, "height": 4, "cellCount": 4}
+        ])
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_normalizes_objective_weights() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(2, 2),
+        "weights": {"CompanionScore": 3, "diversity": 1, "Yield": 0}
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(
+        body["payload"]["applied"]["weights"],
+        serde_json::json!({"CompanionScore": 0.75, "Diversity": 0.25})
+    );
+    let reason = body["payload"]["weeks"][0]["grid"][0][0]["reason"]
+        .as_str()
+        .unwrap();
+    assert!(reason.contains("[companion "), "{reason}");
+    assert!(reason.contains(" × 0.25 = "), "{reason}");
+}
+
+#[actix_web::test]
+async fn test_post_plan_negative_weight_returns_400() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "region": "Temperate",
+        "layout": null_layout(2, 2),
+        "weights": {"CompanionScore": 1, "Diversity": -0.5}
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(
+        body["error"],
+        "Objective weights must be non-negative numbers."
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_weights_summing_to_infinity_return_400() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "region": "Temperate",
+        "layout": null_layout(2, 2),
+        "weights": {"CompanionScore": 3e38, "Diversity": 3e38}
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(
+        body["error"],
+        "Objective weights are too large: their sum must be a finite number."
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_weighting_companion_then_diversity_returns_400() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "region": "Temperate",
+        "layout": null_layout(2, 2),
+        "weights": {"CompanionScore": 1, "companion_then_diversity": 1}
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(
+        body["error"],
+        "CompanionThenDiversity cannot be weighted; weight CompanionScore and Diversity instead."
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_companion_then_diversity_objective_is_applied() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-01", "end": "2025-06-30"},
+        "region": "Temperate",
+        "layout": null_layout(4, 4),
+        "objective": "companion_then_diversity"
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(
+        body["payload"]["applied"]["objective"],
+        "CompanionThenDiversity"
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_unknown_layout_vegetable_returns_422() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "region": "Temperate",
+        "layout": [[{"type": "SelfContained", "id": "triffid"}, {"type": "Empty"}]]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["error"], "Unknown vegetable 'triffid' in layout.");
+}
+
+#[actix_web::test]
+async fn test_post_plan_oversized_grid_returns_422() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "region": "Temperate",
+        "layout": null_layout(101, 100)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+}
+
+#[actix_web::test]
+async fn test_post_plan_ragged_layout_returns_422() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "region": "Temperate",
+        "layout": [[{"type": "Empty"}], [{"type": "Empty"}, {"type": "Empty"}]]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(
+        body["error"],
+        "Layout row 1 has 2 cell(s); every row must have 1."
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_normalized_score_is_independent_of_grid_size() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let mut results = Vec::new();
+    // Carrots above onions: every onion touches one carrot, whatever the grid width.
+    for (cols, quantity) in [(1, 1), (3, 3)] {
+        let payload = serde_json::json!({
+            "period": {"start": "2025-06-02", "end": "2025-06-08"},
+            "region": "Temperate",
+            "fill": false,
+            "preferences": [
+                {"id": "carrot", "quantity": quantity},
+                {"id": "onion", "quantity": quantity}
+            ],
+            "layout": null_layout(2, cols)
+        });
+        let req = test::TestRequest::post()
+            .uri("/api/plan")
+            .set_json(&payload)
+            .to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        results.push((
+            body["payload"]["weeks"][0]["score"].as_i64().unwrap(),
+            body["payload"]["normalizedScore"].as_f64().unwrap(),
+        ));
+    }
+    let [(small_score, small_norm), (large_score, large_norm)] = results[..] else {
+        unreachable!()
+    };
+    assert!(large_score > small_score, "raw score grows with the grid");
+    assert!(small_norm > 0.0);
+    assert_eq!(small_norm, large_norm);
+}
+
+#[actix_web::test]
+async fn test_post_plan_timeout_returns_partial_grid_with_warning() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "timeoutMs": 1,
+        "layout": null_layout(100, 100)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert!(
+        warnings.iter().any(|w| w.as_str()
+            == Some("Planning stopped early after 1ms; the grid may be partially filled.")),
+        "Expected a stopped-early warning, got {warnings:?}"
+    );
+    let grid = body["payload"]["weeks"][0]["grid"].as_array().unwrap();
+    assert_eq!(grid.len(), 100);
+    assert!(grid.iter().all(|row| row.as_array().unwrap().len() == 100));
+}
+
+#[actix_web::test]
+async fn test_post_plan_same_request_yields_identical_bytes() {
+    let app = test::init_service(build_app_postgres().await).await;
+    // Sown entries and weights are JSON maps; both used to leak hash iteration order.
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-15"},
+        "region": "Temperate",
+        "sown": {
+            "lettuce": [{"sowingDate": "2025-04-01", "seedsSown": 2}],
+            "basil": [{"sowingDate": "2025-04-01", "seedsSown": 2}],
+            "radish": [{"sowingDate": "2025-05-01", "seedsSown": 2}],
+            "carrot": [{"sowingDate": "2025-04-15", "seedsSown": 2}]
+        },
+        "weights": {"CompanionScore": 2, "Diversity": 1, "Yield": 1},
+        "layout": null_layout(4, 5)
+    });
+    let mut bodies = Vec::new();
+    for _ in 0..2 {
+        let req = test::TestRequest::post()
+            .uri("/api/plan")
+            .set_json(&payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        bodies.push(test::read_body(resp).await);
+    }
+    assert_eq!(bodies[0], bodies[1]);
+}
+
+#[actix_web::test]
+async fn test_post_plan_neutral_competitor_penalty_is_applied() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(3, 3),
+        "neutralCompetitorPenalty": 2
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["payload"]["applied"]["neutralCompetitorPenalty"], 2);
+
+    let mut default = payload.clone();
+    default
+        .as_object_mut()
+        .unwrap()
+        .remove("neutralCompetitorPenalty");
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&default)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["payload"]["applied"]["neutralCompetitorPenalty"], 0);
+
+    // 2³¹ would wrap to a negative penalty, a bonus.
+    let mut huge = payload.clone();
+    huge["neutralCompetitorPenalty"] = serde_json::json!(2_147_483_648u32);
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&huge)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(
+        body["error"],
+        "neutralCompetitorPenalty must be at most 1000."
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_rejects_oversized_avoid_pair_penalty() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(serde_json::json!({
+            "period": {"start": "2025-06-02", "end": "2025-06-08"},
+            "region": "Temperate",
+            "layout": null_layout(3, 3),
+            "avoidPairs": [["mint", "lettuce"]],
+            "avoidPairPenalty": 30_000_000,
+            "weights": {"CompanionScore": 1}
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["error"], "avoidPairPenalty must be at most 1000.");
+}
+
+#[actix_web::test]
+async fn test_post_plan_mutual_companions_is_applied() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-01", "end": "2025-06-30"},
+        "region": "Temperate",
+        "layout": null_layout(4, 4),
+        "mutualCompanions": true
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["payload"]["applied"]["mutualCompanions"], true);
+    assert!(!body["payload"]["weeks"][0]["grid"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+}
+
+#[actix_web::test]
+async fn test_post_plan_quick_summer_contains_only_beginner_friendly_species() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/plan/quick")
+        .set_json(serde_json::json!({"season": "Summer", "rows": 4, "cols": 4}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["payload"]["applied"]["strictCompanions"], true);
+
+    let req = test::TestRequest::get()
+        .uri("/api/varieties?beginner_friendly=true&size=100")
+        .to_request();
+    let varieties: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let beginner: Vec<&str> = varieties["payload"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["payload"]["id"].as_str().unwrap())
+        .collect();
+
+    let placed: Vec<&str> = body["payload"]["weeks"][0]["grid"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .filter_map(|cell| cell["id"].as_str())
+        .collect();
+    assert!(!placed.is_empty(), "expected a planted grid");
+    for id in placed {
+        assert!(beginner.contains(&id), "'{id}' is not beginner-friendly");
+    }
+}
+
+#[actix_web::test]
+async fn test_post_plan_beginner_prefer_mode_falls_back_to_advanced_varieties() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/varieties?beginner_friendly=true&size=100")
+        .to_request();
+    let varieties: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let beginner: Vec<&str> = varieties["payload"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["payload"]["id"].as_str().unwrap())
+        .collect();
+
+    let placed_for = |mode: &str| {
+        serde_json::json!({
+            "period": {"start": "2025-06-02", "end": "2025-06-08"},
+            "region": "Temperate",
+            "level": "Beginner",
+            "beginnerMode": mode,
+            "exclusions": beginner,
+            "layout": null_layout(3, 3)
+        })
+    };
+    let mut placed = Vec::new();
+    for mode in ["Strict", "Prefer"] {
+        let req = test::TestRequest::post()
+            .uri("/api/plan")
+            .set_json(placed_for(mode))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        let ids: Vec<String> = body["payload"]["weeks"][0]["grid"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|row| row.as_array().unwrap())
+            .filter_map(|cell| cell["id"].as_str().map(str::to_owned))
+            .collect();
+        placed.push(ids);
+    }
+    assert!(placed[0].is_empty(), "strict mode planted {:?}", placed[0]);
+    assert!(!placed[1].is_empty(), "prefer mode planted nothing");
+    for id in &placed[1] {
+        assert!(!beginner.contains(&id.as_str()), "'{id}' was excluded");
+    }
+}
+
+#[actix_web::test]
+async fn test_post_plan_quick_oversized_grid_returns_422() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/plan/quick")
+        .set_json(serde_json::json!({"season": "Spring", "rows": 100000, "cols": 100000}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+}
+
+#[actix_web::test]
+async fn test_post_plan_quick_empty_grid_is_rejected_before_allocating() {
+    let app = test::init_service(build_app_postgres().await).await;
+    for (rows, cols, error) in [
+        (
+            0,
+            1_000_000_000usize,
+            "Layout must contain at least one row.",
+        ),
+        (1_000_000_000, 0, "Layout rows must not be empty."),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/api/plan/quick")
+            .set_json(serde_json::json!({"season": "Summer", "rows": rows, "cols": cols}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 422, "{rows}×{cols}");
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"], error);
+    }
+}
+
+#[actix_web::test]
+async fn test_post_plan_cells_report_the_phase_that_placed_them() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "preferences": [{"id": "lettuce", "quantity": 1}],
+        "layout": null_layout(2, 3)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let anchors: Vec<&serde_json::Value> = body["payload"]["weeks"][0]["grid"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .filter(|cell| cell["id"].is_string())
+        .collect();
+    assert!(anchors.len() > 1, "expected the fill to plant more cells");
+
+    let explicit: Vec<_> = anchors
+        .iter()
+        .filter(|cell| cell["placedInPhase"] == 1)
+        .collect();
+    assert_eq!(explicit.len(), 1, "{anchors:?}");
+    assert_eq!(explicit[0]["id"], "lettuce");
+    assert_eq!(
+        anchors
+            .iter()
+            .filter(|cell| cell["placedInPhase"] == 2)
+            .count(),
+        anchors.len() - 1,
+        "{anchors:?}"
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_pre_placed_cells_have_no_phase() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": [[{"type": "SelfContained", "id": "lettuce"}, {"type": "Empty"}]]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let cell = &body["payload"]["weeks"][0]["grid"][0][0];
+    assert_eq!(cell["id"], "lettuce");
+    assert!(cell.get("placedInPhase").is_none(), "{cell}");
+}
+
+#[actix_web::test]
+async fn test_post_plan_fully_blocked_grid_warns_that_preferences_are_ignored() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let blocked_row = vec![serde_json::json!({"type": "Blocked"}); 3];
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "preferences": [{"id": "tomato", "quantity": 2}],
+        "layout": vec![blocked_row; 3]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert_eq!(
+        warnings,
+        &vec![serde_json::json!(
+            "No plantable cells available; 1 preference(s) ignored."
+        )]
+    );
+    let grid = body["payload"]["weeks"][0]["grid"].as_array().unwrap();
+    assert!(grid
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .all(|cell| cell["type"] == "Blocked"));
+}
+
+#[actix_web::test]
+async fn test_post_plan_intensive_density_fits_more_plants_per_cell_than_spacious() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let mut plants_per_cell = Vec::new();
+    for density in ["Intensive", "Spacious"] {
+        // Basil's 20 cm spacing sits between 2×2 and 1×1 plants per 30 cm cell.
+        let payload = serde_json::json!({
+            "period": {"start": "2025-06-02", "end": "2025-06-08"},
+            "region": "Temperate",
+            "preferences": [{"id": "basil", "quantity": 1}],
+            "fill": false,
+            "density": density,
+            "layout": null_layout(1, 1)
+        });
+        let req = test::TestRequest::post()
+            .uri("/api/plan")
+            .set_json(&payload)
+            .to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        let cell = &body["payload"]["weeks"][0]["grid"][0][0];
+        assert_eq!(cell["id"], "basil", "{body}");
+        assert_eq!(body["payload"]["applied"]["density"], density);
+        plants_per_cell.push(cell["plantsPerCell"].as_u64().unwrap());
+    }
+    assert!(
+        plants_per_cell[0] > plants_per_cell[1],
+        "intensive vs spacious: {plants_per_cell:?}"
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_echoes_standard_density_by_default() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(1, 1)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["payload"]["applied"]["density"], "Standard");
+    assert_eq!(body["payload"]["applied"]["spacingMultiplier"], 1.0);
+}
+
+#[actix_web::test]
+async fn test_post_plan_recommendations_for_tomato_rank_basil_near_the_top() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let mut layout = null_layout(2, 2);
+    layout[0][0] = serde_json::json!({"type": "SelfContained", "id": "tomato"});
+    let req = test::TestRequest::post()
+        .uri("/api/plan/recommendations")
+        .set_json(serde_json::json!({"layout": layout, "season": "Summer"}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["payload"]["present"], serde_json::json!(["tomato"]));
+
+    let ids: Vec<&str> = body["payload"]["recommendations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["id"].as_str().unwrap())
+        .collect();
+    let basil = ids.iter().position(|id| *id == "basil");
+    assert!(
+        basil.is_some_and(|i| i < 5),
+        "basil should be among the top recommendations: {ids:?}"
+    );
+    assert!(!ids.contains(&"fennel"), "fennel harms tomato: {ids:?}");
+    assert!(!ids.contains(&"tomato"), "tomato is already planted");
+}
+
+#[actix_web::test]
+async fn test_post_plan_recommendations_unknown_vegetable_returns_422() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/plan/recommendations")
+        .set_json(serde_json::json!({
+            "layout": [[{"type": "SelfContained", "id": "mandrake"}]]
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+}
+
+#[actix_web::test]
+async fn test_post_plan_place_one_puts_basil_next_to_tomato() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let mut layout = null_layout(3, 3);
+    layout[0][0] = serde_json::json!({"type": "SelfContained", "id": "tomato"});
+    let req = test::TestRequest::post()
+        .uri("/api/plan/place-one")
+        .set_json(serde_json::json!({"id": "basil", "layout": layout}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let payload = &body["payload"];
+    assert_eq!(payload["id"], "basil");
+
+    let (row, col) = (
+        payload["anchor"]["row"].as_u64().unwrap(),
+        payload["anchor"]["col"].as_u64().unwrap(),
+    );
+    assert_eq!(
+        row + col,
+        1,
+        "basil must touch the tomato at [0,0]: {payload}"
+    );
+    assert!(payload["score"].as_i64().unwrap() > 0);
+    assert!(!payload["reason"].as_str().unwrap().is_empty());
+
+    let grid = payload["grid"].as_array().unwrap();
+    assert_eq!(grid[0][0]["id"], "tomato", "the tomato is left in place");
+    assert_eq!(grid[row as usize][col as usize]["id"], "basil");
+    let planted = grid
+        .iter()
+        .flat_map(|r| r.as_array().unwrap())
+        .filter(|c| c["type"] != "Empty")
+        .count();
+    assert_eq!(planted, 2, "nothing else is planted: {grid:?}");
+}
+
+#[actix_web::test]
+async fn test_post_plan_place_one_without_free_block_returns_422() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/plan/place-one")
+        .set_json(serde_json::json!({
+            "id": "basil",
+            "layout": [[{"type": "SelfContained", "id": "tomato"}, {"type": "Blocked"}]]
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["error"], "No free 1×1 block is left for 'basil'.");
+}
+
+#[actix_web::test]
+async fn test_post_plan_season_overlap_lists_shoulder_varieties() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let mut shoulder = Vec::new();
+    for overlap in [false, true] {
+        let req = test::TestRequest::post()
+            .uri("/api/plan")
+            .set_json(serde_json::json!({
+                "period": {"start": "2025-03-03", "end": "2025-03-09"},
+                "region": "Temperate",
+                "seasonOverlap": overlap,
+                "layout": null_layout(4, 4)
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        let applied = &body["payload"]["applied"];
+        assert_eq!(applied["seasonOverlap"], overlap);
+        shoulder.push(applied["shoulderVarieties"].as_array().unwrap().clone());
+    }
+    assert!(shoulder[0].is_empty(), "off by default");
+    // Green beans are sown from April to July: not in March, but in June, the first
+    // month of the season after spring.
+    assert!(
+        shoulder[1].contains(&serde_json::json!("green-bean")),
+        "early March borders June's sowings: {:?}",
+        shoulder[1]
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_compact_view_is_smaller_and_resolves_every_id() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-01", "end": "2025-06-14"},
+        "region": "Temperate",
+        "layout": null_layout(10, 10)
+    });
+    let mut sizes = Vec::new();
+    let mut compact = serde_json::Value::Null;
+    for uri in ["/api/plan", "/api/plan?view=compact"] {
+        let req = test::TestRequest::post()
+            .uri(uri)
+            .set_json(&payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let bytes = test::read_body(resp).await;
+        sizes.push(bytes.len());
+        compact = serde_json::from_slice(&bytes).unwrap();
+    }
+    assert!(
+        sizes[1] * 3 < sizes[0] * 2,
+        "compact view should be under two thirds of the full size: {sizes:?}"
+    );
+
+    let body = &compact["payload"];
+    let species = body["species"].as_object().unwrap();
+    let mut planted = 0;
+    for week in body["weeks"].as_array().unwrap() {
+        for cell in week["grid"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|r| r.as_array().unwrap())
+        {
+            assert!(cell.get("reason").is_none() && cell.get("name").is_none());
+            if let Some(id) = cell["id"].as_str() {
+                planted += 1;
+                assert!(
+                    species[id]["name"].is_string(),
+                    "'{id}' missing from species"
+                );
+                assert!(species[id]["reason"].is_string());
+            }
+        }
+    }
+    assert!(planted > 0, "expected a planted garden");
+}
+
+#[actix_web::test]
+async fn test_post_plan_round_trips_its_own_grid_as_layout() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let plan = |layout: serde_json::Value| {
+        test::TestRequest::post()
+            .uri("/api/plan")
+            .set_json(serde_json::json!({
+                "period": {"start": "2025-06-02", "end": "2025-06-08"},
+                "region": "Temperate",
+                "layout": layout
+            }))
+            .to_request()
+    };
+    let first: serde_json::Value =
+        test::call_and_read_body_json(&app, plan(null_layout(6, 6))).await;
+    let grid = first["payload"]["weeks"][0]["grid"].clone();
+    let second: serde_json::Value = test::call_and_read_body_json(&app, plan(grid.clone())).await;
+    let replanned = &second["payload"]["weeks"][0];
+
+    // Only what the planner decides is dropped: plants from a layout get a fixed reason
+    // and no `placedInPhase`.
+    let placement = |cell: &serde_json::Value| {
+        let mut cell = cell.clone();
+        let fields = cell.as_object_mut().unwrap();
+        fields.remove("reason");
+        fields.remove("placedInPhase");
+        cell
+    };
+    let cells = |g: &serde_json::Value| -> Vec<serde_json::Value> {
+        g.as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|r| r.as_array().unwrap().iter().map(placement))
+            .collect()
+    };
+    assert!(cells(&grid).iter().any(|c| c["type"] == "Overflowed"));
+    assert_eq!(cells(&replanned["grid"]), cells(&grid));
+    // The week score is recomputed from the finished grid, however it was planted.
+    assert_eq!(replanned["score"], first["payload"]["weeks"][0]["score"]);
+}
+
+#[actix_web::test]
+async fn test_post_plan_max_total_plants_leaves_cells_empty() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(serde_json::json!({
+            "period": {"start": "2025-06-02", "end": "2025-06-08"},
+            "region": "Temperate",
+            "preferences": [{"id": "chive", "quantity": 9}],
+            "maxTotalPlants": 40,
+            "fill": false,
+            "layout": null_layout(3, 3)
+        }))
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let payload = &body["payload"];
+    assert_eq!(payload["applied"]["maxTotalPlants"], 40);
+
+    let cells: Vec<&serde_json::Value> = payload["weeks"][0]["grid"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .collect();
+    let plants: u64 = cells
+        .iter()
+        .filter_map(|c| c["plantsPerCell"].as_u64())
+        .sum();
+    assert!(plants <= 40, "budget exceeded: {plants} plants");
+    assert!(cells.iter().any(|c| c["type"] == "Empty"));
+    let warnings = payload["warnings"].as_array().unwrap();
+    assert!(
+        warnings.iter().any(|w| w
+            .as_str()
+            .unwrap()
+            .starts_with("Plant budget of 40 reached")),
+        "{warnings:?}"
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_zero_max_total_plants_returns_400() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(serde_json::json!({
+            "region": "Temperate",
+            "maxTotalPlants": 0,
+            "layout": null_layout(2, 2)
+        }))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_post_plan_debug_raw_grid_matches_the_last_week() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(4, 4),
+        "blockedRegions": [{"topLeft": {"row": 0, "col": 0}, "width": 1, "height": 1}]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert!(
+        body["payload"].get("rawGrid").is_none(),
+        "debug is off by default"
+    );
+
+    let req = test::TestRequest::post()
+        .uri("/api/plan?debug=true")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let body = &body["payload"];
+    let raw = &body["rawGrid"]["cells"];
+    let last = body["weeks"].as_array().unwrap().last().unwrap();
+    let grid = &last["grid"];
+    let mut placed = 0;
+    for (r, row) in grid.as_array().unwrap().iter().enumerate() {
+        for (c, cell) in row.as_array().unwrap().iter().enumerate() {
+            let raw_cell = &raw[r][c];
+            assert_eq!(
+                raw_cell["blocked"].as_bool(),
+                Some(cell["type"] == "Blocked"),
+                "({r}, {c})"
+            );
+            let raw_id = raw_cell["variety"]["id"].as_str();
+            let id = match &cell["coveredBy"] {
+                serde_json::Value::Null => cell["id"].as_str(),
+                anchor => {
+                    assert_eq!(&raw_cell["variety"]["anchor"], anchor, "({r}, {c})");
+                    grid[anchor["row"].as_u64().unwrap() as usize]
+                        [anchor["col"].as_u64().unwrap() as usize]["id"]
+                        .as_str()
+                }
+            };
+            assert_eq!(raw_id, id, "({r}, {c})");
+            placed += usize::from(raw_id.is_some());
+        }
+    }
+    assert!(placed > 0, "expected a planted garden");
+
+    let req = test::TestRequest::post()
+        .uri("/api/plan?debug=true&store=true")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_post_plan_plantable_mask_plants_only_the_cross() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let mask = serde_json::json!([
+        [false, true, false],
+        [true, true, true],
+        [false, true, false]
+    ]);
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(serde_json::json!({
+            "period": {"start": "2025-06-02", "end": "2025-06-08"},
+            "region": "Temperate",
+            "layout": null_layout(3, 3),
+            "plantableMask": mask
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let grid = &body["payload"]["weeks"][0]["grid"];
+    for r in 0..3 {
+        for c in 0..3 {
+            let cell = &grid[r][c];
+            if mask[r][c] == true {
+                assert_ne!(cell["type"], "Blocked", "({r}, {c})");
+                assert_ne!(cell["type"], "Empty", "({r}, {c}) should be planted");
+            } else {
+                assert_eq!(cell["type"], "Blocked", "({r}, {c})");
+            }
+        }
+    }
+
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(serde_json::json!({
+            "region": "Temperate",
+            "layout": null_layout(3, 3),
+            "plantableMask": [[true, true], [true, true], [true, true]]
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert!(body["error"].as_str().unwrap().contains("plantableMask"));
+}
+
+#[actix_web::test]
+async fn test_post_plan_layout_warnings_follow_accept_language() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let mut layout = null_layout(3, 3);
+    layout[0][0] = serde_json::json!({"type": "SelfContained", "id": "tomato"});
+    layout[0][1] = serde_json::json!({"type": "SelfContained", "id": "fennel"});
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-04", "end": "2025-06-10"},
+        "region": "Temperate",
+        "layout": layout,
+        "blockedRegions": [{"topLeft": {"row": 5, "col": 5}, "width": 1, "height": 1}],
+        "blockedCells": [{"row": 7, "col": 7}, {"row": 0, "col": 0}]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .insert_header(("Accept-Language", "fr"))
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let warnings: Vec<&str> = body["payload"]["warnings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|w| w.as_str())
+        .collect();
+    for expected in [
+        "Période de planification ajustée à des semaines complètes",
+        "La zone bloquée en [5,5] (1x1) est hors de la grille, ignorée.",
+        "est voisin de son mauvais compagnon",
+        "La case bloquée [7,7] est hors de la grille, ignorée.",
+        "La case bloquée [0,0] contient une plante déjà en place, conservée.",
+    ] {
+        assert!(
+            warnings.iter().any(|w| w.contains(expected)),
+            "missing {expected:?} in {warnings:?}"
+        );
+    }
+    assert!(
+        !warnings
+            .iter()
+            .any(|w| w.contains("Planning period") || w.contains("Blocked ")),
+        "no English left: {warnings:?}"
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_warnings_follow_accept_language() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "availableSunHours": 0,
+        "layout": null_layout(2, 2)
+    });
+    let mut warnings = Vec::new();
+    for language in ["fr-FR,fr;q=0.9", "en"] {
+        let req = test::TestRequest::post()
+            .uri("/api/plan")
+            .insert_header(("Accept-Language", language))
+            .set_json(&payload)
+            .to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        warnings.push(body["payload"]["warnings"].clone());
+    }
+    // No variety grows without sun: the no-candidates warning comes first.
+    assert!(warnings[0][0]
+        .as_str()
+        .unwrap()
+        .starts_with("Aucun légume ne correspond aux contraintes"));
+    assert_eq!(
+        warnings[0][1],
+        "4 case(s) vide(s) : pas assez de variétés compatibles pour remplir toute la grille."
+    );
+    assert!(warnings[1][0]
+        .as_str()
+        .unwrap()
+        .starts_with("No vegetables match the given"));
+    assert_eq!(
+        warnings[1][1],
+        "4 empty cell(s): not enough compatible varieties to fill the entire grid."
+    );
+}
+
+#[actix_web::test]
+async fn test_post_plan_health_rates_a_companion_garden_highly() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(serde_json::json!({
+            "period": {"start": "2025-06-02", "end": "2025-06-08"},
+            "region": "Temperate",
+            "layout": [[
+                {"type": "SelfContained", "id": "tomato"},
+                {"type": "SelfContained", "id": "basil"}
+            ]]
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let health = &body["payload"]["health"];
+    assert_eq!(health["companion"], 100, "{health}");
+    assert_eq!(health["fill"], 100, "{health}");
+    assert_eq!(health["diversity"], 100, "{health}");
+    let overall = health["overall"].as_u64().unwrap();
+    assert!(overall >= 70, "{health}");
+    assert!(["A", "B"].contains(&health["grade"].as_str().unwrap()));
+}
+
+#[actix_web::test]
+async fn test_post_plan_what_if_removing_basil_drops_its_tomato_contribution() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let layout = serde_json::json!([[
+        {"type": "SelfContained", "id": "tomato"},
+        {"type": "SelfContained", "id": "basil"},
+        {"type": "Empty"}
+    ]]);
+    let req = test::TestRequest::post()
+        .uri("/api/plan/what-if")
+        .set_json(serde_json::json!({"layout": layout, "cell": {"row": 0, "col": 1}}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let body = &body["payload"];
+    // Tomato and basil list each other as good companions: +2 each way.
+    let contribution = 2 + 2;
+    assert_eq!(body["scoreBefore"], contribution);
+    assert_eq!(body["scoreAfter"], 0);
+    assert_eq!(body["scoreDelta"], -contribution);
+    assert_eq!(
+        body["removed"],
+        serde_json::json!([{"id": "basil", "anchor": {"row": 0, "col": 1}}])
+    );
+    assert_eq!(
+        body["affected"],
+        serde_json::json!([
+            {"id": "tomato", "anchor": {"row": 0, "col": 0}, "before": 2, "after": 0}
+        ])
+    );
+    assert_eq!(body["grid"][0][1]["type"], "Empty");
+
+    let req = test::TestRequest::post()
+        .uri("/api/plan/what-if")
+        .set_json(serde_json::json!({"layout": layout, "id": "basil"}))
+        .to_request();
+    let by_id: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(by_id["payload"]["scoreDelta"], -contribution);
+
+    for (body, status) in [
+        (serde_json::json!({"layout": layout}), 400),
+        (
+            serde_json::json!({"layout": layout, "cell": {"row": 0, "col": 2}}),
+            422,
+        ),
+        (serde_json::json!({"layout": layout, "id": "carrot"}), 422),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/api/plan/what-if")
+            .set_json(&body)
+            .to_request();
+        assert_eq!(
+            test::call_service(&app, req).await.status(),
+            status,
+            "{body}"
+        );
+    }
+}
+
+#[actix_web::test]
+async fn test_post_plan_reserved_cells_stay_unplanted_without_empty_warning() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let row = || {
+        serde_json::json!([
+            {"type": "Empty"},
+            {"type": "Empty"},
+            {"type": "Reserved"}
+        ])
+    };
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(serde_json::json!({
+            "period": {"start": "2025-06-02", "end": "2025-06-15"},
+            "region": "Temperate",
+            "layout": [row(), row(), row()]
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let body = &body["payload"];
+    for week in body["weeks"].as_array().unwrap() {
+        for row in week["grid"].as_array().unwrap() {
+            assert_eq!(row[2]["type"], "Reserved", "{row}");
+            assert_ne!(row[0]["type"], "Empty", "{row}");
+        }
+    }
+    let warnings = body["warnings"].as_array().unwrap();
+    assert!(
+        !warnings
+            .iter()
+            .any(|w| w.as_str().unwrap_or_default().contains("empty cell")),
+        "reserved cells are not empty cells: {warnings:?}"
+    );
+    assert_eq!(body["emptyRegions"], serde_json::json!([]));
+    assert_eq!(body["health"]["fill"], 100);
+}
+
+#[actix_web::test]
+async fn test_post_plan_rle_layout_plans_like_its_expanded_form() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let base = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate"
+    });
+    let with = |key: &str, layout: serde_json::Value| {
+        let mut body = base.clone();
+        body[key] = layout;
+        body
+    };
+    let expanded = with(
+        "layout",
+        serde_json::json!([
+            [{"type": "SelfContained", "id": "tomato"}, {"type": "Empty"}, {"type": "Empty"}, {"type": "Empty"}],
+            [{"type": "Blocked"}, {"type": "Blocked"}, {"type": "Empty"}, {"type": "Empty"}],
+            [{"type": "Empty"}, {"type": "Empty"}, {"type": "Empty"}, {"type": "Empty"}]
+        ]),
+    );
+    let rle = with(
+        "layoutRle",
+        serde_json::json!([
+            [{"type": "SelfContained", "id": "tomato"}, {"type": "Empty", "count": 3}],
+            [{"type": "Blocked", "count": 2}, {"type": "Empty", "count": 2}],
+            [{"type": "Empty", "count": 4}]
+        ]),
+    );
+    let mut payloads = Vec::new();
+    for body in [&expanded, &rle] {
+        let req = test::TestRequest::post()
+            .uri("/api/plan")
+            .set_json(body)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        payloads.push(body["payload"].clone());
+    }
+    assert_eq!(payloads[0], payloads[1]);
+
+    for layout in [
+        serde_json::json!([[{"type": "Empty", "count": 2}], [{"type": "Empty", "count": 3}]]),
+        serde_json::json!([[{"type": "Empty", "count": 0}]]),
+        serde_json::json!([[{"type": "Empty", "count": 1_000_000}]]),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/api/plan")
+            .set_json(with("layoutRle", layout.clone()))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 422, "{layout}");
+    }
+
+    // The same layouts sent expanded get the same status.
+    let ragged = serde_json::json!([[{"type": "Empty"}], [{"type": "Empty"}, {"type": "Empty"}]]);
+    let oversized = serde_json::json!([vec![serde_json::json!({"type": "Empty"}); 10_001]]);
+    for layout in [ragged, oversized] {
+        let req = test::TestRequest::post()
+            .uri("/api/plan")
+            .set_json(with("layout", layout))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 422);
+    }
+}
+
+#[actix_web::test]
+async fn test_post_plan_week_score_matches_a_full_grid_recompute() {
+    use std::collections::HashMap;
+
+    let app = test::init_service(build_app_postgres().await).await;
+    let mut layout = null_layout(4, 5);
+    layout[0][0] = serde_json::json!({"type": "SelfContained", "id": "fennel"});
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(serde_json::json!({
+            "period": {"start": "2025-06-02", "end": "2025-06-08"},
+            "region": "Temperate",
+            "layout": layout
+        }))
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let week = &body["payload"]["weeks"][0];
+    let grid = week["grid"].as_array().unwrap();
+    let (rows, cols) = (grid.len(), grid[0].as_array().unwrap().len());
+
+    // Anchor and variety id of the plant covering each cell.
+    let cell = |r: usize, c: usize| -> Option<((u64, u64), String)> {
+        let cell = &grid[r][c];
+        let anchor = match cell["type"].as_str().unwrap() {
+            "SelfContained" | "Overflowing" => (r as u64, c as u64),
+            "Overflowed" => (
+                cell["coveredBy"]["row"].as_u64().unwrap(),
+                cell["coveredBy"]["col"].as_u64().unwrap(),
+            ),
+            _ => return None,
+        };
+        let id = grid[anchor.0 as usize][anchor.1 as usize]["id"].as_str()?;
+        Some((anchor, id.to_owned()))
+    };
+
+    // Companion lists of every planted variety, looked up through the catalogue.
+    let mut lists: HashMap<String, (String, Vec<String>, Vec<String>)> = HashMap::new();
+    for r in 0..rows {
+        for c in 0..cols {
+            let Some((_, id)) = cell(r, c) else { continue };
+            if lists.contains_key(&id) {
+                continue;
+            }
+            let req = test::TestRequest::get()
+                .uri(&format!("/api/varieties/{id}"))
+                .to_request();
+            let variety: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+            let vegetable = variety["payload"]["vegetableId"]
+                .as_str()
+                .unwrap()
+                .to_owned();
+            let req = test::TestRequest::get()
+                .uri(&format!("/api/vegetables/{vegetable}/companions"))
+                .to_request();
+            let companions: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+            let ids = |key: &str| -> Vec<String> {
+                companions["payload"][key]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|c| c["id"].as_str().unwrap().to_owned())
+                    .collect()
+            };
+            lists.insert(id, (vegetable, ids("good"), ids("bad")));
+        }
+    }
+    let one_way = |from: &str, to: &str| {
+        let (_, good, bad) = &lists[from];
+        let to = &lists[to].0;
+        2 * i64::from(good.contains(to)) - 3 * i64::from(bad.contains(to))
+    };
+
+    // Every border between two different plants counts once.
+    let mut expected = 0i64;
+    for r in 0..rows {
+        for c in 0..cols {
+            let Some((anchor, a)) = cell(r, c) else {
+                continue;
+            };
+            for (nr, nc) in [(r, c + 1), (r + 1, c)] {
+                if nr >= rows || nc >= cols {
+                    continue;
+                }
+                let Some((other, b)) = cell(nr, nc) else {
+                    continue;
+                };
+                if other == anchor {
+                    continue;
+                }
+                expected += match (one_way(&a, &b) + one_way(&b, &a)).signum() {
+                    1 => 2,
+                    -1 => -3,
+                    _ => 0,
+                };
+            }
+        }
+    }
+    assert_eq!(week["score"].as_i64().unwrap(), expected);
+}
+
+#[actix_web::test]
+async fn test_post_plan_zoned_species_stays_inside_its_zone() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(serde_json::json!({
+            "period": {"start": "2025-06-02", "end": "2025-06-08"},
+            "region": "Temperate",
+            "preferences": [{"id": "basil", "quantity": 3}],
+            "zones": [{
+                "id": "basil",
+                "region": {"topLeft": {"row": 0, "col": 0}, "width": 6, "height": 1}
+            }],
+            "layout": null_layout(4, 6)
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let grid = body["payload"]["weeks"][0]["grid"].as_array().unwrap();
+    let mut basil = 0;
+    for (r, row) in grid.iter().enumerate() {
+        for cell in row.as_array().unwrap() {
+            assert_ne!(cell["type"], "Empty", "other species fill the rest");
+            if cell["id"] == "basil" {
+                assert_eq!(r, 0, "basil is pinned to the first row");
+                basil += 1;
+            }
+        }
+    }
+    assert!(basil >= 3);
+
+    // A zone running far past the grid is clipped to it: basil still fits below row 2.
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(serde_json::json!({
+            "period": {"start": "2025-06-02", "end": "2025-06-08"},
+            "region": "Temperate",
+            "preferences": [{"id": "basil", "quantity": 3}],
+            "zones": [{
+                "id": "basil",
+                "region": {
+                    "topLeft": {"row": 2, "col": 0},
+                    "width": usize::MAX,
+                    "height": usize::MAX
+                }
+            }],
+            "layout": null_layout(4, 6)
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let grid = body["payload"]["weeks"][0]["grid"].as_array().unwrap();
+    let mut basil = 0;
+    for (r, row) in grid.iter().enumerate() {
+        for cell in row.as_array().unwrap() {
+            if cell["id"] == "basil" {
+                assert!(r >= 2, "basil is pinned below row 2");
+                basil += 1;
+            }
+        }
+    }
+    assert!(basil >= 3, "the oversized zone still takes basil");
+
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(serde_json::json!({
+            "region": "Temperate",
+            "zones": [{
+                "id": "basil",
+                "region": {"topLeft": {"row": 0, "col": 0}, "width": 0, "height": 1}
+            }],
+            "layout": null_layout(2, 2)
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(
+        body["error"],
+        "Zone for 'basil' must be at least one cell wide and high."
+    );
+}
+
+// ---------------------------------------------------------------------------
+// OPTIONS / HEAD /api/plan
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_options_plan_advertises_post() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::default()
+        .method(actix_web::http::Method::OPTIONS)
+        .uri("/api/plan")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 204);
+    let allow = resp.headers().get("allow").expect("Allow header");
+    assert!(allow.to_str().unwrap().contains("POST"));
+    assert_eq!(
+        resp.headers().get("accept-post").unwrap(),
+        "application/json"
+    );
+}
+
+#[actix_web::test]
+async fn test_head_plan_returns_allow_without_body() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::default()
+        .method(actix_web::http::Method::HEAD)
+        .uri("/api/plan")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 204);
+    let allow = resp.headers().get("allow").expect("Allow header");
+    assert!(allow.to_str().unwrap().contains("POST"));
+    let body = test::read_body(resp).await;
+    assert!(body.is_empty());
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/plan — unplaced candidates
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_post_plan_small_grid_lists_unplaced_candidates_with_reasons() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(1, 3)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    let unplaced = body["payload"]["unplacedCandidates"].as_array().unwrap();
+    let reason_of = |id: &str| {
+        unplaced
+            .iter()
+            .find(|u| u["id"] == id)
+            .map(|u| u["reason"].as_str().unwrap().to_owned())
+    };
+    // A tomato needs a 2×2 block, which a single row can never hold.
+    assert_eq!(reason_of("tomato").as_deref(), Some("spanTooLarge"));
+    assert!(
+        unplaced.iter().any(|u| u["reason"] == "outcompeted"),
+        "{unplaced:?}"
+    );
+    let planted: Vec<&serde_json::Value> = body["payload"]["weeks"][0]["grid"][0]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| &c["id"])
+        .collect();
+    for u in unplaced {
+        assert!(!u["name"].as_str().unwrap().is_empty());
+        assert!(!planted.contains(&&u["id"]), "{} was planted", u["id"]);
+    }
+}
+
+#[actix_web::test]
+async fn test_post_plan_grid_filled_by_preferences_reports_grid_full() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "preferences": [{"id": "basil", "quantity": 4}],
+        "layout": null_layout(2, 2)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    let unplaced = body["payload"]["unplacedCandidates"].as_array().unwrap();
+    assert!(!unplaced.is_empty());
+    assert!(unplaced.iter().all(|u| u["id"] != "basil"));
+    let carrot = unplaced.iter().find(|u| u["id"] == "carrot").unwrap();
+    assert_eq!(carrot["reason"], "gridFull");
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/plan/example
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_get_plan_example_returns_a_planted_grid() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/plan/example")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+
+    let grid = body["payload"]["weeks"][0]["grid"].as_array().unwrap();
+    assert_eq!(grid.len(), 4);
+    assert_eq!(grid[0].as_array().unwrap().len(), 6);
+    let planted: Vec<&str> = grid
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .filter_map(|c| c["id"].as_str())
+        .collect();
+    assert!(!planted.is_empty());
+    for id in ["tomato", "basil", "lettuce"] {
+        assert!(planted.contains(&id), "{id} missing from {planted:?}");
+    }
+    assert_eq!(body["_links"]["plan"]["href"], "/api/plan");
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/plan — no candidates
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_post_plan_impossible_constraints_report_no_candidates() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-01-06", "end": "2025-01-12"},
+        "region": "Mountain",
+        "sun": "Shade",
+        "level": "Beginner",
+        "layout": null_layout(3, 3)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    // Nothing tolerates shade, and nothing is sown in the mountains in January.
+    let first = body["payload"]["warnings"][0].as_str().unwrap();
+    assert!(
+        first.starts_with("No vegetables match the given season/soil/sun/region/level constraints"),
+        "{first}"
+    );
+    let counts = &body["payload"]["noCandidates"];
+    assert_eq!(counts["sun"], counts["season"], "{counts}");
+    assert!(counts["sun"].as_u64().unwrap() > 0);
+    assert_eq!(counts["soil"], 0);
+    assert_eq!(counts["excluded"], 0);
+    assert!(
+        first.contains(&format!("sun: {}", counts["sun"])),
+        "{first}"
+    );
+
+    // A request with candidates carries no such signal.
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "layout": null_layout(3, 3)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert!(body["payload"].get("noCandidates").is_none());
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/plan/trace
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_post_plan_trace_reconstructs_the_final_grid() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let (rows, cols) = (5, 6);
+    let mut layout = null_layout(rows, cols);
+    layout[2][2] = serde_json::json!({"type": "Blocked"});
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-02", "end": "2025-06-08"},
+        "region": "Temperate",
+        "preferences": [{"id": "tomato", "quantity": 1}, {"id": "basil", "quantity": 2}],
+        "layout": layout
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan/trace")
+        .set_json(&payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+
+    let trace = body["payload"]["trace"].as_array().unwrap();
+    assert!(!trace.is_empty());
+    assert_eq!(trace[0]["id"], "tomato", "explicit preferences come first");
+    assert_eq!(trace[0]["phase"], 1);
+    assert_eq!(trace.last().unwrap()["phase"], 2);
+
+    // Replaying the steps on an empty grid gives the variety of every planted cell.
+    let mut replayed = vec![vec![None; cols]; rows];
+    for step in trace {
+        let (row, col) = (
+            step["anchor"]["row"].as_u64().unwrap() as usize,
+            step["anchor"]["col"].as_u64().unwrap() as usize,
+        );
+        let span = step["span"].as_u64().unwrap() as usize;
+        for cells in &mut replayed[row..row + span] {
+            for cell in &mut cells[col..col + span] {
+                assert!(cell.is_none(), "cell placed twice: {step}");
+                *cell = Some(step["id"].as_str().unwrap().to_owned());
+            }
+        }
+    }
+    let grid = &body["payload"]["weeks"][0]["grid"];
+    for (r, replayed_row) in replayed.iter().enumerate() {
+        for (c, expected) in replayed_row.iter().enumerate() {
+            let cell = &grid[r][c];
+            let owner = match cell["type"].as_str().unwrap() {
+                "Overflowed" => {
+                    let anchor = &cell["coveredBy"];
+                    &grid[anchor["row"].as_u64().unwrap() as usize]
+                        [anchor["col"].as_u64().unwrap() as usize]
+                }
+                _ => cell,
+            };
+            assert_eq!(owner["id"].as_str(), expected.as_deref(), "cell [{r},{c}]");
+        }
+    }
+}
+
+#[actix_web::test]
+async fn test_post_plan_size_for_four_tomatoes_needs_at_least_sixteen_cells() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/plan/size-for")
+        .set_json(serde_json::json!({"preferences": [{"id": "tomato", "quantity": 4}]}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let size = &body["payload"];
+    assert_eq!(size["items"][0]["span"], 2);
+    assert_eq!(size["plantCells"], 16);
+    let (rows, cols) = (
+        size["rows"].as_u64().unwrap(),
+        size["cols"].as_u64().unwrap(),
+    );
+    assert!(rows * cols >= 16, "{size}");
+    assert_eq!(size["cells"], rows * cols);
+
+    // The suggested bed really holds the four tomatoes.
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(serde_json::json!({
+            "period": {"start": "2025-06-02", "end": "2025-06-08"},
+            "region": "Temperate",
+            "preferences": [{"id": "tomato", "quantity": 4}],
+            "fill": false,
+            "layout": null_layout(rows as usize, cols as usize)
+        }))
+        .to_request();
+    let plan: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let tomatoes = plan["payload"]["weeks"][0]["grid"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|row| row.as_array().unwrap())
+        .filter(|cell| cell["type"] == "Overflowing" && cell["id"] == "tomato")
+        .count();
+    assert_eq!(tomatoes, 4);
+}
+
+#[actix_web::test]
+async fn test_post_plan_size_for_more_than_the_largest_grid_returns_400() {
+    let app = test::init_service(build_app_postgres().await).await;
+    for preferences in [
+        serde_json::json!([{"id": "radish", "quantity": 20_000}]),
+        serde_json::json!([{"id": "radish", "quantity": u32::MAX}]),
+        serde_json::json!([{"id": "radish", "areaM2": 600.0}, {"id": "basil", "areaM2": 600.0}]),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/api/plan/size-for")
+            .set_json(serde_json::json!({ "preferences": preferences }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 400, "{preferences}");
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert!(
+            body["error"]
+                .as_str()
+                .unwrap()
+                .ends_with("more than the maximum of 10000 cells."),
+            "{body}"
+        );
+    }
+
+    // Within the cell budget per plant, but tomatoes take 2×2 blocks.
+    let req = test::TestRequest::post()
+        .uri("/api/plan/size-for")
+        .set_json(serde_json::json!({"preferences": [{"id": "tomato", "quantity": 5_000}]}))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 422);
+}
+
+#[actix_web::test]
+async fn test_post_plan_size_for_unknown_variety_returns_422() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::post()
+        .uri("/api/plan/size-for")
+        .set_json(serde_json::json!({"preferences": [{"id": "dragonfruit", "quantity": 1}]}))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 422);
+}
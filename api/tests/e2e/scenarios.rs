@@ -85,7 +85,7 @@ async fn scenario_spring_cool_climate() {
         Some(true)
     );
 
-    let summer_only_vegs = ["tomato", "cucumber", "zucchini", "eggplant", "corn"];
+    let summer_only_vegs = ["tomato", "cucumber", "zucchini", "eggplant", "maïs"];
     let placed = collect_placed_ids(&body);
     for id in &summer_only_vegs {
         assert!(
@@ -311,3 +311,98 @@ async fn scenario_exclusions_prevent_placement() {
         }
     }
 }
+
+// ---------------------------------------------------------------------------
+// Scenario 8: Out-of-season preference is reported instead of silently dropped
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn scenario_out_of_season_preference_warns() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2024-12-02", "end": "2024-12-29"},
+        "region": "Oceanic",
+        "preferences": [{"id": "tomato"}],
+        "layout": null_layout(3, 3)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    assert!(!collect_placed_ids(&body).contains(&"tomato".to_string()));
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert!(
+        warnings
+            .iter()
+            .any(|w| w.as_str() == Some("Preferred variety 'tomato' is not available in Winter.")),
+        "Expected an out-of-season warning for tomato, got {warnings:?}"
+    );
+    assert!(
+        !warnings.iter().any(|w| w
+            .as_str()
+            .is_some_and(|w| w.starts_with("Unknown vegetable"))),
+        "An out-of-season preference is not unknown, got {warnings:?}"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Scenario 9: Unknown and filtered-out preferences are told apart
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn scenario_unknown_preference_warns() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-01", "end": "2025-06-29"},
+        "region": "Temperate",
+        "preferences": [{"id": "dragonfruit"}, {"id": "tomato"}],
+        "layout": null_layout(3, 3)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert!(
+        warnings.iter().any(|w| w.as_str()
+            == Some("Unknown vegetable 'dragonfruit' in preferences; it was ignored.")),
+        "Expected an unknown-preference warning for dragonfruit, got {warnings:?}"
+    );
+    assert!(
+        !warnings
+            .iter()
+            .any(|w| w.as_str().is_some_and(|w| w.contains("'tomato'"))),
+        "Tomato is known and in season, got {warnings:?}"
+    );
+}
+
+#[actix_web::test]
+async fn scenario_filtered_out_preference_warns() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let payload = serde_json::json!({
+        "period": {"start": "2025-06-01", "end": "2025-06-29"},
+        "region": "Temperate",
+        "exclusions": ["tomato"],
+        "preferences": [{"id": "tomato"}],
+        "layout": null_layout(3, 3)
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/plan")
+        .set_json(&payload)
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+    assert!(!collect_placed_ids(&body).contains(&"tomato".to_string()));
+    let warnings = body["payload"]["warnings"].as_array().unwrap();
+    assert!(
+        warnings
+            .iter()
+            .any(|w| w.as_str()
+                == Some("Preferred 'tomato' is not available for these constraints.")),
+        "Expected a constraints warning for tomato, got {warnings:?}"
+    );
+}
@@ -88,6 +88,51 @@ async fn test_get_variety_by_id_returns_200() {
     assert_eq!(resp.status(), 200);
 }
 
+#[actix_web::test]
+async fn test_get_variety_reports_cell_span_and_plants_per_cell() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let get = |id: &str| {
+        test::TestRequest::get()
+            .uri(&format!("/api/varieties/{id}"))
+            .to_request()
+    };
+    let tomato: serde_json::Value = test::call_and_read_body_json(&app, get("tomato")).await;
+    assert_eq!(tomato["payload"]["cellSpan"], 2);
+    assert_eq!(tomato["payload"]["plantsPerCell"], 1);
+
+    let pumpkin: serde_json::Value = test::call_and_read_body_json(&app, get("pumpkin")).await;
+    assert!(pumpkin["payload"]["cellSpan"].as_u64().unwrap() > 2);
+
+    let radish: serde_json::Value = test::call_and_read_body_json(&app, get("radish")).await;
+    assert_eq!(radish["payload"]["cellSpan"], 1);
+    assert_eq!(radish["payload"]["plantsPerCell"], 16);
+}
+
+#[actix_web::test]
+async fn test_get_variety_spacing_in_inches_only_under_imperial_units() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let get = |uri: &str| test::TestRequest::get().uri(uri).to_request();
+
+    let metric: serde_json::Value =
+        test::call_and_read_body_json(&app, get("/api/varieties/tomato")).await;
+    let metric = &metric["payload"];
+    assert_eq!(metric["units"], "Metric");
+    assert!(metric.get("spacingIn").is_none(), "{metric}");
+    assert!(metric.get("yieldPerPlantOz").is_none(), "{metric}");
+
+    let imperial: serde_json::Value =
+        test::call_and_read_body_json(&app, get("/api/varieties/tomato?units=imperial")).await;
+    let imperial = &imperial["payload"];
+    assert_eq!(imperial["units"], "Imperial");
+    let cm = imperial["spacingCm"].as_f64().unwrap();
+    assert_eq!(metric["spacingCm"], imperial["spacingCm"]);
+    assert_eq!(
+        imperial["spacingIn"].as_f64().unwrap(),
+        (cm / 2.54 * 2.0).round() / 2.0
+    );
+    assert!(imperial["yieldPerPlantOz"].as_f64().unwrap() > 0.0);
+}
+
 #[actix_web::test]
 async fn test_get_variety_by_id_unknown_returns_404() {
     let app = test::init_service(build_app_postgres().await).await;
@@ -357,3 +357,367 @@ async fn test_varieties_by_vegetable_filter_no_match_returns_empty() {
         "Biennial filter must yield no pepper varieties"
     );
 }
+
+// ---------------------------------------------------------------------------
+// Content negotiation — Accept: application/xml
+// ---------------------------------------------------------------------------
+
+/// Walks the whole document (failing on malformed XML) and returns the text of the
+/// first element named `name` under the root's `payload` element.
+fn xml_payload_text(xml: &str, name: &str) -> Option<String> {
+    use quick_xml::events::Event;
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let mut path: Vec<String> = Vec::new();
+    let mut found = None;
+    loop {
+        match reader
+            .read_event()
+            .expect("response must be well-formed XML")
+        {
+            Event::Start(e) => path.push(e.name().as_ref().to_owned()),
+            Event::End(_) => {
+                path.pop();
+            }
+            Event::Text(t) if found.is_none() && path == ["response", "payload", name] => {
+                found = Some(t.into_inner().into_owned());
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    assert!(path.is_empty(), "all elements must be closed");
+    found
+}
+
+#[actix_web::test]
+async fn test_get_vegetable_accept_xml_returns_xml() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/tomato")
+        .insert_header(("Accept", "application/xml"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert!(
+        content_type.starts_with("application/xml"),
+        "got {content_type}"
+    );
+
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    assert_eq!(xml_payload_text(&body, "id").as_deref(), Some("tomato"));
+}
+
+#[actix_web::test]
+async fn test_get_vegetable_skips_markdown_for_the_next_accepted_type() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/tomato")
+        .insert_header(("Accept", "text/markdown, application/xml"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert!(
+        content_type.starts_with("application/xml"),
+        "got {content_type}"
+    );
+}
+
+#[actix_web::test]
+async fn test_get_vegetable_defaults_to_hal_json() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/tomato")
+        .insert_header(("Accept", "*/*"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert_eq!(content_type, "application/hal+json");
+}
+
+#[actix_web::test]
+async fn test_get_vegetable_honors_accept_hal_json() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/tomato")
+        .insert_header(("Accept", "application/hal+json, application/xml;q=0.5"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        "application/hal+json"
+    );
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["payload"]["id"], "tomato");
+}
+
+#[actix_web::test]
+async fn test_get_vegetable_plain_json_when_explicitly_requested() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/tomato")
+        .insert_header(("Accept", "application/json"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+}
+
+#[actix_web::test]
+async fn test_get_vegetable_corn_and_mais_resolve_to_same_vegetable() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/corn")
+        .to_request();
+    let by_alias: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/ma%C3%AFs")
+        .to_request();
+    let canonical: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(canonical["payload"]["id"], "maïs");
+    assert_eq!(by_alias["payload"], canonical["payload"]);
+    assert_eq!(
+        by_alias["_links"]["self"]["href"], "/api/vegetables/maïs",
+        "Links must use the canonical id"
+    );
+}
+
+#[actix_web::test]
+async fn test_get_vegetable_by_alias_returns_canonical_vegetable() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/courgette")
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["payload"]["id"], "zucchini");
+    assert_eq!(body["payload"]["aliases"], serde_json::json!(["courgette"]));
+}
+
+// ---------------------------------------------------------------------------
+// X-Request-Id correlation header
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_response_carries_generated_request_id() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get().uri("/api/vegetables").to_request();
+    let resp = test::call_service(&app, req).await;
+    let id = resp
+        .headers()
+        .get("x-request-id")
+        .expect("Response must carry an X-Request-Id header")
+        .to_str()
+        .unwrap();
+    assert!(!id.is_empty(), "Generated request id must not be empty");
+}
+
+#[actix_web::test]
+async fn test_inbound_request_id_is_propagated() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables")
+        .insert_header(("X-Request-Id", "client-abc-123"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(
+        resp.headers().get("x-request-id").unwrap(),
+        "client-abc-123",
+        "A client-supplied X-Request-Id must be echoed back"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// ETag / conditional GET
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_get_vegetable_with_matching_etag_returns_304() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/tomato")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let etag = resp
+        .headers()
+        .get("etag")
+        .expect("Response must carry an ETag")
+        .to_str()
+        .unwrap()
+        .to_owned();
+
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/tomato")
+        .insert_header(("If-None-Match", etag.as_str()))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 304);
+    assert_eq!(resp.headers().get("etag").unwrap(), etag.as_str());
+    let body = test::read_body(resp).await;
+    assert!(body.is_empty(), "304 must have an empty body");
+}
+
+#[actix_web::test]
+async fn test_list_vegetables_etag_round_trip_and_stale_tag() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get().uri("/api/vegetables").to_request();
+    let resp = test::call_service(&app, req).await;
+    let etag = resp
+        .headers()
+        .get("etag")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_owned();
+
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables")
+        .insert_header(("If-None-Match", etag.as_str()))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 304);
+
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables")
+        .insert_header(("If-None-Match", "\"stale\""))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 200);
+}
+
+#[actix_web::test]
+async fn test_vegetable_etag_differs_per_locale() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let mut etags = Vec::new();
+    for locale in ["en", "fr"] {
+        let req = test::TestRequest::get()
+            .uri("/api/vegetables/tomato")
+            .insert_header(("Accept-Language", locale))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        etags.push(resp.headers().get("etag").unwrap().clone());
+    }
+    assert_ne!(etags[0], etags[1]);
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/categories/{category}/vegetables, GET /api/vegetables/by-category
+// ---------------------------------------------------------------------------
+
+fn ids(list: &serde_json::Value) -> Vec<&str> {
+    list["payload"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v["payload"]["id"].as_str().unwrap())
+        .collect()
+}
+
+#[actix_web::test]
+async fn test_herb_category_contains_basil_not_tomato() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/categories/Herb/vegetables?size=100")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let herbs = ids(&body);
+    assert!(herbs.contains(&"basil"), "{herbs:?}");
+    assert!(!herbs.contains(&"tomato"), "{herbs:?}");
+    assert_eq!(body["pagination"]["total"], herbs.len());
+
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/by-category?size=100")
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let grouped = &body["payload"];
+    assert_eq!(ids(&grouped["herb"]), herbs);
+    assert!(ids(&grouped["fruit"]).contains(&"tomato"));
+}
+
+#[actix_web::test]
+async fn test_unknown_category_returns_404() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/categories/mushroom/vegetables")
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 404);
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/vegetables/random
+// ---------------------------------------------------------------------------
+
+#[actix_web::test]
+async fn test_random_vegetable_with_seed_is_deterministic_and_in_season() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let uri = "/api/vegetables/random?season=Summer&beginner=true&seed=42";
+    let first: serde_json::Value =
+        test::call_and_read_body_json(&app, test::TestRequest::get().uri(uri).to_request()).await;
+    let second: serde_json::Value =
+        test::call_and_read_body_json(&app, test::TestRequest::get().uri(uri).to_request()).await;
+    let id = first["payload"]["id"]
+        .as_str()
+        .expect("payload.id")
+        .to_owned();
+    assert_eq!(second["payload"]["id"], first["payload"]["id"]);
+    assert_eq!(
+        first["_links"]["self"]["href"],
+        format!("/api/vegetables/{id}")
+    );
+
+    // The draw must have a beginner-friendly variety active in a summer month.
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/vegetables/{id}/varieties?size=100"))
+        .to_request();
+    let varieties: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let summer = ["June", "July", "August"];
+    let varieties = varieties["payload"].as_array().unwrap();
+    assert!(varieties
+        .iter()
+        .any(|v| v["payload"]["beginnerFriendly"] == true));
+    let in_summer = varieties.iter().any(|v| {
+        v["payload"]["calendars"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|c| {
+                ["sowing", "planting"]
+                    .into_iter()
+                    .flat_map(move |w| ["outdoor", "indoor"].map(|k| c[w][k].clone()))
+            })
+            .filter_map(|months| months.as_array().cloned())
+            .flatten()
+            .any(|m| summer.contains(&m.as_str().unwrap_or_default()))
+    });
+    assert!(in_summer, "'{id}' has no variety active in summer");
+}
+
+#[actix_web::test]
+async fn test_random_vegetable_without_seed_returns_a_vegetable() {
+    let app = test::init_service(build_app_postgres().await).await;
+    let req = test::TestRequest::get()
+        .uri("/api/vegetables/random")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert!(body["payload"]["id"].is_string());
+}
@@ -16,6 +16,7 @@ use garden::application::ports::variety_response_repository::{
     VarietyListFilter, VarietyResponseRepository,
 };
 use garden::application::ports::vegetable_repository::VegetableRepository;
+use garden::application::use_cases::check_catalogue::CheckCatalogueUseCase;
 
 // ---------------------------------------------------------------------------
 // Tests
@@ -85,6 +86,26 @@ async fn test_locale_fallback_to_en() {
     );
 }
 
+#[tokio::test]
+async fn test_dense_crop_plants_per_cell_is_pinned() {
+    use garden::domain::services::planner::plants_per_cell;
+    let pool = test_pool().await;
+    let repo = PostgresVarietyRepository::new(pool);
+    for (id, expected) in [("basil", 4), ("parsley", 4), ("chive", 16), ("radish", 16)] {
+        let variety = repo
+            .get_by_id(id, "en")
+            .await
+            .expect("get_by_id failed")
+            .unwrap_or_else(|| panic!("{id} not found"));
+        assert_eq!(
+            plants_per_cell(variety.spacing_cm),
+            expected,
+            "plants per cell changed for {id} ({} cm spacing)",
+            variety.spacing_cm
+        );
+    }
+}
+
 // ---------------------------------------------------------------------------
 // GroupRepository tests
 // ---------------------------------------------------------------------------
@@ -221,6 +242,48 @@ async fn test_vegetable_get_by_id_returns_vegetable() {
     assert_eq!(onion.group_id, "bulbes", "onion must belong to bulbes");
 }
 
+#[tokio::test]
+async fn test_vegetable_get_by_id_resolves_alias() {
+    let pool = test_pool().await;
+    let repo = PostgresVegetableRepository::new(pool);
+    let by_alias = repo
+        .get_by_id("corn", "en")
+        .await
+        .expect("get_by_id failed")
+        .expect("corn alias not found");
+    let canonical = repo
+        .get_by_id("maïs", "en")
+        .await
+        .expect("get_by_id failed")
+        .expect("maïs not found");
+    assert_eq!(by_alias, canonical);
+}
+
+#[tokio::test]
+async fn test_vegetable_get_by_id_courgette_returns_zucchini() {
+    let pool = test_pool().await;
+    let repo = PostgresVegetableRepository::new(pool);
+    let zucchini = repo
+        .get_by_id("courgette", "en")
+        .await
+        .expect("get_by_id failed")
+        .expect("courgette alias not found");
+    assert_eq!(zucchini.id, "zucchini");
+    assert_eq!(zucchini.aliases, vec!["courgette".to_string()]);
+}
+
+#[tokio::test]
+async fn test_variety_get_by_ids_resolves_alias_to_namesake_variety() {
+    let pool = test_pool().await;
+    let repo = PostgresVarietyRepository::new(pool);
+    let varieties = repo
+        .get_by_ids(&["capsicum".to_string()], "en")
+        .await
+        .expect("get_by_ids failed");
+    let ids: Vec<&str> = varieties.iter().map(|v| v.id.as_str()).collect();
+    assert_eq!(ids, vec!["pepper"]);
+}
+
 #[tokio::test]
 async fn test_vegetable_get_by_id_unknown_returns_none() {
     let pool = test_pool().await;
@@ -510,3 +573,24 @@ async fn test_variety_response_list_page_by_vegetable_with_filter() {
         assert_eq!(item.lifecycle, Lifecycle::Annual);
     }
 }
+
+#[tokio::test]
+async fn test_seeded_catalogue_only_has_dangling_companion_ids() {
+    let pool = test_pool().await;
+    let vegetables = PostgresVegetableRepository::new(pool.clone());
+    let varieties = PostgresVarietyRepository::new(pool);
+    let problems = CheckCatalogueUseCase::new(&vegetables, &varieties)
+        .execute()
+        .await
+        .expect("catalogue check failed");
+    // Companion lists may name plants outside the catalogue (e.g. "rose"); anything
+    // else (empty names, zero spacing, self-companionship) is a data bug.
+    let bugs: Vec<_> = problems
+        .iter()
+        .filter(|p| !p.contains("lists unknown vegetable"))
+        .collect();
+    assert!(bugs.is_empty(), "{bugs:#?}");
+    assert!(problems.contains(
+        &"Vegetable 'chive' lists unknown vegetable 'rose' in goodCompanions.".to_owned()
+    ));
+}